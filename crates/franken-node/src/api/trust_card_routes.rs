@@ -49,6 +49,47 @@ pub struct ApiResponse<T> {
     pub page: Option<PageMeta>,
 }
 
+/// Optional reputation-decay window for [`list_trust_cards`]: applies
+/// [`TrustCardRegistry::list_decayed`] instead of a plain listing, without
+/// mutating stored registry state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, arbitrary::Arbitrary)]
+pub struct ReputationDecayQuery {
+    pub as_of_secs: u64,
+    pub half_life_secs: u64,
+    pub floor_basis_points: u16,
+}
+
+/// A trust card paired with its relevance score for a [`search_trust_cards`]
+/// query, so clients can display why a result ranked where it did.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustCardSearchResult {
+    pub card: TrustCard,
+    pub relevance_score: u32,
+}
+
+/// Score how well `card` matches `query_lc` (already lowercased): an exact
+/// `extension_id` match outranks a prefix match, which outranks a substring
+/// hit in a capability description. Zero means the card only matched on one
+/// of [`TrustCardRegistry::search`]'s other haystack fields (publisher ID or
+/// capability name).
+fn relevance_score(card: &TrustCard, query_lc: &str) -> u32 {
+    let extension_id_lc = card.extension.extension_id.to_ascii_lowercase();
+    if extension_id_lc == query_lc {
+        return 3;
+    }
+    if extension_id_lc.starts_with(query_lc) {
+        return 2;
+    }
+    let description_hit = card
+        .capability_declarations
+        .iter()
+        .any(|cap| cap.description.to_ascii_lowercase().contains(query_lc));
+    if description_hit {
+        return 1;
+    }
+    0
+}
+
 fn paged_response<T: Clone>(
     all: &[T],
     pagination: Pagination,
@@ -259,6 +300,8 @@ pub fn get_trust_card(
 /// - `now_secs`: unix timestamp used for telemetry and cache refresh decisions.
 /// - `trace_id`: operator-visible correlation ID recorded in trust-card telemetry.
 /// - `pagination`: page and page-size settings for the response envelope.
+/// - `decay`: when set, reputation is decayed as of `decay.as_of_secs` in the
+///   returned cards only; stored registry state is untouched.
 ///
 /// # Returns
 /// An `ApiResponse` containing the current page of matching trust cards.
@@ -273,9 +316,20 @@ pub fn list_trust_cards(
     filter: &TrustCardListFilter,
     now_secs: u64,
     pagination: Pagination,
+    decay: Option<ReputationDecayQuery>,
 ) -> Result<ApiResponse<Vec<TrustCard>>, TrustCardError> {
     enforce_handler_contract(identity, trace, "GET", "/api/v1/trust-cards")?;
-    let all = registry.list(filter, &trace.trace_id, now_secs)?;
+    let all = match decay {
+        Some(decay) => registry.list_decayed(
+            filter,
+            &trace.trace_id,
+            now_secs,
+            decay.as_of_secs,
+            decay.half_life_secs,
+            decay.floor_basis_points,
+        )?,
+        None => registry.list(filter, &trace.trace_id, now_secs)?,
+    };
     paged_response(&all, pagination)
 }
 
@@ -305,7 +359,8 @@ pub fn get_trust_cards_by_publisher(
     paged_response(&all, pagination)
 }
 
-/// Search trust cards by extension, publisher, or capability text.
+/// Search trust cards by extension, publisher, or capability text, ranked by
+/// relevance so the closest name/description match sorts first.
 ///
 /// # Parameters
 /// - `registry`: mutable trust-card registry used for search execution.
@@ -315,7 +370,8 @@ pub fn get_trust_cards_by_publisher(
 /// - `pagination`: page and page-size settings for the response envelope.
 ///
 /// # Returns
-/// An `ApiResponse` containing the current page of search results.
+/// An `ApiResponse` containing the current page of search results, sorted by
+/// descending relevance score and tie-broken by `extension_id`.
 ///
 /// # Errors
 /// Returns `TrustCardError` if pagination is invalid or any matched card fails
@@ -326,9 +382,28 @@ pub fn search_trust_cards(
     now_secs: u64,
     trace_id: &str,
     pagination: Pagination,
-) -> Result<ApiResponse<Vec<TrustCard>>, TrustCardError> {
-    let all = registry.search(query, now_secs, trace_id)?;
-    paged_response(&all, pagination)
+) -> Result<ApiResponse<Vec<TrustCardSearchResult>>, TrustCardError> {
+    let query_lc = query.to_ascii_lowercase();
+    let mut ranked: Vec<TrustCardSearchResult> = registry
+        .search(query, now_secs, trace_id)?
+        .into_iter()
+        .map(|card| TrustCardSearchResult {
+            relevance_score: relevance_score(&card, &query_lc),
+            card,
+        })
+        .collect();
+    ranked.sort_by(|left, right| {
+        right
+            .relevance_score
+            .cmp(&left.relevance_score)
+            .then_with(|| {
+                left.card
+                    .extension
+                    .extension_id
+                    .cmp(&right.card.extension.extension_id)
+            })
+    });
+    paged_response(&ranked, pagination)
 }
 
 /// Compare the latest trust cards for two extensions.
@@ -608,6 +683,7 @@ mod tests {
                 page: 1,
                 per_page: 10,
             },
+            None,
         )
         .expect("response");
         assert!(response.ok);
@@ -627,6 +703,7 @@ mod tests {
                 page: 1,
                 per_page: 0,
             },
+            None,
         )
         .expect_err("zero per_page must be rejected");
         assert!(matches!(
@@ -674,6 +751,7 @@ mod tests {
             },
             1_000,
             Pagination::default(),
+            None,
         )
         .expect("response");
         assert!(response.ok);
@@ -696,6 +774,41 @@ mod tests {
         assert!(response.data.is_empty());
     }
 
+    #[test]
+    fn search_ranks_exact_extension_id_match_first_despite_later_sort_order() {
+        let mut registry = TrustCardRegistry::default();
+        let query = "zzz-widget-exact";
+
+        // Alphabetically first extension_id, matched only through a
+        // capability name that happens to contain the query string.
+        let decoy = TrustCardInput {
+            capability_declarations: vec![CapabilityDeclaration {
+                name: query.to_string(),
+                description: "unrelated decoy capability".to_string(),
+                risk: CapabilityRisk::Medium,
+            }],
+            ..sample_input("npm:@aaa/decoy")
+        };
+        registry.create(decoy, 1_000, "trace").expect("create decoy");
+
+        // Alphabetically later extension_id that matches the query exactly.
+        registry
+            .create(sample_input(query), 1_001, "trace")
+            .expect("create exact match");
+
+        let response = search_trust_cards(&mut registry, query, 1_002, "trace", Pagination::default())
+            .expect("response");
+
+        assert_eq!(response.data.len(), 2);
+        assert_eq!(response.data[0].card.extension.extension_id, query);
+        assert_eq!(response.data[0].relevance_score, 3);
+        assert_eq!(
+            response.data[1].card.extension.extension_id,
+            "npm:@aaa/decoy"
+        );
+        assert_eq!(response.data[1].relevance_score, 0);
+    }
+
     #[test]
     fn create_card_has_version_one() {
         let mut registry = TrustCardRegistry::default();
@@ -741,12 +854,65 @@ mod tests {
             },
             1_001,
             Pagination::default(),
+            None,
         )
         .expect("response");
         assert!(response.ok);
         // Should return cards matching certification level
     }
 
+    #[test]
+    fn list_route_applies_decay_without_mutating_stored_state() {
+        let mut registry = fixture_registry(1_000).expect("fixture registry");
+        let publisher_filter = TrustCardListFilter {
+            certification_level: None,
+            publisher_id: Some("pub-acme".to_string()),
+            capability: None,
+        };
+
+        let baseline = list_trust_cards(
+            &route_identity(),
+            &route_trace("trace"),
+            &mut registry,
+            &publisher_filter,
+            1_001,
+            Pagination::default(),
+            None,
+        )
+        .expect("baseline");
+        assert_eq!(baseline.data.len(), 1);
+        let original_score = baseline.data[0].reputation_score_basis_points;
+
+        let decayed = list_trust_cards(
+            &route_identity(),
+            &route_trace("trace"),
+            &mut registry,
+            &publisher_filter,
+            1_002,
+            Pagination::default(),
+            Some(ReputationDecayQuery {
+                as_of_secs: 4_000_000_000,
+                half_life_secs: 3_600,
+                floor_basis_points: 100,
+            }),
+        )
+        .expect("decayed");
+        assert_eq!(decayed.data.len(), 1);
+        assert!(decayed.data[0].reputation_score_basis_points < original_score);
+
+        let after = list_trust_cards(
+            &route_identity(),
+            &route_trace("trace"),
+            &mut registry,
+            &publisher_filter,
+            1_003,
+            Pagination::default(),
+            None,
+        )
+        .expect("after");
+        assert_eq!(after.data[0].reputation_score_basis_points, original_score);
+    }
+
     #[test]
     fn page_meta_serde_roundtrip() {
         let meta = PageMeta {
@@ -1043,6 +1209,7 @@ mod tests {
                 page: 0,
                 per_page: 10,
             },
+            None,
         )
         .expect_err("zero page must fail");
 
@@ -1577,6 +1744,7 @@ mod tests {
                 page: 0,
                 per_page: 20,
             },
+            None,
         )
         .expect_err("invalid page must fail even when the registry is empty");
 
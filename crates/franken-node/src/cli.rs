@@ -815,6 +815,30 @@ pub struct VerifyLockstepArgs {
     /// Emit structured JSON output.
     #[arg(long)]
     pub json: bool,
+
+    /// Percentage of registered runtimes that must agree for the oracle to
+    /// consider a cross-check conclusive.
+    #[arg(long, default_value_t = 100)]
+    pub quorum_threshold: u8,
+
+    /// Minimum divergence risk tier that blocks release.
+    ///
+    /// Divergences below this tier fall back to the tier's own receipt
+    /// requirement instead of blocking.
+    #[arg(long, value_enum, default_value_t = VerifyBlockingFloor::High)]
+    pub blocking_floor: VerifyBlockingFloor,
+}
+
+/// Minimum divergence risk tier that blocks release, as accepted on the
+/// `verify lockstep --blocking-floor` flag. Maps onto
+/// `runtime::nversion_oracle::RiskTier` in the handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum VerifyBlockingFloor {
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
 }
 
 #[derive(Debug, Parser)]
@@ -1076,9 +1100,27 @@ pub struct TrustQuarantineArgs {
 
 #[derive(Debug, Parser)]
 pub struct TrustSyncArgs {
-    /// Force sync even if cache is fresh.
+    /// Force sync even if cache is fresh, and overwrite locally-modified
+    /// cards when reconciling against a remote snapshot.
     #[arg(long)]
     pub force: bool,
+
+    /// Path to a signed remote trust-card registry snapshot to reconcile
+    /// against. When omitted, only the local cache/audit refresh runs.
+    #[arg(long)]
+    pub remote_snapshot: Option<PathBuf>,
+
+    /// Optional explicit Ed25519 signing key file for receipt export.
+    #[arg(long)]
+    pub receipt_signing_key: Option<PathBuf>,
+
+    /// Optional path to export signed decision receipts (JSON or `.cbor`).
+    #[arg(long)]
+    pub receipt_out: Option<PathBuf>,
+
+    /// Optional path to export human-readable receipt summary markdown.
+    #[arg(long)]
+    pub receipt_summary_out: Option<PathBuf>,
 }
 
 // -- remotecap --
@@ -2384,6 +2426,41 @@ mod parser_contract_extra_tests {
         assert_eq!(args.runtimes, "node,bun,franken-node");
     }
 
+    #[test]
+    fn verify_lockstep_defaults_to_full_quorum_and_high_blocking_floor() {
+        let cli = parse(&["franken-node", "verify", "lockstep", "."])
+            .expect("verify lockstep should parse with default quorum and blocking floor");
+
+        let Command::Verify(VerifyCommand::Lockstep(args)) = cli.command else {
+            panic!("expected verify lockstep command");
+        };
+
+        assert_eq!(args.quorum_threshold, 100);
+        assert_eq!(args.blocking_floor, VerifyBlockingFloor::High);
+    }
+
+    #[test]
+    fn verify_lockstep_accepts_explicit_quorum_threshold_and_blocking_floor() {
+        let cli = parse(&[
+            "franken-node",
+            "verify",
+            "lockstep",
+            ".",
+            "--quorum-threshold",
+            "67",
+            "--blocking-floor",
+            "medium",
+        ])
+        .expect("verify lockstep should accept explicit quorum threshold and blocking floor");
+
+        let Command::Verify(VerifyCommand::Lockstep(args)) = cli.command else {
+            panic!("expected verify lockstep command");
+        };
+
+        assert_eq!(args.quorum_threshold, 67);
+        assert_eq!(args.blocking_floor, VerifyBlockingFloor::Medium);
+    }
+
     #[test]
     fn unknown_top_level_subcommand_is_rejected() {
         let err = parse(&["franken-node", "launch"]).expect_err("unknown command should fail");
@@ -1150,13 +1150,13 @@ impl Config {
         if let Some(section) = &overrides.security
             && let Some(value) = &section.authorized_api_keys
         {
-            self.security.authorized_api_keys = value.clone();
+            self.security.authorized_api_keys = value.expose().clone();
             push_bounded(
                 decisions,
                 MergeDecision::new(
                     stage.clone(),
                     "security.authorized_api_keys",
-                    format!("[{} keys configured]", value.len()),
+                    format!("[{} keys configured]", value.expose().len()),
                 ),
                 MAX_MERGE_DECISIONS,
             );
@@ -2161,6 +2161,130 @@ impl Config {
         }
         Ok(())
     }
+
+    /// Parse and fully validate a replacement configuration for hot reload.
+    ///
+    /// `self` is never mutated; the returned config is built purely from
+    /// `source`. A malformed or invalid `source` leaves `self` completely
+    /// untouched -- the caller is expected to atomically swap the returned
+    /// [`Config`] in for the old one (e.g. via `ArcSwap`) only once this
+    /// returns `Ok`, so a bad reload can never half-apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use frankenengine_node::config::{Config, Profile};
+    ///
+    /// let current = Config::for_profile(Profile::Balanced);
+    /// let source = Config::for_profile(Profile::Strict)
+    ///     .to_toml()
+    ///     .expect("serialize config");
+    ///
+    /// let reloaded = current.reload_from(&source).expect("reload");
+    /// assert_eq!(reloaded.profile, Profile::Strict);
+    /// ```
+    pub fn reload_from(&self, source: &str) -> Result<Self, ConfigError> {
+        let parsed: Self = toml::from_str(source)
+            .map_err(|e| ConfigError::ParseFailed(PathBuf::from("<reload_from>"), e))?;
+        parsed.validate()?;
+        Ok(parsed)
+    }
+
+    /// Compute which top-level config sections changed between `self` and
+    /// `new`.
+    ///
+    /// Each changed section is reported once, with its old and new value
+    /// rendered via `Debug` (so [`SecurityConfig`]'s redacting `Debug` impl
+    /// is honored rather than bypassed). Unchanged sections are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use frankenengine_node::config::{Config, Profile};
+    ///
+    /// let old = Config::for_profile(Profile::Balanced);
+    /// let new = Config::for_profile(Profile::Strict);
+    ///
+    /// let diff = old.diff(&new);
+    /// assert!(diff.field_names().any(|name| name == "profile"));
+    /// ```
+    #[must_use]
+    pub fn diff(&self, new: &Self) -> ConfigDiff {
+        let mut diff = ConfigDiff::new();
+        diff.push_if_changed("profile", &self.profile, &new.profile);
+        diff.push_if_changed("compatibility", &self.compatibility, &new.compatibility);
+        diff.push_if_changed("migration", &self.migration, &new.migration);
+        diff.push_if_changed("trust", &self.trust, &new.trust);
+        diff.push_if_changed("replay", &self.replay, &new.replay);
+        diff.push_if_changed("registry", &self.registry, &new.registry);
+        diff.push_if_changed("fleet", &self.fleet, &new.fleet);
+        diff.push_if_changed("observability", &self.observability, &new.observability);
+        diff.push_if_changed("remote", &self.remote, &new.remote);
+        diff.push_if_changed("security", &self.security, &new.security);
+        diff.push_if_changed("engine", &self.engine, &new.engine);
+        diff.push_if_changed("runtime", &self.runtime, &new.runtime);
+        diff.push_if_changed("thresholds", &self.thresholds, &new.thresholds);
+        diff.push_if_changed("benchmark", &self.benchmark, &new.benchmark);
+        diff.push_if_changed("verifier", &self.verifier, &new.verifier);
+        diff
+    }
+}
+
+/// One top-level [`Config`] section whose value changed across a
+/// [`Config::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigFieldDiff {
+    pub field_name: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// Result of [`Config::diff`]: the top-level config sections that changed,
+/// in field-declaration order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    fields: Vec<ConfigFieldDiff>,
+}
+
+impl ConfigDiff {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn push_if_changed<T: std::fmt::Debug + PartialEq>(
+        &mut self,
+        field_name: &str,
+        old: &T,
+        new: &T,
+    ) {
+        if old != new {
+            self.fields.push(ConfigFieldDiff {
+                field_name: field_name.to_string(),
+                old_value: format!("{old:?}"),
+                new_value: format!("{new:?}"),
+            });
+        }
+    }
+
+    /// All changed sections, in field-declaration order.
+    pub fn fields(&self) -> &[ConfigFieldDiff] {
+        &self.fields
+    }
+
+    /// Names of all changed sections, in field-declaration order.
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|f| f.field_name.as_str())
+    }
+
+    /// `true` if no top-level section changed.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Number of changed sections.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
 }
 
 fn default_candidates() -> Vec<PathBuf> {
@@ -2656,6 +2780,63 @@ fn validate_registry_signing_key(encoded: &str) -> Result<(), ConfigError> {
     Ok(())
 }
 
+// -- Secret handling --
+
+/// Wraps a configuration value that must never appear in `Debug` output or
+/// serialized config dumps (e.g. a `doctor` snapshot or an incidental log
+/// line). Both impls emit `"***redacted***"`; call [`Secret::expose`] at the
+/// one or two call sites that have a genuine reason to see the real value.
+///
+/// Only apply this to fields that are never round-tripped through
+/// [`Config::to_toml`] — the config file an operator edits is itself the
+/// trusted channel for these values, so wrapping a persisted field here
+/// would silently corrupt it on save.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the real value. Named loudly so call sites read as a
+    /// deliberate decision, not an accident.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***redacted***")
+    }
+}
+
+impl<T> Serialize for Secret<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str("***redacted***")
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Secret<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Secret)
+    }
+}
+
 // -- Resolution Model --
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -2910,7 +3091,7 @@ struct SecurityOverrides {
     pub max_degraded_duration_secs: Option<u64>,
     pub max_merge_decisions: Option<usize>,
     pub decision_receipt_signing_key_path: Option<PathBuf>,
-    pub authorized_api_keys: Option<BTreeSet<String>>,
+    pub authorized_api_keys: Option<Secret<BTreeSet<String>>>,
     /// Optional override for the security network egress policy. Required so
     /// the TOML written by `franken-node init` (which serializes the full
     /// [`SecurityConfig`] including `[security.network_policy]`) round-trips
@@ -2932,7 +3113,7 @@ impl std::fmt::Debug for SecurityOverrides {
                 "decision_receipt_signing_key_path",
                 &self.decision_receipt_signing_key_path,
             )
-            .field("authorized_api_keys", &"[REDACTED]")
+            .field("authorized_api_keys", &self.authorized_api_keys)
             .field("network_policy", &self.network_policy)
             .field("child_process_spawn", &self.child_process_spawn)
             .finish()
@@ -4669,6 +4850,52 @@ mod tests {
         assert!(!debug.contains("never-print-this-secret"));
     }
 
+    #[test]
+    fn secret_debug_never_contains_raw_value() {
+        let secret = Secret::new("never-print-this-secret".to_string());
+        let debug = format!("{secret:?}");
+        assert!(!debug.contains("never-print-this-secret"));
+        assert_eq!(debug, "***redacted***");
+    }
+
+    #[test]
+    fn secret_serialize_never_contains_raw_value() {
+        let secret = Secret::new("never-print-this-secret".to_string());
+        let json = serde_json::to_string(&secret).expect("serialize");
+        assert!(!json.contains("never-print-this-secret"));
+        assert_eq!(json, "\"***redacted***\"");
+    }
+
+    #[test]
+    fn secret_expose_returns_real_value() {
+        let secret = Secret::new("never-print-this-secret".to_string());
+        assert_eq!(secret.expose(), "never-print-this-secret");
+    }
+
+    #[test]
+    fn security_overrides_debug_redacts_authorized_api_keys() {
+        let overrides = SecurityOverrides {
+            authorized_api_keys: Some(Secret::new(BTreeSet::from([
+                "never-print-this-key".to_string(),
+            ]))),
+            ..Default::default()
+        };
+        let debug = format!("{overrides:?}");
+        assert!(!debug.contains("never-print-this-key"));
+    }
+
+    #[test]
+    fn security_overrides_serialize_redacts_authorized_api_keys() {
+        let overrides = SecurityOverrides {
+            authorized_api_keys: Some(Secret::new(BTreeSet::from([
+                "never-print-this-key".to_string(),
+            ]))),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&overrides).expect("serialize");
+        assert!(!json.contains("never-print-this-key"));
+    }
+
     /// Write a TOML file carrying only the two fail-closed security fields that
     /// `validate()` now requires and return its temp dir (kept alive by the
     /// caller) plus path. Lets env-only resolve fixtures pass validation
@@ -6563,4 +6790,59 @@ max_merge_decisions = 100
                 .contains("authorized_api_keys")
         );
     }
+
+    #[test]
+    fn reload_from_rejects_invalid_config_and_leaves_old_config_usable() {
+        let old = valid_base_config(Profile::Balanced);
+
+        let mut broken = old.clone();
+        broken.fleet.convergence_timeout_seconds = 0;
+        let bad_source = broken.to_toml().expect("serialize broken config");
+
+        let err = old.reload_from(&bad_source).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("fleet.convergence_timeout_seconds")
+        );
+
+        // The old config was never touched and remains fully usable.
+        assert!(old.validate().is_ok());
+        assert_eq!(old.profile, Profile::Balanced);
+    }
+
+    #[test]
+    fn reload_from_accepts_valid_config() {
+        let old = valid_base_config(Profile::Balanced);
+        let mut new = old.clone();
+        new.profile = Profile::Strict;
+        let source = new.to_toml().expect("serialize config");
+
+        let reloaded = old.reload_from(&source).expect("reload succeeds");
+        assert_eq!(reloaded.profile, Profile::Strict);
+    }
+
+    #[test]
+    fn diff_reports_exactly_the_changed_fields() {
+        let old = valid_base_config(Profile::Balanced);
+        let mut new = old.clone();
+        new.profile = Profile::Strict;
+        new.fleet.convergence_timeout_seconds += 1;
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.len(), 2);
+        let names: Vec<&str> = diff.field_names().collect();
+        assert!(names.contains(&"profile"));
+        assert!(names.contains(&"fleet"));
+        assert!(!names.contains(&"trust"));
+        assert!(!names.contains(&"security"));
+    }
+
+    #[test]
+    fn diff_is_empty_for_identical_configs() {
+        let config = valid_base_config(Profile::Balanced);
+        let diff = config.diff(&config.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.len(), 0);
+    }
 }
@@ -0,0 +1,207 @@
+//! Determinism conformance audit for cross-module reports.
+//!
+//! Every report-producing type in this crate claims byte-identical,
+//! order-insensitive serialization by building its maps on `BTreeMap`
+//! rather than `HashMap`. A `HashMap` slipping into an intermediate
+//! construction step (or a `Vec` built straight from unsorted map
+//! iteration) would reintroduce insertion-order dependence without
+//! necessarily breaking any other test. `determinism_audit()` builds
+//! each report-producing fixture from several different input orders
+//! and asserts the serializations are byte-identical.
+
+use serde::Serialize;
+
+use crate::runtime::authority_audit::{AuditReport, ModuleAuditResult};
+use crate::runtime::nversion_oracle::{DivergenceReport, OracleVerdict, RuntimeEntry};
+use crate::security::lineage_tracker::{LineageSnapshot, TaintLabel};
+
+/// Asserts that every permutation in `permutations` produces byte-identical
+/// serialized output when passed through `build`.
+///
+/// `permutations` is a list of orderings of the same logical input set
+/// (e.g. `vec![vec![0, 1, 2], vec![2, 1, 0], vec![1, 2, 0]]`); `build`
+/// must construct the fixture by inserting entries in the order given.
+///
+/// # Panics
+///
+/// Panics if fewer than two permutations are supplied, or if any
+/// permutation's serialization differs from the first one produced.
+pub fn assert_order_insensitive<T, F>(permutations: &[Vec<usize>], build: F)
+where
+    T: Serialize,
+    F: Fn(&[usize]) -> T,
+{
+    assert!(
+        permutations.len() >= 2,
+        "need at least two permutations to exercise order-insensitivity"
+    );
+    let mut baseline: Option<(Vec<usize>, String)> = None;
+    for perm in permutations {
+        let value = build(perm);
+        let json = serde_json::to_string(&value).expect("fixture must serialize");
+        match &baseline {
+            None => baseline = Some((perm.clone(), json)),
+            Some((base_perm, base_json)) => {
+                assert_eq!(
+                    base_json, &json,
+                    "serialization depends on insertion order: {:?} vs {:?}",
+                    base_perm, perm
+                );
+            }
+        }
+    }
+}
+
+/// Insertion-order permutations exercised by `determinism_audit()`.
+fn audit_permutations() -> Vec<Vec<usize>> {
+    vec![vec![0, 1, 2], vec![2, 1, 0], vec![1, 2, 0], vec![0, 2, 1]]
+}
+
+fn build_divergence_report(order: &[usize]) -> DivergenceReport {
+    let runtime_ids = ["rt-alpha", "rt-beta", "rt-gamma"];
+    let mut runtimes = std::collections::BTreeMap::new();
+    for &i in order {
+        let id = runtime_ids[i];
+        runtimes.insert(
+            id.to_string(),
+            RuntimeEntry {
+                runtime_id: id.to_string(),
+                runtime_name: format!("{id}-runtime"),
+                version: "1.0.0".to_string(),
+                is_reference: i == 0,
+                engine_family: format!("{id}-runtime"),
+            },
+        );
+    }
+    DivergenceReport {
+        schema_version: "divergence-report-v1".to_string(),
+        trace_id: "determinism-audit-trace".to_string(),
+        runtimes,
+        checks: Vec::new(),
+        divergences: Vec::new(),
+        voting_results: Vec::new(),
+        vote_conflicts: Vec::new(),
+        receipts: Vec::new(),
+        verdict: OracleVerdict::Pass,
+        risk_tier_counts: std::collections::BTreeMap::new(),
+        event_log: Vec::new(),
+    }
+}
+
+fn build_audit_report(order: &[usize]) -> AuditReport {
+    let module_paths = [
+        "franken_node::runtime::alpha",
+        "franken_node::runtime::beta",
+        "franken_node::runtime::gamma",
+    ];
+    let mut module_results = std::collections::BTreeMap::new();
+    for &i in order {
+        let path = module_paths[i];
+        module_results.insert(
+            path.to_string(),
+            ModuleAuditResult {
+                module_path: path.to_string(),
+                passed: true,
+                violation: None,
+                missing_capabilities: Vec::new(),
+            },
+        );
+    }
+    AuditReport {
+        schema_version: "audit-report-v1".to_string(),
+        total_modules: module_results.len(),
+        passed: module_results.len(),
+        failed: 0,
+        verdict: "PASS".to_string(),
+        module_results,
+        events: Vec::new(),
+        violations: Vec::new(),
+        missing_capability_counts: std::collections::BTreeMap::new(),
+    }
+}
+
+fn build_lineage_snapshot(order: &[usize]) -> LineageSnapshot {
+    let label_ids = ["PII", "SECRET", "INTERNAL"];
+    let mut labels = std::collections::BTreeMap::new();
+    for &i in order {
+        let id = label_ids[i];
+        labels.insert(
+            id.to_string(),
+            TaintLabel {
+                id: id.to_string(),
+                description: format!("{id} taint label"),
+                severity: (i + 1) as u32,
+                expires_at_ms: None,
+            },
+        );
+    }
+    LineageSnapshot {
+        snapshot_id: "determinism-audit-snapshot".to_string(),
+        timestamp_ms: 0,
+        edge_count: 0,
+        label_count: labels.len(),
+        edges: Vec::new(),
+        labels,
+        schema_version: "lineage-snapshot-v1".to_string(),
+    }
+}
+
+/// A fixture builder that deliberately ignores the `BTreeMap` convention:
+/// it collects entries into a `Vec` in whatever order the caller supplied
+/// them, rather than sorting by key first. Used only to prove that
+/// `assert_order_insensitive` actually catches an insertion-order bug.
+fn build_order_dependent_fixture(order: &[usize]) -> Vec<(usize, &'static str)> {
+    let values = ["alpha", "beta", "gamma"];
+    order.iter().map(|&i| (i, values[i])).collect()
+}
+
+/// Runs the full determinism audit across every report-producing fixture
+/// builder registered in this module, panicking on the first mismatch.
+///
+/// There is no `MeshReport` type in this crate today (the isolation mesh
+/// exposes `Vec<TopologyWarning>` from `MeshTopology::validate_strict`,
+/// not a standalone report struct), so it is not covered here. Add a
+/// fixture builder for it if/when such a type is introduced.
+pub fn determinism_audit() {
+    let permutations = audit_permutations();
+    assert_order_insensitive(&permutations, build_divergence_report);
+    assert_order_insensitive(&permutations, build_audit_report);
+    assert_order_insensitive(&permutations, build_lineage_snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determinism_audit_passes_for_all_registered_reports() {
+        determinism_audit();
+    }
+
+    #[test]
+    fn divergence_report_is_order_insensitive() {
+        assert_order_insensitive(&audit_permutations(), build_divergence_report);
+    }
+
+    #[test]
+    fn audit_report_is_order_insensitive() {
+        assert_order_insensitive(&audit_permutations(), build_audit_report);
+    }
+
+    #[test]
+    fn lineage_snapshot_is_order_insensitive() {
+        assert_order_insensitive(&audit_permutations(), build_lineage_snapshot);
+    }
+
+    #[test]
+    #[should_panic(expected = "serialization depends on insertion order")]
+    fn assert_order_insensitive_catches_an_order_dependent_fixture_builder() {
+        assert_order_insensitive(&audit_permutations(), build_order_dependent_fixture);
+    }
+
+    #[test]
+    #[should_panic(expected = "need at least two permutations")]
+    fn assert_order_insensitive_requires_at_least_two_permutations() {
+        assert_order_insensitive(&[vec![0, 1, 2]], build_divergence_report);
+    }
+}
@@ -0,0 +1,316 @@
+//! End-to-end conformance scenario spanning the oracle, mesh, lineage, and
+//! receipt subsystems.
+//!
+//! `full_scenario` drives each subsystem through its real public API --
+//! registering runtimes and running a cross-check, placing and elevating a
+//! workload on an isolation mesh, tracking a tainted data flow across a
+//! boundary, and minting a decision receipt -- then folds each subsystem's
+//! own verdict into one overall pass/fail. This is the smoke test for
+//! cross-module contracts: a change that silently breaks one subsystem's
+//! public API in a way another subsystem's callers rely on should surface
+//! here before it surfaces in production.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::runtime::isolation_mesh::{
+    ElevationPolicy, IsolationMesh, IsolationRail, IsolationRailLevel, MeshTopology,
+};
+use crate::runtime::nversion_oracle::{
+    BoundaryScope, OracleVerdict, RiskTier, RuntimeEntry, RuntimeOracle,
+};
+use crate::security::decision_receipt::{Decision, Receipt};
+use crate::security::lineage_tracker::{
+    ExfiltrationSentinel, FlowVerdict, LineageGraph, SentinelConfig, TaintBoundary, TaintLabel,
+};
+
+/// Verdict contributed by one subsystem to a [`ScenarioReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubsystemVerdict {
+    pub subsystem: String,
+    pub verdict: String,
+    pub detail: String,
+}
+
+/// Aggregate result of [`full_scenario`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScenarioReport {
+    pub verdict: String,
+    pub subsystems: Vec<SubsystemVerdict>,
+}
+
+/// Run the happy-path fixture across the oracle, mesh, lineage, and receipt
+/// subsystems and aggregate their verdicts.
+///
+/// # Returns
+/// A [`ScenarioReport`] whose overall `verdict` is `"FAIL"` if any
+/// subsystem's own verdict is `"FAIL"`, otherwise `"PASS"`.
+pub fn full_scenario() -> ScenarioReport {
+    run_scenario(false)
+}
+
+fn run_scenario(inject_critical_divergence: bool) -> ScenarioReport {
+    let subsystems = vec![
+        run_oracle_scenario(inject_critical_divergence),
+        run_mesh_scenario(),
+        run_lineage_scenario(),
+        run_receipt_scenario(),
+    ];
+    let verdict = if subsystems.iter().any(|s| s.verdict == "FAIL") {
+        "FAIL"
+    } else {
+        "PASS"
+    };
+    ScenarioReport {
+        verdict: verdict.to_string(),
+        subsystems,
+    }
+}
+
+fn run_oracle_scenario(inject_critical_divergence: bool) -> SubsystemVerdict {
+    let mut oracle = RuntimeOracle::new("trace-conformance-oracle", 66);
+    oracle
+        .register_runtime(RuntimeEntry {
+            runtime_id: "rt-a".to_string(),
+            runtime_name: "engine-a".to_string(),
+            version: "1.0.0".to_string(),
+            is_reference: true,
+            engine_family: "engine-a".to_string(),
+        })
+        .expect("register reference runtime");
+    oracle
+        .register_runtime(RuntimeEntry {
+            runtime_id: "rt-b".to_string(),
+            runtime_name: "engine-b".to_string(),
+            version: "1.0.0".to_string(),
+            is_reference: false,
+            engine_family: "engine-b".to_string(),
+        })
+        .expect("register candidate runtime");
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert("rt-a".to_string(), vec![1, 2, 3]);
+    outputs.insert("rt-b".to_string(), vec![1, 2, 3]);
+    oracle
+        .run_cross_check(
+            "chk-conformance-1",
+            BoundaryScope::TypeSystem,
+            b"conformance-input",
+            &outputs,
+        )
+        .expect("cross-check runs against registered runtimes");
+
+    if inject_critical_divergence {
+        oracle.classify_divergence(
+            "div-conformance-critical",
+            "chk-conformance-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &outputs,
+        );
+    }
+
+    match oracle.check_release_gate(1_000) {
+        OracleVerdict::Pass => SubsystemVerdict {
+            subsystem: "oracle".to_string(),
+            verdict: "PASS".to_string(),
+            detail: "no blocking divergences".to_string(),
+        },
+        OracleVerdict::BlockRelease {
+            blocking_divergence_ids,
+        } => SubsystemVerdict {
+            subsystem: "oracle".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!(
+                "release blocked by {} unresolved divergence(s)",
+                blocking_divergence_ids.len()
+            ),
+        },
+        OracleVerdict::RequiresReceipt {
+            pending_divergence_ids,
+        } => SubsystemVerdict {
+            subsystem: "oracle".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!(
+                "{} divergence(s) pending a policy receipt",
+                pending_divergence_ids.len()
+            ),
+        },
+    }
+}
+
+fn run_mesh_scenario() -> SubsystemVerdict {
+    let mut rails = BTreeMap::new();
+    for rail in [
+        IsolationRail {
+            rail_id: "shared-1".to_string(),
+            level: IsolationRailLevel::Shared,
+            latency_overhead_us: 10,
+            capacity: 4,
+            cost_units: 1,
+        },
+        IsolationRail {
+            rail_id: "sandbox-1".to_string(),
+            level: IsolationRailLevel::SandboxIsolated,
+            latency_overhead_us: 200,
+            capacity: 2,
+            cost_units: 5,
+        },
+    ] {
+        rails.insert(rail.rail_id.clone(), rail);
+    }
+
+    let mut mesh = match IsolationMesh::new(MeshTopology { rails }) {
+        Ok(mesh) => mesh,
+        Err(err) => {
+            return SubsystemVerdict {
+                subsystem: "mesh".to_string(),
+                verdict: "FAIL".to_string(),
+                detail: format!("topology rejected: {err}"),
+            };
+        }
+    };
+
+    let policy = ElevationPolicy {
+        elevation_allowed: true,
+        max_target_level: IsolationRailLevel::SandboxIsolated,
+        preserve_latency_budget: false,
+        latency_budget_us: 0,
+    };
+    if let Err(err) = mesh.place_workload("wl-conformance-1", "shared-1", policy, 1_000) {
+        return SubsystemVerdict {
+            subsystem: "mesh".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("placement failed: {err}"),
+        };
+    }
+    if let Err(err) = mesh.elevate_workload("wl-conformance-1", "sandbox-1", 1_010) {
+        return SubsystemVerdict {
+            subsystem: "mesh".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("elevation failed: {err}"),
+        };
+    }
+
+    SubsystemVerdict {
+        subsystem: "mesh".to_string(),
+        verdict: "PASS".to_string(),
+        detail: "workload placed on shared-1 and elevated to sandbox-1".to_string(),
+    }
+}
+
+fn run_lineage_scenario() -> SubsystemVerdict {
+    let mut graph = LineageGraph::new(SentinelConfig::default());
+    graph.register_label(TaintLabel {
+        id: "PUBLIC".to_string(),
+        description: "Non-sensitive data".to_string(),
+        severity: 10,
+        expires_at_ms: None,
+    });
+    if let Err(err) = graph.assign_taint("internal:cache", "PUBLIC") {
+        return SubsystemVerdict {
+            subsystem: "lineage".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("taint assignment failed: {err}"),
+        };
+    }
+
+    let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    if let Err(err) = sentinel.add_boundary(TaintBoundary {
+        boundary_id: "b-conformance-1".to_string(),
+        from_zone: "internal".to_string(),
+        to_zone: "external".to_string(),
+        denied_labels: BTreeSet::from(["SECRET".to_string()]),
+        deny_all: false,
+        operation_restriction: None,
+    }) {
+        return SubsystemVerdict {
+            subsystem: "lineage".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("boundary registration failed: {err}"),
+        };
+    }
+
+    match sentinel.track_flow(&mut graph, "internal:cache", "external:api", "export", 1_000) {
+        Ok(FlowVerdict::Pass) => SubsystemVerdict {
+            subsystem: "lineage".to_string(),
+            verdict: "PASS".to_string(),
+            detail: "flow allowed across the internal/external boundary".to_string(),
+        },
+        Ok(verdict) => SubsystemVerdict {
+            subsystem: "lineage".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("flow was contained instead of allowed: {verdict}"),
+        },
+        Err(err) => SubsystemVerdict {
+            subsystem: "lineage".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("flow evaluation failed: {err}"),
+        },
+    }
+}
+
+fn run_receipt_scenario() -> SubsystemVerdict {
+    match Receipt::new(
+        "conformance.full_scenario",
+        "system:conformance",
+        "audience:conformance",
+        &"conformance-input",
+        &"conformance-output",
+        Decision::Approved,
+        "scenario fixture produced a coherent release verdict",
+        Vec::new(),
+        Vec::new(),
+        1.0,
+        "no-op",
+    ) {
+        Ok(_receipt) => SubsystemVerdict {
+            subsystem: "receipt".to_string(),
+            verdict: "PASS".to_string(),
+            detail: "receipt minted for the scenario's release verdict".to_string(),
+        },
+        Err(err) => SubsystemVerdict {
+            subsystem: "receipt".to_string(),
+            verdict: "FAIL".to_string(),
+            detail: format!("receipt construction failed: {err}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn happy_path_fixture_reports_overall_pass() {
+        let report = full_scenario();
+        assert_eq!(report.verdict, "PASS");
+        assert_eq!(report.subsystems.len(), 4);
+        assert!(
+            report
+                .subsystems
+                .iter()
+                .all(|subsystem| subsystem.verdict == "PASS")
+        );
+    }
+
+    #[test]
+    fn unresolved_critical_divergence_flips_overall_verdict_to_fail() {
+        let report = run_scenario(true);
+        assert_eq!(report.verdict, "FAIL");
+        let oracle = report
+            .subsystems
+            .iter()
+            .find(|subsystem| subsystem.subsystem == "oracle")
+            .expect("oracle subsystem result present");
+        assert_eq!(oracle.verdict, "FAIL");
+
+        let others_still_pass = report
+            .subsystems
+            .iter()
+            .filter(|subsystem| subsystem.subsystem != "oracle")
+            .all(|subsystem| subsystem.verdict == "PASS");
+        assert!(others_still_pass);
+    }
+}
@@ -1,6 +1,9 @@
 pub mod connector_method_validator;
+pub mod determinism_audit;
 pub mod fsqlite_inspired_suite;
+pub mod full_scenario;
 pub mod protocol_harness;
+pub mod schema_matrix;
 
 /// Initialize tracing subscriber for test runs.
 ///
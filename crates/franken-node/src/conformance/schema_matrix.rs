@@ -0,0 +1,164 @@
+//! Cross-module schema-version compatibility matrix.
+//!
+//! Several unrelated modules each maintain their own `SCHEMA_VERSION`
+//! constant for their own wire/snapshot payloads: the N-version oracle's
+//! divergence reports, the information-flow lineage tracker's snapshots,
+//! the isolation mesh's topology state, the authority audit report, and
+//! the storage layer's model payloads. Nothing ties these together, so a
+//! constant bumped in one module can silently drift out of sync with what
+//! the rest of the build expects. `schema_matrix()` snapshots the current
+//! build's versions; `check_compatibility` compares that snapshot against
+//! a pinned expected matrix and reports every module that moved.
+
+use std::collections::BTreeMap;
+
+use crate::runtime::authority_audit::SCHEMA_VERSION as AUTHORITY_AUDIT_SCHEMA_VERSION;
+use crate::runtime::isolation_mesh::SCHEMA_VERSION as ISOLATION_MESH_SCHEMA_VERSION;
+use crate::runtime::nversion_oracle::SCHEMA_VERSION as NVERSION_ORACLE_SCHEMA_VERSION;
+use crate::security::lineage_tracker::SCHEMA_VERSION as LINEAGE_TRACKER_SCHEMA_VERSION;
+use crate::storage::models::MODEL_SCHEMA_VERSION;
+
+/// A snapshot of module-name -> schema-version strings, keyed by the same
+/// short module identifiers used throughout this crate's telemetry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaMatrix {
+    pub versions: BTreeMap<String, String>,
+}
+
+/// One module whose schema version differs between two matrices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaMismatch {
+    pub module: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl SchemaMatrix {
+    /// Compares this matrix against a pinned `expected` matrix, reporting
+    /// every module whose version differs or is missing from either side.
+    ///
+    /// # Errors
+    ///
+    /// Returns every mismatch found; an empty mismatch list is never
+    /// returned as an error, `Ok(())` is used instead.
+    pub fn check_compatibility(&self, expected: &SchemaMatrix) -> Result<(), Vec<SchemaMismatch>> {
+        let mut mismatches = Vec::new();
+        for (module, expected_version) in &expected.versions {
+            match self.versions.get(module) {
+                Some(actual_version) if actual_version == expected_version => {}
+                Some(actual_version) => mismatches.push(SchemaMismatch {
+                    module: module.clone(),
+                    expected: expected_version.clone(),
+                    actual: actual_version.clone(),
+                }),
+                None => mismatches.push(SchemaMismatch {
+                    module: module.clone(),
+                    expected: expected_version.clone(),
+                    actual: "<missing>".to_string(),
+                }),
+            }
+        }
+        for (module, actual_version) in &self.versions {
+            if !expected.versions.contains_key(module) {
+                mismatches.push(SchemaMismatch {
+                    module: module.clone(),
+                    expected: "<not tracked>".to_string(),
+                    actual: actual_version.clone(),
+                });
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
+    }
+}
+
+/// Collects the current build's module schema versions into a matrix.
+#[must_use]
+pub fn schema_matrix() -> SchemaMatrix {
+    let mut versions = BTreeMap::new();
+    versions.insert(
+        "nversion_oracle".to_string(),
+        NVERSION_ORACLE_SCHEMA_VERSION.to_string(),
+    );
+    versions.insert(
+        "lineage_tracker".to_string(),
+        LINEAGE_TRACKER_SCHEMA_VERSION.to_string(),
+    );
+    versions.insert(
+        "isolation_mesh".to_string(),
+        ISOLATION_MESH_SCHEMA_VERSION.to_string(),
+    );
+    versions.insert(
+        "authority_audit".to_string(),
+        AUTHORITY_AUDIT_SCHEMA_VERSION.to_string(),
+    );
+    versions.insert(
+        "storage_models".to_string(),
+        MODEL_SCHEMA_VERSION.to_string(),
+    );
+    SchemaMatrix { versions }
+}
+
+/// The pinned schema matrix this build is expected to match. Bump an entry
+/// here in the same commit that intentionally changes the corresponding
+/// module's `SCHEMA_VERSION`, so `check_compatibility` only flags
+/// unintentional drift.
+#[must_use]
+pub fn expected_schema_matrix() -> SchemaMatrix {
+    let mut versions = BTreeMap::new();
+    versions.insert("nversion_oracle".to_string(), "nvo-v1.0".to_string());
+    versions.insert("lineage_tracker".to_string(), "ifl-v1.0".to_string());
+    versions.insert(
+        "isolation_mesh".to_string(),
+        "isolation-mesh-v1.0".to_string(),
+    );
+    versions.insert("authority_audit".to_string(), "aa-v1.0".to_string());
+    versions.insert("storage_models".to_string(), "1.0.0".to_string());
+    SchemaMatrix { versions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unchanged_matrix_passes_compatibility_check() {
+        let current = schema_matrix();
+        let expected = expected_schema_matrix();
+        assert_eq!(current.check_compatibility(&expected), Ok(()));
+    }
+
+    #[test]
+    fn bumped_module_version_is_reported_as_mismatch() {
+        let mut bumped = schema_matrix();
+        bumped
+            .versions
+            .insert("nversion_oracle".to_string(), "nvo-v2.0".to_string());
+        let expected = expected_schema_matrix();
+
+        let mismatches = bumped
+            .check_compatibility(&expected)
+            .expect_err("bumped schema version should be reported");
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].module, "nversion_oracle");
+        assert_eq!(mismatches[0].expected, "nvo-v1.0");
+        assert_eq!(mismatches[0].actual, "nvo-v2.0");
+    }
+
+    #[test]
+    fn missing_module_is_reported_as_mismatch() {
+        let mut partial = schema_matrix();
+        partial.versions.remove("storage_models");
+        let expected = expected_schema_matrix();
+
+        let mismatches = partial
+            .check_compatibility(&expected)
+            .expect_err("missing schema module should be reported");
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].module, "storage_models");
+        assert_eq!(mismatches[0].actual, "<missing>");
+    }
+}
@@ -68,15 +68,35 @@ pub struct RepairCycleAudit {
     pub tenants_skipped: usize,
     pub trace_id: String,
     pub timestamp: String,
+    /// Items that were skipped because they are currently quarantined by the
+    /// lineage `ExfiltrationSentinel` and must not be repaired back into
+    /// circulation. Counted separately from `tenants_skipped`, which tracks
+    /// tenants dropped by the `max_tenants_per_cycle` limit.
+    pub items_skipped_quarantined: usize,
 }
 
 /// Errors from repair operations.
 #[derive(Debug, Clone, PartialEq)]
 pub enum RepairError {
-    CapExceeded { used: u64, cap: u64 },
-    InvalidConfig { reason: String },
+    CapExceeded {
+        used: u64,
+        cap: u64,
+    },
+    InvalidConfig {
+        reason: String,
+    },
     NoPending,
-    Starvation { tenant_id: String },
+    Starvation {
+        tenant_id: String,
+    },
+    /// Raised by [`plan_repairs`] when the dependency graph of repair
+    /// actions contains a cycle, so no valid execution order exists.
+    /// `cycle` lists the (sorted) action ids left unresolved once all
+    /// resolvable actions have been removed: the cycle's members and any
+    /// actions that depend on them.
+    CycleDetected {
+        cycle: Vec<String>,
+    },
 }
 
 impl RepairError {
@@ -86,6 +106,7 @@ impl RepairError {
             Self::InvalidConfig { .. } => "BRC_INVALID_CONFIG",
             Self::NoPending => "BRC_NO_PENDING",
             Self::Starvation { .. } => "BRC_STARVATION",
+            Self::CycleDetected { .. } => "BRC_CYCLE_DETECTED",
         }
     }
 }
@@ -97,6 +118,9 @@ impl std::fmt::Display for RepairError {
             Self::InvalidConfig { reason } => write!(f, "BRC_INVALID_CONFIG: {reason}"),
             Self::NoPending => write!(f, "BRC_NO_PENDING"),
             Self::Starvation { tenant_id } => write!(f, "BRC_STARVATION: {tenant_id}"),
+            Self::CycleDetected { cycle } => {
+                write!(f, "BRC_CYCLE_DETECTED: {}", cycle.join(", "))
+            }
         }
     }
 }
@@ -138,6 +162,26 @@ pub fn run_cycle(
     cycle_id: &str,
     trace_id: &str,
     timestamp: &str,
+) -> Result<(Vec<RepairAllocation>, RepairCycleAudit), RepairError> {
+    run_cycle_with_quarantine(pending, config, cycle_id, trace_id, timestamp, &|_| false)
+}
+
+/// Run a repair cycle, consulting `is_quarantined` before repairing each
+/// item back into circulation.
+///
+/// Items for which `is_quarantined(item_id)` returns `true` (e.g. an item
+/// whose edge is held by the lineage `ExfiltrationSentinel`) are excluded
+/// from allocation and counted in `RepairCycleAudit::items_skipped_quarantined`
+/// instead. This keeps the repair loop from undoing containment.
+///
+/// INV-BRC-QUARANTINE-SAFE: a quarantined item is never allocated by this cycle.
+pub fn run_cycle_with_quarantine(
+    pending: &[RepairItem],
+    config: &RepairConfig,
+    cycle_id: &str,
+    trace_id: &str,
+    timestamp: &str,
+    is_quarantined: &dyn Fn(&str) -> bool,
 ) -> Result<(Vec<RepairAllocation>, RepairCycleAudit), RepairError> {
     validate_config(config)?;
 
@@ -174,6 +218,7 @@ pub fn run_cycle(
     // Group by tenant, sorted by tenant_id for determinism
     let mut by_tenant: BTreeMap<String, Vec<&RepairItem>> = BTreeMap::new();
     let mut item_ids = BTreeSet::new();
+    let mut items_skipped_quarantined: usize = 0;
     for item in pending {
         let item_id = item.item_id.as_str();
         if item_id.trim().is_empty() || item_id.trim() != item_id {
@@ -192,6 +237,10 @@ pub fn run_cycle(
                 reason: format!("duplicate item_id: {item_id}"),
             });
         }
+        if is_quarantined(item_id) {
+            items_skipped_quarantined += 1;
+            continue;
+        }
         let tenant_items = by_tenant.entry(item.tenant_id.clone()).or_default();
         push_bounded(tenant_items, item, MAX_PENDING_REPAIR_ITEMS_PER_CYCLE);
     }
@@ -316,11 +365,190 @@ pub fn run_cycle(
         tenants_skipped: skipped,
         trace_id: trace_id.to_string(),
         timestamp: timestamp.to_string(),
+        items_skipped_quarantined,
     };
 
     Ok((result, audit))
 }
 
+// ---------------------------------------------------------------------------
+// Dependency-ordered repair sequencing
+// ---------------------------------------------------------------------------
+
+/// A repair action that may depend on other actions completing first (e.g.
+/// repairing the state root before repairing records that reference it).
+#[derive(Debug, Clone)]
+pub struct RepairAction {
+    pub action_id: String,
+    pub depends_on: Vec<String>,
+}
+
+/// A topologically-ordered sequence of repair actions, produced by
+/// [`plan_repairs`]. Every prerequisite precedes the actions that depend
+/// on it.
+#[derive(Debug, Clone)]
+pub struct RepairPlan {
+    pub ordered_action_ids: Vec<String>,
+}
+
+/// Outcome of executing a single action from a [`RepairPlan`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepairActionOutcome {
+    Succeeded,
+    Failed {
+        reason: String,
+    },
+    /// Not attempted because `failed_dependency` (a direct or transitive
+    /// prerequisite) failed earlier in the plan.
+    SkippedDependencyFailed {
+        failed_dependency: String,
+    },
+}
+
+/// Per-action record produced by [`execute_plan`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepairExecutionRecord {
+    pub action_id: String,
+    pub outcome: RepairActionOutcome,
+}
+
+/// Plan a dependency-ordered sequence of repair actions using Kahn's
+/// algorithm, breaking ties deterministically by `action_id`.
+///
+/// INV-BRC-PLAN-ACYCLIC: refuses to plan (returns
+/// [`RepairError::CycleDetected`]) when the dependency graph contains a
+/// cycle, since no valid execution order would exist.
+pub fn plan_repairs(actions: &[RepairAction]) -> Result<RepairPlan, RepairError> {
+    if actions.is_empty() {
+        return Err(RepairError::InvalidConfig {
+            reason: "no repair actions to plan".into(),
+        });
+    }
+
+    let mut known_ids: BTreeSet<&str> = BTreeSet::new();
+    for action in actions {
+        if !known_ids.insert(action.action_id.as_str()) {
+            return Err(RepairError::InvalidConfig {
+                reason: format!("duplicate repair action id: {}", action.action_id),
+            });
+        }
+    }
+    for action in actions {
+        for dep in &action.depends_on {
+            if !known_ids.contains(dep.as_str()) {
+                return Err(RepairError::InvalidConfig {
+                    reason: format!(
+                        "repair action '{}' depends on unknown action '{dep}'",
+                        action.action_id
+                    ),
+                });
+            }
+        }
+    }
+
+    let mut in_degree: BTreeMap<&str, usize> = actions
+        .iter()
+        .map(|action| (action.action_id.as_str(), action.depends_on.len()))
+        .collect();
+    let mut dependents: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for action in actions {
+        for dep in &action.depends_on {
+            dependents
+                .entry(dep.as_str())
+                .or_default()
+                .push(action.action_id.as_str());
+        }
+    }
+
+    let mut ready: BTreeSet<&str> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(id, _)| *id)
+        .collect();
+
+    let mut ordered: Vec<String> = Vec::with_capacity(actions.len());
+    while let Some(id) = ready.iter().next().copied() {
+        ready.remove(id);
+        ordered.push(id.to_string());
+        if let Some(deps) = dependents.get(id) {
+            for &dependent in deps {
+                if let Some(degree) = in_degree.get_mut(dependent) {
+                    *degree = degree.saturating_sub(1);
+                    if *degree == 0 {
+                        ready.insert(dependent);
+                    }
+                }
+            }
+        }
+    }
+
+    if ordered.len() < actions.len() {
+        let mut cycle: Vec<String> = in_degree
+            .into_iter()
+            .filter(|(_, degree)| *degree > 0)
+            .map(|(id, _)| id.to_string())
+            .collect();
+        cycle.sort();
+        return Err(RepairError::CycleDetected { cycle });
+    }
+
+    Ok(RepairPlan {
+        ordered_action_ids: ordered,
+    })
+}
+
+/// Execute a [`RepairPlan`] in order, calling `run_action` for each action.
+///
+/// If an action's prerequisite (direct or transitive, via an earlier
+/// skip) failed, the dependent action is not attempted and is instead
+/// recorded as [`RepairActionOutcome::SkippedDependencyFailed`].
+pub fn execute_plan(
+    plan: &RepairPlan,
+    actions: &[RepairAction],
+    mut run_action: impl FnMut(&str) -> Result<(), String>,
+) -> Vec<RepairExecutionRecord> {
+    let depends_on: BTreeMap<&str, &[String]> = actions
+        .iter()
+        .map(|action| (action.action_id.as_str(), action.depends_on.as_slice()))
+        .collect();
+
+    let mut failed: BTreeSet<&str> = BTreeSet::new();
+    let mut records = Vec::with_capacity(plan.ordered_action_ids.len());
+
+    for action_id in &plan.ordered_action_ids {
+        let deps = depends_on
+            .get(action_id.as_str())
+            .copied()
+            .unwrap_or(&[] as &[String]);
+        if let Some(failed_dependency) = deps.iter().find(|dep| failed.contains(dep.as_str())) {
+            failed.insert(action_id.as_str());
+            records.push(RepairExecutionRecord {
+                action_id: action_id.clone(),
+                outcome: RepairActionOutcome::SkippedDependencyFailed {
+                    failed_dependency: failed_dependency.clone(),
+                },
+            });
+            continue;
+        }
+
+        match run_action(action_id) {
+            Ok(()) => records.push(RepairExecutionRecord {
+                action_id: action_id.clone(),
+                outcome: RepairActionOutcome::Succeeded,
+            }),
+            Err(reason) => {
+                failed.insert(action_id.as_str());
+                records.push(RepairExecutionRecord {
+                    action_id: action_id.clone(),
+                    outcome: RepairActionOutcome::Failed { reason },
+                });
+            }
+        }
+    }
+
+    records
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -962,6 +1190,57 @@ mod tests {
         assert_eq!(audit.total_units_used, 5);
     }
 
+    #[test]
+    fn quarantined_item_is_skipped_and_counted_while_normal_item_is_repaired() {
+        let items = vec![
+            item("quarantined-1", "t1", 10, 5),
+            item("normal-1", "t1", 5, 5),
+        ];
+
+        let (allocs, audit) = run_cycle_with_quarantine(
+            &items,
+            &config(),
+            "c-quarantine",
+            "tr-quarantine",
+            "ts",
+            &|item_id| item_id == "quarantined-1",
+        )
+        .expect("cycle succeeds");
+
+        assert_eq!(allocs.len(), 1);
+        assert_eq!(allocs[0].items_allocated, vec!["normal-1".to_string()]);
+        assert_eq!(audit.items_skipped_quarantined, 1);
+        assert_eq!(audit.tenants_served, 1);
+    }
+
+    #[test]
+    fn all_items_quarantined_yields_no_allocations_but_no_error() {
+        let items = vec![item("quarantined-1", "t1", 10, 5)];
+
+        let (allocs, audit) = run_cycle_with_quarantine(
+            &items,
+            &config(),
+            "c-all-quarantine",
+            "tr-all-quarantine",
+            "ts",
+            &|_| true,
+        )
+        .expect("cycle succeeds");
+
+        assert!(allocs.is_empty() || allocs.iter().all(|a| a.items_allocated.is_empty()));
+        assert_eq!(audit.items_skipped_quarantined, 1);
+        assert_eq!(audit.total_units_used, 0);
+    }
+
+    #[test]
+    fn run_cycle_default_predicate_never_quarantines() {
+        let items = vec![item("r1", "t1", 5, 10)];
+
+        let (_, audit) = run_cycle(&items, &config(), "c1", "tr", "ts").expect("should succeed");
+
+        assert_eq!(audit.items_skipped_quarantined, 0);
+    }
+
     #[test]
     fn negative_extreme_fairness_minimum_still_respects_cycle_cap() {
         let cfg = RepairConfig {
@@ -979,4 +1258,82 @@ mod tests {
         assert_eq!(audit.total_units_used, cfg.max_units_per_cycle);
         assert_eq!(audit.cap, cfg.max_units_per_cycle);
     }
+
+    fn action(id: &str, depends_on: &[&str]) -> RepairAction {
+        RepairAction {
+            action_id: id.into(),
+            depends_on: depends_on.iter().map(|dep| (*dep).to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn plan_repairs_orders_dependencies_before_dependents() {
+        let actions = vec![
+            action("repair-records", &["repair-state-root"]),
+            action("repair-state-root", &[]),
+            action("repair-index", &["repair-records"]),
+        ];
+
+        let plan = plan_repairs(&actions).expect("acyclic plan succeeds");
+
+        let pos = |id: &str| {
+            plan.ordered_action_ids
+                .iter()
+                .position(|x| x == id)
+                .unwrap()
+        };
+        assert!(pos("repair-state-root") < pos("repair-records"));
+        assert!(pos("repair-records") < pos("repair-index"));
+        assert_eq!(plan.ordered_action_ids.len(), actions.len());
+    }
+
+    #[test]
+    fn plan_repairs_detects_cycle() {
+        let actions = vec![action("a", &["b"]), action("b", &["a"])];
+
+        let err = plan_repairs(&actions).expect_err("cyclic graph is rejected");
+        match err {
+            RepairError::CycleDetected { cycle } => {
+                assert_eq!(cycle, vec!["a".to_string(), "b".to_string()]);
+            }
+            other => panic!("expected CycleDetected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn execute_plan_skips_dependent_after_prerequisite_failure() {
+        let actions = vec![
+            action("repair-state-root", &[]),
+            action("repair-records", &["repair-state-root"]),
+        ];
+        let plan = plan_repairs(&actions).expect("acyclic plan succeeds");
+
+        let records = execute_plan(&plan, &actions, |action_id| {
+            if action_id == "repair-state-root" {
+                Err("state root repair failed".to_string())
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0],
+            RepairExecutionRecord {
+                action_id: "repair-state-root".to_string(),
+                outcome: RepairActionOutcome::Failed {
+                    reason: "state root repair failed".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            records[1],
+            RepairExecutionRecord {
+                action_id: "repair-records".to_string(),
+                outcome: RepairActionOutcome::SkippedDependencyFailed {
+                    failed_dependency: "repair-state-root".to_string(),
+                },
+            }
+        );
+    }
 }
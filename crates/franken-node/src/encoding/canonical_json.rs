@@ -0,0 +1,75 @@
+//! Single canonical-JSON entry point for encoders scattered across the tree.
+//!
+//! `trust_card` and `counterfactual_replay` (among others) each need a
+//! deterministic JSON string for hashing and signing: object keys sorted
+//! lexicographically, arrays left in order, and numbers formatted the same
+//! way regardless of platform or locale. Rather than every caller
+//! reimplementing that walk, [`canonical_json`] delegates to the
+//! byte-oriented encoder in [`crate::connector::canonical_serializer`] and
+//! is the encoder every such caller should use.
+//!
+//! `serde_json::Value` cannot represent `NaN` or infinite floats --
+//! `serde_json::Number::from_f64` only succeeds for finite values, so a
+//! `Value` built through the safe API has already rejected them by the time
+//! it reaches this function.
+
+use crate::connector::canonical_serializer::canonical_bytes;
+use serde_json::Value;
+
+/// Serialize `value` to a canonical JSON string: object keys sorted
+/// lexicographically at every nesting level, arrays left in element order.
+pub fn canonical_json(value: &Value) -> String {
+    let bytes = canonical_bytes(value);
+    // canonical_bytes only ever writes serde_json-escaped strings and ASCII
+    // structural characters, which is always valid UTF-8.
+    String::from_utf8(bytes).expect("canonical JSON bytes are always valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::canonical_json;
+    use serde_json::json;
+
+    #[test]
+    fn reordered_object_keys_produce_identical_output() {
+        let a = json!({"b": 1, "a": 2, "c": {"z": 1, "y": 2}});
+        let b = json!({"a": 2, "c": {"y": 2, "z": 1}, "b": 1});
+
+        assert_eq!(canonical_json(&a), canonical_json(&b));
+        assert_eq!(canonical_json(&a), r#"{"a":2,"b":1,"c":{"y":2,"z":1}}"#);
+    }
+
+    #[test]
+    fn nested_arrays_of_objects_sort_keys_at_every_level() {
+        let value = json!({"items": [{"z": 1, "a": 2}, {"b": 3, "a": 4}]});
+
+        assert_eq!(
+            canonical_json(&value),
+            r#"{"items":[{"a":2,"z":1},{"a":4,"b":3}]}"#
+        );
+    }
+
+    #[test]
+    fn floats_serialize_without_locale_dependence() {
+        let value = json!({"pi": 3.5, "half": 0.5, "whole": 2.0});
+
+        // Shortest round-trip representation, always with a `.` decimal
+        // point and no thousands separators, regardless of the platform's
+        // locale settings.
+        assert_eq!(canonical_json(&value), r#"{"half":0.5,"pi":3.5,"whole":2.0}"#);
+    }
+
+    #[test]
+    fn empty_object_and_array_round_trip() {
+        assert_eq!(canonical_json(&json!({})), "{}");
+        assert_eq!(canonical_json(&json!([])), "[]");
+    }
+
+    #[test]
+    fn is_deterministic_across_repeated_calls() {
+        let value = json!({"c": 1, "b": 2, "a": 3});
+        let first = canonical_json(&value);
+        let second = canonical_json(&value);
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,193 @@
+//! Selectable hash algorithm for canonical state-root computation.
+//!
+//! `CanonicalStateRootRecord::algorithm` has always carried a free-form
+//! string, but until now the crate only ever computed SHA-256 roots. This
+//! module adds BLAKE3 as a second, faster option for large inputs while
+//! keeping SHA-256 as the default, and gives both a single computation and
+//! verification path so a persisted `algorithm` string round-trips back to
+//! the algorithm that produced it.
+
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+const SHA256_ROOT_DOMAIN: &[u8] = b"franken_node.encoding.state_root.sha256.v1";
+const BLAKE3_ROOT_DOMAIN: &[u8] = b"franken_node.encoding.state_root.blake3.v1";
+
+/// A hash algorithm selectable for [`compute_root`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = HashAlgorithmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(Self::Sha256),
+            "blake3" => Ok(Self::Blake3),
+            other => Err(HashAlgorithmError::Unknown(other.to_string())),
+        }
+    }
+}
+
+/// Errors from [`compute_root`] / [`verify_root`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HashAlgorithmError {
+    #[error("unknown hash algorithm `{0}`")]
+    Unknown(String),
+    #[error("blake3 support is not compiled in (build with `--features blake3`)")]
+    Blake3Unavailable,
+    #[error("state root mismatch: expected {expected}, computed {actual}")]
+    RootMismatch { expected: String, actual: String },
+}
+
+/// Length-prefix every input before concatenating so no two distinct input
+/// sequences can collide by concatenation alone (e.g. `["ab", "c"]` vs.
+/// `["a", "bc"]`).
+fn length_prefixed_material(inputs: &[&[u8]]) -> Vec<u8> {
+    let mut material = Vec::new();
+    for input in inputs {
+        material.extend_from_slice(&u64::try_from(input.len()).unwrap_or(u64::MAX).to_le_bytes());
+        material.extend_from_slice(input);
+    }
+    material
+}
+
+/// Compute a hex-encoded canonical state root over `inputs` using
+/// `algorithm`. Each algorithm's digest is domain-separated so the same
+/// input material never collides across algorithms.
+pub fn compute_root(inputs: &[&[u8]], algorithm: HashAlgorithm) -> Result<String, HashAlgorithmError> {
+    let material = length_prefixed_material(inputs);
+    match algorithm {
+        HashAlgorithm::Sha256 => {
+            let mut hasher = Sha256::new();
+            hasher.update(SHA256_ROOT_DOMAIN);
+            hasher.update(&material);
+            Ok(hex::encode(hasher.finalize()))
+        }
+        HashAlgorithm::Blake3 => blake3_root(&material),
+    }
+}
+
+#[cfg(feature = "blake3")]
+fn blake3_root(material: &[u8]) -> Result<String, HashAlgorithmError> {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(BLAKE3_ROOT_DOMAIN);
+    hasher.update(material);
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+#[cfg(not(feature = "blake3"))]
+fn blake3_root(_material: &[u8]) -> Result<String, HashAlgorithmError> {
+    Err(HashAlgorithmError::Blake3Unavailable)
+}
+
+/// Recompute the root over `inputs` under the algorithm named by
+/// `algorithm_str` and check it matches `expected_root`. Round-trips
+/// `algorithm_str` through [`HashAlgorithm::from_str`], so a persisted
+/// `CanonicalStateRootRecord::algorithm` value is parsed the same way
+/// [`compute_root`] would have selected it, and an unrecognized string is
+/// rejected rather than silently falling back to a default algorithm.
+pub fn verify_root(
+    inputs: &[&[u8]],
+    expected_root: &str,
+    algorithm_str: &str,
+) -> Result<(), HashAlgorithmError> {
+    let algorithm = HashAlgorithm::from_str(algorithm_str)?;
+    let actual = compute_root(inputs, algorithm)?;
+    if actual == expected_root {
+        Ok(())
+    } else {
+        Err(HashAlgorithmError::RootMismatch {
+            expected: expected_root.to_string(),
+            actual,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_root_is_stable_across_calls() {
+        let inputs: &[&[u8]] = &[b"a", b"bc"];
+        let first = compute_root(inputs, HashAlgorithm::Sha256).expect("sha256 root");
+        let second = compute_root(inputs, HashAlgorithm::Sha256).expect("sha256 root");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn from_str_round_trips_through_as_str() {
+        for algo in [HashAlgorithm::Sha256, HashAlgorithm::Blake3] {
+            let parsed: HashAlgorithm = algo.as_str().parse().expect("known algorithm parses");
+            assert_eq!(parsed, algo);
+        }
+    }
+
+    #[test]
+    fn verify_rejects_unknown_algorithm() {
+        let inputs: &[&[u8]] = &[b"a"];
+        let err = verify_root(inputs, "deadbeef", "sha512")
+            .expect_err("unknown algorithm must be rejected");
+        assert_eq!(err, HashAlgorithmError::Unknown("sha512".to_string()));
+    }
+
+    #[test]
+    fn verify_round_trips_the_algorithm_that_produced_the_root() {
+        let inputs: &[&[u8]] = &[b"a", b"bc"];
+        let root = compute_root(inputs, HashAlgorithm::Sha256).expect("sha256 root");
+        verify_root(inputs, &root, "sha256").expect("root verifies under its own algorithm");
+    }
+
+    #[test]
+    fn verify_rejects_root_computed_under_a_different_algorithm() {
+        let inputs: &[&[u8]] = &[b"a", b"bc"];
+        let sha_root = compute_root(inputs, HashAlgorithm::Sha256).expect("sha256 root");
+
+        #[cfg(feature = "blake3")]
+        {
+            let err = verify_root(inputs, &sha_root, "blake3")
+                .expect_err("sha256 root must not verify as blake3");
+            assert!(matches!(err, HashAlgorithmError::RootMismatch { .. }));
+        }
+        #[cfg(not(feature = "blake3"))]
+        {
+            let err = verify_root(inputs, &sha_root, "blake3")
+                .expect_err("blake3 must be rejected when the feature is not compiled in");
+            assert_eq!(err, HashAlgorithmError::Blake3Unavailable);
+        }
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn sha256_and_blake3_produce_different_stable_roots_for_the_same_input() {
+        let inputs: &[&[u8]] = &[b"same", b"input"];
+        let sha_first = compute_root(inputs, HashAlgorithm::Sha256).expect("sha256 root");
+        let sha_second = compute_root(inputs, HashAlgorithm::Sha256).expect("sha256 root");
+        let blake_first = compute_root(inputs, HashAlgorithm::Blake3).expect("blake3 root");
+        let blake_second = compute_root(inputs, HashAlgorithm::Blake3).expect("blake3 root");
+
+        assert_eq!(sha_first, sha_second);
+        assert_eq!(blake_first, blake_second);
+        assert_ne!(sha_first, blake_first);
+    }
+}
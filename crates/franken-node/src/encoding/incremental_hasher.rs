@@ -0,0 +1,176 @@
+//! Streaming, chunk-boundary-independent hashing for large artifacts.
+//!
+//! Hashing a multi-gigabyte artifact before writing an
+//! `ArtifactJournalRecord` shouldn't require loading it into memory.
+//! [`IncrementalHasher`] accepts `update` calls with chunks of any size and
+//! produces the exact same digest as a single call over the concatenated
+//! bytes, regardless of how the caller split the stream.
+
+use super::hash_algorithm::{HashAlgorithm, HashAlgorithmError};
+use sha2::{Digest, Sha256};
+
+const SHA256_STREAM_DOMAIN: &[u8] = b"franken_node.encoding.incremental_hasher.sha256.v1";
+const BLAKE3_STREAM_DOMAIN: &[u8] = b"franken_node.encoding.incremental_hasher.blake3.v1";
+
+enum HasherState {
+    Sha256(Sha256),
+    #[cfg(feature = "blake3")]
+    Blake3(blake3::Hasher),
+}
+
+/// Chunked hasher for streaming a [`std::io::Read`] source through
+/// [`Self::update`] in fixed-size chunks without buffering the whole
+/// payload.
+pub struct IncrementalHasher {
+    state: HasherState,
+}
+
+impl IncrementalHasher {
+    /// Start a new incremental hash under `algorithm`.
+    pub fn new(algorithm: HashAlgorithm) -> Result<Self, HashAlgorithmError> {
+        match algorithm {
+            HashAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                hasher.update(SHA256_STREAM_DOMAIN);
+                Ok(Self {
+                    state: HasherState::Sha256(hasher),
+                })
+            }
+            HashAlgorithm::Blake3 => Self::new_blake3(),
+        }
+    }
+
+    #[cfg(feature = "blake3")]
+    fn new_blake3() -> Result<Self, HashAlgorithmError> {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(BLAKE3_STREAM_DOMAIN);
+        Ok(Self {
+            state: HasherState::Blake3(hasher),
+        })
+    }
+
+    #[cfg(not(feature = "blake3"))]
+    fn new_blake3() -> Result<Self, HashAlgorithmError> {
+        Err(HashAlgorithmError::Blake3Unavailable)
+    }
+
+    /// Feed the next chunk of the stream into the hash. May be called any
+    /// number of times with chunks of any size, including empty ones.
+    pub fn update(&mut self, chunk: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha256(hasher) => hasher.update(chunk),
+            #[cfg(feature = "blake3")]
+            HasherState::Blake3(hasher) => {
+                hasher.update(chunk);
+            }
+        }
+    }
+
+    /// Read `reader` to completion in `chunk_size`-byte reads, feeding each
+    /// through [`Self::update`], and return the finalized hex digest.
+    pub fn hash_reader<R: std::io::Read>(
+        mut self,
+        reader: &mut R,
+        chunk_size: usize,
+    ) -> std::io::Result<String> {
+        let mut buf = vec![0u8; chunk_size.max(1)];
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            self.update(&buf[..n]);
+        }
+        Ok(self.finalize())
+    }
+
+    /// Finalize the hash and return the hex digest.
+    pub fn finalize(self) -> String {
+        match self.state {
+            HasherState::Sha256(hasher) => hex::encode(hasher.finalize()),
+            #[cfg(feature = "blake3")]
+            HasherState::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_in_chunks(algorithm: HashAlgorithm, data: &[u8], chunk_sizes: &[usize]) -> String {
+        let mut hasher = IncrementalHasher::new(algorithm).expect("algorithm should be available");
+        let mut offset = 0;
+        for &size in chunk_sizes {
+            let end = (offset + size).min(data.len());
+            hasher.update(&data[offset..end]);
+            offset = end;
+        }
+        if offset < data.len() {
+            hasher.update(&data[offset..]);
+        }
+        hasher.finalize()
+    }
+
+    #[test]
+    fn sha256_digest_is_independent_of_chunk_boundaries() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+        let one_chunk = hash_in_chunks(HashAlgorithm::Sha256, &data, &[data.len()]);
+        let tiny_chunks = hash_in_chunks(HashAlgorithm::Sha256, &data, &vec![1; data.len()]);
+        let uneven_chunks = hash_in_chunks(HashAlgorithm::Sha256, &data, &[7, 3000, 1, 6992]);
+
+        assert_eq!(one_chunk, tiny_chunks);
+        assert_eq!(one_chunk, uneven_chunks);
+    }
+
+    #[test]
+    fn hash_reader_matches_manual_update_calls() {
+        let data: Vec<u8> = (0..5_000u32).map(|i| (i % 199) as u8).collect();
+
+        let mut manual = IncrementalHasher::new(HashAlgorithm::Sha256).expect("sha256 available");
+        for chunk in data.chunks(64) {
+            manual.update(chunk);
+        }
+        let manual_digest = manual.finalize();
+
+        let via_reader = IncrementalHasher::new(HashAlgorithm::Sha256)
+            .expect("sha256 available")
+            .hash_reader(&mut data.as_slice(), 17)
+            .expect("reading from a slice cannot fail");
+
+        assert_eq!(manual_digest, via_reader);
+    }
+
+    #[test]
+    fn empty_stream_produces_a_stable_digest() {
+        let first = IncrementalHasher::new(HashAlgorithm::Sha256)
+            .expect("sha256 available")
+            .finalize();
+        let second = IncrementalHasher::new(HashAlgorithm::Sha256)
+            .expect("sha256 available")
+            .finalize();
+        assert_eq!(first, second);
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_digest_is_independent_of_chunk_boundaries() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+        let one_chunk = hash_in_chunks(HashAlgorithm::Blake3, &data, &[data.len()]);
+        let tiny_chunks = hash_in_chunks(HashAlgorithm::Blake3, &data, &vec![1; data.len()]);
+        let uneven_chunks = hash_in_chunks(HashAlgorithm::Blake3, &data, &[7, 3000, 1, 6992]);
+
+        assert_eq!(one_chunk, tiny_chunks);
+        assert_eq!(one_chunk, uneven_chunks);
+    }
+
+    #[cfg(not(feature = "blake3"))]
+    #[test]
+    fn blake3_is_rejected_when_the_feature_is_not_compiled_in() {
+        let err = IncrementalHasher::new(HashAlgorithm::Blake3)
+            .expect_err("blake3 must be unavailable without the feature");
+        assert_eq!(err, HashAlgorithmError::Blake3Unavailable);
+    }
+}
@@ -1,4 +1,7 @@
+pub mod canonical_json;
 pub mod deterministic_seed;
+pub mod hash_algorithm;
+pub mod incremental_hasher;
 
 #[cfg(test)]
 pub mod additional_edge_tests;
@@ -101,8 +101,8 @@ use crate::api::{
     },
     middleware::{AuthIdentity, AuthMethod, TraceContext},
     trust_card_routes::{
-        Pagination, compare_trust_card_versions, compare_trust_cards, get_trust_card,
-        get_trust_cards_by_publisher, list_trust_cards, search_trust_cards,
+        ApiResponse, Pagination, compare_trust_card_versions, compare_trust_cards,
+        get_trust_card, get_trust_cards_by_publisher, list_trust_cards, search_trust_cards,
     },
 };
 use crate::cli::{
@@ -116,9 +116,9 @@ use crate::cli::{
     ProofWorkersCommand, ProofWorkersRestartArgs, ProofsCommand, RegistryCommand, RemoteCapCommand,
     RemoteCapIssueArgs, RemoteCapRevokeArgs, RemoteCapUseArgs, RemoteCapVerifyArgs, RuntimeCommand,
     RuntimeLaneCommand, SafeModeCommand, SafeModeEnterArgs, SafeModeExitArgs, SafeModeStatusArgs,
-    TrustCardCommand, TrustCommand, VerifyCommand, VerifyCompatibilityArgs, VerifyCorpusArgs,
-    VerifyMigrationArgs, VerifyModuleArgs, VerifyRecoveryRunbookArgs, VerifyReleaseArgs,
-    VerifyTransparencyLogArgs, load_doctor_policy_activation_input,
+    TrustCardCommand, TrustCommand, VerifyBlockingFloor, VerifyCommand, VerifyCompatibilityArgs,
+    VerifyCorpusArgs, VerifyMigrationArgs, VerifyModuleArgs, VerifyRecoveryRunbookArgs,
+    VerifyReleaseArgs, VerifyTransparencyLogArgs, load_doctor_policy_activation_input,
 };
 use crate::ops::workspace_pressure_policy::WorkspacePressureInputs;
 use crate::policy::{
@@ -166,6 +166,7 @@ use frankenengine_node::{
             CapabilityGate, CapabilityProvider, RemoteCap, RemoteCapError, RemoteOperation,
             RemoteScope,
         },
+        ssrf_policy::SsrfPolicyTemplate,
     },
     supply_chain::category_shift::validate_benchmark_thresholds,
     supply_chain::{
@@ -180,8 +181,9 @@ use frankenengine_node::{
             DependencyTrustStatus, ExtensionIdentity, ProvenanceSummary, PublisherIdentity,
             ReputationTrend, RevocationStatus, RiskAssessment, RiskLevel, SnapshotSourceContext,
             TrustCard, TrustCardError, TrustCardInput, TrustCardListFilter, TrustCardMutation,
-            TrustCardRegistry, TrustCardSyncReport, render_comparison_human,
-            render_trust_card_human, to_canonical_json as trust_card_to_json,
+            TrustCardRegistry, TrustCardRegistrySnapshot, TrustCardRemoteSyncReport,
+            TrustCardSyncReport, render_comparison_human, render_trust_card_human,
+            to_canonical_json as trust_card_to_json,
         },
     },
     tools::{
@@ -5443,6 +5445,10 @@ struct IncidentListEntry {
     path: String,
 }
 
+/// Severity label reported for a bundle that failed to read or verify, so
+/// `incident list` can degrade gracefully instead of aborting the whole scan.
+const INCIDENT_SEVERITY_CORRUPT: &str = "corrupt";
+
 fn normalize_incident_severity_label(raw: &str) -> Option<&'static str> {
     let normalized = raw.trim().to_ascii_lowercase();
     match normalized.as_str() {
@@ -5451,6 +5457,7 @@ fn normalize_incident_severity_label(raw: &str) -> Option<&'static str> {
         "high" => Some("high"),
         "critical" => Some("critical"),
         "unknown" => Some("unknown"),
+        "corrupt" => Some(INCIDENT_SEVERITY_CORRUPT),
         _ => None,
     }
 }
@@ -5461,7 +5468,7 @@ fn parse_incident_severity_filter(raw: Option<&str>) -> Result<Option<String>> {
             .map(str::to_string)
             .ok_or_else(|| {
                 anyhow::anyhow!(
-                    "invalid --severity `{value}`; expected one of: low, medium, high, critical, unknown"
+                    "invalid --severity `{value}`; expected one of: low, medium, high, critical, unknown, corrupt"
                 )
             })
     })
@@ -5564,26 +5571,44 @@ fn collect_incident_list_entries(
     let trusted_key_id = signing_material_key_id(&trusted_signing_material);
 
     for path in bundle_paths {
-        let bundle = read_bundle_from_path_with_trusted_key(&path, Some(&trusted_key_id))
-            .with_context(|| format!("failed reading incident bundle {}", path.display()))?;
-        let severity = infer_incident_bundle_severity(&bundle);
-        if let Some(filter) = severity_filter
-            && severity != filter
-        {
-            continue;
-        }
         let display_path = path
             .strip_prefix(root)
             .unwrap_or(&path)
             .display()
             .to_string();
-        entries.push(IncidentListEntry {
-            incident_id: bundle.incident_id,
-            severity,
-            event_count: bundle.manifest.event_count,
-            created_at: bundle.created_at,
-            path: display_path,
-        });
+
+        // An unreadable or tamper-evident bundle should not abort the whole
+        // listing; report it as corrupt so operators can see the rest.
+        let entry = match read_bundle_from_path_with_trusted_key(&path, Some(&trusted_key_id)) {
+            Ok(bundle) => {
+                let severity = infer_incident_bundle_severity(&bundle);
+                IncidentListEntry {
+                    incident_id: bundle.incident_id,
+                    severity,
+                    event_count: bundle.manifest.event_count,
+                    created_at: bundle.created_at,
+                    path: display_path,
+                }
+            }
+            Err(_) => IncidentListEntry {
+                incident_id: path
+                    .file_stem()
+                    .and_then(std::ffi::OsStr::to_str)
+                    .unwrap_or("unknown")
+                    .to_string(),
+                severity: INCIDENT_SEVERITY_CORRUPT.to_string(),
+                event_count: 0,
+                created_at: String::new(),
+                path: display_path,
+            },
+        };
+
+        if let Some(filter) = severity_filter
+            && entry.severity != filter
+        {
+            continue;
+        }
+        entries.push(entry);
     }
 
     entries.sort_by(|left, right| {
@@ -7317,6 +7342,64 @@ fn init_target_paths(out_dir: &Path) -> (PathBuf, PathBuf) {
     )
 }
 
+/// Starter template for the security-critical inventory: operators record
+/// the connectors, extensions, and credentials that require elevated
+/// scrutiny during audits and trust reviews. `init` only scaffolds the
+/// empty structure; populating it is part of onboarding the deployment.
+const SECURITY_CRITICAL_INVENTORY_TEMPLATE: &str = r#"# Security-critical inventory
+#
+# List the connectors, extensions, and credentials in this deployment
+# that require elevated scrutiny during audits and trust reviews. Left
+# empty by `init` -- populate it as part of onboarding.
+[[assets]]
+# name = "example-connector"
+# kind = "connector"
+# owner = "team-name"
+# notes = "why this asset is security-critical"
+"#;
+
+fn init_security_scaffold_paths(out_dir: &Path) -> (PathBuf, PathBuf) {
+    (
+        out_dir.join("ssrf_policy.toml"),
+        out_dir.join("security_critical_inventory.toml"),
+    )
+}
+
+/// Scaffolds the default SSRF egress policy and the starter
+/// security-critical inventory into `out_dir`, honoring the same
+/// overwrite/backup-existing write policy as the config and profile
+/// examples files. `connector_id` seeds the SSRF policy template's
+/// identifying field; callers typically pass the init trace id.
+fn scaffold_security_files(
+    out_dir: &Path,
+    connector_id: &str,
+    overwrite: bool,
+    backup_existing: bool,
+    timestamp_suffix: &str,
+) -> Result<Vec<InitFileAction>> {
+    let (ssrf_path, inventory_path) = init_security_scaffold_paths(out_dir);
+    let ssrf_policy = SsrfPolicyTemplate::default_template(connector_id.to_string());
+    let ssrf_toml =
+        toml::to_string_pretty(&ssrf_policy).context("failed serializing default SSRF policy")?;
+
+    Ok(vec![
+        apply_init_write_policy(
+            &ssrf_path,
+            &ssrf_toml,
+            overwrite,
+            backup_existing,
+            timestamp_suffix,
+        )?,
+        apply_init_write_policy(
+            &inventory_path,
+            SECURITY_CRITICAL_INVENTORY_TEMPLATE,
+            overwrite,
+            backup_existing,
+            timestamp_suffix,
+        )?,
+    ])
+}
+
 fn build_init_report(
     trace_id: &str,
     resolved: &config::ResolvedConfig,
@@ -12719,6 +12802,41 @@ mod init_tests {
             "old"
         );
     }
+
+    #[test]
+    fn scaffold_security_files_creates_expected_files_in_empty_dir() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let actions = scaffold_security_files(dir.path(), "init:trace-1", false, false, "t0")
+            .expect("scaffold into empty dir");
+        assert_eq!(actions.len(), 2);
+        assert!(
+            actions
+                .iter()
+                .all(|a| a.action == InitFileActionKind::Created)
+        );
+
+        let (ssrf_path, inventory_path) = init_security_scaffold_paths(dir.path());
+        assert!(ssrf_path.exists());
+        assert!(inventory_path.exists());
+        assert!(
+            std::fs::read_to_string(&ssrf_path)
+                .expect("read should succeed")
+                .contains("connector_id")
+        );
+    }
+
+    #[test]
+    fn scaffold_security_files_rejects_rerun_without_overwrite() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        scaffold_security_files(dir.path(), "init:trace-1", false, false, "t0")
+            .expect("first scaffold succeeds");
+        let err = scaffold_security_files(dir.path(), "init:trace-1", false, false, "t1")
+            .expect_err("rerun without --overwrite should fail");
+        assert!(
+            err.to_string()
+                .contains("without --overwrite or --backup-existing")
+        );
+    }
 }
 
 #[cfg(test)]
@@ -13880,6 +13998,23 @@ mod trust_command_tests {
         assert!(rendered.contains("quarantined=1"));
     }
 
+    #[test]
+    fn render_trust_remote_sync_summary_reports_all_counters() {
+        let report = TrustCardRemoteSyncReport {
+            additions: 1,
+            updates: 2,
+            revocations: 3,
+            unchanged: 4,
+        };
+
+        let rendered = render_trust_remote_sync_summary(&report);
+
+        assert!(rendered.contains("additions=1"));
+        assert!(rendered.contains("updates=2"));
+        assert!(rendered.contains("revocations=3"));
+        assert!(rendered.contains("unchanged=4"));
+    }
+
     #[test]
     fn trust_revoke_uses_logical_now_secs_for_timestamps() {
         let now_secs = 1_700_000_123;
@@ -15498,7 +15633,7 @@ mod incident_list_tests {
     fn parse_incident_severity_filter_rejects_unknown_values() {
         let err = parse_incident_severity_filter(Some("severe")).expect_err("must fail");
         assert!(err.to_string().contains(
-            "invalid --severity `severe`; expected one of: low, medium, high, critical, unknown"
+            "invalid --severity `severe`; expected one of: low, medium, high, critical, unknown, corrupt"
         ));
     }
 
@@ -15533,6 +15668,43 @@ mod incident_list_tests {
         assert!(high[0].path.ends_with("high-incident.fnbundle"));
     }
 
+    #[test]
+    fn collect_incident_list_entries_degrades_corrupt_bundle_to_corrupt_status() {
+        let _lock = cwd_test_lock()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        let previous_cwd = std::env::current_dir().expect("cwd");
+        configure_incident_test_signing_key(root);
+
+        let good_path = root.join("good-incident.fnbundle");
+        let corrupt_path = root.join("corrupt-incident.fnbundle");
+        write_fixture_bundle(&good_path, "INC-GOOD-001", "high");
+        write_fixture_bundle(&corrupt_path, "INC-CORRUPT-001", "high");
+        corrupt_bundle_integrity_hash(&corrupt_path);
+        std::env::set_current_dir(root).expect("set cwd");
+
+        let result = collect_incident_list_entries(root, None);
+        let restore_result = std::env::set_current_dir(&previous_cwd);
+
+        restore_result.expect("restore cwd");
+        let entries = result.expect("listing degrades rather than aborting");
+        assert_eq!(entries.len(), 2);
+
+        let corrupt = entries
+            .iter()
+            .find(|entry| entry.path.ends_with("corrupt-incident.fnbundle"))
+            .expect("corrupt entry present");
+        assert_eq!(corrupt.severity, "corrupt");
+
+        let good = entries
+            .iter()
+            .find(|entry| entry.path.ends_with("good-incident.fnbundle"))
+            .expect("good entry present");
+        assert_eq!(good.severity, "high");
+    }
+
     #[test]
     fn render_incident_list_handles_empty_results() {
         let rendered = render_incident_list(&[], Some("critical"));
@@ -21028,6 +21200,13 @@ fn render_trust_sync_summary(
     )
 }
 
+fn render_trust_remote_sync_summary(report: &TrustCardRemoteSyncReport) -> String {
+    format!(
+        "remote sync completed: additions={} updates={} revocations={} unchanged={}",
+        report.additions, report.updates, report.revocations, report.unchanged
+    )
+}
+
 fn trust_sync_card_needs_network_refresh(
     card: &TrustCard,
     now_secs: u64,
@@ -27484,7 +27663,13 @@ fn handle_trust_card_command(command: TrustCardCommand) -> Result<()> {
                 per_page: args.per_page,
             };
             let response = if let Some(query) = args.query.as_deref() {
-                search_trust_cards(&mut state.registry, query, now_secs, trace_id, pagination)?
+                let ranked =
+                    search_trust_cards(&mut state.registry, query, now_secs, trace_id, pagination)?;
+                ApiResponse {
+                    ok: ranked.ok,
+                    data: ranked.data.into_iter().map(|result| result.card).collect(),
+                    page: ranked.page,
+                }
             } else if let Some(publisher_id) = args.publisher.as_deref() {
                 get_trust_cards_by_publisher(
                     &mut state.registry,
@@ -27501,6 +27686,7 @@ fn handle_trust_card_command(command: TrustCardCommand) -> Result<()> {
                     &TrustCardListFilter::empty(),
                     now_secs,
                     pagination,
+                    None,
                 )?
             };
 
@@ -28089,9 +28275,10 @@ fn main() -> Result<()> {
                     format!("failed creating init output dir {}", out_dir.display())
                 })?;
                 let (config_path, profile_path) = init_target_paths(out_dir);
+                let (ssrf_path, inventory_path) = init_security_scaffold_paths(out_dir);
 
                 if !overwrite && !backup_existing {
-                    let existing = [&config_path, &profile_path]
+                    let existing = [&config_path, &profile_path, &ssrf_path, &inventory_path]
                         .into_iter()
                         .filter(|path| path.exists())
                         .map(|path| path.display().to_string())
@@ -28119,6 +28306,13 @@ fn main() -> Result<()> {
                     backup_existing,
                     &backup_suffix,
                 )?);
+                file_actions.extend(scaffold_security_files(
+                    out_dir,
+                    &format!("init:{trace_id}"),
+                    overwrite,
+                    backup_existing,
+                    &backup_suffix,
+                )?);
             } else {
                 wrote_to_stdout = true;
                 stdout_config_toml = Some(config_toml.clone());
@@ -28355,11 +28549,19 @@ fn main() -> Result<()> {
                     )
                 })?;
                 let rendered = migration::render_audit_report(&report, format)?;
+                let has_critical_findings = report.has_critical_findings();
 
                 if let Some(out_path) = emit_migration_audit_report(&rendered, args.out.as_deref())?
                 {
                     eprintln!("migration audit report written: {}", out_path.display());
                 }
+
+                if has_critical_findings {
+                    anyhow::bail!(
+                        "migration audit found critical findings for {}",
+                        args.project_path.display()
+                    );
+                }
             }
             MigrateCommand::Rewrite(args) => {
                 let report =
@@ -28456,11 +28658,24 @@ fn main() -> Result<()> {
                     .map(|s| s.trim().to_string())
                     .collect();
                 let harness = runtime::lockstep_harness::LockstepHarness::new(runtimes);
+                let blocking_floor = match args.blocking_floor {
+                    VerifyBlockingFloor::Info => runtime::nversion_oracle::RiskTier::Info,
+                    VerifyBlockingFloor::Low => runtime::nversion_oracle::RiskTier::Low,
+                    VerifyBlockingFloor::Medium => runtime::nversion_oracle::RiskTier::Medium,
+                    VerifyBlockingFloor::High => runtime::nversion_oracle::RiskTier::High,
+                    VerifyBlockingFloor::Critical => runtime::nversion_oracle::RiskTier::Critical,
+                };
+                let options = runtime::lockstep_harness::LockstepVerifyOptions {
+                    emit_fixtures: args.emit_fixtures,
+                    json: args.json,
+                    quorum_threshold_percent: args.quorum_threshold,
+                    blocking_floor,
+                };
                 eprintln!(
                     "Running lockstep verification on {}",
                     args.project_path.display()
                 );
-                if let Err(e) = harness.verify_lockstep(&args.project_path, args.emit_fixtures) {
+                if let Err(e) = harness.verify_lockstep(&args.project_path, &options) {
                     eprintln!("Lockstep harness failed: {}", e);
                     std::process::exit(1);
                 }
@@ -28579,6 +28794,13 @@ fn main() -> Result<()> {
                 handle_trust_release_command(&args)?;
             }
             TrustCommand::Sync(args) => {
+                // Prepare receipt export context upfront - fails immediately if receipt export
+                // is requested but signing material is unavailable (sign-or-fail).
+                let receipt_export_ctx = prepare_receipt_export_context(
+                    args.receipt_out.as_deref(),
+                    args.receipt_summary_out.as_deref(),
+                    args.receipt_signing_key.as_deref(),
+                )?;
                 let now_secs = now_unix_secs();
                 let mut state = trust_card_cli_registry(now_secs)?;
                 let sync_report = state
@@ -28591,7 +28813,30 @@ fn main() -> Result<()> {
                     args.force,
                     fetch_trust_scan_audit_metadata,
                 );
-                if audit_report.refreshed_count > 0 {
+                let mut persist_needed = audit_report.refreshed_count > 0;
+
+                let remote_sync_report = match args.remote_snapshot.as_deref() {
+                    Some(remote_path) => {
+                        let registry_key = state.registry.registry_key().to_vec();
+                        let remote_snapshot =
+                            TrustCardRegistry::load_remote_snapshot(remote_path, &registry_key)
+                                .map_err(|err| anyhow::anyhow!(err.to_string()))?;
+                        let report = state
+                            .registry
+                            .sync_from_remote(
+                                &remote_snapshot,
+                                now_secs,
+                                "trace-cli-trust-sync",
+                                args.force,
+                            )
+                            .map_err(|err| anyhow::anyhow!(err.to_string()))?;
+                        persist_needed = true;
+                        Some(report)
+                    }
+                    None => None,
+                };
+
+                if persist_needed {
                     persist_trust_card_cli_registry(&state)?;
                 }
                 for warning in &audit_report.warnings {
@@ -28609,6 +28854,17 @@ fn main() -> Result<()> {
                     "{}",
                     render_trust_sync_summary(&cards, &sync_report, &audit_report, args.force)
                 );
+                if let Some(ref report) = remote_sync_report {
+                    println!("{}", render_trust_remote_sync_summary(report));
+                }
+                if let Some(ref ctx) = receipt_export_ctx {
+                    export_signed_receipts(
+                        "sync",
+                        "trust-control-plane",
+                        "Trust-card sync decision exported for audit traceability",
+                        ctx,
+                    )?;
+                }
             }
         },
 
@@ -3,6 +3,8 @@
 //! This module hosts deterministic migration policy gates used to decide
 //! whether topology risk deltas are acceptable before and during rollout.
 
+use frankenengine_node::runtime::authority_audit::{AmbientAuthorityPattern, builtin_patterns};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::BTreeSet;
@@ -200,6 +202,17 @@ pub struct MigrationAuditReport {
     pub findings: Vec<MigrationAuditFinding>,
 }
 
+impl MigrationAuditReport {
+    /// Whether any finding is severe enough to block migration (this crate's
+    /// top severity tier, `High`, e.g. ambient `std::net::` usage).
+    #[must_use]
+    pub fn has_critical_findings(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|finding| finding.severity == MigrationSeverity::High)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum MigrationRewriteAction {
@@ -213,6 +226,8 @@ pub enum MigrationRewriteAction {
     ManifestReadError,
     ManifestParseError,
     NoPackageManifest,
+    RewriteAmbientAuthority,
+    AmbientAuthorityChecksumMismatch,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -222,6 +237,11 @@ pub struct MigrationRewriteEntry {
     pub action: MigrationRewriteAction,
     pub detail: String,
     pub applied: bool,
+    /// Unified diff of the rewrite, present for entries that touch file
+    /// content (dry-run preview); `None` for manifest/manual-review entries
+    /// that do not carry a textual rewrite.
+    #[serde(default)]
+    pub diff: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -655,6 +675,7 @@ pub fn run_audit(project_path: &Path) -> anyhow::Result<MigrationAuditReport> {
     let mut lockfiles = BTreeSet::new();
     let mut scripts_flagged = 0_usize;
     let mut engine_gaps = 0_usize;
+    let ambient_authority_patterns = compiled_ambient_authority_patterns();
 
     for path in files {
         summary.files_scanned = summary.files_scanned.saturating_add(1);
@@ -682,6 +703,28 @@ pub fn run_audit(project_path: &Path) -> anyhow::Result<MigrationAuditReport> {
                     summary.js_files = summary.js_files.saturating_add(1)
                 }
                 "ts" | "tsx" => summary.ts_files = summary.ts_files.saturating_add(1),
+                "rs" => match read_file_bounded(&path) {
+                    Ok(content) => scan_rust_ambient_authority(
+                        &relative_path,
+                        &content,
+                        &ambient_authority_patterns,
+                        &mut findings,
+                    ),
+                    Err(err) => {
+                        push_bounded(
+                            &mut findings,
+                            MigrationAuditFinding {
+                                id: String::new(),
+                                category: MigrationCategory::Runtime,
+                                severity: MigrationSeverity::Info,
+                                message: format!("failed to read Rust source file: {err}"),
+                                path: Some(relative_path.clone()),
+                                recommendation: None,
+                            },
+                            MAX_TOTAL_FINDINGS,
+                        );
+                    }
+                },
                 _ => {}
             }
         }
@@ -745,6 +788,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                             action: MigrationRewriteAction::ManifestReadError,
                             detail: format!("unable to read package manifest: {err}"),
                             applied: false,
+                            diff: None,
                         },
                         MAX_TOTAL_FINDINGS,
                     );
@@ -764,6 +808,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                             action: MigrationRewriteAction::ManifestParseError,
                             detail: format!("package manifest JSON parse failed: {err}"),
                             applied: false,
+                            diff: None,
                         },
                         MAX_TOTAL_FINDINGS,
                     );
@@ -781,6 +826,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                         action: MigrationRewriteAction::ManualScriptReview,
                         detail: format!("script `{script_name}` requires manual hardening review"),
                         applied: false,
+                        diff: None,
                     },
                     MAX_TOTAL_FINDINGS,
                 );
@@ -828,6 +874,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                                 "set engines.node to >=20 <23 to reduce migration runtime drift"
                                     .to_string(),
                             applied: apply,
+                            diff: None,
                         },
                         MAX_TOTAL_FINDINGS,
                     );
@@ -848,6 +895,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                                     rewritten_command
                                 ),
                                 applied: apply,
+                                diff: None,
                             },
                             MAX_TOTAL_FINDINGS,
                         );
@@ -884,6 +932,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                             "unable to read JavaScript source for module rewrite: {err}"
                         ),
                         applied: false,
+                        diff: None,
                     },
                     MAX_TOTAL_FINDINGS,
                 );
@@ -931,6 +980,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                     action: MigrationRewriteAction::ManualModuleReview,
                     detail,
                     applied: false,
+                    diff: None,
                 },
                 MAX_TOTAL_FINDINGS,
             );
@@ -952,6 +1002,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                     action: rewrite_action,
                     detail: rewrite_detail,
                     applied: apply,
+                    diff: Some(unified_diff(&relative_path, &raw, &rewritten_content)),
                 },
                 MAX_TOTAL_FINDINGS,
             );
@@ -967,6 +1018,76 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
         }
     }
 
+    for candidate in collect_ambient_authority_rewrites(project_path)? {
+        rewrites_planned = rewrites_planned.saturating_add(1);
+        let diff = unified_diff(
+            &candidate.relative_path,
+            &candidate.original_content,
+            &candidate.rewritten_content,
+        );
+
+        if !apply {
+            push_bounded(
+                &mut entries,
+                MigrationRewriteEntry {
+                    id: String::new(),
+                    path: Some(candidate.relative_path.clone()),
+                    action: MigrationRewriteAction::RewriteAmbientAuthority,
+                    detail: format!(
+                        "rewrote {} `std::env::var` call(s) to `capability_context.env_var(...)` (dry run)",
+                        candidate.rewrite_count
+                    ),
+                    applied: false,
+                    diff: Some(diff),
+                },
+                MAX_TOTAL_FINDINGS,
+            );
+            continue;
+        }
+
+        if apply_ambient_authority_rewrite(project_path, &candidate)? {
+            rewrites_applied = rewrites_applied.saturating_add(1);
+            push_bounded(
+                &mut entries,
+                MigrationRewriteEntry {
+                    id: String::new(),
+                    path: Some(candidate.relative_path.clone()),
+                    action: MigrationRewriteAction::RewriteAmbientAuthority,
+                    detail: format!(
+                        "rewrote {} `std::env::var` call(s) to `capability_context.env_var(...)`",
+                        candidate.rewrite_count
+                    ),
+                    applied: true,
+                    diff: Some(diff),
+                },
+                MAX_TOTAL_FINDINGS,
+            );
+            push_bounded(
+                &mut rollback_entries,
+                MigrationRollbackEntry {
+                    path: candidate.relative_path,
+                    original_content: candidate.original_content,
+                    rewritten_content: candidate.rewritten_content,
+                },
+                MAX_TOTAL_FINDINGS,
+            );
+        } else {
+            manual_review_items = manual_review_items.saturating_add(1);
+            push_bounded(
+                &mut entries,
+                MigrationRewriteEntry {
+                    id: String::new(),
+                    path: Some(candidate.relative_path),
+                    action: MigrationRewriteAction::AmbientAuthorityChecksumMismatch,
+                    detail: "file changed since the rewrite was scanned; apply refused to avoid clobbering the newer content".to_string(),
+                    applied: false,
+                    diff: Some(diff),
+                },
+                MAX_TOTAL_FINDINGS,
+            );
+        }
+    }
+
     if package_manifests_scanned == 0 {
         manual_review_items = manual_review_items.saturating_add(1);
         push_bounded(
@@ -977,6 +1098,7 @@ pub fn run_rewrite(project_path: &Path, apply: bool) -> anyhow::Result<Migration
                 action: MigrationRewriteAction::NoPackageManifest,
                 detail: "no package.json files found; manifest pin rewrite unavailable".to_string(),
                 applied: false,
+                diff: None,
             },
             MAX_TOTAL_FINDINGS,
         );
@@ -1065,6 +1187,9 @@ pub fn render_rewrite_report(report: &MigrationRewriteReport) -> String {
                 .map_or_else(String::new, |path| format!(" (path: {path})")),
             entry.applied
         );
+        if let Some(diff) = &entry.diff {
+            let _ = write!(&mut output, "{diff}");
+        }
     }
 
     output
@@ -4290,6 +4415,7 @@ fn build_module_graph_entry(
             overflow_detail
         ),
         applied: false,
+        diff: None,
     })
 }
 
@@ -4628,6 +4754,193 @@ fn append_summary_findings(
     }
 }
 
+fn compiled_ambient_authority_patterns() -> Vec<(Regex, AmbientAuthorityPattern)> {
+    builtin_patterns()
+        .into_iter()
+        .map(|pattern| {
+            let regex = Regex::new(&pattern.pattern)
+                .expect("builtin ambient authority pattern must compile");
+            (regex, pattern)
+        })
+        .collect()
+}
+
+fn ambient_authority_severity(pattern_severity: &str) -> MigrationSeverity {
+    match pattern_severity {
+        "critical" | "high" => MigrationSeverity::High,
+        "medium" => MigrationSeverity::Medium,
+        "low" => MigrationSeverity::Low,
+        _ => MigrationSeverity::Info,
+    }
+}
+
+/// Scan a Rust source file for ambient-authority anti-patterns (reusing
+/// `authority_audit::builtin_patterns`), appending one finding per matching
+/// line with a `path:line` location.
+fn scan_rust_ambient_authority(
+    relative_path: &str,
+    content: &str,
+    patterns: &[(Regex, AmbientAuthorityPattern)],
+    findings: &mut Vec<MigrationAuditFinding>,
+) {
+    for (line_number, line) in content.lines().enumerate() {
+        for (regex, pattern) in patterns {
+            if regex.is_match(line) {
+                push_bounded(
+                    findings,
+                    MigrationAuditFinding {
+                        id: String::new(),
+                        category: MigrationCategory::Runtime,
+                        severity: ambient_authority_severity(&pattern.severity),
+                        message: format!("{} ({})", pattern.description, pattern.id),
+                        path: Some(format!(
+                            "{relative_path}:{}",
+                            line_number.saturating_add(1)
+                        )),
+                        recommendation: Some(
+                            "Route this capability through a capability-gated wrapper instead of ambient std access."
+                                .to_string(),
+                        ),
+                    },
+                    MAX_TOTAL_FINDINGS,
+                );
+            }
+        }
+    }
+}
+
+/// A pending rewrite of ambient-authority usage in a Rust source file,
+/// produced by [`collect_ambient_authority_rewrites`]. Carries the sha256
+/// checksum of `original_content` at scan time so [`apply_ambient_authority_rewrite`]
+/// can detect the file changing underneath it and refuse to clobber it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AmbientAuthorityRewriteCandidate {
+    relative_path: String,
+    original_content: String,
+    rewritten_content: String,
+    rewrite_count: usize,
+    checksum_sha256: String,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Rewrite direct `std::env::var(...)` calls (the `AA-PAT-001` ambient
+/// authority pattern) to `capability_context.env_var(...)`, threading
+/// environment access through an explicit capability context instead of
+/// ambient `std::env`. Usages that aren't a direct call (e.g. `use
+/// std::env::var;` or a path reference passed as a function value) are left
+/// untouched and returned as manual-finding notes in the third tuple
+/// element, since rewriting them safely
+/// would require knowing where `capability_context` is bound in scope.
+fn rewrite_std_env_var_usage(content: &str) -> (String, usize, Vec<String>) {
+    let call_pattern =
+        Regex::new(r"std::env::var\s*\(").expect("std::env::var call regex must compile");
+    let bare_pattern = Regex::new(r"std::env::var\b").expect("std::env::var regex must compile");
+    let mut rewritten = String::with_capacity(content.len());
+    let mut rewrite_count = 0_usize;
+    let mut manual_findings = Vec::new();
+
+    for (line_number, line) in content.split_inclusive('\n').enumerate() {
+        let (body, line_ending) = split_line_ending(line);
+        if call_pattern.is_match(body) {
+            rewritten.push_str(&call_pattern.replace_all(body, "capability_context.env_var("));
+            rewrite_count = rewrite_count.saturating_add(1);
+        } else {
+            if bare_pattern.is_match(body) {
+                manual_findings.push(format!(
+                    "line {}: `std::env::var` used outside a direct call form; requires manual rewrite to capability-context access",
+                    line_number.saturating_add(1)
+                ));
+            }
+            rewritten.push_str(body);
+        }
+        rewritten.push_str(line_ending);
+    }
+
+    (rewritten, rewrite_count, manual_findings)
+}
+
+/// Scan a project for Rust source files with rewritable `std::env::var`
+/// ambient-authority usage, without touching any files. Each candidate
+/// records the sha256 of the file's content at scan time, so a later apply
+/// pass can detect the file changing out from under it.
+fn collect_ambient_authority_rewrites(
+    project_path: &Path,
+) -> anyhow::Result<Vec<AmbientAuthorityRewriteCandidate>> {
+    let mut candidates = Vec::new();
+
+    for path in collect_project_files(project_path)? {
+        if path.extension().and_then(std::ffi::OsStr::to_str) != Some("rs") {
+            continue;
+        }
+        let relative_path = relative_display(project_path, &path);
+        let Ok(content) = read_file_bounded(&path) else {
+            continue;
+        };
+        let (rewritten_content, rewrite_count, _manual_findings) =
+            rewrite_std_env_var_usage(&content);
+        if rewrite_count == 0 {
+            continue;
+        }
+        candidates.push(AmbientAuthorityRewriteCandidate {
+            checksum_sha256: sha256_hex(content.as_bytes()),
+            relative_path,
+            original_content: content,
+            rewritten_content,
+            rewrite_count,
+        });
+    }
+
+    Ok(candidates)
+}
+
+/// Apply a single [`AmbientAuthorityRewriteCandidate`], refusing if the file
+/// on disk no longer matches the checksum captured at scan time. Returns
+/// `Ok(true)` if the rewrite was applied, `Ok(false)` if it was refused due
+/// to a checksum mismatch.
+fn apply_ambient_authority_rewrite(
+    project_path: &Path,
+    candidate: &AmbientAuthorityRewriteCandidate,
+) -> anyhow::Result<bool> {
+    let path = project_path.join(&candidate.relative_path);
+    let current = read_file_bounded(&path)
+        .map_err(|err| anyhow::anyhow!("failed to re-read {}: {err}", candidate.relative_path))?;
+    if sha256_hex(current.as_bytes()) != candidate.checksum_sha256 {
+        return Ok(false);
+    }
+
+    write_migration_backup(project_path, &path, &current)?;
+    write_migration_file_atomically(project_path, &path, &candidate.rewritten_content)?;
+    Ok(true)
+}
+
+/// Render a minimal unified diff between `original` and `rewritten`, one
+/// hunk covering the full file (migration rewrites are small, targeted
+/// transforms, so a single hunk is clearer than line-level hunk-splitting).
+fn unified_diff(relative_path: &str, original: &str, rewritten: &str) -> String {
+    let original_lines: Vec<&str> = original.lines().collect();
+    let rewritten_lines: Vec<&str> = rewritten.lines().collect();
+
+    let mut diff = format!("--- a/{relative_path}\n+++ b/{relative_path}\n");
+    let _ = write!(
+        diff,
+        "@@ -1,{} +1,{} @@\n",
+        original_lines.len(),
+        rewritten_lines.len()
+    );
+
+    for line in &original_lines {
+        let _ = writeln!(diff, "-{line}");
+    }
+    for line in &rewritten_lines {
+        let _ = writeln!(diff, "+{line}");
+    }
+
+    diff
+}
+
 fn sort_and_assign_ids(findings: &mut [MigrationAuditFinding]) {
     findings.sort_by(|left, right| {
         right
@@ -4994,6 +5307,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_audit_flags_std_net_usage_in_rust_sources_as_critical() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let project = temp.path();
+
+        std::fs::write(
+            project.join("lib.rs"),
+            "fn connect() {\n    let _ = std::net::TcpStream::connect(\"example.invalid:80\");\n}\n",
+        )
+        .expect("write rust source");
+
+        let report = run_audit(project).expect("audit should succeed");
+
+        assert!(report.has_critical_findings());
+        let finding = report
+            .findings
+            .iter()
+            .find(|finding| finding.message.contains("std::net::"))
+            .expect("std::net finding present");
+        assert_eq!(finding.severity, MigrationSeverity::High);
+        assert_eq!(finding.category, MigrationCategory::Runtime);
+        assert_eq!(finding.path.as_deref(), Some("lib.rs:2"));
+    }
+
+    #[test]
+    fn run_audit_does_not_flag_clean_rust_sources() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let project = temp.path();
+
+        std::fs::write(
+            project.join("lib.rs"),
+            "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n",
+        )
+        .expect("write rust source");
+
+        let report = run_audit(project).expect("audit should succeed");
+
+        assert!(!report.has_critical_findings());
+        assert!(
+            report
+                .findings
+                .iter()
+                .filter(|finding| finding
+                    .path
+                    .as_deref()
+                    .is_some_and(|path| path.starts_with("lib.rs")))
+                .all(|finding| finding.category != MigrationCategory::Runtime)
+        );
+    }
+
     #[test]
     fn render_text_report_contains_summary_and_findings() {
         let report = MigrationAuditReport {
@@ -5112,6 +5475,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_rewrite_dry_run_previews_ambient_authority_diff_without_touching_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let project = temp.path();
+        let original = "fn connect() -> String {\n    std::env::var(\"API_TOKEN\").unwrap()\n}\n";
+        std::fs::write(project.join("lib.rs"), original).expect("write rust source");
+
+        let dry_run = run_rewrite(project, false).expect("dry-run rewrite");
+
+        assert_eq!(dry_run.rewrites_applied, 0);
+        let entry = dry_run
+            .entries
+            .iter()
+            .find(|entry| entry.action == MigrationRewriteAction::RewriteAmbientAuthority)
+            .expect("ambient authority rewrite entry present");
+        assert!(!entry.applied);
+        let diff = entry.diff.as_ref().expect("dry-run entry carries a diff");
+        assert!(diff.contains("-    std::env::var(\"API_TOKEN\").unwrap()"));
+        assert!(diff.contains("+    capability_context.env_var(\"API_TOKEN\").unwrap()"));
+
+        let on_disk = std::fs::read_to_string(project.join("lib.rs")).expect("read rust source");
+        assert_eq!(on_disk, original, "dry run must not mutate files");
+    }
+
+    #[test]
+    fn run_rewrite_applies_ambient_authority_rewrite() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let project = temp.path();
+        std::fs::write(
+            project.join("lib.rs"),
+            "fn connect() -> String {\n    std::env::var(\"API_TOKEN\").unwrap()\n}\n",
+        )
+        .expect("write rust source");
+
+        let applied = run_rewrite(project, true).expect("applied rewrite");
+
+        assert_eq!(applied.rewrites_applied, 1);
+        let on_disk = std::fs::read_to_string(project.join("lib.rs")).expect("read rust source");
+        assert!(on_disk.contains("capability_context.env_var(\"API_TOKEN\")"));
+        assert!(!on_disk.contains("std::env::var"));
+    }
+
+    #[test]
+    fn apply_ambient_authority_rewrite_aborts_on_checksum_mismatch() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let project = temp.path();
+        std::fs::write(
+            project.join("lib.rs"),
+            "fn connect() -> String {\n    std::env::var(\"API_TOKEN\").unwrap()\n}\n",
+        )
+        .expect("write rust source");
+
+        let candidates =
+            collect_ambient_authority_rewrites(project).expect("scan ambient rewrites");
+        assert_eq!(candidates.len(), 1);
+
+        // The file changes after the scan but before the apply.
+        std::fs::write(
+            project.join("lib.rs"),
+            "fn connect() -> String {\n    std::env::var(\"API_TOKEN\").unwrap() // edited\n}\n",
+        )
+        .expect("mutate rust source after scan");
+
+        let applied = apply_ambient_authority_rewrite(project, &candidates[0])
+            .expect("apply attempt should not error");
+        assert!(!applied, "checksum mismatch must abort the apply");
+
+        let on_disk = std::fs::read_to_string(project.join("lib.rs")).expect("read rust source");
+        assert!(
+            on_disk.contains("// edited"),
+            "the concurrently-written content must survive an aborted apply"
+        );
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(32))]
 
@@ -1891,6 +1891,7 @@ mod tests {
                 runtime_name: "bun".to_string(),
                 version: "1.0-test".to_string(),
                 is_reference: true,
+                engine_family: "bun".to_string(),
             })
             .unwrap();
         oracle
@@ -1899,6 +1900,7 @@ mod tests {
                 runtime_name: "franken-engine-native".to_string(),
                 version: "0.1-test".to_string(),
                 is_reference: false,
+                engine_family: "franken-engine-native".to_string(),
             })
             .unwrap();
         let mut outputs = std::collections::BTreeMap::new();
@@ -1945,6 +1947,7 @@ mod tests {
                 runtime_name: "bun".to_string(),
                 version: "1.0-test".to_string(),
                 is_reference: true,
+                engine_family: "bun".to_string(),
             })
             .unwrap();
         oracle
@@ -1953,6 +1956,7 @@ mod tests {
                 runtime_name: "franken-engine-native".to_string(),
                 version: "0.1-test".to_string(),
                 is_reference: false,
+                engine_family: "franken-engine-native".to_string(),
             })
             .unwrap();
         let mut outputs = std::collections::BTreeMap::new();
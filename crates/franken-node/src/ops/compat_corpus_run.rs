@@ -1806,6 +1806,7 @@ pub fn run_corpus(
                 runtime_name: "bun".to_string(),
                 version: bun_version.clone(),
                 is_reference: true,
+                engine_family: "bun".to_string(),
             })
             .map_err(|err| anyhow::anyhow!("oracle registration failed for bun: {err}"))?;
         oracle
@@ -1814,6 +1815,7 @@ pub fn run_corpus(
                 runtime_name: "franken-engine-native".to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 is_reference: false,
+                engine_family: "franken-engine-native".to_string(),
             })
             .map_err(|err| anyhow::anyhow!("oracle registration failed for franken leg: {err}"))?;
         let mut outputs = BTreeMap::new();
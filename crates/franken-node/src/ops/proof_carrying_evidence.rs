@@ -397,6 +397,7 @@ pub fn produce_lockstep_verdict_evidence() -> Result<LockstepVerdictEvidence> {
             runtime_name: "bun".to_string(),
             version: bun_version,
             is_reference: true,
+            engine_family: "bun".to_string(),
         })
         .map_err(|err| anyhow::anyhow!("oracle registration failed for bun: {err}"))?;
     oracle
@@ -405,6 +406,7 @@ pub fn produce_lockstep_verdict_evidence() -> Result<LockstepVerdictEvidence> {
             runtime_name: "franken-engine-native".to_string(),
             version: env!("CARGO_PKG_VERSION").to_string(),
             is_reference: false,
+            engine_family: "franken-engine-native".to_string(),
         })
         .map_err(|err| anyhow::anyhow!("oracle registration failed for franken leg: {err}"))?;
 
@@ -663,6 +665,7 @@ mod tests {
                 runtime_name: "bun".to_string(),
                 version: "1.0-test".to_string(),
                 is_reference: true,
+                engine_family: "bun".to_string(),
             })
             .expect("register bun");
         oracle
@@ -671,6 +674,7 @@ mod tests {
                 runtime_name: "franken-engine-native".to_string(),
                 version: "0.1-test".to_string(),
                 is_reference: false,
+                engine_family: "franken-engine-native".to_string(),
             })
             .expect("register franken leg");
         let mut outputs = std::collections::BTreeMap::new();
@@ -18,20 +18,44 @@ mod tests {
             arb_predicted_metrics(),
             "[a-z]{1,20}",
             "[a-z]{1,15}",
+            "[a-z]{1,10}",
+            arb_proposal_source(),
         )
             .prop_map(
-                |(id, knob, old_val, new_val, metrics, rationale, trace_id)| OptimizationProposal {
-                    proposal_id: id,
+                |(
+                    id,
                     knob,
-                    old_value: old_val,
-                    new_value: new_val,
-                    predicted: metrics,
+                    old_val,
+                    new_val,
+                    metrics,
                     rationale,
                     trace_id,
+                    submitted_by,
+                    source,
+                )| {
+                    OptimizationProposal {
+                        proposal_id: id,
+                        knob,
+                        old_value: old_val,
+                        new_value: new_val,
+                        predicted: metrics,
+                        rationale,
+                        trace_id,
+                        submitted_by,
+                        source,
+                    }
                 },
             )
     }
 
+    fn arb_proposal_source() -> impl Strategy<Value = ProposalSource> {
+        prop_oneof![
+            Just(ProposalSource::Human),
+            Just(ProposalSource::Autotuner),
+            Just(ProposalSource::Policy),
+        ]
+    }
+
     fn arb_predicted_metrics() -> impl Strategy<Value = PredictedMetrics> {
         (
             1u64..2000,   // latency_ms
@@ -12,8 +12,8 @@ mod metamorphic_tests;
 mod tests {
     use super::optimization_governor::{
         GOV_010_KNOB_DISPATCHED, GovernorDecision, GovernorGate, OptimizationGovernor,
-        OptimizationProposal, PredictedMetrics, RejectionReason, RuntimeKnob, error_codes,
-        event_codes,
+        OptimizationProposal, PredictedMetrics, ProposalSource, RejectionReason, RuntimeKnob,
+        error_codes, event_codes,
     };
 
     fn push_bounded<T>(items: &mut Vec<T>, item: T, cap: usize) {
@@ -61,6 +61,8 @@ mod tests {
             predicted,
             rationale: format!("negative perf module probe {id}"),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -277,8 +279,8 @@ mod tests {
 mod perf_module_negative_tests {
     use super::optimization_governor::{
         GOV_010_KNOB_DISPATCHED, GovernorDecision, GovernorGate, OptimizationGovernor,
-        OptimizationProposal, PredictedMetrics, RejectionReason, RuntimeKnob, SafetyEnvelope,
-        error_codes,
+        OptimizationProposal, PredictedMetrics, ProposalSource, RejectionReason, RuntimeKnob,
+        SafetyEnvelope, error_codes,
     };
 
     fn safe_metrics() -> PredictedMetrics {
@@ -299,6 +301,8 @@ mod perf_module_negative_tests {
             predicted: safe_metrics(),
             rationale: "negative-path governor probe".to_string(),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -548,8 +552,8 @@ mod perf_module_negative_tests {
 mod perf_module_dispatch_boundary_negative_tests {
     use super::optimization_governor::{
         DispatchHookPayload, GovernorDecision, GovernorGate, OptimizationGovernor,
-        OptimizationProposal, PredictedMetrics, RejectionReason, RuntimeKnob, SafetyEnvelope,
-        event_codes,
+        OptimizationProposal, PredictedMetrics, ProposalSource, RejectionReason, RuntimeKnob,
+        SafetyEnvelope, event_codes,
     };
     use std::collections::BTreeMap;
 
@@ -586,6 +590,8 @@ mod perf_module_dispatch_boundary_negative_tests {
             predicted,
             rationale: format!("negative dispatch boundary probe {id}"),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -748,7 +754,8 @@ mod perf_module_dispatch_boundary_negative_tests {
 mod perf_module_extreme_adversarial_negative_tests {
     use super::optimization_governor::{
         GovernorDecision, GovernorGate, OptimizationGovernor, OptimizationProposal,
-        PredictedMetrics, RejectionReason, RuntimeKnob, SafetyEnvelope, error_codes,
+        PredictedMetrics, ProposalSource, RejectionReason, RuntimeKnob, SafetyEnvelope,
+        error_codes,
     };
 
     fn push_bounded<T>(items: &mut Vec<T>, item: T, cap: usize) {
@@ -788,6 +795,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "unicode injection test".to_string(),
             trace_id: "trace-unicode-bomb".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gate.submit(candidate);
@@ -816,6 +825,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: massive_rationale,
             trace_id: "trace-memory-stress".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gate.submit(candidate);
@@ -843,6 +854,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "arithmetic boundary test".to_string(),
             trace_id: "trace-overflow".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gate.submit(candidate);
@@ -884,6 +897,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "contradictory envelope test".to_string(),
             trace_id: "trace-contradictory".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gate.submit(candidate);
@@ -917,6 +932,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "concurrent test 1".to_string(),
             trace_id: "trace-concurrent-1".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let proposal2 = OptimizationProposal {
@@ -932,6 +949,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "concurrent test 2".to_string(),
             trace_id: "trace-concurrent-2".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         // Simulate concurrent submission against shared state.
@@ -963,6 +982,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: format!("control{control_chars}rationale"),
             trace_id: format!("trace{control_chars}control"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gate.submit(candidate);
@@ -1011,6 +1032,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: nested.to_string(), // Massive nested JSON string
             trace_id: "trace-deep-json".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let mut gate = GovernorGate::with_defaults();
@@ -1050,6 +1073,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 predicted: metrics,
                 rationale: format!("edge case test {i}"),
                 trace_id: format!("trace-edge-{i}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gate.submit(candidate);
@@ -1081,6 +1106,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "normalization test NFC".to_string(),
             trace_id: format!("trace-{nfc_string}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         // bd-o776s: candidate1 applies ConcurrencyLimit 64 -> 128, so candidate2 must
@@ -1102,6 +1129,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "normalization test NFD".to_string(),
             trace_id: format!("trace-{nfd_string}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision1 = gate.submit(candidate1);
@@ -1131,6 +1160,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "cascade base".to_string(),
             trace_id: "trace-cascade-base".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
         assert!(matches!(gate.submit(initial), GovernorDecision::Approved));
 
@@ -1169,6 +1200,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 predicted: metrics,
                 rationale: format!("cascade attempt {i}"),
                 trace_id: format!("trace-cascade-{i}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gate.submit(cascade_candidate);
@@ -1214,6 +1247,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("hash collision test {}", i),
                 trace_id: format!("trace-{}-{}", hash_like, i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gate.submit(candidate.clone());
@@ -1297,6 +1332,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                         "timing test".to_string()
                     },
                     trace_id: format!("trace-short-{}-{}", case_name, iteration),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
 
                 let start = Instant::now();
@@ -1331,6 +1368,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                         "timing test".to_string()
                     },
                     trace_id: format!("trace-long-{}-{}", case_name, iteration),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
 
                 let start = Instant::now();
@@ -1391,6 +1430,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("fragmentation stress test iteration {}", i),
                 trace_id: format!("trace-frag-{}", i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let start = std::time::Instant::now();
@@ -1452,6 +1493,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "post cleanup test".to_string(),
             trace_id: "trace-post-cleanup".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let post_decision = gate.submit(post_cleanup_candidate);
@@ -1486,6 +1529,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("chain building {}", id),
                 trace_id: format!("trace-{}", id),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gate.submit(candidate);
@@ -1578,6 +1623,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                         },
                         rationale: format!("retry {} after revert {}", id, i),
                         trace_id: format!("trace-retry-{}-{}", id, i),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     };
                     let _decision = gate.submit(candidate);
                 }
@@ -1663,6 +1710,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "cascade error test base".to_string(),
             trace_id: "trace-cascade-base".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         assert!(matches!(
@@ -1687,6 +1736,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: "cascade NaN error".to_string(),
                 trace_id: "trace-cascade-nan".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             // Envelope violation
             OptimizationProposal {
@@ -1702,6 +1753,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: "cascade envelope violation".to_string(),
                 trace_id: "trace-cascade-envelope".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             // Stale old value
             OptimizationProposal {
@@ -1717,6 +1770,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: "cascade stale value".to_string(),
                 trace_id: "trace-cascade-stale".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
         ];
 
@@ -1776,6 +1831,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "cascade recovery test".to_string(),
             trace_id: "trace-cascade-recovery".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let recovery_decision = gate.submit(recovery_proposal);
@@ -1812,6 +1869,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: "x".repeat(data_multiplier), // Variable length rationale
                 trace_id: format!("trace_massive_{:06}", i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let iteration_start = std::time::Instant::now();
@@ -1889,6 +1948,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "post batch responsiveness test".to_string(),
             trace_id: "trace-post-batch".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let post_start = std::time::Instant::now();
@@ -2051,7 +2112,7 @@ mod perf_module_extreme_adversarial_negative_tests {
     #[test]
     fn extreme_adversarial_unicode_confusable_homograph_attack_in_knob_names() {
         use super::optimization_governor::{
-            GovernorGate, OptimizationProposal, PredictedMetrics, RuntimeKnob,
+            GovernorGate, OptimizationProposal, PredictedMetrics, ProposalSource, RuntimeKnob,
         };
 
         let mut gate = GovernorGate::with_defaults();
@@ -2119,6 +2180,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "legitimate confusable test".to_string(),
             trace_id: "trace-legitimate".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gate.submit(legitimate_proposal);
@@ -2141,7 +2204,7 @@ mod perf_module_extreme_adversarial_negative_tests {
     #[ignore = "timing-sensitive (bd-m87xv): run via scripts/run_timing_tests.sh on an isolated core"]
     fn extreme_adversarial_timing_attack_via_proposal_id_length_correlation() {
         use super::optimization_governor::{
-            GovernorGate, OptimizationProposal, PredictedMetrics, RuntimeKnob,
+            GovernorGate, OptimizationProposal, PredictedMetrics, ProposalSource, RuntimeKnob,
         };
         use std::time::Instant;
 
@@ -2192,6 +2255,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                     },
                     rationale: "timing correlation test".to_string(),
                     trace_id: format!("trace-timing-{}", iteration),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
 
                 let start = Instant::now();
@@ -2375,7 +2440,8 @@ mod perf_module_extreme_adversarial_negative_tests {
     #[test]
     fn extreme_adversarial_concurrent_audit_trail_corruption_via_interleaved_mutations() {
         use super::optimization_governor::{
-            GovernorGate, OptimizationGovernor, OptimizationProposal, PredictedMetrics, RuntimeKnob,
+            GovernorGate, OptimizationGovernor, OptimizationProposal, PredictedMetrics,
+            ProposalSource, RuntimeKnob,
         };
         use std::sync::{Arc, Mutex};
         use std::thread;
@@ -2428,6 +2494,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                                     thread_id, iteration
                                 ),
                                 trace_id: format!("trace-corrupt-{}-{}", thread_id, iteration),
+                                submitted_by: "test-harness".to_string(),
+                                source: ProposalSource::Autotuner,
                             };
 
                             if let Ok(mut g) = gate_clone.lock() {
@@ -2602,6 +2670,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: "post corruption functionality test".to_string(),
                 trace_id: "trace-post-corruption".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             // Gate should remain functional after corruption attempts
@@ -2613,7 +2683,7 @@ mod perf_module_extreme_adversarial_negative_tests {
     #[test]
     fn extreme_adversarial_floating_point_denormal_injection_in_safety_calculations() {
         use super::optimization_governor::{
-            GovernorGate, OptimizationProposal, PredictedMetrics, RuntimeKnob,
+            GovernorGate, OptimizationProposal, PredictedMetrics, ProposalSource, RuntimeKnob,
         };
 
         let mut gate = GovernorGate::with_defaults();
@@ -2671,6 +2741,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                         predicted: metrics.clone(),
                         rationale: format!("denormal injection test {}", test_name),
                         trace_id: format!("trace-denormal-{}-{}", test_name, field_idx),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     };
 
                     // Measure processing time to detect denormal performance impact
@@ -2733,6 +2805,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             predicted: combined_denormal,
             rationale: "combined denormal attack".to_string(),
             trace_id: "trace-combined-denormal".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let combined_start = std::time::Instant::now();
@@ -2769,6 +2843,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "post denormal normal test".to_string(),
             trace_id: "trace-post-denormal".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let normal_decision = gate.submit(normal_proposal);
@@ -2782,7 +2858,7 @@ mod perf_module_extreme_adversarial_negative_tests {
     fn extreme_adversarial_algorithmic_complexity_explosion_via_pathological_inputs() {
         use super::optimization_governor::{
             GovernorGate, OptimizationGovernor, OptimizationProposal, PredictedMetrics,
-            RuntimeKnob, SafetyEnvelope,
+            ProposalSource, RuntimeKnob, SafetyEnvelope,
         };
         use std::collections::BTreeMap;
 
@@ -2828,6 +2904,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: pathological_pattern.clone(),
                 trace_id: format!("trace-complexity-{}", attack_idx),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let _decision = gate.submit(proposal);
@@ -2909,6 +2987,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("overlap stress test {}", i),
                 trace_id: format!("trace-overlap-{:04}", i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let iter_start = std::time::Instant::now();
@@ -2949,6 +3029,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("revert stress setup {}", i),
                 trace_id: format!("trace-revert-{:03}", i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let _decision = complex_gate.submit(revert_proposal);
@@ -2983,7 +3065,7 @@ mod perf_module_extreme_adversarial_negative_tests {
     #[test]
     fn extreme_adversarial_state_machine_transition_fuzzing_with_invalid_sequences() {
         use super::optimization_governor::{
-            GovernorDecision, GovernorGate, OptimizationProposal, PredictedMetrics,
+            GovernorDecision, GovernorGate, OptimizationProposal, PredictedMetrics, ProposalSource,
             RejectionReason, RuntimeKnob,
         };
 
@@ -3007,6 +3089,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("rapid cycle apply {}", cycle),
                 trace_id: format!("trace-apply-{}", cycle),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let apply_decision = gate.submit(apply_proposal);
@@ -3074,6 +3158,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("progression test correct {}", i),
                 trace_id: format!("trace-correct-{}", i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let correct_decision = gate.submit(correct_proposal);
@@ -3096,6 +3182,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                 },
                 rationale: format!("progression test wrong {}", i),
                 trace_id: format!("trace-wrong-{}", i),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let wrong_decision = gate.submit(wrong_proposal);
@@ -3143,6 +3231,8 @@ mod perf_module_extreme_adversarial_negative_tests {
                     },
                     rationale: format!("interleaved test round {} proposal {}", round, i),
                     trace_id: format!("trace-interleaved-{}-{}", round, i),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
 
                 let _decision = gate.submit(interleaved_proposal);
@@ -3235,6 +3325,8 @@ mod perf_module_extreme_adversarial_negative_tests {
             },
             rationale: "final state machine test".to_string(),
             trace_id: "trace-final-state".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let final_decision = gate.submit(final_test_proposal);
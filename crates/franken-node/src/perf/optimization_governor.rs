@@ -52,8 +52,8 @@ const MAX_KNOBS: usize = 64;
 // Re-export the core governor and its types from the runtime module.
 pub use crate::runtime::optimization_governor::{
     DecisionRecord, GovernorDecision, GovernorSnapshot, KnobState, OptimizationGovernor,
-    OptimizationProposal, PredictedMetrics, RejectionReason, RuntimeKnob, SCHEMA_VERSION,
-    SafetyEnvelope, ShadowResult, error_codes, event_codes, invariants,
+    OptimizationProposal, PredictedMetrics, ProposalSource, RejectionReason, RuntimeKnob,
+    SCHEMA_VERSION, SafetyEnvelope, ShadowResult, error_codes, event_codes, invariants,
 };
 
 /// A gateway audit record that uses the bd-21fo canonical event codes.
@@ -225,6 +225,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let decision = gate.submit(empty_id_proposal);
             assert!(
@@ -255,6 +257,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _decision = gate.submit(long_id_proposal);
             assert!(
@@ -279,6 +283,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let decision = gate.submit(no_change_proposal);
             // Should be processed (inner governor decides whether it's beneficial)
@@ -305,6 +311,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _decision = gate.submit(extreme_metrics_proposal);
             assert!(
@@ -330,6 +338,8 @@ impl GovernorGate {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _decision = gate.submit(rapid_proposal);
             }
@@ -355,6 +365,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _decision = gate.submit(special_id_proposal);
             assert!(
@@ -379,6 +391,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _decision = gate.submit(zero_values_proposal);
             assert!(
@@ -401,6 +415,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let decision = gate.submit(large_change_proposal);
             assert!(
@@ -426,6 +442,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let initial_count = gate.audit_trail().len();
             let _decision = gate.submit(ordering_proposal);
@@ -464,6 +482,8 @@ impl GovernorGate {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _decision = gate.submit(detail_proposal);
             let candidate_event = gate
@@ -2236,6 +2256,8 @@ mod tests {
             predicted: safe_metrics(),
             rationale: "Increase concurrency under low load".to_string(),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -2253,6 +2275,8 @@ mod tests {
             },
             rationale: "Aggressive batch size".to_string(),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -2265,6 +2289,8 @@ mod tests {
             predicted,
             rationale: "Probe an unsafe metric edge".to_string(),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -3573,6 +3599,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let _decision = gate.submit(proposal);
@@ -3629,6 +3657,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let _decision = gate.submit(proposal);
@@ -3684,6 +3714,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gate.submit(proposal);
@@ -3747,6 +3779,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             OptimizationProposal {
                 proposal_id: "concurrent_test_2".to_string(),
@@ -3761,6 +3795,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             OptimizationProposal {
                 proposal_id: "concurrent_test_3".to_string(),
@@ -3775,6 +3811,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
         ];
 
@@ -3836,6 +3874,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             // Same old and new values (no-op proposal)
             OptimizationProposal {
@@ -3851,6 +3891,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             // Decreasing values (potential performance regression)
             OptimizationProposal {
@@ -3866,6 +3908,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
         ];
 
@@ -3940,6 +3984,8 @@ mod tests {
             },
             rationale: "bd-yom8c reconciled test".to_string(),
             trace_id: "trace-test".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
         let _ = gate.submit(baseline_proposal);
 
@@ -4029,6 +4075,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _ = gate.submit(proposal);
         }
@@ -4053,6 +4101,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _ = gate.submit(rapid_proposal);
         }
@@ -4074,6 +4124,8 @@ mod tests {
             },
             rationale: "bd-yom8c reconciled test".to_string(),
             trace_id: "trace-test".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
         let _ = gate.submit(extreme_detail_proposal);
 
@@ -4096,6 +4148,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _ = gate.submit(interleaved_proposal);
 
@@ -4156,6 +4210,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let result = gate.submit(proposal);
@@ -4204,6 +4260,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let result = gate.submit(proposal);
@@ -4253,6 +4311,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
 
                 let _ = gate.submit(proposal);
@@ -4313,6 +4373,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let result = gate.submit(proposal);
@@ -4373,6 +4435,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let result = gate.submit(proposal);
@@ -4446,6 +4510,8 @@ mod tests {
                                 },
                                 rationale: "bd-yom8c reconciled test".to_string(),
                                 trace_id: "trace-test".to_string(),
+                                submitted_by: "test-harness".to_string(),
+                                source: ProposalSource::Autotuner,
                             };
                             if let Ok(mut g) = gate_clone.lock() {
                                 let _ = g.submit(proposal);
@@ -4517,6 +4583,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             // This access pattern might need adjustment based on actual API
             // let _ = final_gate.submit(test_proposal);
@@ -4551,6 +4619,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 },
                 OptimizationProposal {
                     proposal_id: hash_target_id.clone(),
@@ -4565,6 +4635,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 },
             ];
 
@@ -4645,6 +4717,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let result = gate.submit(precision_proposal);
@@ -4703,6 +4777,8 @@ mod tests {
             },
             rationale: "bd-yom8c reconciled test".to_string(),
             trace_id: "trace-test".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let final_result = gate.submit(final_proposal);
@@ -4739,6 +4815,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(flood_proposal);
 
@@ -4765,6 +4843,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _ = gate.submit(memory_pressure_proposal);
 
@@ -4782,6 +4862,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _ = gate.submit(expansion_proposal);
 
@@ -4803,6 +4885,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             let _ = gate.submit(normalization_attack);
 
@@ -4842,6 +4926,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 })
                 .collect::<Vec<_>>();
 
@@ -4866,6 +4952,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(collision_proposal);
             }
@@ -4900,6 +4988,8 @@ mod tests {
                         predicted: metrics,
                         rationale: "bd-yom8c reconciled test".to_string(),
                         trace_id: "trace-test".to_string(),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     };
                     let _ = gate.submit(interleaved_proposal);
                 }
@@ -4935,6 +5025,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let decision = gate.submit(nan_proposal);
 
@@ -4974,6 +5066,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(boundary_proposal);
 
@@ -5003,6 +5097,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(precision_proposal);
             }
@@ -5053,6 +5149,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(injection_proposal);
             }
@@ -5080,6 +5178,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(json_proposal);
             }
@@ -5109,6 +5209,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(traversal_proposal);
             }
@@ -5156,6 +5258,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(unicode_proposal);
             }
@@ -5203,6 +5307,8 @@ mod tests {
                         },
                         rationale: "bd-yom8c reconciled test".to_string(),
                         trace_id: "trace-test".to_string(),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     };
                     let decision = gate.submit(boundary_proposal);
 
@@ -5234,6 +5340,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(sequence_proposal);
             }
@@ -5267,6 +5375,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(oscillation_proposal);
             }
@@ -5318,6 +5428,8 @@ mod tests {
                         },
                         rationale: "bd-yom8c reconciled test".to_string(),
                         trace_id: "trace-test".to_string(),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     }
                 })
                 .collect();
@@ -5357,6 +5469,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
 
                 let baseline_trail_len = gate.audit_trail().len();
@@ -5419,6 +5533,8 @@ mod tests {
                     predicted: metrics.clone(),
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(collision_proposal);
 
@@ -5501,6 +5617,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let decision = gate.submit(creeping_proposal);
 
@@ -5532,6 +5650,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(oscillation_proposal);
 
@@ -5573,6 +5693,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(ratio_proposal);
 
@@ -5610,6 +5732,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(precision_proposal);
             }
@@ -5682,6 +5806,8 @@ mod tests {
                         },
                         rationale: "bd-yom8c reconciled test".to_string(),
                         trace_id: "trace-test".to_string(),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     };
                     let _ = gate.submit(complex_proposal);
 
@@ -5712,6 +5838,8 @@ mod tests {
                 },
                 rationale: "bd-yom8c reconciled test".to_string(),
                 trace_id: "trace-test".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             // Submit the same proposal many times to stress deduplication/handling
@@ -5742,6 +5870,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(pressure_proposal);
             }
@@ -5771,6 +5901,8 @@ mod tests {
                         },
                         rationale: "bd-yom8c reconciled test".to_string(),
                         trace_id: "trace-test".to_string(),
+                        submitted_by: "test-harness".to_string(),
+                        source: ProposalSource::Autotuner,
                     };
                     let _ = gate.submit(rapid_proposal);
                 }
@@ -5855,6 +5987,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(float_proposal);
 
@@ -5894,6 +6028,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(encoding_proposal);
             }
@@ -5922,6 +6058,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(endian_proposal);
             }
@@ -5943,6 +6081,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(consistency_proposal);
 
@@ -5982,6 +6122,8 @@ mod tests {
                     },
                     rationale: "bd-yom8c reconciled test".to_string(),
                     trace_id: "trace-test".to_string(),
+                    submitted_by: "test-harness".to_string(),
+                    source: ProposalSource::Autotuner,
                 };
                 let _ = gate.submit(time_proposal);
             }
@@ -127,7 +127,7 @@ fn sanitize_for_json(explanation: &PolicyExplanation) -> PolicyExplanation {
 // ---------------------------------------------------------------------------
 
 /// Diagnostic (Bayesian, heuristic) confidence section.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DiagnosticSection {
     /// Posterior probability of the chosen candidate.
     pub posterior_prob: Option<f64>,
@@ -142,7 +142,7 @@ pub struct DiagnosticSection {
 }
 
 /// Guarantee (guardrail, invariant-backed) confidence section.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GuaranteeSection {
     /// Whether all applicable guardrails passed for the chosen action.
     pub all_guardrails_passed: bool,
@@ -155,7 +155,7 @@ pub struct GuaranteeSection {
 }
 
 /// Explanation for why a higher-ranked alternative was blocked.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockedExplanation {
     /// The candidate that was blocked.
     pub candidate: CandidateRef,
@@ -171,7 +171,7 @@ pub struct BlockedExplanation {
 ///
 /// INV-EXPLAIN-SEPARATION: diagnostic and guarantee sections are always present
 /// and use distinct vocabulary.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PolicyExplanation {
     /// Diagnostic (heuristic, data-driven) confidence assessment.
     pub diagnostic_confidence: DiagnosticSection,
@@ -21,8 +21,9 @@
 //! - INV-AA-DETERMINISTIC: Audit results are deterministic for the same input;
 //!   BTreeMap is used for ordered output.
 
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 use crate::capacity_defaults::aliases::MAX_EVENTS;
@@ -60,6 +61,7 @@ pub mod error_codes {
     pub const ERR_AA_MISSING_CAPABILITY: &str = "ERR_AA_MISSING_CAPABILITY";
     pub const ERR_AA_AMBIENT_DETECTED: &str = "ERR_AA_AMBIENT_DETECTED";
     pub const ERR_AA_INVENTORY_STALE: &str = "ERR_AA_INVENTORY_STALE";
+    pub const ERR_AA_INVENTORY_INVALID: &str = "ERR_AA_INVENTORY_INVALID";
     pub const ERR_AA_AUDIT_INCOMPLETE: &str = "ERR_AA_AUDIT_INCOMPLETE";
     pub const ERR_AA_GUARD_BYPASSED: &str = "ERR_AA_GUARD_BYPASSED";
 }
@@ -191,6 +193,34 @@ impl fmt::Display for Capability {
     }
 }
 
+/// Named groupings of related capabilities, so a principal can be granted
+/// "all filesystem capabilities" or "all key operations" without enumerating
+/// each member of the group by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum CapabilityCategory {
+    /// Read and write access to the file system.
+    FileSystem,
+    /// Network egress.
+    Network,
+    /// Key access, signing, and signature verification.
+    KeyOperations,
+}
+
+impl Capability {
+    /// All capabilities belonging to `category`.
+    pub fn all_of_category(category: CapabilityCategory) -> &'static [Capability] {
+        match category {
+            CapabilityCategory::FileSystem => &[Self::FileSystemRead, Self::FileSystemWrite],
+            CapabilityCategory::Network => &[Self::NetworkEgress],
+            CapabilityCategory::KeyOperations => &[
+                Self::KeyAccess,
+                Self::ArtifactSigning,
+                Self::SignatureVerification,
+            ],
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // CapabilityContext
 // ---------------------------------------------------------------------------
@@ -207,6 +237,12 @@ pub struct CapabilityContext {
     pub trace_id: String,
     /// Principal that established this context.
     pub principal: String,
+    /// Capabilities explicitly revoked after being granted. Consulted before
+    /// `granted` by [`Self::has_capability`], so a revoked capability is
+    /// denied even if it was granted individually or via a
+    /// [`Capability::all_of_category`] wildcard grant.
+    #[serde(default)]
+    pub revoked: BTreeSet<String>,
 }
 
 impl CapabilityContext {
@@ -224,11 +260,29 @@ impl CapabilityContext {
             granted,
             trace_id: trace_id.into(),
             principal: principal.into(),
+            revoked: BTreeSet::new(),
         }
     }
 
+    /// Grant an additional capability, clearing any prior revocation of it.
+    pub fn grant(&mut self, cap: &Capability) {
+        self.granted.insert(cap.label().to_string(), true);
+        self.revoked.remove(cap.label());
+    }
+
+    /// Revoke a previously granted capability. Takes effect even if the
+    /// capability was granted via a [`Capability::all_of_category`]
+    /// wildcard, since [`Self::has_capability`] consults `revoked` first
+    /// and a revocation takes precedence over `granted`.
+    pub fn revoke(&mut self, cap: &Capability) {
+        self.revoked.insert(cap.label().to_string());
+    }
+
     /// Check whether a specific capability is granted.
     pub fn has_capability(&self, cap: &Capability) -> bool {
+        if self.revoked.contains(cap.label()) {
+            return false;
+        }
         self.granted.get(cap.label()).copied().unwrap_or(false)
     }
 
@@ -280,6 +334,21 @@ pub struct SecurityCriticalInventory {
     pub modules: BTreeMap<String, SecurityCriticalModule>,
 }
 
+/// Error loading a [`SecurityCriticalInventory`] from a serialized manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryLoadError {
+    /// Error code (see `error_codes`).
+    pub error_code: String,
+    /// Human-readable description of what went wrong.
+    pub detail: String,
+}
+
+impl fmt::Display for InventoryLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.error_code, self.detail)
+    }
+}
+
 impl SecurityCriticalInventory {
     /// Create a new empty inventory.
     pub fn new() -> Self {
@@ -379,6 +448,66 @@ impl SecurityCriticalInventory {
 
         inv
     }
+
+    /// Parse an inventory from its JSON manifest representation, so it can
+    /// live in a versioned config file the audit gate loads at startup
+    /// instead of being recompiled every time a module is added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InventoryLoadError`] with
+    /// [`error_codes::ERR_AA_INVENTORY_STALE`] if the manifest's `version`
+    /// does not match [`SCHEMA_VERSION`], or with
+    /// [`error_codes::ERR_AA_INVENTORY_INVALID`] if the manifest is
+    /// malformed JSON or any module has an unrecognized `risk_level`.
+    pub fn from_json(bytes: &[u8]) -> Result<Self, InventoryLoadError> {
+        let inventory: Self = serde_json::from_slice(bytes).map_err(|e| InventoryLoadError {
+            error_code: error_codes::ERR_AA_INVENTORY_INVALID.to_string(),
+            detail: format!(
+                "{}: malformed inventory manifest: {e}",
+                error_codes::ERR_AA_INVENTORY_INVALID
+            ),
+        })?;
+
+        if inventory.version != SCHEMA_VERSION {
+            return Err(InventoryLoadError {
+                error_code: error_codes::ERR_AA_INVENTORY_STALE.to_string(),
+                detail: format!(
+                    "{}: inventory manifest version '{}' does not match expected '{}'",
+                    error_codes::ERR_AA_INVENTORY_STALE,
+                    inventory.version,
+                    SCHEMA_VERSION
+                ),
+            });
+        }
+
+        for module in inventory.modules.values() {
+            if RiskLevel::from_label(&module.risk_level).is_none() {
+                return Err(InventoryLoadError {
+                    error_code: error_codes::ERR_AA_INVENTORY_INVALID.to_string(),
+                    detail: format!(
+                        "{}: module '{}' has unknown risk_level '{}'",
+                        error_codes::ERR_AA_INVENTORY_INVALID,
+                        module.module_path,
+                        module.risk_level
+                    ),
+                });
+            }
+        }
+
+        Ok(inventory)
+    }
+
+    /// Serialize the inventory to its JSON manifest representation.
+    pub fn to_json(&self) -> Result<Vec<u8>, InventoryLoadError> {
+        serde_json::to_vec_pretty(self).map_err(|e| InventoryLoadError {
+            error_code: error_codes::ERR_AA_INVENTORY_INVALID.to_string(),
+            detail: format!(
+                "{}: failed to serialize inventory: {e}",
+                error_codes::ERR_AA_INVENTORY_INVALID
+            ),
+        })
+    }
 }
 
 impl Default for SecurityCriticalInventory {
@@ -624,15 +753,29 @@ impl AuthorityAuditGuard {
         let module_paths: Vec<String> = self.inventory.modules.keys().cloned().collect();
 
         let mut module_results: BTreeMap<String, ModuleAuditResult> = BTreeMap::new();
+        let mut missing_capability_counts: BTreeMap<String, usize> = BTreeMap::new();
 
         for path in &module_paths {
             let result = self.check_context(path, context);
+            let missing_capabilities: Vec<String> = match self.inventory.modules.get(path) {
+                Some(module) => module
+                    .required_capabilities
+                    .iter()
+                    .filter(|cap| !context.granted.get(*cap).copied().unwrap_or(false))
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            };
+            for cap in &missing_capabilities {
+                *missing_capability_counts.entry(cap.clone()).or_insert(0) += 1;
+            }
             module_results.insert(
                 path.clone(),
                 ModuleAuditResult {
                     module_path: path.clone(),
                     passed: result.is_ok() || !self.strict_mode,
                     violation: result.err(),
+                    missing_capabilities,
                 },
             );
         }
@@ -657,9 +800,63 @@ impl AuthorityAuditGuard {
             module_results,
             events: self.events.clone(),
             violations: self.violations.clone(),
+            missing_capability_counts,
         }
     }
 
+    /// Compile [`builtin_patterns`] and run them against `source`, recording
+    /// a violation for each match found inside a security-critical module
+    /// (`module_path` not present in [`Self::inventory`] is treated as
+    /// out of scope and always returns an empty vector).
+    ///
+    /// # INV-AA-NO-AMBIENT
+    /// This is the static-analysis half of ambient authority detection; the
+    /// `pattern` field on [`AmbientAuthorityPattern`] is no longer
+    /// documentation-only once this runs.
+    pub fn scan_source(
+        &mut self,
+        module_path: &str,
+        source: &str,
+    ) -> Vec<AmbientAuthorityViolation> {
+        let mut found = Vec::new();
+
+        if !self.inventory.modules.contains_key(module_path) {
+            return found;
+        }
+
+        for pattern in builtin_patterns() {
+            let Ok(re) = Regex::new(&pattern.pattern) else {
+                continue;
+            };
+            for (line_no, line) in source.lines().enumerate() {
+                if !re.is_match(line) {
+                    continue;
+                }
+                let violation = AmbientAuthorityViolation {
+                    module_path: module_path.to_string(),
+                    pattern_id: pattern.id.clone(),
+                    description: pattern.description.clone(),
+                    location: Some(format!("{}:{}", module_path, line_no + 1)),
+                    error_code: error_codes::ERR_AA_AMBIENT_DETECTED.to_string(),
+                };
+                self.emit_event(AuditEvent {
+                    event_code: event_codes::FN_AA_005.to_string(),
+                    module_path: module_path.to_string(),
+                    detail: format!(
+                        "static analysis pattern {} matched at line {}",
+                        pattern.id,
+                        line_no + 1
+                    ),
+                    trace_id: String::new(),
+                });
+                push_bounded(&mut self.violations, violation.clone(), MAX_VIOLATIONS);
+                found.push(violation);
+            }
+        }
+
+        found
+    }
+
     /// Return a snapshot of events.
     pub fn events(&self) -> &[AuditEvent] {
         &self.events
@@ -694,6 +891,11 @@ pub struct ModuleAuditResult {
     pub module_path: String,
     pub passed: bool,
     pub violation: Option<AmbientAuthorityViolation>,
+    /// Required capabilities the context was missing for this module,
+    /// populated regardless of `strict_mode` so advisory-mode callers get
+    /// the same per-capability breakdown as strict mode.
+    #[serde(default)]
+    pub missing_capabilities: Vec<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -714,6 +916,11 @@ pub struct AuditReport {
     pub module_results: BTreeMap<String, ModuleAuditResult>,
     pub events: Vec<AuditEvent>,
     pub violations: Vec<AmbientAuthorityViolation>,
+    /// How often each capability (by label) was missing across all audited
+    /// modules, e.g. to find which capability is most commonly missing
+    /// fleet-wide.
+    #[serde(default)]
+    pub missing_capability_counts: BTreeMap<String, usize>,
 }
 
 impl AuditReport {
@@ -890,6 +1097,37 @@ mod tests {
         assert!(!ctx.has_all(&[Capability::KeyAccess, Capability::NetworkEgress]));
     }
 
+    #[test]
+    fn test_capability_context_grant_and_revoke() {
+        let mut ctx = CapabilityContext::new(&[], "trace-1", "agent-1");
+        assert!(!ctx.has_capability(&Capability::NetworkEgress));
+
+        ctx.grant(&Capability::NetworkEgress);
+        assert!(ctx.has_capability(&Capability::NetworkEgress));
+
+        ctx.revoke(&Capability::NetworkEgress);
+        assert!(!ctx.has_capability(&Capability::NetworkEgress));
+
+        // Re-granting clears the revocation.
+        ctx.grant(&Capability::NetworkEgress);
+        assert!(ctx.has_capability(&Capability::NetworkEgress));
+    }
+
+    #[test]
+    fn test_capability_context_filesystem_wildcard_with_write_revoked() {
+        let mut ctx = CapabilityContext::new(&[], "trace-1", "agent-1");
+        for cap in Capability::all_of_category(CapabilityCategory::FileSystem) {
+            ctx.grant(cap);
+        }
+        assert!(ctx.has_all(&[Capability::FileSystemRead, Capability::FileSystemWrite]));
+
+        ctx.revoke(&Capability::FileSystemWrite);
+
+        assert!(ctx.has_all(&[Capability::FileSystemRead]));
+        assert!(!ctx.has_all(&[Capability::FileSystemRead, Capability::FileSystemWrite]));
+        assert!(!ctx.has_capability(&Capability::FileSystemWrite));
+    }
+
     #[test]
     fn test_capability_context_missing() {
         let ctx = CapabilityContext::new(&[Capability::KeyAccess], "trace-1", "agent-1");
@@ -924,6 +1162,7 @@ mod tests {
             granted,
             trace_id: "trace-2".to_string(),
             principal: "agent-2".to_string(),
+            revoked: BTreeSet::new(),
         };
 
         // Test that granted capabilities return true
@@ -993,6 +1232,45 @@ mod tests {
         assert_eq!(parsed.module_count(), inv.module_count());
     }
 
+    #[test]
+    fn test_inventory_json_round_trip() {
+        let inv = SecurityCriticalInventory::default_inventory();
+        let bytes = inv.to_json().unwrap();
+        let parsed = SecurityCriticalInventory::from_json(&bytes).unwrap();
+        assert_eq!(parsed.module_count(), inv.module_count());
+        assert_eq!(parsed.version, inv.version);
+        assert_eq!(
+            parsed.modules.keys().collect::<Vec<_>>(),
+            inv.modules.keys().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_inventory_from_json_rejects_stale_schema_version() {
+        let mut inv = SecurityCriticalInventory::new();
+        inv.version = "aa-v0.1".to_string();
+        let bytes = serde_json::to_vec(&inv).unwrap();
+
+        let err = SecurityCriticalInventory::from_json(&bytes).unwrap_err();
+        assert_eq!(err.error_code, error_codes::ERR_AA_INVENTORY_STALE);
+    }
+
+    #[test]
+    fn test_inventory_from_json_rejects_unknown_risk_level() {
+        let mut inv = SecurityCriticalInventory::new();
+        inv.add_module(SecurityCriticalModule {
+            module_path: "test::module".to_string(),
+            required_capabilities: vec!["key_access".to_string()],
+            risk_level: "apocalyptic".to_string(),
+            description: "test".to_string(),
+        });
+        let bytes = serde_json::to_vec(&inv).unwrap();
+
+        let err = SecurityCriticalInventory::from_json(&bytes).unwrap_err();
+        assert_eq!(err.error_code, error_codes::ERR_AA_INVENTORY_INVALID);
+        assert!(err.detail.contains("apocalyptic"));
+    }
+
     // ── AmbientAuthorityPattern ──────────────────────────────────────
 
     #[test]
@@ -1330,6 +1608,58 @@ mod tests {
         assert!(!report.violations.is_empty());
     }
 
+    #[test]
+    fn test_scan_source_detects_std_env_var_usage() {
+        let mut guard = AuthorityAuditGuard::with_default_inventory(true);
+        let source = "fn load() {\n    let key = std::env::var(\"API_KEY\").unwrap();\n}\n";
+
+        let found = guard.scan_source("crate::security::network_guard", source);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pattern_id, "AA-PAT-001");
+        assert_eq!(
+            found[0].location,
+            Some("crate::security::network_guard:2".to_string())
+        );
+        assert_eq!(found[0].error_code, error_codes::ERR_AA_AMBIENT_DETECTED);
+        assert_eq!(guard.violations().len(), 1);
+    }
+
+    #[test]
+    fn test_scan_source_detects_tcpstream_connect_as_critical_pattern() {
+        let mut guard = AuthorityAuditGuard::with_default_inventory(true);
+        let source = "std::net::TcpStream::connect(addr)?;";
+
+        let found = guard.scan_source("crate::security::network_guard", source);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].pattern_id, "AA-PAT-003");
+        let critical_pattern = builtin_patterns()
+            .into_iter()
+            .find(|p| p.id == "AA-PAT-003")
+            .unwrap();
+        assert_eq!(critical_pattern.severity, "critical");
+        assert_eq!(found[0].error_code, error_codes::ERR_AA_AMBIENT_DETECTED);
+    }
+
+    #[test]
+    fn test_scan_source_ignores_modules_outside_the_inventory() {
+        let mut guard = AuthorityAuditGuard::with_default_inventory(true);
+        let found = guard.scan_source("crate::not_security_critical", "std::env::var(\"X\")");
+        assert!(found.is_empty());
+        assert!(guard.violations().is_empty());
+    }
+
+    #[test]
+    fn test_scan_source_reports_no_violations_for_clean_source() {
+        let mut guard = AuthorityAuditGuard::with_default_inventory(true);
+        let found = guard.scan_source(
+            "crate::security::network_guard",
+            "fn handle(ctx: &CapabilityContext) -> bool { ctx.has_capability(&Capability::NetworkEgress) }",
+        );
+        assert!(found.is_empty());
+    }
+
     #[test]
     fn test_audit_report_deterministic() {
         let ctx = CapabilityContext::new(Capability::all(), "t1", "p1");
@@ -1363,6 +1693,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_audit_report_aggregates_missing_capability_counts_fleet_wide() {
+        // Grant everything except epoch_store_access and signature_verification,
+        // each of which is required by three modules in the default inventory
+        // (control_epoch/fork_detection/lease_conflict, and
+        // interface_hash/fork_detection/manifest, respectively).
+        let granted: Vec<Capability> = Capability::all()
+            .iter()
+            .filter(|c| {
+                !matches!(
+                    c,
+                    Capability::EpochStoreAccess | Capability::SignatureVerification
+                )
+            })
+            .cloned()
+            .collect();
+        let ctx = CapabilityContext::new(&granted, "t1", "p1");
+
+        let report = generate_audit_report(&ctx, false);
+
+        assert_eq!(
+            report.missing_capability_counts.get("epoch_store_access"),
+            Some(&3)
+        );
+        assert_eq!(
+            report
+                .missing_capability_counts
+                .get("signature_verification"),
+            Some(&3)
+        );
+        assert_eq!(
+            report
+                .module_results
+                .get("crate::control_plane::fork_detection")
+                .unwrap()
+                .missing_capabilities,
+            vec![
+                "epoch_store_access".to_string(),
+                "signature_verification".to_string()
+            ]
+        );
+    }
+
     #[test]
     fn test_audit_report_serde() {
         let ctx = CapabilityContext::new(Capability::all(), "t1", "p1");
@@ -1972,6 +2345,7 @@ mod authority_audit_comprehensive_negative_tests {
                         location: Some("evil.rs:1337\u{200B}".to_string()),
                         error_code: "ERR_AA_EVIL\u{FFFD}\u{FFFD}".to_string(),
                     }),
+                    missing_capabilities: Vec::new(),
                 },
             );
         }
@@ -2006,6 +2380,7 @@ mod authority_audit_comprehensive_negative_tests {
             module_results: malicious_module_results,
             events: massive_events,
             violations: massive_violations,
+            missing_capability_counts: BTreeMap::new(),
         };
 
         // Test serialization with malicious and massive content
@@ -2149,6 +2524,7 @@ mod authority_audit_comprehensive_negative_tests {
             },
             trace_id: "trace\u{10FFFF}".repeat(10000), // ~40KB trace ID
             principal: "principal\u{FFFD}\u{FFFD}".repeat(5000), // ~20KB principal
+            revoked: BTreeSet::new(),
         };
 
         // Generate report in both strict and advisory modes
@@ -15,13 +15,14 @@
 //! - INV-MESH-FAIL-CLOSED: unknown rails, invalid policies, demotions fail closed
 
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 /// Schema version for isolation mesh reports.
 pub const SCHEMA_VERSION: &str = "isolation-mesh-v1.0";
 
 use crate::capacity_defaults::aliases::MAX_EVENTS;
+use crate::storage::models::IsolationMeshSnapshotRecord;
 const MAX_ELEVATION_HISTORY: usize = 256;
 
 fn push_bounded<T>(items: &mut Vec<T>, item: T, cap: usize) {
@@ -64,6 +65,16 @@ pub mod error_codes {
     pub const ERR_MESH_RAIL_AT_CAPACITY: &str = "ERR_MESH_RAIL_AT_CAPACITY";
     pub const ERR_MESH_DUPLICATE_WORKLOAD: &str = "ERR_MESH_DUPLICATE_WORKLOAD";
     pub const ERR_MESH_INVALID_TOPOLOGY: &str = "ERR_MESH_INVALID_TOPOLOGY";
+    pub const ERR_MESH_AFFINITY_VIOLATED: &str = "ERR_MESH_AFFINITY_VIOLATED";
+}
+
+// ---------------------------------------------------------------------------
+// Warning codes -- soft, non-fatal findings from `MeshTopology::validate_strict`
+// ---------------------------------------------------------------------------
+pub mod warning_codes {
+    pub const WARN_MESH_DUPLICATE_LEVEL: &str = "WARN_MESH_DUPLICATE_LEVEL";
+    pub const WARN_MESH_NON_MONOTONIC_LATENCY: &str = "WARN_MESH_NON_MONOTONIC_LATENCY";
+    pub const WARN_MESH_MISSING_LOWEST_LEVEL: &str = "WARN_MESH_MISSING_LOWEST_LEVEL";
 }
 
 // ---------------------------------------------------------------------------
@@ -145,6 +156,12 @@ pub struct IsolationRail {
     pub latency_overhead_us: u64,
     /// Maximum number of workloads that can run concurrently on this rail.
     pub capacity: usize,
+    /// Relative cost of running a workload on this rail, in whatever unit
+    /// the deployment's billing model uses. Stricter rails are typically
+    /// (but not necessarily) more expensive; this is tracked independently
+    /// of `level` so [`IsolationMesh::auto_place_workload_optimized`] can
+    /// optimize for cost without assuming it tracks isolation strictness.
+    pub cost_units: u32,
 }
 
 // ---------------------------------------------------------------------------
@@ -259,6 +276,10 @@ pub enum MeshError {
     InvalidTopology {
         detail: String,
     },
+    AffinityViolated {
+        rail_id: String,
+        conflicting_workload_id: String,
+    },
 }
 
 impl MeshError {
@@ -273,6 +294,7 @@ impl MeshError {
             Self::RailAtCapacity { .. } => error_codes::ERR_MESH_RAIL_AT_CAPACITY,
             Self::DuplicateWorkload { .. } => error_codes::ERR_MESH_DUPLICATE_WORKLOAD,
             Self::InvalidTopology { .. } => error_codes::ERR_MESH_INVALID_TOPOLOGY,
+            Self::AffinityViolated { .. } => error_codes::ERR_MESH_AFFINITY_VIOLATED,
         }
     }
 }
@@ -317,6 +339,16 @@ impl fmt::Display for MeshError {
             Self::InvalidTopology { detail } => {
                 write!(f, "{}: {detail}", self.code())
             }
+            Self::AffinityViolated {
+                rail_id,
+                conflicting_workload_id,
+            } => {
+                write!(
+                    f,
+                    "{}: rail_id={rail_id} conflicting_workload_id={conflicting_workload_id}",
+                    self.code()
+                )
+            }
         }
     }
 }
@@ -372,6 +404,59 @@ pub struct WorkloadPlacement {
     pub policy: ElevationPolicy,
     pub placed_at_ms: u64,
     pub elevation_history: Vec<ElevationRecord>,
+    /// `latency_overhead_us` of `current_rail_id` as of the last placement,
+    /// elevation, or topology reload. Cached rather than looked up on demand
+    /// so [`IsolationMesh::total_latency_overhead_us`] and
+    /// [`IsolationMesh::workloads_over_budget`] don't need topology access.
+    #[serde(default)]
+    pub current_latency_overhead_us: u64,
+}
+
+// ---------------------------------------------------------------------------
+// AffinityConstraints: workload co-location preferences
+// ---------------------------------------------------------------------------
+/// Optional co-location constraints referencing other workload ids already
+/// placed on the mesh, honored by [`IsolationMesh::place_workload_with_affinity`]
+/// and [`IsolationMesh::auto_place_workload`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AffinityConstraints {
+    /// A rail currently hosting any of these workloads is never chosen,
+    /// even if it has spare capacity.
+    pub anti_affinity: Vec<String>,
+    /// A rail currently hosting one of these workloads is preferred over
+    /// one that does not, all else equal.
+    pub affinity: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// OptimizeFor: placement objective for auto_place_workload_optimized
+// ---------------------------------------------------------------------------
+/// Objective used by [`IsolationMesh::auto_place_workload_optimized`] to rank
+/// otherwise-eligible candidate rails.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OptimizeFor {
+    /// Prefer the lowest `latency_overhead_us`.
+    Latency,
+    /// Prefer the lowest `cost_units`.
+    Cost,
+    /// Prefer the lowest weighted combination of normalized latency and
+    /// normalized cost, each rescaled to `[0.0, 1.0]` across the candidate
+    /// set before weighting so the two units are comparable.
+    ///
+    /// `latency_weight` is clamped to `[0.0, 1.0]`; `1.0` behaves like
+    /// [`Self::Latency`], `0.0` behaves like [`Self::Cost`].
+    Balanced { latency_weight: f64 },
+}
+
+/// Rescale `value` into `[0.0, 1.0]` given the `min`/`max` observed across a
+/// candidate set. Returns `0.0` when every candidate ties (`min == max`), so
+/// a uniform set never distorts the other half of a `Balanced` score.
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        0.0
+    } else {
+        (value - min) / (max - min)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -405,6 +490,149 @@ impl MeshTopology {
         }
         Ok(())
     }
+
+    /// Run [`Self::validate`] and then a stricter, non-fatal pass over the
+    /// topology's level/latency consistency. Unlike `validate`, problems here
+    /// don't necessarily make the topology unusable -- they're returned as
+    /// [`TopologyWarning`]s for an operator to review rather than hard errors.
+    pub fn validate_strict(&self) -> Result<Vec<TopologyWarning>, MeshError> {
+        self.validate()?;
+
+        let mut by_level: BTreeMap<IsolationRailLevel, Vec<String>> = BTreeMap::new();
+        for rail in self.rails.values() {
+            by_level.entry(rail.level).or_default().push(rail.rail_id.clone());
+        }
+
+        let mut warnings = Vec::new();
+
+        for (level, rail_ids) in &by_level {
+            if rail_ids.len() > 1 {
+                warnings.push(TopologyWarning::DuplicateLevel {
+                    level: *level,
+                    rail_ids: rail_ids.clone(),
+                });
+            }
+        }
+
+        // Representative latency per level is the minimum across its rails --
+        // the best case the level can offer. These are already ordered by
+        // increasing strictness because `by_level` is a `BTreeMap` keyed on
+        // `IsolationRailLevel`, which derives `Ord` from discriminant order.
+        let level_latencies: Vec<(IsolationRailLevel, u64)> = by_level
+            .iter()
+            .map(|(level, rail_ids)| {
+                let min_latency_us = rail_ids
+                    .iter()
+                    .filter_map(|rail_id| self.rails.get(rail_id))
+                    .map(|rail| rail.latency_overhead_us)
+                    .min()
+                    .unwrap_or(0);
+                (*level, min_latency_us)
+            })
+            .collect();
+
+        for pair in level_latencies.windows(2) {
+            let (lower_level, lower_latency_us) = pair[0];
+            let (higher_level, higher_latency_us) = pair[1];
+            if higher_latency_us < lower_latency_us {
+                warnings.push(TopologyWarning::NonMonotonicLatency {
+                    lower_level,
+                    higher_level,
+                    lower_latency_us,
+                    higher_latency_us,
+                });
+            }
+        }
+
+        if !by_level.contains_key(&IsolationRailLevel::Shared) {
+            warnings.push(TopologyWarning::MissingLowestLevel);
+        }
+
+        Ok(warnings)
+    }
+}
+
+/// A non-fatal finding from [`MeshTopology::validate_strict`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TopologyWarning {
+    /// More than one rail claims the same isolation level.
+    DuplicateLevel {
+        level: IsolationRailLevel,
+        rail_ids: Vec<String>,
+    },
+    /// A more strict level has a lower latency overhead than a less strict
+    /// one, which usually indicates a misconfigured rail.
+    NonMonotonicLatency {
+        lower_level: IsolationRailLevel,
+        higher_level: IsolationRailLevel,
+        lower_latency_us: u64,
+        higher_latency_us: u64,
+    },
+    /// No rail exists at [`IsolationRailLevel::Shared`], so every workload is
+    /// forced onto an isolated rail even when isolation isn't needed.
+    MissingLowestLevel,
+}
+
+impl TopologyWarning {
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DuplicateLevel { .. } => warning_codes::WARN_MESH_DUPLICATE_LEVEL,
+            Self::NonMonotonicLatency { .. } => warning_codes::WARN_MESH_NON_MONOTONIC_LATENCY,
+            Self::MissingLowestLevel => warning_codes::WARN_MESH_MISSING_LOWEST_LEVEL,
+        }
+    }
+}
+
+impl fmt::Display for TopologyWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateLevel { level, rail_ids } => {
+                write!(
+                    f,
+                    "{}: level={} rails={rail_ids:?}",
+                    self.code(),
+                    level.as_str()
+                )
+            }
+            Self::NonMonotonicLatency {
+                lower_level,
+                higher_level,
+                lower_latency_us,
+                higher_latency_us,
+            } => {
+                write!(
+                    f,
+                    "{}: {} latency_us={lower_latency_us} is not less than {} latency_us={higher_latency_us}",
+                    self.code(),
+                    lower_level.as_str(),
+                    higher_level.as_str()
+                )
+            }
+            Self::MissingLowestLevel => {
+                write!(
+                    f,
+                    "{}: no rail at {}",
+                    self.code(),
+                    IsolationRailLevel::Shared.as_str()
+                )
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MeshState: full checkpoint of an IsolationMesh, for failover restore
+// ---------------------------------------------------------------------------
+/// Complete snapshot of an [`IsolationMesh`], produced by
+/// [`IsolationMesh::to_state`] and restored via [`IsolationMesh::from_state`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeshState {
+    pub topology: MeshTopology,
+    pub workloads: BTreeMap<String, WorkloadPlacement>,
+    pub rail_states: BTreeMap<String, RailState>,
+    pub events: Vec<MeshEvent>,
+    pub event_seq: u64,
 }
 
 // ---------------------------------------------------------------------------
@@ -464,6 +692,197 @@ impl IsolationMesh {
         &self.events
     }
 
+    /// Current `active_count / capacity` ratio for every rail in the
+    /// topology, keyed by rail_id. A rail with zero capacity (rejected by
+    /// [`MeshTopology::validate`] and therefore unreachable in practice)
+    /// reports `0.0` rather than dividing by zero.
+    #[must_use]
+    pub fn rail_utilization(&self) -> BTreeMap<String, f64> {
+        self.topology
+            .rails
+            .iter()
+            .map(|(rail_id, rail)| {
+                let active_count = self
+                    .rail_states
+                    .get(rail_id)
+                    .map_or(0, |rs| rs.active_count);
+                let utilization = if rail.capacity == 0 {
+                    0.0
+                } else {
+                    active_count as f64 / rail.capacity as f64
+                };
+                (rail_id.clone(), utilization)
+            })
+            .collect()
+    }
+
+    /// Sum of `current_latency_overhead_us` across every active placement --
+    /// the fleet-wide isolation tax right now.
+    #[must_use]
+    pub fn total_latency_overhead_us(&self) -> u64 {
+        self.workloads
+            .values()
+            .map(|placement| placement.current_latency_overhead_us)
+            .sum()
+    }
+
+    /// Workload ids whose `current_latency_overhead_us` already exceeds
+    /// their policy's `latency_budget_us`, for policies that opted into
+    /// `preserve_latency_budget`. A policy that doesn't preserve the budget
+    /// has no budget to exceed.
+    ///
+    /// This can surface workloads that were compliant when placed or last
+    /// elevated but are now over budget because [`Self::reload_topology`]
+    /// increased their rail's `latency_overhead_us`.
+    #[must_use]
+    pub fn workloads_over_budget(&self) -> Vec<String> {
+        self.workloads
+            .values()
+            .filter(|placement| {
+                placement.policy.preserve_latency_budget
+                    && placement.current_latency_overhead_us > placement.policy.latency_budget_us
+            })
+            .map(|placement| placement.workload_id.clone())
+            .collect()
+    }
+
+    // -----------------------------------------------------------------------
+    // Checkpoint / restore
+    // -----------------------------------------------------------------------
+    /// Snapshot the full mesh state for checkpoint/restore, including the
+    /// internal `event_seq` counter so a restored mesh continues event
+    /// numbering exactly where the checkpointed one left off.
+    #[must_use]
+    pub fn to_state(&self) -> MeshState {
+        MeshState {
+            topology: self.topology.clone(),
+            workloads: self.workloads.clone(),
+            rail_states: self.rail_states.clone(),
+            events: self.events.clone(),
+            event_seq: self.event_seq,
+        }
+    }
+
+    /// Restore a mesh from a [`MeshState`] snapshot.
+    ///
+    /// Unlike a plain derived `Deserialize`, this validates the snapshot
+    /// before trusting it (INV-MESH-FAIL-CLOSED): every workload's
+    /// `current_rail_id` must name a rail that exists in the topology, and
+    /// every rail's `active_count` must match the number of workloads
+    /// actually placed on it.
+    pub fn from_state(state: MeshState) -> Result<Self, MeshError> {
+        state.topology.validate()?;
+
+        let mut expected_active_counts: BTreeMap<String, usize> = BTreeMap::new();
+        for (workload_id, placement) in &state.workloads {
+            if !state
+                .topology
+                .rails
+                .contains_key(&placement.current_rail_id)
+            {
+                return Err(MeshError::InvalidTopology {
+                    detail: format!(
+                        "workload {workload_id} placed on unknown rail {}",
+                        placement.current_rail_id
+                    ),
+                });
+            }
+            *expected_active_counts
+                .entry(placement.current_rail_id.clone())
+                .or_insert(0) += 1;
+        }
+
+        for rail_id in state.topology.rails.keys() {
+            let rail_state =
+                state
+                    .rail_states
+                    .get(rail_id)
+                    .ok_or_else(|| MeshError::InvalidTopology {
+                        detail: format!("rail {rail_id} has no rail_state entry"),
+                    })?;
+            let expected = expected_active_counts.get(rail_id).copied().unwrap_or(0);
+            if rail_state.active_count != expected {
+                return Err(MeshError::InvalidTopology {
+                    detail: format!(
+                        "rail {rail_id} active_count={} does not match placed workload count={expected}",
+                        rail_state.active_count
+                    ),
+                });
+            }
+        }
+
+        Ok(Self {
+            topology: state.topology,
+            workloads: state.workloads,
+            rail_states: state.rail_states,
+            events: state.events,
+            event_seq: state.event_seq,
+        })
+    }
+
+    /// Build a [`IsolationMeshSnapshotRecord`] suitable for persisting
+    /// through the storage layer, encoding topology, workload placements
+    /// (including each workload's full `elevation_history`), and per-rail
+    /// state as canonical JSON columns.
+    ///
+    /// The structured event log is not persisted here -- the elevation
+    /// history on each [`WorkloadPlacement`] is already the durable audit
+    /// trail of what happened to a workload; `events` is an in-memory
+    /// ring buffer for live observability, not a record of truth.
+    #[must_use]
+    pub fn to_snapshot_record(
+        &self,
+        snapshot_id: &str,
+        captured_at: &str,
+    ) -> IsolationMeshSnapshotRecord {
+        IsolationMeshSnapshotRecord {
+            snapshot_id: snapshot_id.to_string(),
+            topology_json: serde_json::to_string(&self.topology).expect("topology serializes"),
+            workloads_json: serde_json::to_string(&self.workloads).expect("workloads serialize"),
+            rail_states_json: serde_json::to_string(&self.rail_states)
+                .expect("rail states serialize"),
+            event_seq: self.event_seq,
+            captured_at: captured_at.to_string(),
+        }
+    }
+
+    /// Restore a mesh from a [`IsolationMeshSnapshotRecord`], going through
+    /// the same [`Self::from_state`] validation as any other snapshot
+    /// restore (INV-MESH-FAIL-CLOSED) -- a corrupted or tampered record
+    /// fails closed rather than producing an inconsistent mesh.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeshError::InvalidTopology`] if any JSON column fails to
+    /// parse, or if the decoded state itself fails `from_state` validation.
+    pub fn from_snapshot_record(record: &IsolationMeshSnapshotRecord) -> Result<Self, MeshError> {
+        let topology: MeshTopology = serde_json::from_str(&record.topology_json).map_err(|e| {
+            MeshError::InvalidTopology {
+                detail: format!("malformed topology_json: {e}"),
+            }
+        })?;
+        let workloads: BTreeMap<String, WorkloadPlacement> =
+            serde_json::from_str(&record.workloads_json).map_err(|e| {
+                MeshError::InvalidTopology {
+                    detail: format!("malformed workloads_json: {e}"),
+                }
+            })?;
+        let rail_states: BTreeMap<String, RailState> =
+            serde_json::from_str(&record.rail_states_json).map_err(|e| {
+                MeshError::InvalidTopology {
+                    detail: format!("malformed rail_states_json: {e}"),
+                }
+            })?;
+
+        Self::from_state(MeshState {
+            topology,
+            workloads,
+            rail_states,
+            events: Vec::new(),
+            event_seq: record.event_seq,
+        })
+    }
+
     // -----------------------------------------------------------------------
     // Place a workload on an initial rail
     // -----------------------------------------------------------------------
@@ -517,6 +936,7 @@ impl IsolationMesh {
             policy,
             placed_at_ms: now_ms,
             elevation_history: Vec::new(),
+            current_latency_overhead_us: rail.latency_overhead_us,
         };
 
         self.workloads
@@ -533,6 +953,255 @@ impl IsolationMesh {
         Ok(placement)
     }
 
+    /// Rail ids currently hosting any of `workload_ids`.
+    fn rails_hosting(&self, workload_ids: &[String]) -> BTreeSet<String> {
+        workload_ids
+            .iter()
+            .filter_map(|id| self.workloads.get(id))
+            .map(|placement| placement.current_rail_id.clone())
+            .collect()
+    }
+
+    // -----------------------------------------------------------------------
+    // Place a workload on an explicit rail, honoring affinity constraints
+    // -----------------------------------------------------------------------
+    /// Place a workload like [`Self::place_workload`], but first reject
+    /// `rail_id` if it currently hosts any of `constraints.anti_affinity`.
+    ///
+    /// `constraints.affinity` is not enforced here -- a direct rail request
+    /// is either consistent with it or it isn't; steering toward an
+    /// affinity-preferred rail is [`Self::auto_place_workload`]'s job.
+    pub fn place_workload_with_affinity(
+        &mut self,
+        workload_id: &str,
+        rail_id: &str,
+        policy: ElevationPolicy,
+        now_ms: u64,
+        constraints: &AffinityConstraints,
+    ) -> Result<WorkloadPlacement, MeshError> {
+        if let Some(conflicting_workload_id) = constraints
+            .anti_affinity
+            .iter()
+            .find(|id| {
+                self.workloads
+                    .get(id.as_str())
+                    .is_some_and(|placement| placement.current_rail_id == rail_id)
+            })
+            .cloned()
+        {
+            return Err(MeshError::AffinityViolated {
+                rail_id: rail_id.to_string(),
+                conflicting_workload_id,
+            });
+        }
+
+        self.place_workload(workload_id, rail_id, policy, now_ms)
+    }
+
+    // -----------------------------------------------------------------------
+    // Place a workload on the least-strict rail with free capacity
+    // -----------------------------------------------------------------------
+    /// Place a workload on the least-strict rail at or above `min_level` that
+    /// currently has free capacity, ties broken deterministically by
+    /// `rail_id` (INV-MESH-DETERMINISTIC-TOPOLOGY).
+    ///
+    /// This walks up the strictness ladder from `min_level` rather than
+    /// requiring the caller to guess a specific rail and retry on
+    /// `ERR_MESH_RAIL_AT_CAPACITY`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeshError::RailAtCapacity`] if no rail at or above
+    /// `min_level` has free capacity; its `rail_id` field names every level
+    /// that was tried rather than a single rail, since no one rail is at
+    /// fault.
+    pub fn place_best_fit(
+        &mut self,
+        workload_id: &str,
+        min_level: IsolationRailLevel,
+        policy: ElevationPolicy,
+        now_ms: u64,
+    ) -> Result<WorkloadPlacement, MeshError> {
+        let mut candidates: Vec<(IsolationRailLevel, String)> = self
+            .topology
+            .rails
+            .values()
+            .filter(|rail| rail.level >= min_level)
+            .map(|rail| (rail.level, rail.rail_id.clone()))
+            .collect();
+        candidates.sort();
+
+        for (_, rail_id) in &candidates {
+            let has_room = self
+                .rail_states
+                .get(rail_id)
+                .zip(self.topology.rails.get(rail_id))
+                .is_some_and(|(rs, rail)| rs.active_count < rail.capacity);
+            if has_room {
+                return self.place_workload(workload_id, rail_id, policy, now_ms);
+            }
+        }
+
+        let tried_levels: Vec<&'static str> =
+            candidates.iter().map(|(level, _)| level.as_str()).collect();
+        Err(MeshError::RailAtCapacity {
+            rail_id: format!(
+                "no rail at or above {} has capacity (tried {})",
+                min_level.as_str(),
+                tried_levels.join(", ")
+            ),
+            capacity: 0,
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // Auto-place a workload onto the best of several candidate rails
+    // -----------------------------------------------------------------------
+    /// Choose a rail from `candidate_rail_ids` honoring `constraints`, then
+    /// place the workload there via [`Self::place_workload`].
+    ///
+    /// Candidates currently hosting an `anti_affinity` workload are excluded
+    /// outright (fail-closed): if every candidate is excluded this way,
+    /// returns `ERR_MESH_AFFINITY_VIOLATED` without attempting a placement.
+    /// Among the remaining candidates, rails currently hosting an `affinity`
+    /// workload are tried first, in the order given; if a preferred rail is
+    /// at capacity, the next candidate is tried rather than failing outright.
+    pub fn auto_place_workload(
+        &mut self,
+        workload_id: &str,
+        candidate_rail_ids: &[String],
+        policy: ElevationPolicy,
+        now_ms: u64,
+        constraints: &AffinityConstraints,
+    ) -> Result<WorkloadPlacement, MeshError> {
+        let forbidden_rails = self.rails_hosting(&constraints.anti_affinity);
+        let preferred_rails = self.rails_hosting(&constraints.affinity);
+
+        let mut allowed: Vec<&String> = candidate_rail_ids
+            .iter()
+            .filter(|rail_id| !forbidden_rails.contains(rail_id.as_str()))
+            .collect();
+
+        if allowed.is_empty() {
+            return Err(MeshError::AffinityViolated {
+                rail_id: candidate_rail_ids.first().cloned().unwrap_or_default(),
+                conflicting_workload_id: constraints
+                    .anti_affinity
+                    .first()
+                    .cloned()
+                    .unwrap_or_default(),
+            });
+        }
+
+        // Stable sort: affinity-preferred rails first, otherwise candidates
+        // keep the relative order the caller supplied them in.
+        allowed.sort_by_key(|rail_id| !preferred_rails.contains(rail_id.as_str()));
+
+        let mut last_err = None;
+        for rail_id in allowed {
+            match self.place_workload(workload_id, rail_id, policy.clone(), now_ms) {
+                Ok(placement) => return Ok(placement),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.expect("allowed is non-empty"))
+    }
+
+    // -----------------------------------------------------------------------
+    // Auto-place a workload, ranking candidates by a cost/latency objective
+    // -----------------------------------------------------------------------
+    /// Like [`Self::auto_place_workload`], but instead of preferring
+    /// affinity-hosting rails, ranks every `anti_affinity`-eligible candidate
+    /// by `objective` and tries them lowest-score first.
+    ///
+    /// `constraints.affinity` is not consulted here -- a caller wanting both
+    /// affinity-steering and cost awareness should pre-filter
+    /// `candidate_rail_ids` to the affinity-preferred set before calling in.
+    /// As with [`Self::auto_place_workload`], a candidate that turns out to
+    /// be at capacity is skipped in favor of the next-best one rather than
+    /// failing outright.
+    pub fn auto_place_workload_optimized(
+        &mut self,
+        workload_id: &str,
+        candidate_rail_ids: &[String],
+        policy: ElevationPolicy,
+        now_ms: u64,
+        constraints: &AffinityConstraints,
+        objective: OptimizeFor,
+    ) -> Result<WorkloadPlacement, MeshError> {
+        let forbidden_rails = self.rails_hosting(&constraints.anti_affinity);
+
+        let mut allowed: Vec<&String> = candidate_rail_ids
+            .iter()
+            .filter(|rail_id| !forbidden_rails.contains(rail_id.as_str()))
+            .collect();
+
+        if allowed.is_empty() {
+            return Err(MeshError::AffinityViolated {
+                rail_id: candidate_rail_ids.first().cloned().unwrap_or_default(),
+                conflicting_workload_id: constraints
+                    .anti_affinity
+                    .first()
+                    .cloned()
+                    .unwrap_or_default(),
+            });
+        }
+
+        let (min_latency, max_latency, min_cost, max_cost) = allowed
+            .iter()
+            .filter_map(|rail_id| self.topology.rails.get(rail_id.as_str()))
+            .fold(
+                (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+                |(min_l, max_l, min_c, max_c), rail| {
+                    let latency = rail.latency_overhead_us as f64;
+                    let cost = rail.cost_units as f64;
+                    (
+                        min_l.min(latency),
+                        max_l.max(latency),
+                        min_c.min(cost),
+                        max_c.max(cost),
+                    )
+                },
+            );
+
+        // Unknown rail ids score last; `place_workload` below still reports
+        // `ERR_MESH_UNKNOWN_RAIL` for them if every candidate turns out bogus.
+        let score = |rail_id: &str| -> f64 {
+            let Some(rail) = self.topology.rails.get(rail_id) else {
+                return f64::MAX;
+            };
+            match objective {
+                OptimizeFor::Latency => rail.latency_overhead_us as f64,
+                OptimizeFor::Cost => rail.cost_units as f64,
+                OptimizeFor::Balanced { latency_weight } => {
+                    let latency_weight = latency_weight.clamp(0.0, 1.0);
+                    let norm_latency =
+                        normalize(rail.latency_overhead_us as f64, min_latency, max_latency);
+                    let norm_cost = normalize(rail.cost_units as f64, min_cost, max_cost);
+                    latency_weight * norm_latency + (1.0 - latency_weight) * norm_cost
+                }
+            }
+        };
+
+        // Stable sort: lowest score first, ties keep the candidate order the
+        // caller supplied them in (same tie-breaking convention as
+        // `auto_place_workload`'s affinity-preference sort).
+        allowed.sort_by(|a, b| {
+            score(a)
+                .partial_cmp(&score(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut last_err = None;
+        for rail_id in allowed {
+            match self.place_workload(workload_id, rail_id, policy.clone(), now_ms) {
+                Ok(placement) => return Ok(placement),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.expect("allowed is non-empty"))
+    }
+
     // -----------------------------------------------------------------------
     // Hot-elevate a workload to a stricter rail
     // -----------------------------------------------------------------------
@@ -648,6 +1317,7 @@ impl IsolationMesh {
             );
             placement.current_rail_id = target_rail_id.to_string();
             placement.current_level = target_level;
+            placement.current_latency_overhead_us = target_latency;
             placement.clone()
         };
 
@@ -666,6 +1336,89 @@ impl IsolationMesh {
         Ok(updated_placement)
     }
 
+    // -----------------------------------------------------------------------
+    // Drain a rail by elevating every workload off it
+    // -----------------------------------------------------------------------
+    /// Attempt to migrate every workload currently on `rail_id` onto the
+    /// next-strictest rail that each workload's [`ElevationPolicy`] permits,
+    /// so the rail can subsequently be dropped via [`Self::reload_topology`]
+    /// (which already refuses to remove a rail that still hosts a workload).
+    ///
+    /// Candidate rails are tried in ascending strictness order starting just
+    /// above the workload's current level -- INV-MESH-MONOTONIC-ELEVATION
+    /// means a workload is never demoted to make room. The first candidate
+    /// the workload's policy permits and that has spare capacity wins, via
+    /// [`Self::elevate_workload`], so `MESH_002` is emitted per successful
+    /// migration exactly as it would be for a manual elevation.
+    ///
+    /// A workload that cannot be moved to any stricter rail (forbidden by
+    /// policy, over latency budget everywhere, or every stricter rail is at
+    /// capacity) is left in place; its id is collected into the returned
+    /// `Vec` rather than failing the whole drain, so a caller can see exactly
+    /// how much of the rail it managed to empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeshError::UnknownRail`] if `rail_id` does not name a rail
+    /// in the current topology.
+    pub fn drain_rail(&mut self, rail_id: &str, now_ms: u64) -> Result<Vec<String>, MeshError> {
+        if !self.topology.rails.contains_key(rail_id) {
+            return Err(MeshError::UnknownRail {
+                rail_id: rail_id.to_string(),
+            });
+        }
+
+        let mut rails_by_level: Vec<&IsolationRail> = self.topology.rails.values().collect();
+        rails_by_level.sort_by_key(|rail| rail.level);
+        let rails_by_level: Vec<String> = rails_by_level
+            .into_iter()
+            .map(|rail| rail.rail_id.clone())
+            .collect();
+
+        let workload_ids: Vec<String> = self
+            .workloads
+            .iter()
+            .filter(|(_, placement)| placement.current_rail_id == rail_id)
+            .map(|(workload_id, _)| workload_id.clone())
+            .collect();
+
+        let mut unmoved = Vec::new();
+
+        for workload_id in workload_ids {
+            let Some(current_level) = self.workloads.get(&workload_id).map(|p| p.current_level)
+            else {
+                continue;
+            };
+
+            let targets: Vec<&String> = rails_by_level
+                .iter()
+                .filter(|candidate_id| {
+                    self.topology
+                        .rails
+                        .get(candidate_id.as_str())
+                        .is_some_and(|rail| rail.level > current_level)
+                })
+                .collect();
+
+            let mut migrated = false;
+            for target_rail_id in targets {
+                if self
+                    .elevate_workload(&workload_id, target_rail_id, now_ms)
+                    .is_ok()
+                {
+                    migrated = true;
+                    break;
+                }
+            }
+
+            if !migrated {
+                unmoved.push(workload_id);
+            }
+        }
+
+        Ok(unmoved)
+    }
+
     // -----------------------------------------------------------------------
     // Remove a workload from the mesh
     // -----------------------------------------------------------------------
@@ -726,6 +1479,15 @@ impl IsolationMesh {
                 .or_insert_with(|| RailState::new(rail_id));
         }
 
+        // Refresh each workload's cached latency overhead in case its rail's
+        // cost changed under it -- this is what lets workloads_over_budget
+        // catch a reload that retroactively violates a budget.
+        for placement in self.workloads.values_mut() {
+            if let Some(rail) = new_topology.rails.get(&placement.current_rail_id) {
+                placement.current_latency_overhead_us = rail.latency_overhead_us;
+            }
+        }
+
         self.topology = new_topology;
 
         self.push_event(
@@ -780,6 +1542,7 @@ mod tests {
             level: IsolationRailLevel::Shared,
             latency_overhead_us: 10,
             capacity: 4,
+            cost_units: 1,
         }
     }
 
@@ -789,6 +1552,7 @@ mod tests {
             level: IsolationRailLevel::ProcessIsolated,
             latency_overhead_us: 50,
             capacity: 4,
+            cost_units: 3,
         }
     }
 
@@ -798,6 +1562,7 @@ mod tests {
             level: IsolationRailLevel::SandboxIsolated,
             latency_overhead_us: 200,
             capacity: 2,
+            cost_units: 5,
         }
     }
 
@@ -807,6 +1572,7 @@ mod tests {
             level: IsolationRailLevel::HardwareIsolated,
             latency_overhead_us: 500,
             capacity: 1,
+            cost_units: 10,
         }
     }
 
@@ -845,107 +1611,433 @@ mod tests {
         }
     }
 
-    // --- topology validation ---
+    // --- topology validation ---
+
+    #[test]
+    fn empty_topology_rejected() {
+        let topo = MeshTopology {
+            rails: BTreeMap::new(),
+        };
+        let err = IsolationMesh::new(topo).expect_err("empty topology");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
+    #[test]
+    fn zero_capacity_rail_rejected() {
+        let mut rails = BTreeMap::new();
+        rails.insert(
+            "r".to_string(),
+            IsolationRail {
+                rail_id: "r".to_string(),
+                level: IsolationRailLevel::Shared,
+                latency_overhead_us: 0,
+                capacity: 0,
+                cost_units: 1,
+            },
+        );
+        let topo = MeshTopology { rails };
+        let err = IsolationMesh::new(topo).expect_err("zero capacity");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
+    #[test]
+    fn mismatched_rail_id_rejected() {
+        let mut rails = BTreeMap::new();
+        rails.insert(
+            "wrong-key".to_string(),
+            IsolationRail {
+                rail_id: "right-id".to_string(),
+                level: IsolationRailLevel::Shared,
+                latency_overhead_us: 0,
+                capacity: 1,
+                cost_units: 1,
+            },
+        );
+        let topo = MeshTopology { rails };
+        let err = IsolationMesh::new(topo).expect_err("mismatch");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
+    // --- place workload ---
+
+    #[test]
+    fn place_workload_happy_path() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        let p = mesh
+            .place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place");
+        assert_eq!(p.current_rail_id, "shared-1");
+        assert_eq!(p.current_level, IsolationRailLevel::Shared);
+        assert!(p.elevation_history.is_empty());
+
+        let rs = mesh.rail_states().get("shared-1").unwrap();
+        assert_eq!(rs.active_count, 1);
+        assert_eq!(rs.total_placed, 1);
+    }
+
+    #[test]
+    fn place_on_unknown_rail_fails_closed() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        let err = mesh
+            .place_workload("w1", "nonexistent", permissive_policy(), 1)
+            .expect_err("unknown rail");
+        assert_eq!(err.code(), error_codes::ERR_MESH_UNKNOWN_RAIL);
+    }
+
+    #[test]
+    fn duplicate_workload_rejected() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("first");
+        let err = mesh
+            .place_workload("w1", "proc-1", permissive_policy(), 2)
+            .expect_err("dup");
+        assert_eq!(err.code(), error_codes::ERR_MESH_DUPLICATE_WORKLOAD);
+    }
+
+    #[test]
+    fn rail_at_capacity_rejected() {
+        let mut rails = BTreeMap::new();
+        rails.insert(
+            "tiny".to_string(),
+            IsolationRail {
+                rail_id: "tiny".to_string(),
+                level: IsolationRailLevel::Shared,
+                latency_overhead_us: 0,
+                capacity: 1,
+                cost_units: 1,
+            },
+        );
+        let mut mesh = IsolationMesh::new(MeshTopology { rails }).expect("mesh");
+        mesh.place_workload("w1", "tiny", permissive_policy(), 1)
+            .expect("first");
+        let err = mesh
+            .place_workload("w2", "tiny", permissive_policy(), 2)
+            .expect_err("at capacity");
+        assert_eq!(err.code(), error_codes::ERR_MESH_RAIL_AT_CAPACITY);
+    }
+
+    // --- rail utilization / best fit ---
+
+    #[test]
+    fn rail_utilization_reports_active_count_over_capacity() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+        mesh.place_workload("w2", "shared-1", permissive_policy(), 2)
+            .expect("place w2");
+
+        let utilization = mesh.rail_utilization();
+        // shared-1 has capacity 4, two workloads placed.
+        assert!((utilization["shared-1"] - 0.5).abs() < f64::EPSILON);
+        assert!((utilization["proc-1"] - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn place_best_fit_picks_least_strict_qualifying_rail() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        let placement = mesh
+            .place_best_fit("w1", IsolationRailLevel::Shared, permissive_policy(), 1)
+            .expect("best fit");
+        assert_eq!(placement.current_rail_id, "shared-1");
+    }
+
+    #[test]
+    fn place_best_fit_falls_back_up_the_ladder_when_preferred_level_is_full() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        // shared-1 has capacity 4; fill it so the next call must fall back.
+        for i in 0..4 {
+            mesh.place_workload(&format!("filler-{i}"), "shared-1", permissive_policy(), 1)
+                .expect("fill shared-1");
+        }
+
+        let placement = mesh
+            .place_best_fit("w1", IsolationRailLevel::Shared, permissive_policy(), 2)
+            .expect("falls back to proc-1");
+        assert_eq!(placement.current_rail_id, "proc-1");
+    }
+
+    #[test]
+    fn place_best_fit_fails_closed_when_nothing_qualifies() {
+        let mut rails = BTreeMap::new();
+        rails.insert(
+            "tiny".to_string(),
+            IsolationRail {
+                rail_id: "tiny".to_string(),
+                level: IsolationRailLevel::Shared,
+                latency_overhead_us: 0,
+                capacity: 1,
+                cost_units: 1,
+            },
+        );
+        let mut mesh = IsolationMesh::new(MeshTopology { rails }).expect("mesh");
+        mesh.place_workload("w1", "tiny", permissive_policy(), 1)
+            .expect("fill tiny");
+
+        let err = mesh
+            .place_best_fit("w2", IsolationRailLevel::Shared, permissive_policy(), 2)
+            .expect_err("no capacity anywhere");
+        assert_eq!(err.code(), error_codes::ERR_MESH_RAIL_AT_CAPACITY);
+    }
+
+    // --- affinity / anti-affinity ---
+
+    #[test]
+    fn anti_affinity_rejects_co_placement_on_explicit_rail() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: vec!["w1".to_string()],
+            affinity: Vec::new(),
+        };
+        let err = mesh
+            .place_workload_with_affinity("w2", "shared-1", permissive_policy(), 2, &constraints)
+            .expect_err("anti-affinity violation");
+        assert_eq!(err.code(), error_codes::ERR_MESH_AFFINITY_VIOLATED);
+        assert!(mesh.workloads().get("w2").is_none());
+    }
+
+    #[test]
+    fn anti_affinity_allows_placement_on_a_different_rail() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: vec!["w1".to_string()],
+            affinity: Vec::new(),
+        };
+        let placement = mesh
+            .place_workload_with_affinity("w2", "proc-1", permissive_policy(), 2, &constraints)
+            .expect("different rail is fine");
+        assert_eq!(placement.current_rail_id, "proc-1");
+    }
+
+    #[test]
+    fn auto_place_steers_toward_rail_hosting_affinity_workload() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "proc-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: Vec::new(),
+            affinity: vec!["w1".to_string()],
+        };
+        let placement = mesh
+            .auto_place_workload(
+                "w2",
+                &["shared-1".to_string(), "proc-1".to_string()],
+                permissive_policy(),
+                2,
+                &constraints,
+            )
+            .expect("auto place");
+        assert_eq!(placement.current_rail_id, "proc-1");
+    }
+
+    #[test]
+    fn auto_place_excludes_candidates_hosting_anti_affinity_workload() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "proc-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: vec!["w1".to_string()],
+            affinity: Vec::new(),
+        };
+        let placement = mesh
+            .auto_place_workload(
+                "w2",
+                &["proc-1".to_string(), "shared-1".to_string()],
+                permissive_policy(),
+                2,
+                &constraints,
+            )
+            .expect("auto place onto the non-excluded candidate");
+        assert_eq!(placement.current_rail_id, "shared-1");
+    }
 
     #[test]
-    fn empty_topology_rejected() {
-        let topo = MeshTopology {
-            rails: BTreeMap::new(),
+    fn auto_place_fails_closed_when_every_candidate_is_excluded() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "proc-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: vec!["w1".to_string()],
+            affinity: Vec::new(),
         };
-        let err = IsolationMesh::new(topo).expect_err("empty topology");
-        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+        let err = mesh
+            .auto_place_workload(
+                "w2",
+                &["proc-1".to_string()],
+                permissive_policy(),
+                2,
+                &constraints,
+            )
+            .expect_err("every candidate excluded");
+        assert_eq!(err.code(), error_codes::ERR_MESH_AFFINITY_VIOLATED);
     }
 
     #[test]
-    fn zero_capacity_rail_rejected() {
+    fn auto_place_falls_back_when_preferred_rail_is_at_capacity() {
         let mut rails = BTreeMap::new();
         rails.insert(
-            "r".to_string(),
+            "tiny".to_string(),
             IsolationRail {
-                rail_id: "r".to_string(),
+                rail_id: "tiny".to_string(),
                 level: IsolationRailLevel::Shared,
                 latency_overhead_us: 0,
-                capacity: 0,
+                capacity: 1,
+                cost_units: 1,
             },
         );
-        let topo = MeshTopology { rails };
-        let err = IsolationMesh::new(topo).expect_err("zero capacity");
-        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+        rails.insert("shared-1".to_string(), shared_rail());
+        let mut mesh = IsolationMesh::new(MeshTopology { rails }).expect("mesh");
+        mesh.place_workload("w1", "tiny", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: Vec::new(),
+            affinity: vec!["w1".to_string()],
+        };
+        let placement = mesh
+            .auto_place_workload(
+                "w2",
+                &["tiny".to_string(), "shared-1".to_string()],
+                permissive_policy(),
+                2,
+                &constraints,
+            )
+            .expect("falls back off the full preferred rail");
+        assert_eq!(placement.current_rail_id, "shared-1");
     }
 
-    #[test]
-    fn mismatched_rail_id_rejected() {
+    // --- optimized auto-placement ---
+
+    /// A topology where the cheapest rail is not the fastest one, so
+    /// `Latency` and `Cost` objectives disagree on the best candidate.
+    fn cost_latency_tradeoff_topology() -> MeshTopology {
         let mut rails = BTreeMap::new();
         rails.insert(
-            "wrong-key".to_string(),
+            "fast-expensive".to_string(),
             IsolationRail {
-                rail_id: "right-id".to_string(),
+                rail_id: "fast-expensive".to_string(),
                 level: IsolationRailLevel::Shared,
-                latency_overhead_us: 0,
-                capacity: 1,
+                latency_overhead_us: 5,
+                capacity: 4,
+                cost_units: 100,
             },
         );
-        let topo = MeshTopology { rails };
-        let err = IsolationMesh::new(topo).expect_err("mismatch");
-        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+        rails.insert(
+            "cheap-slow".to_string(),
+            IsolationRail {
+                rail_id: "cheap-slow".to_string(),
+                level: IsolationRailLevel::Shared,
+                latency_overhead_us: 500,
+                capacity: 4,
+                cost_units: 1,
+            },
+        );
+        MeshTopology { rails }
     }
 
-    // --- place workload ---
-
     #[test]
-    fn place_workload_happy_path() {
-        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
-        let p = mesh
-            .place_workload("w1", "shared-1", permissive_policy(), 1)
+    fn optimized_auto_place_prefers_lowest_latency_rail_under_latency_objective() {
+        let mut mesh = IsolationMesh::new(cost_latency_tradeoff_topology()).expect("mesh");
+        let placement = mesh
+            .auto_place_workload_optimized(
+                "w1",
+                &["fast-expensive".to_string(), "cheap-slow".to_string()],
+                permissive_policy(),
+                1,
+                &AffinityConstraints::default(),
+                OptimizeFor::Latency,
+            )
             .expect("place");
-        assert_eq!(p.current_rail_id, "shared-1");
-        assert_eq!(p.current_level, IsolationRailLevel::Shared);
-        assert!(p.elevation_history.is_empty());
-
-        let rs = mesh.rail_states().get("shared-1").unwrap();
-        assert_eq!(rs.active_count, 1);
-        assert_eq!(rs.total_placed, 1);
+        assert_eq!(placement.current_rail_id, "fast-expensive");
     }
 
     #[test]
-    fn place_on_unknown_rail_fails_closed() {
-        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
-        let err = mesh
-            .place_workload("w1", "nonexistent", permissive_policy(), 1)
-            .expect_err("unknown rail");
-        assert_eq!(err.code(), error_codes::ERR_MESH_UNKNOWN_RAIL);
+    fn optimized_auto_place_prefers_lowest_cost_rail_under_cost_objective() {
+        let mut mesh = IsolationMesh::new(cost_latency_tradeoff_topology()).expect("mesh");
+        let placement = mesh
+            .auto_place_workload_optimized(
+                "w1",
+                &["fast-expensive".to_string(), "cheap-slow".to_string()],
+                permissive_policy(),
+                1,
+                &AffinityConstraints::default(),
+                OptimizeFor::Cost,
+            )
+            .expect("place");
+        assert_eq!(placement.current_rail_id, "cheap-slow");
     }
 
     #[test]
-    fn duplicate_workload_rejected() {
-        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
-        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
-            .expect("first");
-        let err = mesh
-            .place_workload("w1", "proc-1", permissive_policy(), 2)
-            .expect_err("dup");
-        assert_eq!(err.code(), error_codes::ERR_MESH_DUPLICATE_WORKLOAD);
+    fn optimized_auto_place_balanced_weights_latency_over_cost_as_latency_weight_increases() {
+        let mut mesh = IsolationMesh::new(cost_latency_tradeoff_topology()).expect("mesh");
+        let placement = mesh
+            .auto_place_workload_optimized(
+                "w1",
+                &["fast-expensive".to_string(), "cheap-slow".to_string()],
+                permissive_policy(),
+                1,
+                &AffinityConstraints::default(),
+                OptimizeFor::Balanced {
+                    latency_weight: 0.9,
+                },
+            )
+            .expect("place");
+        assert_eq!(placement.current_rail_id, "fast-expensive");
     }
 
     #[test]
-    fn rail_at_capacity_rejected() {
-        let mut rails = BTreeMap::new();
-        rails.insert(
-            "tiny".to_string(),
-            IsolationRail {
-                rail_id: "tiny".to_string(),
-                level: IsolationRailLevel::Shared,
-                latency_overhead_us: 0,
-                capacity: 1,
-            },
-        );
+    fn optimized_auto_place_falls_back_when_best_scoring_rail_is_at_capacity() {
+        let mut rails = cost_latency_tradeoff_topology().rails;
+        if let Some(rail) = rails.get_mut("fast-expensive") {
+            rail.capacity = 1;
+        }
         let mut mesh = IsolationMesh::new(MeshTopology { rails }).expect("mesh");
-        mesh.place_workload("w1", "tiny", permissive_policy(), 1)
-            .expect("first");
-        let err = mesh
-            .place_workload("w2", "tiny", permissive_policy(), 2)
-            .expect_err("at capacity");
-        assert_eq!(err.code(), error_codes::ERR_MESH_RAIL_AT_CAPACITY);
+        mesh.place_workload("w0", "fast-expensive", permissive_policy(), 1)
+            .expect("place w0");
+
+        let placement = mesh
+            .auto_place_workload_optimized(
+                "w1",
+                &["fast-expensive".to_string(), "cheap-slow".to_string()],
+                permissive_policy(),
+                2,
+                &AffinityConstraints::default(),
+                OptimizeFor::Latency,
+            )
+            .expect("falls back off the full best-scoring rail");
+        assert_eq!(placement.current_rail_id, "cheap-slow");
+    }
+
+    #[test]
+    fn optimized_auto_place_excludes_candidates_hosting_anti_affinity_workload() {
+        let mut mesh = IsolationMesh::new(cost_latency_tradeoff_topology()).expect("mesh");
+        mesh.place_workload("w1", "cheap-slow", permissive_policy(), 1)
+            .expect("place w1");
+
+        let constraints = AffinityConstraints {
+            anti_affinity: vec!["w1".to_string()],
+            affinity: Vec::new(),
+        };
+        let placement = mesh
+            .auto_place_workload_optimized(
+                "w2",
+                &["fast-expensive".to_string(), "cheap-slow".to_string()],
+                permissive_policy(),
+                2,
+                &constraints,
+                OptimizeFor::Cost,
+            )
+            .expect("cheap-slow excluded, falls back to fast-expensive");
+        assert_eq!(placement.current_rail_id, "fast-expensive");
     }
 
     // --- hot elevation ---
@@ -1071,6 +2163,73 @@ mod tests {
         assert_eq!(err.code(), error_codes::ERR_MESH_RAIL_AT_CAPACITY);
     }
 
+    // --- drain rail ---
+
+    #[test]
+    fn drain_rail_elevates_every_workload_to_the_next_strictest_rail() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+        mesh.place_workload("w2", "shared-1", permissive_policy(), 2)
+            .expect("place w2");
+
+        let unmoved = mesh.drain_rail("shared-1", 3).expect("drain");
+        assert!(unmoved.is_empty());
+
+        assert_eq!(
+            mesh.workloads().get("w1").unwrap().current_rail_id,
+            "proc-1"
+        );
+        assert_eq!(
+            mesh.workloads().get("w2").unwrap().current_rail_id,
+            "proc-1"
+        );
+        assert_eq!(mesh.rail_states().get("shared-1").unwrap().active_count, 0);
+
+        // The rail is now empty, so reload_topology can drop it.
+        let mut remaining = test_topology().rails;
+        remaining.remove("shared-1");
+        mesh.reload_topology(MeshTopology { rails: remaining }, 4)
+            .expect("shared-1 can be dropped once drained");
+    }
+
+    #[test]
+    fn drain_rail_reports_workloads_blocked_by_a_no_elevation_policy() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+        mesh.place_workload("w2", "shared-1", no_elevation_policy(), 2)
+            .expect("place w2");
+
+        let unmoved = mesh.drain_rail("shared-1", 3).expect("drain");
+        assert_eq!(unmoved, vec!["w2".to_string()]);
+
+        assert_eq!(
+            mesh.workloads().get("w1").unwrap().current_rail_id,
+            "proc-1"
+        );
+        assert_eq!(
+            mesh.workloads().get("w2").unwrap().current_rail_id,
+            "shared-1"
+        );
+        assert_eq!(mesh.rail_states().get("shared-1").unwrap().active_count, 1);
+
+        // w2 is still on shared-1, so reload_topology must keep refusing.
+        let mut remaining = test_topology().rails;
+        remaining.remove("shared-1");
+        let err = mesh
+            .reload_topology(MeshTopology { rails: remaining }, 4)
+            .expect_err("shared-1 still hosts w2");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
+    #[test]
+    fn drain_unknown_rail_fails_closed() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        let err = mesh.drain_rail("nowhere", 1).expect_err("unknown rail");
+        assert_eq!(err.code(), error_codes::ERR_MESH_UNKNOWN_RAIL);
+    }
+
     // --- remove workload ---
 
     #[test]
@@ -1108,6 +2267,7 @@ mod tests {
                 level: IsolationRailLevel::SandboxIsolated,
                 latency_overhead_us: 100,
                 capacity: 2,
+                cost_units: 2,
             },
         );
         mesh.reload_topology(new_topo, 5).expect("reload");
@@ -1129,6 +2289,146 @@ mod tests {
         assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
     }
 
+    // --- latency overhead accounting ---
+
+    #[test]
+    fn place_and_elevate_update_current_latency_overhead() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        let p = mesh
+            .place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place");
+        assert_eq!(p.current_latency_overhead_us, 10);
+        assert_eq!(mesh.total_latency_overhead_us(), 10);
+
+        let p = mesh.elevate_workload("w1", "hw-1", 2).expect("elevate");
+        assert_eq!(p.current_latency_overhead_us, 500);
+        assert_eq!(mesh.total_latency_overhead_us(), 500);
+    }
+
+    #[test]
+    fn workloads_over_budget_is_empty_when_everyone_is_within_budget() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", budget_policy(1_000), 1)
+            .expect("place");
+        assert!(mesh.workloads_over_budget().is_empty());
+    }
+
+    #[test]
+    fn reload_retroactively_raising_rail_overhead_is_caught_by_workloads_over_budget() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        // Budget of 20us is fine on shared-1's current 10us overhead.
+        mesh.place_workload("w1", "shared-1", budget_policy(20), 1)
+            .expect("place");
+        assert!(mesh.workloads_over_budget().is_empty());
+
+        // Reload bumps shared-1's overhead past w1's budget without moving it.
+        let mut reloaded = test_topology();
+        reloaded
+            .rails
+            .get_mut("shared-1")
+            .unwrap()
+            .latency_overhead_us = 1_000;
+        mesh.reload_topology(reloaded, 2).expect("reload");
+
+        assert_eq!(
+            mesh.workloads()
+                .get("w1")
+                .unwrap()
+                .current_latency_overhead_us,
+            1_000
+        );
+        assert_eq!(mesh.workloads_over_budget(), vec!["w1".to_string()]);
+    }
+
+    // --- checkpoint / restore ---
+
+    #[test]
+    fn to_state_then_from_state_reconstructs_an_identical_mesh() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+        mesh.place_workload("w2", "proc-1", permissive_policy(), 2)
+            .expect("place w2");
+        mesh.elevate_workload("w1", "sandbox-1", 3)
+            .expect("elevate w1");
+
+        let state = mesh.to_state();
+        let restored = IsolationMesh::from_state(state).expect("restore");
+
+        assert_eq!(restored.topology(), mesh.topology());
+        assert_eq!(restored.workloads(), mesh.workloads());
+        assert_eq!(restored.rail_states(), mesh.rail_states());
+        assert_eq!(restored.events(), mesh.events());
+        assert_eq!(restored.event_seq, mesh.event_seq);
+    }
+
+    #[test]
+    fn snapshot_record_round_trip_preserves_elevation_history() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+        mesh.elevate_workload("w1", "proc-1", 2)
+            .expect("elevate w1 to proc-1");
+        mesh.elevate_workload("w1", "hw-1", 3)
+            .expect("elevate w1 to hw-1");
+
+        let record = mesh.to_snapshot_record("snap-1", "2026-08-08T00:00:00Z");
+        assert_eq!(record.snapshot_id, "snap-1");
+
+        let restored = IsolationMesh::from_snapshot_record(&record).expect("restore");
+
+        assert_eq!(restored.topology(), mesh.topology());
+        assert_eq!(restored.rail_states(), mesh.rail_states());
+        let restored_history = &restored.workloads().get("w1").unwrap().elevation_history;
+        let original_history = &mesh.workloads().get("w1").unwrap().elevation_history;
+        assert_eq!(restored_history, original_history);
+        assert_eq!(restored_history.len(), 2);
+        assert_eq!(
+            restored.workloads().get("w1").unwrap().current_rail_id,
+            "hw-1"
+        );
+    }
+
+    #[test]
+    fn from_snapshot_record_rejects_malformed_json() {
+        let record = IsolationMeshSnapshotRecord {
+            snapshot_id: "bad".to_string(),
+            topology_json: "not json".to_string(),
+            workloads_json: "{}".to_string(),
+            rail_states_json: "{}".to_string(),
+            event_seq: 0,
+            captured_at: "2026-08-08T00:00:00Z".to_string(),
+        };
+        let err = IsolationMesh::from_snapshot_record(&record).expect_err("malformed json");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
+    #[test]
+    fn from_state_rejects_workload_on_unknown_rail() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let mut state = mesh.to_state();
+        state.workloads.get_mut("w1").unwrap().current_rail_id = "nonexistent".to_string();
+
+        let err = IsolationMesh::from_state(state).expect_err("dangling rail reference");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
+    #[test]
+    fn from_state_rejects_active_count_mismatch() {
+        let mut mesh = IsolationMesh::new(test_topology()).expect("mesh");
+        mesh.place_workload("w1", "shared-1", permissive_policy(), 1)
+            .expect("place w1");
+
+        let mut state = mesh.to_state();
+        state.rail_states.get_mut("shared-1").unwrap().active_count = 0;
+
+        let err = IsolationMesh::from_state(state).expect_err("active_count mismatch");
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
+
     // --- event codes ---
 
     #[test]
@@ -1295,6 +2595,7 @@ mod tests {
                 level: IsolationRailLevel::Shared,
                 latency_overhead_us: 0,
                 capacity: 1,
+                cost_units: 1,
             },
         );
         let mut mesh = IsolationMesh::new(MeshTopology { rails }).expect("mesh");
@@ -1662,6 +2963,7 @@ mod tests {
                 level: IsolationRailLevel::SandboxIsolated,
                 latency_overhead_us: 10,
                 capacity: 0,
+                cost_units: 1,
             },
         );
 
@@ -1726,6 +3028,7 @@ mod tests {
                 level: IsolationRailLevel::Shared,
                 latency_overhead_us: 0,
                 capacity: 1,
+                cost_units: 1,
             },
         );
 
@@ -1861,4 +3164,73 @@ mod tests {
         push_bounded(&mut single, 200, 1);
         assert_eq!(single, vec![200]);
     }
+
+    #[test]
+    fn validate_strict_clean_topology_has_no_warnings() {
+        let mut topology = MeshTopology { rails: BTreeMap::new() };
+        topology.rails.insert("shared-1".to_string(), shared_rail());
+        topology.rails.insert("proc-1".to_string(), process_rail());
+        topology.rails.insert("sandbox-1".to_string(), sandbox_rail());
+        topology.rails.insert("hw-1".to_string(), hw_rail());
+
+        let warnings = topology.validate_strict().expect("valid topology");
+        assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+    }
+
+    #[test]
+    fn validate_strict_flags_non_monotonic_latency() {
+        let mut topology = MeshTopology { rails: BTreeMap::new() };
+        topology.rails.insert("shared-1".to_string(), shared_rail());
+        let mut misconfigured_proc = process_rail();
+        // A stricter rail with a lower latency overhead than the shared rail
+        // it supersedes is suspicious and should be flagged.
+        misconfigured_proc.latency_overhead_us = 1;
+        topology.rails.insert("proc-1".to_string(), misconfigured_proc);
+
+        let warnings = topology.validate_strict().expect("topology structurally valid");
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            TopologyWarning::NonMonotonicLatency {
+                lower_level: IsolationRailLevel::Shared,
+                higher_level: IsolationRailLevel::ProcessIsolated,
+                lower_latency_us: 10,
+                higher_latency_us: 1,
+            }
+        )));
+        assert_eq!(
+            warnings[0].code(),
+            warning_codes::WARN_MESH_NON_MONOTONIC_LATENCY
+        );
+    }
+
+    #[test]
+    fn validate_strict_flags_duplicate_level() {
+        let mut topology = MeshTopology { rails: BTreeMap::new() };
+        topology.rails.insert("shared-1".to_string(), shared_rail());
+        let mut shared_2 = shared_rail();
+        shared_2.rail_id = "shared-2".to_string();
+        topology.rails.insert("shared-2".to_string(), shared_2);
+
+        let warnings = topology.validate_strict().expect("topology structurally valid");
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            TopologyWarning::DuplicateLevel { level: IsolationRailLevel::Shared, .. }
+        )));
+    }
+
+    #[test]
+    fn validate_strict_flags_missing_lowest_level() {
+        let mut topology = MeshTopology { rails: BTreeMap::new() };
+        topology.rails.insert("proc-1".to_string(), process_rail());
+
+        let warnings = topology.validate_strict().expect("topology structurally valid");
+        assert!(warnings.contains(&TopologyWarning::MissingLowestLevel));
+    }
+
+    #[test]
+    fn validate_strict_propagates_hard_errors_from_validate() {
+        let topology = MeshTopology { rails: BTreeMap::new() };
+        let err = topology.validate_strict().unwrap_err();
+        assert_eq!(err.code(), error_codes::ERR_MESH_INVALID_TOPOLOGY);
+    }
 }
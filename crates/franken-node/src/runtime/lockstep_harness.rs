@@ -1,6 +1,6 @@
 use crate::runtime::nversion_oracle::{
-    BoundaryScope, CheckOutcome, DivergenceReport, OracleVerdict, RiskTier, RuntimeEntry,
-    RuntimeOracle, SemanticDivergence,
+    BoundaryScope, CheckOutcome, DivergenceReport, DivergenceState, OracleVerdict, RiskTier,
+    RuntimeEntry, RuntimeOracle, SemanticDivergence,
 };
 use anyhow::{Context, Result};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
@@ -102,6 +102,32 @@ pub struct LockstepHarness {
     runtimes: Vec<String>,
 }
 
+/// Options controlling a [`LockstepHarness::verify_lockstep`] run.
+#[derive(Debug, Clone, Copy)]
+pub struct LockstepVerifyOptions {
+    /// Emit divergence fixtures for failing comparisons.
+    pub emit_fixtures: bool,
+    /// Emit the oracle's `DivergenceReport` as structured JSON instead of a
+    /// human-readable summary.
+    pub json: bool,
+    /// Percentage of registered runtimes that must agree for the oracle to
+    /// consider a cross-check conclusive.
+    pub quorum_threshold_percent: u8,
+    /// Minimum divergence risk tier that blocks release.
+    pub blocking_floor: RiskTier,
+}
+
+impl Default for LockstepVerifyOptions {
+    fn default() -> Self {
+        Self {
+            emit_fixtures: false,
+            json: true,
+            quorum_threshold_percent: 100,
+            blocking_floor: RiskTier::High,
+        }
+    }
+}
+
 #[derive(Debug)]
 /// One lockstep leg's captured execution. `comparison` carries the bytes the
 /// cross-runtime oracle equates (guest stdout/stderr + exit code);
@@ -373,11 +399,11 @@ impl LockstepHarness {
 
     /// Spawns the specified runtimes concurrently, intercepts their outputs,
     /// and feeds the results to the Oracle.
-    pub fn verify_lockstep(&self, app_path: &Path, emit_fixtures: bool) -> Result<()> {
+    pub fn verify_lockstep(&self, app_path: &Path, options: &LockstepVerifyOptions) -> Result<()> {
         self.validate_runtimes()?;
         if let Some(corpus_entries) = Self::resolve_lockstep_corpus_entries(app_path)? {
             for entry in corpus_entries {
-                self.verify_lockstep_entry(&entry, emit_fixtures)
+                self.verify_lockstep_entry(&entry, options)
                     .with_context(|| {
                         format!("lockstep corpus fixture failed: {}", entry.display())
                     })?;
@@ -385,7 +411,7 @@ impl LockstepHarness {
             return Ok(());
         }
 
-        self.verify_lockstep_entry(app_path, emit_fixtures)
+        self.verify_lockstep_entry(app_path, options)
     }
 
     fn resolve_lockstep_corpus_entries(app_path: &Path) -> Result<Option<Vec<PathBuf>>> {
@@ -485,8 +511,14 @@ impl LockstepHarness {
         Ok(relative_path)
     }
 
-    fn verify_lockstep_entry(&self, app_path: &Path, emit_fixtures: bool) -> Result<()> {
-        let mut oracle = RuntimeOracle::new("lockstep-harness-trace", 100);
+    fn verify_lockstep_entry(
+        &self,
+        app_path: &Path,
+        options: &LockstepVerifyOptions,
+    ) -> Result<()> {
+        let mut oracle =
+            RuntimeOracle::new("lockstep-harness-trace", options.quorum_threshold_percent)
+                .with_blocking_floor(options.blocking_floor);
 
         for rt in &self.runtimes {
             oracle
@@ -495,6 +527,7 @@ impl LockstepHarness {
                     runtime_name: rt.clone(),
                     version: "unknown".to_string(),
                     is_reference: !Self::is_franken_runtime(rt),
+                    engine_family: rt.clone(),
                 })
                 .map_err(|e| anyhow::anyhow!("Oracle registration error: {}", e))?;
         }
@@ -558,17 +591,38 @@ impl LockstepHarness {
 
         // Generate and print the report
         let report = oracle.generate_report(0);
-        if emit_fixtures && !report.divergences.is_empty() {
+        if options.emit_fixtures && !report.divergences.is_empty() {
             for path in Self::emit_divergence_fixtures(app_path, &report, &syscall_audits)? {
                 eprintln!("lockstep divergence fixture written: {}", path.display());
             }
         }
-        let canonical_json = serde_json::to_string_pretty(&report)?;
-        println!("{}", canonical_json);
+        if options.json {
+            let canonical_json = serde_json::to_string_pretty(&report)?;
+            println!("{}", canonical_json);
+        } else {
+            Self::print_human_readable_report(&report);
+        }
 
         Self::ensure_report_passes(&report)
     }
 
+    /// Prints a short human-readable summary of a `DivergenceReport`, used
+    /// when `--json` is not passed.
+    fn print_human_readable_report(report: &DivergenceReport) {
+        println!(
+            "lockstep verdict: {} ({} checks, {} divergences)",
+            report.verdict.label(),
+            report.checks.len(),
+            report.divergences.len()
+        );
+        for divergence in &report.divergences {
+            println!(
+                "  divergence {} [{}] state={}",
+                divergence.divergence_id, divergence.risk_tier, divergence.state
+            );
+        }
+    }
+
     fn ensure_report_passes(report: &DivergenceReport) -> Result<()> {
         match &report.verdict {
             OracleVerdict::Pass => Ok(()),
@@ -2116,6 +2170,7 @@ mod tests {
                 runtime_name: rt.clone(),
                 version: "unknown".to_string(),
                 is_reference: !LockstepHarness::is_franken_runtime(rt),
+                engine_family: rt.clone(),
             };
             oracle.register_runtime(entry).expect("register");
         }
@@ -2135,6 +2190,7 @@ mod tests {
                 runtime_name: "node".into(),
                 version: "20.0".into(),
                 is_reference: true,
+                engine_family: "node".into(),
             })
             .expect("register node");
         oracle
@@ -2143,6 +2199,7 @@ mod tests {
                 runtime_name: "franken-node".into(),
                 version: "0.1".into(),
                 is_reference: false,
+                engine_family: "franken-node".into(),
             })
             .expect("register fn");
 
@@ -2172,6 +2229,7 @@ mod tests {
                 runtime_name: "node".into(),
                 version: "20.0".into(),
                 is_reference: true,
+                engine_family: "node".into(),
             })
             .expect("register");
         oracle
@@ -2180,6 +2238,7 @@ mod tests {
                 runtime_name: "franken-node".into(),
                 version: "0.1".into(),
                 is_reference: false,
+                engine_family: "franken-node".into(),
             })
             .expect("register");
 
@@ -2212,6 +2271,7 @@ mod tests {
                 runtime_name: "node".into(),
                 version: "20.0".into(),
                 is_reference: true,
+                engine_family: "node".into(),
             })
             .expect("register");
 
@@ -2242,6 +2302,7 @@ mod tests {
                 runtime_name: "node".to_string(),
                 version: "20.0.0".to_string(),
                 is_reference: true,
+                engine_family: "node".to_string(),
             },
         );
         runtimes.insert(
@@ -2251,6 +2312,7 @@ mod tests {
                 runtime_name: "franken-node".to_string(),
                 version: "0.1.0".to_string(),
                 is_reference: false,
+                engine_family: "franken-node".to_string(),
             },
         );
 
@@ -2270,6 +2332,7 @@ mod tests {
                 outcome: Some(CheckOutcome::Diverge {
                     outputs: outputs.clone(),
                 }),
+                evidence: BTreeMap::new(),
             }],
             divergences: vec![SemanticDivergence {
                 divergence_id: "div-1".to_string(),
@@ -2277,13 +2340,18 @@ mod tests {
                 boundary_scope: BoundaryScope::IO,
                 risk_tier: RiskTier::High,
                 runtime_outputs: outputs,
-                resolved: false,
+                state: DivergenceState::Open,
                 resolution_note: None,
                 trace_id: "trace-lockstep".to_string(),
+                annotations: BTreeMap::new(),
+                resolution_evidence: None,
+                consistency: 1.0,
             }],
             voting_results: Vec::new(),
+            vote_conflicts: Vec::new(),
             receipts: Vec::new(),
             verdict,
+            risk_tier_counts: BTreeMap::new(),
             event_log: vec![OracleEvent {
                 event_code: "FN-NV-012".to_string(),
                 trace_id: "trace-lockstep".to_string(),
@@ -2319,6 +2387,38 @@ mod tests {
         assert!(message.contains("div-1"));
     }
 
+    #[test]
+    fn forced_critical_divergence_fails_verification_with_divergence_id_in_output() {
+        let mut oracle = RuntimeOracle::new("trace-forced-critical", 100)
+            .with_blocking_floor(crate::runtime::nversion_oracle::RiskTier::High);
+        oracle
+            .register_runtime(RuntimeEntry {
+                runtime_id: "node".into(),
+                runtime_name: "node".into(),
+                version: "20.0".into(),
+                is_reference: true,
+                engine_family: "node".into(),
+            })
+            .expect("register");
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("node".to_string(), b"node-output".to_vec());
+        oracle.classify_divergence(
+            "div-forced-critical",
+            "check-1",
+            BoundaryScope::Security,
+            crate::runtime::nversion_oracle::RiskTier::Critical,
+            &outputs,
+        );
+
+        let report = oracle.generate_report(0);
+        let err = LockstepHarness::ensure_report_passes(&report)
+            .expect_err("a Critical divergence must fail verification");
+        let message = format!("{err:#}");
+        assert!(message.contains("verdict=block_release"));
+        assert!(message.contains("div-forced-critical"));
+    }
+
     #[test]
     fn emit_divergence_fixtures_writes_schema_shaped_fixture() {
         let temp = tempfile::tempdir().expect("tempdir");
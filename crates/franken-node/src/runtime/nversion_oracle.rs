@@ -30,6 +30,17 @@ use std::fmt;
 const MAX_EVENT_LOG_ENTRIES: usize = 4096;
 const L1_LINKAGE_HASH_DOMAIN: &[u8] = b"l1_linkage_v1:";
 const SHA256_HEX_LEN: usize = 64;
+const DIVERGENCE_ID_HASH_DOMAIN: &[u8] = b"divergence_id_v1:";
+/// Length (in hex characters) of the digest suffix used by content-addressed
+/// divergence IDs -- see [`RuntimeOracle::with_content_addressed_ids`]. Short
+/// enough to stay readable in logs while keeping collisions implausible for
+/// the number of divergences a single report will ever contain.
+const CONTENT_ADDRESSED_ID_HEX_LEN: usize = 16;
+/// Default voting round timeout (in milliseconds) used by
+/// [`RuntimeOracle::tally_votes_at`] when no override has been set via
+/// [`RuntimeOracle::set_voting_timeout_ms`]. Generous enough to absorb a
+/// slow runtime under load without masking a genuinely stuck one.
+const DEFAULT_VOTING_TIMEOUT_MS: u64 = 30_000;
 
 // ---------------------------------------------------------------------------
 // Event codes
@@ -60,6 +71,11 @@ pub mod event_codes {
     pub const FN_NV_011: &str = "FN-NV-011";
     /// Comprehensive oracle divergence report generated.
     pub const FN_NV_012: &str = "FN-NV-012";
+    /// Divergence resolved with recorded evidence.
+    pub const FN_NV_013: &str = "FN-NV-013";
+    /// A runtime submitted a vote that contradicts its earlier vote for the
+    /// same check; the first vote was retained and the conflict recorded.
+    pub const FN_NV_014: &str = "FN-NV-014";
 }
 
 // ---------------------------------------------------------------------------
@@ -85,6 +101,29 @@ pub mod error_codes {
     /// A cross-check whose registered runtimes all share one executor fingerprint is a
     /// degenerate self-comparison; a meaningful n-version check needs >=2 distinct executors.
     pub const ERR_NVO_DEGENERATE_PARTITION: &str = "ERR_NVO_DEGENERATE_PARTITION";
+    /// `annotate_divergence` was called with a `divergence_id` that does not
+    /// exist in this oracle run.
+    pub const ERR_NVO_DIVERGENCE_NOT_FOUND: &str = "ERR_NVO_DIVERGENCE_NOT_FOUND";
+    /// `resolve_divergence_with_evidence` was called with an empty
+    /// justification.
+    pub const ERR_NVO_RESOLUTION_JUSTIFICATION_REQUIRED: &str =
+        "ERR_NVO_RESOLUTION_JUSTIFICATION_REQUIRED";
+    /// The voters in a `run_cross_check` met the raw quorum count but spanned
+    /// fewer distinct `engine_family` values than
+    /// `RuntimeOracle::with_min_distinct_engine_families` requires -- e.g.
+    /// two runtime_ids that are both built on the same underlying engine.
+    pub const ERR_NVO_INSUFFICIENT_ENGINE_DIVERSITY: &str = "ERR_NVO_INSUFFICIENT_ENGINE_DIVERSITY";
+    /// `SemanticDivergence::transition` was asked to jump to a
+    /// [`DivergenceState`] that isn't reachable from the current state
+    /// (e.g. `Open` straight to `Resolved`, skipping `Acknowledged`).
+    pub const ERR_NVO_ILLEGAL_DIVERGENCE_TRANSITION: &str = "ERR_NVO_ILLEGAL_DIVERGENCE_TRANSITION";
+    /// `DivergenceReport::merge` was given an empty list of shard reports.
+    pub const ERR_NVO_NO_SHARDS: &str = "ERR_NVO_NO_SHARDS";
+    /// `DivergenceReport::merge` found two shards reporting different
+    /// content for the same id (runtime, check, divergence, or receipt) --
+    /// a sharding bug, since shard-independent identifiers must not collide
+    /// across shards with different content.
+    pub const ERR_NVO_SHARD_CONFLICT: &str = "ERR_NVO_SHARD_CONFLICT";
 }
 
 // ---------------------------------------------------------------------------
@@ -193,6 +232,12 @@ pub enum CheckOutcome {
     Agree { canonical_output: Vec<u8> },
     /// Runtimes diverge; contains per-runtime outputs.
     Diverge { outputs: BTreeMap<String, Vec<u8>> },
+    /// A runtime could not execute the check at all (e.g. an unsupported
+    /// opcode or a sandboxed capability it does not implement) and is
+    /// excluded from agreement/divergence comparison rather than being
+    /// forced to register a fabricated output. See
+    /// [`RuntimeOracle::abstain`].
+    Abstain { reason: String },
 }
 
 // ---------------------------------------------------------------------------
@@ -206,6 +251,12 @@ pub struct RuntimeEntry {
     pub runtime_name: String,
     pub version: String,
     pub is_reference: bool,
+    /// The underlying engine family this runtime is built on (e.g. `"v8"`,
+    /// `"jsc"`, `"quickjs"`). Two runtimes that share an `engine_family` are
+    /// not independent for cross-check purposes even if their
+    /// `runtime_id`/`version` differ -- see
+    /// [`RuntimeOracle::with_min_distinct_engine_families`].
+    pub engine_family: String,
 }
 
 impl RuntimeEntry {
@@ -242,23 +293,197 @@ pub struct CrossRuntimeCheck {
     pub input: Vec<u8>,
     pub trace_id: String,
     pub outcome: Option<CheckOutcome>,
+    /// Per-runtime evidence (output digest or log snippet) keyed by runtime
+    /// id, populated at vote time by
+    /// [`RuntimeOracle::run_cross_check_with_evidence`]. Purely for
+    /// reviewer triage: distinct from `outcome`'s raw byte outputs, and
+    /// never consulted by agreement/divergence classification. Empty for
+    /// checks run via the bare [`RuntimeOracle::run_cross_check`].
+    #[serde(default)]
+    pub evidence: BTreeMap<String, String>,
 }
 
 // ---------------------------------------------------------------------------
 // SemanticDivergence
 // ---------------------------------------------------------------------------
 
+/// Triage lifecycle of a [`SemanticDivergence`], replacing a bare
+/// `resolved: bool` with the actual stages a divergence moves through:
+/// `Open -> Acknowledged -> Mitigated -> Resolved`. `Accepted` is a
+/// separate terminal state reached directly from any non-terminal state,
+/// for divergences waved through via a policy receipt rather than fixed.
+/// [`SemanticDivergence::transition`] enforces this order and rejects any
+/// jump that isn't one of the above; [`RuntimeOracle::resolve_divergence`]
+/// and [`RuntimeOracle::resolve_divergence_with_evidence`] set `Resolved`
+/// directly from any state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DivergenceState {
+    /// Detected, not yet triaged.
+    Open,
+    /// A human or policy has seen the divergence and is investigating.
+    Acknowledged,
+    /// A fix or workaround has been applied; awaiting confirmation.
+    Mitigated,
+    /// Confirmed fixed. Terminal: satisfies `check_release_gate`.
+    Resolved,
+    /// Waved through via a policy receipt instead of being fixed.
+    /// Terminal: satisfies `check_release_gate`.
+    Accepted,
+}
+
+impl DivergenceState {
+    /// Returns `true` for states that satisfy `check_release_gate`'s
+    /// resolution requirement.
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Resolved | Self::Accepted)
+    }
+}
+
+impl fmt::Display for DivergenceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Open => "open",
+            Self::Acknowledged => "acknowledged",
+            Self::Mitigated => "mitigated",
+            Self::Resolved => "resolved",
+            Self::Accepted => "accepted",
+        };
+        f.write_str(label)
+    }
+}
+
 /// Recorded divergence between runtimes with classification.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SemanticDivergenceWire")]
 pub struct SemanticDivergence {
     pub divergence_id: String,
     pub check_id: String,
     pub boundary_scope: BoundaryScope,
     pub risk_tier: RiskTier,
     pub runtime_outputs: BTreeMap<String, Vec<u8>>,
-    pub resolved: bool,
+    pub state: DivergenceState,
     pub resolution_note: Option<String>,
     pub trace_id: String,
+    /// Operator-attached triage context (e.g. a Jira key, a note), keyed by
+    /// annotation name. Purely informational: never consulted by
+    /// `check_release_gate` or `classify_divergence`.
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    /// Structured evidence recorded by
+    /// [`RuntimeOracle::resolve_divergence_with_evidence`]. `None` for
+    /// divergences resolved via the deprecated bare `resolve_divergence`,
+    /// or not yet resolved.
+    #[serde(default)]
+    pub resolution_evidence: Option<ResolutionEvidence>,
+    /// Fraction of checks across which this divergence was observed
+    /// (`observation_count / total_checks`), as supplied to
+    /// [`RuntimeOracle::classify_divergence_with_consistency`]. `1.0` for
+    /// divergences classified via the bare
+    /// [`RuntimeOracle::classify_divergence`], which assumes the
+    /// divergence was observed on every check. Informational only: never
+    /// consulted by `check_release_gate`, which still blocks release on a
+    /// single unresolved Critical/High divergence regardless of
+    /// consistency.
+    #[serde(default = "full_consistency")]
+    pub consistency: f64,
+}
+
+fn full_consistency() -> f64 {
+    1.0
+}
+
+/// Deserialization shape for [`SemanticDivergence`], used only to bridge a
+/// legacy `resolved: bool` column onto the [`DivergenceState`] lifecycle: a
+/// row persisted before this field existed carries `resolved` instead of
+/// `state`, and must still load. `resolved: true` maps to `Resolved`;
+/// `resolved: false` maps to `Open`. A row already carrying `state` ignores
+/// `resolved` entirely.
+#[derive(Debug, Clone, Deserialize)]
+struct SemanticDivergenceWire {
+    divergence_id: String,
+    check_id: String,
+    boundary_scope: BoundaryScope,
+    risk_tier: RiskTier,
+    runtime_outputs: BTreeMap<String, Vec<u8>>,
+    state: Option<DivergenceState>,
+    #[serde(default)]
+    resolved: bool,
+    resolution_note: Option<String>,
+    trace_id: String,
+    #[serde(default)]
+    annotations: BTreeMap<String, String>,
+    #[serde(default)]
+    resolution_evidence: Option<ResolutionEvidence>,
+    #[serde(default = "full_consistency")]
+    consistency: f64,
+}
+
+impl TryFrom<SemanticDivergenceWire> for SemanticDivergence {
+    type Error = OracleError;
+
+    fn try_from(wire: SemanticDivergenceWire) -> Result<Self, Self::Error> {
+        let state = wire.state.unwrap_or(if wire.resolved {
+            DivergenceState::Resolved
+        } else {
+            DivergenceState::Open
+        });
+        Ok(Self {
+            divergence_id: wire.divergence_id,
+            check_id: wire.check_id,
+            boundary_scope: wire.boundary_scope,
+            risk_tier: wire.risk_tier,
+            runtime_outputs: wire.runtime_outputs,
+            state,
+            resolution_note: wire.resolution_note,
+            trace_id: wire.trace_id,
+            annotations: wire.annotations,
+            resolution_evidence: wire.resolution_evidence,
+            consistency: wire.consistency,
+        })
+    }
+}
+
+impl SemanticDivergence {
+    /// Moves this divergence to `to`, enforcing the lifecycle order
+    /// documented on [`DivergenceState`]: `Open -> Acknowledged ->
+    /// Mitigated -> Resolved`, or a direct jump from any non-terminal
+    /// state to `Accepted`. Any other transition (skipping a stage,
+    /// moving backward, or leaving a terminal state) is rejected.
+    pub fn transition(&mut self, to: DivergenceState) -> Result<(), OracleError> {
+        use DivergenceState::{Accepted, Acknowledged, Mitigated, Open, Resolved};
+
+        let allowed = matches!(
+            (self.state, to),
+            (Open, Acknowledged)
+                | (Acknowledged, Mitigated)
+                | (Mitigated, Resolved)
+                | (Open, Accepted)
+                | (Acknowledged, Accepted)
+                | (Mitigated, Accepted)
+        );
+        if !allowed {
+            return Err(OracleError {
+                code: error_codes::ERR_NVO_ILLEGAL_DIVERGENCE_TRANSITION,
+                message: format!(
+                    "divergence '{}' cannot transition from {} to {to}",
+                    self.divergence_id, self.state
+                ),
+            });
+        }
+        self.state = to;
+        Ok(())
+    }
+}
+
+/// Structured justification for resolving a [`SemanticDivergence`], recorded
+/// by [`RuntimeOracle::resolve_divergence_with_evidence`] so the decision
+/// history can be audited after the fact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolutionEvidence {
+    pub resolver: String,
+    pub justification: String,
+    pub evidence_ref: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -334,6 +559,27 @@ fn update_len_prefixed_hash(hasher: &mut Sha256, bytes: &[u8]) {
     hasher.update(bytes);
 }
 
+/// Derive a stable divergence ID from the content of the divergence itself,
+/// rather than from call order -- see
+/// [`RuntimeOracle::with_content_addressed_ids`]. Runtime IDs are hashed in
+/// sorted order so the result does not depend on `runtime_outputs`'
+/// (already-sorted, since it is a `BTreeMap`) insertion history.
+fn content_addressed_divergence_id(
+    check_id: &str,
+    boundary_scope: BoundaryScope,
+    diverging_runtimes: &BTreeMap<String, Vec<u8>>,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(DIVERGENCE_ID_HASH_DOMAIN);
+    update_len_prefixed_hash(&mut hasher, check_id.as_bytes());
+    update_len_prefixed_hash(&mut hasher, boundary_scope.label().as_bytes());
+    for runtime_id in diverging_runtimes.keys() {
+        update_len_prefixed_hash(&mut hasher, runtime_id.as_bytes());
+    }
+    let digest = hex::encode(hasher.finalize());
+    format!("div-{}", &digest[..CONTENT_ADDRESSED_ID_HEX_LEN])
+}
+
 fn is_valid_l1_run_id(value: &str) -> bool {
     let Some(suffix) = value.strip_prefix("l1-run-") else {
         return false;
@@ -365,10 +611,104 @@ fn is_canonical_sha256_hex(value: &str) -> bool {
 pub struct VotingResult {
     pub check_id: String,
     pub votes: BTreeMap<String, Vec<u8>>,
+    /// Runtimes that abstained rather than voting, keyed by `runtime_id`,
+    /// with the reason each one gave. Abstaining runtimes are excluded from
+    /// `total_voters` and do not count toward `agreeing_voters` -- see
+    /// [`RuntimeOracle::abstain`].
+    ///
+    /// `#[serde(default)]` preserves backward compatibility with voting
+    /// results serialized before abstention support existed.
+    #[serde(default)]
+    pub abstentions: BTreeMap<String, String>,
+    /// Number of registered runtimes that abstained, i.e. `abstentions.len()`
+    /// at tally time. Kept as its own field (rather than requiring callers
+    /// to re-derive it) since it is the figure `total_voters` was already
+    /// reduced by.
+    #[serde(default)]
+    pub abstain_count: usize,
     pub quorum_reached: bool,
     pub quorum_threshold: usize,
     pub total_voters: usize,
     pub agreeing_voters: usize,
+    /// Classification of how the votes split, computed by
+    /// [`RuntimeOracle::tally_votes`]. Distinguishes a majority-with-dissent
+    /// split (where a plausible ground truth still exists) from a
+    /// [`VoteOutcome::NoConsensus`] round where every voter disagrees with
+    /// every other voter and no ground truth can be inferred.
+    ///
+    /// `#[serde(default)]` preserves backward compatibility with voting
+    /// results serialized before this field existed; such results default to
+    /// [`VoteOutcome::Unanimous`] so they do not retroactively trip the new
+    /// [`RuntimeOracle::check_release_gate`] behavior for
+    /// [`VoteOutcome::NoConsensus`].
+    #[serde(default)]
+    pub outcome: VoteOutcome,
+}
+
+/// Classification of a tallied [`VotingResult`], distinguishing a clean
+/// majority from the different ways a vote can fail to produce one.
+///
+/// A check where *all* runtimes diverge from each other (every output is
+/// distinct) means no ground truth exists to compare against, which is a
+/// stronger signal than a majority outvoting a single dissenter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteOutcome {
+    /// Every voter produced the same output.
+    Unanimous,
+    /// Quorum was reached, but at least one voter dissented.
+    MajorityAgree,
+    /// Quorum was not reached, but at least two voters still agree with each
+    /// other -- a plurality exists even though it falls short of quorum.
+    Split,
+    /// Every voter produced a distinct output: no two runtimes agree on
+    /// anything, so no majority or plurality exists at all.
+    NoConsensus,
+    /// Every registered runtime abstained, so there was nothing to tally at
+    /// all -- not even disagreement. Distinct from [`Self::NoConsensus`],
+    /// which still requires every runtime to have cast a (disagreeing) vote.
+    Inconclusive,
+}
+
+impl Default for VoteOutcome {
+    fn default() -> Self {
+        Self::Unanimous
+    }
+}
+
+impl VoteOutcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Unanimous => "unanimous",
+            Self::MajorityAgree => "majority_agree",
+            Self::Split => "split",
+            Self::NoConsensus => "no_consensus",
+            Self::Inconclusive => "inconclusive",
+        }
+    }
+}
+
+impl fmt::Display for VoteOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// VoteConflict
+// ---------------------------------------------------------------------------
+
+/// Recorded when a runtime submits a vote for a check it has already voted
+/// on, and the new output disagrees with its earlier output. The earlier
+/// vote is retained in the tally (see [`RuntimeOracle::vote`]); this record
+/// exists purely to surface the contradiction so a flaky or malicious
+/// runtime can be investigated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteConflict {
+    pub check_id: String,
+    pub runtime_id: String,
+    pub original_output: Vec<u8>,
+    pub conflicting_output: Vec<u8>,
 }
 
 // ---------------------------------------------------------------------------
@@ -396,6 +736,80 @@ impl OracleVerdict {
             Self::RequiresReceipt { .. } => "requires_receipt",
         }
     }
+
+    /// The blocking or pending divergence ids carried by this verdict (empty
+    /// for [`OracleVerdict::Pass`]), sorted with [`compare_ids_naturally`]
+    /// rather than the plain lexicographic order `BTreeMap` iteration
+    /// produces (where `div-10` sorts before `div-2`).
+    ///
+    /// This is an additive accessor: the ids embedded in `BlockRelease` and
+    /// `RequiresReceipt` themselves are left in their original (lexicographic,
+    /// `BTreeMap`-derived) order for backward compatibility with any existing
+    /// callers or serialized reports.
+    #[must_use]
+    pub fn numeric_order(&self) -> Vec<String> {
+        let mut ids = match self {
+            Self::Pass => Vec::new(),
+            Self::BlockRelease {
+                blocking_divergence_ids,
+            } => blocking_divergence_ids.clone(),
+            Self::RequiresReceipt {
+                pending_divergence_ids,
+            } => pending_divergence_ids.clone(),
+        };
+        ids.sort_by(|a, b| compare_ids_naturally(a, b));
+        ids
+    }
+}
+
+/// Compares two ids "naturally": runs of ASCII digits are compared by their
+/// numeric value rather than lexicographically, so `div-2` sorts before
+/// `div-10`. Non-digit runs are compared as plain text. Falls back to a
+/// byte-for-byte comparison if one id's numeric run overflows `u64`.
+fn compare_ids_naturally(left: &str, right: &str) -> std::cmp::Ordering {
+    let mut left_chars = left.chars().peekable();
+    let mut right_chars = right.chars().peekable();
+
+    loop {
+        match (left_chars.peek(), right_chars.peek()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            (None, Some(_)) => return std::cmp::Ordering::Less,
+            (Some(_), None) => return std::cmp::Ordering::Greater,
+            (Some(left_peek), Some(right_peek)) => {
+                if left_peek.is_ascii_digit() && right_peek.is_ascii_digit() {
+                    let left_run = take_digit_run(&mut left_chars);
+                    let right_run = take_digit_run(&mut right_chars);
+                    match (left_run.parse::<u64>(), right_run.parse::<u64>()) {
+                        (Ok(left_num), Ok(right_num)) => match left_num.cmp(&right_num) {
+                            std::cmp::Ordering::Equal => continue,
+                            other => return other,
+                        },
+                        _ => return left_run.cmp(&right_run),
+                    }
+                } else {
+                    let left_ch = left_chars.next().expect("peeked Some");
+                    let right_ch = right_chars.next().expect("peeked Some");
+                    match left_ch.cmp(&right_ch) {
+                        std::cmp::Ordering::Equal => continue,
+                        other => return other,
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn take_digit_run(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut run = String::new();
+    while let Some(ch) = chars.peek() {
+        if ch.is_ascii_digit() {
+            run.push(*ch);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    run
 }
 
 impl fmt::Display for OracleVerdict {
@@ -417,11 +831,448 @@ pub struct DivergenceReport {
     pub checks: Vec<CrossRuntimeCheck>,
     pub divergences: Vec<SemanticDivergence>,
     pub voting_results: Vec<VotingResult>,
+    pub vote_conflicts: Vec<VoteConflict>,
     pub receipts: Vec<PolicyReceipt>,
     pub verdict: OracleVerdict,
+    /// Count of unresolved and resolved divergences by [`RiskTier`]. Kept
+    /// in sync with `divergences` by [`RuntimeOracle::generate_report`] and
+    /// [`DivergenceReport::filter_scope`].
+    ///
+    /// `#[serde(default)]` preserves backward compatibility with reports
+    /// serialized before this field existed.
+    #[serde(default)]
+    pub risk_tier_counts: BTreeMap<RiskTier, usize>,
     pub event_log: Vec<OracleEvent>,
 }
 
+impl DivergenceReport {
+    /// Renders a deterministic, human-readable Markdown checklist for
+    /// engineers working through a blocked release, as an alternative to
+    /// reading the raw report JSON.
+    ///
+    /// Ordering, within each section, follows [`compare_ids_naturally`] on
+    /// `divergence_id` so the checklist is stable across report generations:
+    ///
+    /// 1. Unresolved `Critical` divergences.
+    /// 2. Unresolved `High` divergences.
+    /// 3. Unresolved `Low` divergences that still need a policy receipt
+    ///    (mirrors the `requires_receipt`/has-a-valid-receipt logic in
+    ///    [`RuntimeOracle::check_release_gate`], using each receipt's
+    ///    `divergence_id` as a stand-in for liveness since this method takes
+    ///    no `now_epoch_secs`).
+    /// 4. Annotated divergences (any risk tier), listing each annotation.
+    ///
+    /// Returns `"No outstanding remediation items."` when nothing qualifies.
+    #[must_use]
+    pub fn remediation_checklist(&self) -> String {
+        let mut sorted_divergences: Vec<&SemanticDivergence> = self.divergences.iter().collect();
+        sorted_divergences
+            .sort_by(|a, b| compare_ids_naturally(&a.divergence_id, &b.divergence_id));
+
+        let mut sections = Vec::new();
+
+        for tier in [RiskTier::Critical, RiskTier::High] {
+            let items: Vec<&SemanticDivergence> = sorted_divergences
+                .iter()
+                .copied()
+                .filter(|div| !div.state.is_terminal() && div.risk_tier == tier)
+                .collect();
+            if items.is_empty() {
+                continue;
+            }
+            let mut lines = vec![format!("## {} divergences (blocking)", tier.label())];
+            for div in items {
+                lines.push(remediation_checklist_item(
+                    div,
+                    "Resolve this divergence (or raise its risk tier's acceptance) before release.",
+                ));
+            }
+            sections.push(lines.join("\n"));
+        }
+
+        let receipted_ids: std::collections::BTreeSet<&str> = self
+            .receipts
+            .iter()
+            .map(|receipt| receipt.divergence_id.as_str())
+            .collect();
+        let low_pending: Vec<&SemanticDivergence> = sorted_divergences
+            .iter()
+            .copied()
+            .filter(|div| {
+                !div.state.is_terminal()
+                    && div.risk_tier.requires_receipt()
+                    && !receipted_ids.contains(div.divergence_id.as_str())
+            })
+            .collect();
+        if !low_pending.is_empty() {
+            let mut lines = vec!["## Low divergences needing a policy receipt".to_string()];
+            for div in low_pending {
+                lines.push(remediation_checklist_item(
+                    div,
+                    "Issue a policy receipt acknowledging this divergence before release.",
+                ));
+            }
+            sections.push(lines.join("\n"));
+        }
+
+        let annotated: Vec<&SemanticDivergence> = sorted_divergences
+            .iter()
+            .copied()
+            .filter(|div| !div.annotations.is_empty())
+            .collect();
+        if !annotated.is_empty() {
+            let mut lines = vec!["## Annotations".to_string()];
+            for div in annotated {
+                lines.push(format!(
+                    "- `{}` ({} / {}):",
+                    div.divergence_id,
+                    div.boundary_scope.label(),
+                    div.risk_tier.label()
+                ));
+                for (key, value) in &div.annotations {
+                    lines.push(format!("  - **{key}**: {value}"));
+                }
+            }
+            sections.push(lines.join("\n"));
+        }
+
+        if sections.is_empty() {
+            return "No outstanding remediation items.".to_string();
+        }
+
+        sections.join("\n\n")
+    }
+
+    /// Build a sub-report containing only the checks and divergences scoped
+    /// to `scope`, with `verdict` and `risk_tier_counts` recomputed against
+    /// just that subset. Lets a reviewer focused on one `BoundaryScope`
+    /// (e.g. `Security`) see a verdict that reflects only their area,
+    /// which can differ from `self.verdict` when a blocking divergence
+    /// lives in a different scope.
+    ///
+    /// `runtimes`, `voting_results`, `vote_conflicts`, `receipts`, and
+    /// `event_log` are carried through unfiltered, since none of them
+    /// record a `boundary_scope` of their own.
+    ///
+    /// The scoped verdict uses [`RuntimeOracle`]'s default blocking floor
+    /// ([`RiskTier::High`]) and, unlike
+    /// [`RuntimeOracle::check_release_gate`], treats any receipt naming a
+    /// scoped divergence as satisfying it -- a standalone report has no
+    /// `now_epoch_secs` to evaluate expiry or L1 linkage against.
+    #[must_use]
+    pub fn filter_scope(&self, scope: BoundaryScope) -> DivergenceReport {
+        let divergences: Vec<SemanticDivergence> = self
+            .divergences
+            .iter()
+            .filter(|div| div.boundary_scope == scope)
+            .cloned()
+            .collect();
+        let checks: Vec<CrossRuntimeCheck> = self
+            .checks
+            .iter()
+            .filter(|check| check.boundary_scope == scope)
+            .cloned()
+            .collect();
+
+        let verdict = scoped_verdict(&divergences, &self.receipts);
+        let risk_tier_counts = risk_tier_counts(&divergences);
+
+        DivergenceReport {
+            schema_version: self.schema_version.clone(),
+            trace_id: self.trace_id.clone(),
+            runtimes: self.runtimes.clone(),
+            checks,
+            divergences,
+            voting_results: self.voting_results.clone(),
+            vote_conflicts: self.vote_conflicts.clone(),
+            receipts: self.receipts.clone(),
+            verdict,
+            risk_tier_counts,
+            event_log: self.event_log.clone(),
+        }
+    }
+
+    /// Merge reports from independently-sharded oracle runs (e.g. one
+    /// `RuntimeOracle` per worker process, each covering a disjoint set of
+    /// checks) into a single authoritative report before evaluating the
+    /// release gate.
+    ///
+    /// `runtimes`, `checks`, `divergences`, `voting_results`, and `receipts`
+    /// are unioned by id (`runtime_id`, `check_id`, `divergence_id`,
+    /// `check_id`, `receipt_id` respectively). It is an error for two shards
+    /// to report different content for the same id -- that means the shards
+    /// disagree about something that should be shard-independent, which is a
+    /// sharding bug, not something `merge` should silently paper over.
+    /// `vote_conflicts` and `event_log` have no natural id to union by and
+    /// are concatenated in shard order instead.
+    ///
+    /// The verdict is recomputed from the merged divergences and receipts
+    /// via [`scoped_verdict`] (the same logic [`Self::filter_scope`] uses)
+    /// rather than combined from each shard's own verdict, since a receipt
+    /// issued in one shard must be able to satisfy a divergence reported by
+    /// another.
+    pub fn merge(reports: Vec<DivergenceReport>) -> Result<DivergenceReport, OracleError> {
+        let Some(first) = reports.first() else {
+            return Err(OracleError {
+                code: error_codes::ERR_NVO_NO_SHARDS,
+                message: "cannot merge an empty list of divergence reports".to_string(),
+            });
+        };
+        let schema_version = first.schema_version.clone();
+        let trace_id = first.trace_id.clone();
+
+        let mut runtimes: BTreeMap<String, RuntimeEntry> = BTreeMap::new();
+        let mut divergences: BTreeMap<String, SemanticDivergence> = BTreeMap::new();
+        let mut checks: BTreeMap<String, CrossRuntimeCheck> = BTreeMap::new();
+        let mut voting_results: BTreeMap<String, VotingResult> = BTreeMap::new();
+        let mut receipts: BTreeMap<String, PolicyReceipt> = BTreeMap::new();
+        let mut vote_conflicts = Vec::new();
+        let mut event_log = Vec::new();
+
+        for report in &reports {
+            for runtime in report.runtimes.values() {
+                merge_shard_unique(
+                    &mut runtimes,
+                    runtime.runtime_id.clone(),
+                    runtime.clone(),
+                    "runtime",
+                )?;
+            }
+            for div in &report.divergences {
+                merge_shard_unique(
+                    &mut divergences,
+                    div.divergence_id.clone(),
+                    div.clone(),
+                    "divergence",
+                )?;
+            }
+            for check in &report.checks {
+                merge_shard_unique(&mut checks, check.check_id.clone(), check.clone(), "check")?;
+            }
+            for voting_result in &report.voting_results {
+                merge_shard_unique(
+                    &mut voting_results,
+                    voting_result.check_id.clone(),
+                    voting_result.clone(),
+                    "voting result",
+                )?;
+            }
+            for receipt in &report.receipts {
+                merge_shard_unique(
+                    &mut receipts,
+                    receipt.receipt_id.clone(),
+                    receipt.clone(),
+                    "receipt",
+                )?;
+            }
+            vote_conflicts.extend(report.vote_conflicts.iter().cloned());
+            event_log.extend(report.event_log.iter().cloned());
+        }
+
+        let divergences: Vec<SemanticDivergence> = divergences.into_values().collect();
+        let checks: Vec<CrossRuntimeCheck> = checks.into_values().collect();
+        let voting_results: Vec<VotingResult> = voting_results.into_values().collect();
+        let receipts: Vec<PolicyReceipt> = receipts.into_values().collect();
+
+        let verdict = scoped_verdict(&divergences, &receipts);
+        let risk_tier_counts = risk_tier_counts(&divergences);
+
+        Ok(DivergenceReport {
+            schema_version,
+            trace_id,
+            runtimes,
+            checks,
+            divergences,
+            voting_results,
+            vote_conflicts,
+            receipts,
+            verdict,
+            risk_tier_counts,
+            event_log,
+        })
+    }
+}
+
+/// Insert `value` under `id` into `merged`, or error if `id` is already
+/// present with *different* content -- see [`DivergenceReport::merge`].
+/// Re-inserting identical content (the same check reported by two shards
+/// that happen to both observe it) is not a conflict.
+fn merge_shard_unique<V: PartialEq>(
+    merged: &mut BTreeMap<String, V>,
+    id: String,
+    value: V,
+    kind: &str,
+) -> Result<(), OracleError> {
+    if let Some(existing) = merged.get(&id) {
+        if existing != &value {
+            return Err(OracleError {
+                code: error_codes::ERR_NVO_SHARD_CONFLICT,
+                message: format!("shards disagree on {kind} '{id}'"),
+            });
+        }
+        return Ok(());
+    }
+    merged.insert(id, value);
+    Ok(())
+}
+
+/// Blocking floor used to recompute a scoped verdict in
+/// [`DivergenceReport::filter_scope`]. Matches [`RuntimeOracle::new`]'s own
+/// default, since a standalone report has no live oracle to consult for a
+/// configured `blocking_floor`.
+const SCOPED_VERDICT_BLOCKING_FLOOR: RiskTier = RiskTier::High;
+
+/// Recomputes an [`OracleVerdict`] from a subset of divergences, mirroring
+/// [`RuntimeOracle::check_release_gate`]'s blocking/pending-receipt logic.
+fn scoped_verdict(divergences: &[SemanticDivergence], receipts: &[PolicyReceipt]) -> OracleVerdict {
+    let mut blocking = Vec::new();
+    let mut pending_receipt = Vec::new();
+
+    for div in divergences {
+        if div.state.is_terminal() {
+            continue;
+        }
+        if div.risk_tier >= SCOPED_VERDICT_BLOCKING_FLOOR {
+            blocking.push(div.divergence_id.clone());
+        } else if div.risk_tier.requires_receipt() {
+            let has_receipt = receipts
+                .iter()
+                .any(|receipt| receipt.divergence_id == div.divergence_id);
+            if !has_receipt {
+                pending_receipt.push(div.divergence_id.clone());
+            }
+        }
+    }
+
+    if !blocking.is_empty() {
+        OracleVerdict::BlockRelease {
+            blocking_divergence_ids: blocking,
+        }
+    } else if !pending_receipt.is_empty() {
+        OracleVerdict::RequiresReceipt {
+            pending_divergence_ids: pending_receipt,
+        }
+    } else {
+        OracleVerdict::Pass
+    }
+}
+
+/// Counts divergences by [`RiskTier`].
+fn risk_tier_counts(divergences: &[SemanticDivergence]) -> BTreeMap<RiskTier, usize> {
+    let mut counts = BTreeMap::new();
+    for div in divergences {
+        *counts.entry(div.risk_tier).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Renders one remediation-checklist bullet for `div`, including its scope,
+/// a synthesized description, the involved runtimes, and the given action
+/// text. Shared by each section of [`DivergenceReport::remediation_checklist`]
+/// so the bullet format never drifts between sections.
+fn remediation_checklist_item(div: &SemanticDivergence, action: &str) -> String {
+    let mut runtimes: Vec<&str> = div.runtime_outputs.keys().map(String::as_str).collect();
+    runtimes.sort_unstable();
+    format!(
+        "- [ ] `{}` ({}, {}): semantic divergence detected in check `{}` across runtimes [{}]. {}",
+        div.divergence_id,
+        div.boundary_scope.label(),
+        div.risk_tier.label(),
+        div.check_id,
+        runtimes.join(", "),
+        action
+    )
+}
+
+// ---------------------------------------------------------------------------
+// FleetOracleReport
+// ---------------------------------------------------------------------------
+
+/// Fleet-wide verdict aggregated from one [`DivergenceReport`] per zone.
+///
+/// The fleet is blocked if any zone blocks: `verdict` is the strictest of
+/// the per-zone verdicts (`BlockRelease` > `RequiresReceipt` > `Pass`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FleetOracleReport {
+    pub schema_version: String,
+    /// The strictest verdict across all zones.
+    pub verdict: OracleVerdict,
+    /// Union of every zone's divergences. Divergence ids are assumed to be
+    /// zone-prefixed by the caller, so no collision handling is needed here.
+    pub divergences: Vec<SemanticDivergence>,
+    /// Zones whose own verdict was `BlockRelease`, in the order they appear
+    /// in the input `BTreeMap` (i.e. sorted by zone name).
+    pub blocking_zones: Vec<String>,
+    /// Each zone's own verdict, for drill-down from the fleet verdict.
+    pub zone_verdicts: BTreeMap<String, OracleVerdict>,
+}
+
+/// Aggregate one [`DivergenceReport`] per zone into a single fleet-wide
+/// [`FleetOracleReport`]. Deterministic: iterates `reports` (a `BTreeMap`)
+/// in zone-name order, and the ids embedded in the fleet verdict are
+/// deduplicated and sorted.
+#[must_use]
+pub fn aggregate_reports(reports: &BTreeMap<String, DivergenceReport>) -> FleetOracleReport {
+    let mut divergences = Vec::new();
+    let mut blocking_zones = Vec::new();
+    let mut zone_verdicts = BTreeMap::new();
+    let mut any_requires_receipt = false;
+
+    for (zone, report) in reports {
+        divergences.extend(report.divergences.iter().cloned());
+        zone_verdicts.insert(zone.clone(), report.verdict.clone());
+        match &report.verdict {
+            OracleVerdict::BlockRelease { .. } => blocking_zones.push(zone.clone()),
+            OracleVerdict::RequiresReceipt { .. } => any_requires_receipt = true,
+            OracleVerdict::Pass => {}
+        }
+    }
+
+    let verdict = if !blocking_zones.is_empty() {
+        let mut blocking_divergence_ids: Vec<String> = reports
+            .values()
+            .filter_map(|report| match &report.verdict {
+                OracleVerdict::BlockRelease {
+                    blocking_divergence_ids,
+                } => Some(blocking_divergence_ids.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        blocking_divergence_ids.sort();
+        blocking_divergence_ids.dedup();
+        OracleVerdict::BlockRelease {
+            blocking_divergence_ids,
+        }
+    } else if any_requires_receipt {
+        let mut pending_divergence_ids: Vec<String> = reports
+            .values()
+            .filter_map(|report| match &report.verdict {
+                OracleVerdict::RequiresReceipt {
+                    pending_divergence_ids,
+                } => Some(pending_divergence_ids.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        pending_divergence_ids.sort();
+        pending_divergence_ids.dedup();
+        OracleVerdict::RequiresReceipt {
+            pending_divergence_ids,
+        }
+    } else {
+        OracleVerdict::Pass
+    };
+
+    FleetOracleReport {
+        schema_version: SCHEMA_VERSION.to_string(),
+        verdict,
+        divergences,
+        blocking_zones,
+        zone_verdicts,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // OracleEvent (structured log)
 // ---------------------------------------------------------------------------
@@ -453,13 +1304,25 @@ impl fmt::Display for OracleError {
 
 impl std::error::Error for OracleError {}
 
-fn quorum_required_for(total_runtimes: usize, threshold_percent: u8) -> Result<usize, OracleError> {
+fn quorum_required_for(
+    total_runtimes: usize,
+    threshold_percent: u8,
+    rounding: QuorumRounding,
+) -> Result<usize, OracleError> {
     let total = u128::try_from(total_runtimes).map_err(|_| OracleError {
         code: error_codes::ERR_NVO_QUORUM_FAILED,
         message: "invalid quorum calculation: runtime count conversion failed".to_string(),
     })?;
     let percent = u128::from(threshold_percent.clamp(1, 100));
-    let required = total.saturating_mul(percent).saturating_add(99) / 100;
+    let required = match rounding {
+        // required = ceil(total * percent / 100)
+        QuorumRounding::Strict => total.saturating_mul(percent).saturating_add(99) / 100,
+        // required = ceil(total * (2*percent - 1) / 200); see QuorumRounding::RoundNearest.
+        QuorumRounding::RoundNearest => {
+            let numerator = total.saturating_mul(percent.saturating_mul(2).saturating_sub(1));
+            numerator.saturating_add(199) / 200
+        }
+    };
     usize::try_from(required).map_err(|_| OracleError {
         code: error_codes::ERR_NVO_QUORUM_FAILED,
         message: "invalid quorum calculation: threshold exceeds platform capacity".to_string(),
@@ -480,12 +1343,42 @@ pub struct RuntimeOracle {
     divergences: BTreeMap<String, SemanticDivergence>,
     receipts: BTreeMap<String, PolicyReceipt>,
     voting_results: BTreeMap<String, VotingResult>,
+    vote_conflicts: Vec<VoteConflict>,
     event_log: Vec<OracleEvent>,
     active_checks: BTreeMap<String, bool>,
+    check_started_at_ms: BTreeMap<String, u64>,
     quorum_threshold_percent: u8,
+    quorum_rounding: QuorumRounding,
+    blocking_floor: RiskTier,
+    min_distinct_families: Option<usize>,
+    content_addressed_ids: bool,
+    voting_timeout_ms: u64,
     trace_id: String,
 }
 
+/// Controls how a percentage quorum threshold is converted into a required
+/// vote count by [`quorum_required_for`].
+///
+/// The naive check `(agree * 100) >= (total * threshold)` is equivalent to
+/// requiring `agree >= ceil(total * threshold / 100)` -- i.e. it always rounds
+/// the threshold up in its own favor. That means 2 of 3 runtimes (66.67%
+/// agreement) does NOT meet a 67% threshold, since `ceil(3 * 67 / 100) == 3`.
+/// Some deployments consider that surprising and want 66.67% to count as
+/// meeting 67%. `QuorumRounding` makes the choice explicit per oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuorumRounding {
+    /// `required = ceil(total * threshold_percent / 100)`. This is the
+    /// original, default behavior: the threshold is never rounded down.
+    Strict,
+    /// The agreement percentage is rounded to the nearest whole percent
+    /// (round-half-up) before comparing against the threshold, so
+    /// `required` is the smallest `k` such that
+    /// `round_half_up(k * 100 / total) >= threshold_percent`. Equivalent to
+    /// `required = ceil(total * (2 * threshold_percent - 1) / 200)`.
+    RoundNearest,
+}
+
 impl RuntimeOracle {
     /// Create a new oracle with the given trace ID and quorum threshold (percent).
     pub fn new(trace_id: &str, quorum_threshold_percent: u8) -> Self {
@@ -497,15 +1390,75 @@ impl RuntimeOracle {
             divergences: BTreeMap::new(),
             receipts: BTreeMap::new(),
             voting_results: BTreeMap::new(),
+            vote_conflicts: Vec::new(),
             event_log: Vec::new(),
             active_checks: BTreeMap::new(),
+            check_started_at_ms: BTreeMap::new(),
             quorum_threshold_percent,
+            quorum_rounding: QuorumRounding::Strict,
+            blocking_floor: RiskTier::High,
+            min_distinct_families: None,
+            content_addressed_ids: false,
+            voting_timeout_ms: DEFAULT_VOTING_TIMEOUT_MS,
             trace_id: trace_id.to_string(),
         };
         oracle.emit_event(event_codes::FN_NV_001, "Oracle created", BTreeMap::new());
         oracle
     }
 
+    /// Set the quorum rounding mode used by [`Self::tally_votes`] and
+    /// [`Self::run_cross_check`]. Defaults to [`QuorumRounding::Strict`].
+    pub fn with_quorum_rounding(mut self, rounding: QuorumRounding) -> Self {
+        self.quorum_rounding = rounding;
+        self
+    }
+
+    /// Set the minimum risk tier that blocks release in
+    /// [`Self::check_release_gate`]. Divergences at or above this tier block;
+    /// divergences below it fall back to their own
+    /// [`RiskTier::requires_receipt`] behavior. Defaults to
+    /// [`RiskTier::High`], matching [`RiskTier::blocks_release`].
+    pub fn with_blocking_floor(mut self, floor: RiskTier) -> Self {
+        self.blocking_floor = floor;
+        self
+    }
+
+    /// Require at least `min` distinct [`RuntimeEntry::engine_family`] values
+    /// among the voters of every [`Self::run_cross_check`], even if the raw
+    /// voter count already satisfies the quorum threshold. Two runtimes built
+    /// on the same underlying engine are not independent evidence of
+    /// correctness, no matter how many of them vote. Unset (`None`) by
+    /// default, in which case no diversity gate is applied.
+    pub fn with_min_distinct_engine_families(mut self, min: usize) -> Self {
+        self.min_distinct_families = Some(min);
+        self
+    }
+
+    /// When `enabled`, [`Self::classify_divergence`] and
+    /// [`Self::classify_divergence_with_consistency`] ignore the caller's
+    /// `divergence_id` argument and instead derive it as a short hash of
+    /// `(check_id, boundary_scope, sorted diverging runtime ids)`. The same
+    /// logical divergence therefore gets the same ID no matter what order
+    /// checks ran in, which matters for CI that diffs [`DivergenceReport`]s
+    /// across runs -- caller-assigned IDs (e.g. a sequence counter, or a
+    /// per-run UUID baked into `check_id`) vary with ordering even when the
+    /// divergence itself is identical. Defaults to `false`, which preserves
+    /// the caller-assigned ID unchanged.
+    pub fn with_content_addressed_ids(mut self, enabled: bool) -> Self {
+        self.content_addressed_ids = enabled;
+        self
+    }
+
+    /// Reconfigure the voting round timeout (in milliseconds) enforced by
+    /// [`Self::tally_votes_at`]. Defaults to [`DEFAULT_VOTING_TIMEOUT_MS`].
+    /// Unlike the `with_*` builders above, this takes `&mut self` rather
+    /// than consuming the oracle, since a deployment may need to relax or
+    /// tighten the timeout mid-run (e.g. a CI shard known to be slow)
+    /// rather than only at construction time.
+    pub fn set_voting_timeout_ms(&mut self, timeout_ms: u64) {
+        self.voting_timeout_ms = timeout_ms;
+    }
+
     /// Register a reference runtime for comparison.
     pub fn register_runtime(&mut self, entry: RuntimeEntry) -> Result<(), OracleError> {
         if self.runtimes.contains_key(&entry.runtime_id) {
@@ -557,12 +1510,84 @@ impl RuntimeOracle {
     /// `runtime_outputs` provides the pre-computed output from each runtime
     /// for the given boundary check. The oracle compares outputs to determine
     /// if they agree or diverge.
+    ///
+    /// Equivalent to [`Self::run_cross_check_with_evidence`] with an empty
+    /// evidence map; use that instead to attach per-runtime evidence
+    /// (output digests or log snippets) for reviewer triage.
     pub fn run_cross_check(
         &mut self,
         check_id: &str,
         boundary_scope: BoundaryScope,
         input: &[u8],
         runtime_outputs: &BTreeMap<String, Vec<u8>>,
+    ) -> Result<CrossRuntimeCheck, OracleError> {
+        self.run_cross_check_with_evidence(
+            check_id,
+            boundary_scope,
+            input,
+            runtime_outputs,
+            &BTreeMap::new(),
+        )
+    }
+
+    /// Execute a cross-runtime semantic check like [`Self::run_cross_check`],
+    /// additionally recording `evidence` (a runtime id to output digest or
+    /// log snippet map) onto the resulting [`CrossRuntimeCheck`] so it
+    /// surfaces in [`DivergenceReport::checks`] alongside any divergence.
+    pub fn run_cross_check_with_evidence(
+        &mut self,
+        check_id: &str,
+        boundary_scope: BoundaryScope,
+        input: &[u8],
+        runtime_outputs: &BTreeMap<String, Vec<u8>>,
+        evidence: &BTreeMap<String, String>,
+    ) -> Result<CrossRuntimeCheck, OracleError> {
+        self.run_cross_check_with_evidence_inner(
+            check_id,
+            boundary_scope,
+            input,
+            runtime_outputs,
+            evidence,
+        )
+    }
+
+    /// Execute a cross-runtime semantic check like
+    /// [`Self::run_cross_check_with_evidence`], additionally recording
+    /// `started_at_ms` as the voting round's start time so
+    /// [`Self::tally_votes_at`] can later enforce
+    /// [`Self::set_voting_timeout_ms`] against it. Use this instead of
+    /// [`Self::run_cross_check`]/[`Self::run_cross_check_with_evidence`]
+    /// whenever the check's outcome will be decided by [`Self::vote`] /
+    /// [`Self::tally_votes_at`] rather than by the immediate
+    /// byte-comparison [`CheckOutcome`] this method also returns.
+    pub fn run_cross_check_with_evidence_at(
+        &mut self,
+        check_id: &str,
+        boundary_scope: BoundaryScope,
+        input: &[u8],
+        runtime_outputs: &BTreeMap<String, Vec<u8>>,
+        evidence: &BTreeMap<String, String>,
+        started_at_ms: u64,
+    ) -> Result<CrossRuntimeCheck, OracleError> {
+        let check = self.run_cross_check_with_evidence_inner(
+            check_id,
+            boundary_scope,
+            input,
+            runtime_outputs,
+            evidence,
+        )?;
+        self.check_started_at_ms
+            .insert(check_id.to_string(), started_at_ms);
+        Ok(check)
+    }
+
+    fn run_cross_check_with_evidence_inner(
+        &mut self,
+        check_id: &str,
+        boundary_scope: BoundaryScope,
+        input: &[u8],
+        runtime_outputs: &BTreeMap<String, Vec<u8>>,
+        evidence: &BTreeMap<String, String>,
     ) -> Result<CrossRuntimeCheck, OracleError> {
         if self.runtimes.is_empty() {
             return Err(OracleError {
@@ -599,7 +1624,7 @@ impl RuntimeOracle {
         self.active_checks.insert(check_id.to_string(), true);
 
         let quorum_required =
-            quorum_required_for(self.runtimes.len(), self.quorum_threshold_percent)?;
+            quorum_required_for(self.runtimes.len(), self.quorum_threshold_percent, self.quorum_rounding)?;
         if runtime_outputs.len() < quorum_required {
             self.active_checks.remove(check_id);
             return Err(OracleError {
@@ -613,6 +1638,25 @@ impl RuntimeOracle {
             });
         }
 
+        if let Some(min_distinct_families) = self.min_distinct_families {
+            let distinct_families: std::collections::BTreeSet<&str> = self
+                .runtimes
+                .values()
+                .filter(|entry| runtime_outputs.contains_key(&entry.runtime_id))
+                .map(|entry| entry.engine_family.as_str())
+                .collect();
+            if distinct_families.len() < min_distinct_families {
+                self.active_checks.remove(check_id);
+                return Err(OracleError {
+                    code: error_codes::ERR_NVO_INSUFFICIENT_ENGINE_DIVERSITY,
+                    message: format!(
+                        "voters for check '{check_id}' span only {} distinct engine families, need {min_distinct_families}",
+                        distinct_families.len()
+                    ),
+                });
+            }
+        }
+
         let mut details = BTreeMap::new();
         details.insert("check_id".to_string(), check_id.to_string());
         details.insert(
@@ -654,6 +1698,7 @@ impl RuntimeOracle {
             input: input.to_vec(),
             trace_id: self.trace_id.clone(),
             outcome: Some(outcome),
+            evidence: evidence.clone(),
         };
 
         self.checks.insert(check_id.to_string(), check.clone());
@@ -661,7 +1706,11 @@ impl RuntimeOracle {
         Ok(check)
     }
 
-    /// Classify a detected divergence by risk tier.
+    /// Classify a detected divergence by risk tier. Assumes the divergence
+    /// was observed on every check (`consistency` of `1.0`); use
+    /// [`Self::classify_divergence_with_consistency`] when the same scope
+    /// has been checked repeatedly and only some checks observed the
+    /// divergence.
     pub fn classify_divergence(
         &mut self,
         divergence_id: &str,
@@ -670,28 +1719,79 @@ impl RuntimeOracle {
         risk_tier: RiskTier,
         runtime_outputs: &BTreeMap<String, Vec<u8>>,
     ) -> SemanticDivergence {
-        let divergence = SemanticDivergence {
-            divergence_id: divergence_id.to_string(),
-            check_id: check_id.to_string(),
+        self.classify_divergence_with_consistency(
+            divergence_id,
+            check_id,
             boundary_scope,
             risk_tier,
-            runtime_outputs: runtime_outputs.clone(),
-            resolved: false,
-            resolution_note: None,
-            trace_id: self.trace_id.clone(),
-        };
+            runtime_outputs,
+            1,
+            1,
+        )
+    }
 
-        let mut details = BTreeMap::new();
-        details.insert("divergence_id".to_string(), divergence_id.to_string());
+    /// Classify a detected divergence by risk tier, recording how
+    /// consistently it was observed across repeated checks of the same
+    /// scope as `observation_count / total_checks` (e.g. `1` of `10`
+    /// checks is weaker evidence than `10` of `10`). `total_checks == 0`
+    /// is treated as fully consistent. The consistency ratio is purely
+    /// informational: it does not change blocking behavior, since a
+    /// single unresolved Critical still blocks release regardless of how
+    /// consistently it was observed.
+    pub fn classify_divergence_with_consistency(
+        &mut self,
+        divergence_id: &str,
+        check_id: &str,
+        boundary_scope: BoundaryScope,
+        risk_tier: RiskTier,
+        runtime_outputs: &BTreeMap<String, Vec<u8>>,
+        observation_count: u32,
+        total_checks: u32,
+    ) -> SemanticDivergence {
+        let consistency = if total_checks == 0 {
+            1.0
+        } else {
+            f64::from(observation_count) / f64::from(total_checks)
+        };
+
+        let divergence_id = if self.content_addressed_ids {
+            content_addressed_divergence_id(check_id, boundary_scope, runtime_outputs)
+        } else {
+            divergence_id.to_string()
+        };
+
+        let divergence = SemanticDivergence {
+            divergence_id: divergence_id.clone(),
+            check_id: check_id.to_string(),
+            boundary_scope,
+            risk_tier,
+            runtime_outputs: runtime_outputs.clone(),
+            state: DivergenceState::Open,
+            resolution_note: None,
+            trace_id: self.trace_id.clone(),
+            annotations: BTreeMap::new(),
+            resolution_evidence: None,
+            consistency,
+        };
+
+        let mut details = BTreeMap::new();
+        details.insert("divergence_id".to_string(), divergence_id.clone());
         details.insert("risk_tier".to_string(), risk_tier.label().to_string());
+        details.insert("consistency".to_string(), consistency.to_string());
         self.emit_event(event_codes::FN_NV_005, "Divergence classified", details);
 
-        self.divergences
-            .insert(divergence_id.to_string(), divergence.clone());
+        self.divergences.insert(divergence_id, divergence.clone());
         divergence
     }
 
     /// Submit a runtime's vote for a cross-check.
+    ///
+    /// If `runtime_id` already voted on `check_id` with a *different* output,
+    /// the original vote is retained (not overwritten) and the contradiction
+    /// is recorded as a [`VoteConflict`] -- surfaced via
+    /// [`DivergenceReport::vote_conflicts`] -- rather than silently letting
+    /// the second vote win. A repeated vote with the *same* output is not a
+    /// conflict.
     pub fn vote(
         &mut self,
         check_id: &str,
@@ -711,16 +1811,91 @@ impl RuntimeOracle {
             .or_insert_with(|| VotingResult {
                 check_id: check_id.to_string(),
                 votes: BTreeMap::new(),
+                abstentions: BTreeMap::new(),
+                abstain_count: 0,
                 quorum_reached: false,
                 quorum_threshold: 0,
                 total_voters: 0,
                 agreeing_voters: 0,
+                outcome: VoteOutcome::default(),
             });
 
+        // A runtime that already abstained is allowed to cast a real vote
+        // instead -- it is removed from the abstention set so it is not
+        // double-counted as both an abstainer and a voter.
+        entry.abstentions.remove(runtime_id);
+
+        if let Some(original_output) = entry.votes.get(runtime_id) {
+            if original_output != &output {
+                self.vote_conflicts.push(VoteConflict {
+                    check_id: check_id.to_string(),
+                    runtime_id: runtime_id.to_string(),
+                    original_output: original_output.clone(),
+                    conflicting_output: output,
+                });
+
+                let mut details = BTreeMap::new();
+                details.insert("check_id".to_string(), check_id.to_string());
+                details.insert("runtime_id".to_string(), runtime_id.to_string());
+                self.emit_event(
+                    event_codes::FN_NV_014,
+                    "Contradictory vote recorded",
+                    details,
+                );
+            }
+            return Ok(());
+        }
+
         entry.votes.insert(runtime_id.to_string(), output);
         Ok(())
     }
 
+    /// Record that `runtime_id` cannot execute `check_id` at all (see
+    /// [`CheckOutcome::Abstain`]), rather than forcing it to submit a
+    /// fabricated output that would be wrongly compared for agreement.
+    ///
+    /// Abstaining runtimes are excluded from `total_voters` and
+    /// `agreeing_voters` by [`RuntimeOracle::tally_votes`]. If a runtime
+    /// later casts a real vote on the same check, the abstention is dropped
+    /// in favor of the vote -- see [`RuntimeOracle::vote`].
+    pub fn abstain(
+        &mut self,
+        check_id: &str,
+        runtime_id: &str,
+        reason: String,
+    ) -> Result<(), OracleError> {
+        if !self.runtimes.contains_key(runtime_id) {
+            return Err(OracleError {
+                code: error_codes::ERR_NVO_RUNTIME_NOT_FOUND,
+                message: format!("runtime '{runtime_id}' not found"),
+            });
+        }
+
+        let entry = self
+            .voting_results
+            .entry(check_id.to_string())
+            .or_insert_with(|| VotingResult {
+                check_id: check_id.to_string(),
+                votes: BTreeMap::new(),
+                abstentions: BTreeMap::new(),
+                abstain_count: 0,
+                quorum_reached: false,
+                quorum_threshold: 0,
+                total_voters: 0,
+                agreeing_voters: 0,
+                outcome: VoteOutcome::default(),
+            });
+
+        // A runtime that already voted keeps its vote; it cannot retract it
+        // via abstention, mirroring `vote`'s own no-overwrite rule.
+        if entry.votes.contains_key(runtime_id) {
+            return Ok(());
+        }
+
+        entry.abstentions.insert(runtime_id.to_string(), reason);
+        Ok(())
+    }
+
     /// Tally votes and determine quorum result.
     pub fn tally_votes(&mut self, check_id: &str) -> Result<VotingResult, OracleError> {
         let entry = self
@@ -731,14 +1906,42 @@ impl RuntimeOracle {
                 message: format!("no votes recorded for check '{check_id}'"),
             })?;
 
-        let total = self.runtimes.len();
-        if total == 0 {
+        let registered = self.runtimes.len();
+        if registered == 0 {
             return Err(OracleError {
                 code: error_codes::ERR_NVO_NO_RUNTIMES,
                 message: "no runtimes registered".to_string(),
             });
         }
-        let quorum_required = quorum_required_for(total, self.quorum_threshold_percent)?;
+
+        let abstain_count = entry.abstentions.len();
+        let total = registered.saturating_sub(abstain_count);
+        if total == 0 {
+            // Record the inconclusive outcome so `check_release_gate` sees it
+            // even though this call itself returns an error -- mirrors the
+            // `NoConsensus` handling there, which also relies on the stored
+            // `voting_results` entry rather than a direct return value.
+            let result = VotingResult {
+                check_id: check_id.to_string(),
+                votes: entry.votes.clone(),
+                abstentions: entry.abstentions.clone(),
+                abstain_count,
+                quorum_reached: false,
+                quorum_threshold: 0,
+                total_voters: 0,
+                agreeing_voters: 0,
+                outcome: VoteOutcome::Inconclusive,
+            };
+            self.voting_results.insert(check_id.to_string(), result);
+            return Err(OracleError {
+                code: error_codes::ERR_NVO_QUORUM_FAILED,
+                message: format!(
+                    "all {abstain_count} registered runtime(s) abstained from check '{check_id}'; no votes were cast"
+                ),
+            });
+        }
+        let quorum_required =
+            quorum_required_for(total, self.quorum_threshold_percent, self.quorum_rounding)?;
 
         // Count how many runtimes agree with the most common output.
         let mut output_counts: BTreeMap<&[u8], usize> = BTreeMap::new();
@@ -750,13 +1953,31 @@ impl RuntimeOracle {
 
         let quorum_reached = max_agreement >= quorum_required;
 
+        // INV-NVO-RISK-TIERED-adjacent classification: distinguishes "everyone
+        // agrees" from the different ways a round can fail to produce a
+        // majority. `max_agreement == total` is unanimous agreement even if
+        // `total == 1`; `max_agreement <= 1` with more than one voter means
+        // every output is distinct, i.e. no two runtimes agree on anything.
+        let outcome = if max_agreement == total {
+            VoteOutcome::Unanimous
+        } else if quorum_reached {
+            VoteOutcome::MajorityAgree
+        } else if max_agreement > 1 {
+            VoteOutcome::Split
+        } else {
+            VoteOutcome::NoConsensus
+        };
+
         let result = VotingResult {
             check_id: check_id.to_string(),
             votes: entry.votes.clone(),
+            abstentions: entry.abstentions.clone(),
+            abstain_count,
             quorum_reached,
             quorum_threshold: quorum_required,
             total_voters: total,
             agreeing_voters: max_agreement,
+            outcome,
         };
 
         if quorum_reached {
@@ -783,6 +2004,52 @@ impl RuntimeOracle {
         Ok(result)
     }
 
+    /// Tally votes like [`Self::tally_votes`], additionally enforcing the
+    /// voting round timeout configured via [`Self::set_voting_timeout_ms`]
+    /// against the `started_at_ms` recorded by
+    /// [`Self::run_cross_check_with_evidence_at`].
+    ///
+    /// If `now_ms - started_at_ms` exceeds the configured timeout and not
+    /// every registered runtime has voted or abstained yet, this returns
+    /// [`error_codes::ERR_NVO_VOTING_TIMEOUT`] instead of tallying -- a
+    /// late but eventually-complete round should not be silently decided
+    /// on an incomplete vote. Votes already cast are not discarded; they
+    /// remain in [`VotingResult::votes`] for a later retry once a
+    /// straggler responds or is forced to [`Self::abstain`]. Checks with
+    /// no recorded `started_at_ms` (i.e. started via [`Self::run_cross_check`]
+    /// or [`Self::run_cross_check_with_evidence`]) are not subject to a
+    /// deadline and behave exactly like [`Self::tally_votes`].
+    pub fn tally_votes_at(
+        &mut self,
+        check_id: &str,
+        now_ms: u64,
+    ) -> Result<VotingResult, OracleError> {
+        if let Some(&started_at_ms) = self.check_started_at_ms.get(check_id) {
+            let elapsed_ms = now_ms.saturating_sub(started_at_ms);
+            if elapsed_ms > self.voting_timeout_ms {
+                let entry = self
+                    .voting_results
+                    .get(check_id)
+                    .ok_or_else(|| OracleError {
+                        code: error_codes::ERR_NVO_CHECK_NOT_FOUND,
+                        message: format!("no votes recorded for check '{check_id}'"),
+                    })?;
+                let responded = entry.votes.len() + entry.abstentions.len();
+                if responded < self.runtimes.len() {
+                    return Err(OracleError {
+                        code: error_codes::ERR_NVO_VOTING_TIMEOUT,
+                        message: format!(
+                            "voting round for check '{check_id}' timed out after {elapsed_ms}ms (limit {}ms) with {responded} of {} registered runtime(s) responding",
+                            self.voting_timeout_ms,
+                            self.runtimes.len()
+                        ),
+                    });
+                }
+            }
+        }
+        self.tally_votes(check_id)
+    }
+
     /// Issue a policy receipt for a low-risk divergence.
     pub fn issue_policy_receipt(&mut self, receipt: PolicyReceipt) -> Result<(), OracleError> {
         let div = self
@@ -848,7 +2115,11 @@ impl RuntimeOracle {
         }
     }
 
-    /// Mark a divergence as resolved.
+    /// Mark a divergence as resolved, with only a free-form note and no
+    /// record of who resolved it or why.
+    #[deprecated(
+        note = "Use resolve_divergence_with_evidence, which records who resolved the divergence and requires a non-empty justification"
+    )]
     pub fn resolve_divergence(
         &mut self,
         divergence_id: &str,
@@ -861,22 +2132,89 @@ impl RuntimeOracle {
                 code: error_codes::ERR_NVO_DIVERGENCE_UNRESOLVED,
                 message: format!("divergence '{divergence_id}' not found"),
             })?;
-        div.resolved = true;
+        div.state = DivergenceState::Resolved;
         div.resolution_note = Some(resolution_note.to_string());
         Ok(())
     }
 
+    /// Mark a divergence as resolved, recording structured
+    /// [`ResolutionEvidence`] (who resolved it, why, and an optional
+    /// external reference) so the resolution survives into
+    /// `generate_report` and can be audited later. `justification` must be
+    /// non-empty.
+    pub fn resolve_divergence_with_evidence(
+        &mut self,
+        divergence_id: &str,
+        resolver: &str,
+        justification: &str,
+        evidence_ref: Option<String>,
+    ) -> Result<(), OracleError> {
+        if justification.trim().is_empty() {
+            return Err(OracleError {
+                code: error_codes::ERR_NVO_RESOLUTION_JUSTIFICATION_REQUIRED,
+                message: format!(
+                    "divergence '{divergence_id}' resolution requires a non-empty justification"
+                ),
+            });
+        }
+        let div = self
+            .divergences
+            .get_mut(divergence_id)
+            .ok_or_else(|| OracleError {
+                code: error_codes::ERR_NVO_DIVERGENCE_UNRESOLVED,
+                message: format!("divergence '{divergence_id}' not found"),
+            })?;
+        div.state = DivergenceState::Resolved;
+        div.resolution_note = Some(justification.to_string());
+        div.resolution_evidence = Some(ResolutionEvidence {
+            resolver: resolver.to_string(),
+            justification: justification.to_string(),
+            evidence_ref,
+        });
+
+        let mut details = BTreeMap::new();
+        details.insert("divergence_id".to_string(), divergence_id.to_string());
+        details.insert("resolver".to_string(), resolver.to_string());
+        self.emit_event(
+            event_codes::FN_NV_013,
+            "Divergence resolved with evidence",
+            details,
+        );
+        Ok(())
+    }
+
+    /// Attach operator triage context (e.g. a Jira key, a free-form note) to
+    /// a divergence. Annotations are purely informational and surface
+    /// unchanged in `generate_report`; they never influence
+    /// `check_release_gate` or `classify_divergence`.
+    pub fn annotate_divergence(
+        &mut self,
+        divergence_id: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<(), OracleError> {
+        let div = self
+            .divergences
+            .get_mut(divergence_id)
+            .ok_or_else(|| OracleError {
+                code: error_codes::ERR_NVO_DIVERGENCE_NOT_FOUND,
+                message: format!("divergence '{divergence_id}' not found"),
+            })?;
+        div.annotations.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
     /// Evaluate whether release is blocked.
     pub fn check_release_gate(&mut self, now_epoch_secs: u64) -> OracleVerdict {
         let mut blocking = Vec::new();
         let mut pending_receipt = Vec::new();
 
         for (id, div) in &self.divergences {
-            if div.resolved {
+            if div.state.is_terminal() {
                 continue;
             }
 
-            if div.risk_tier.blocks_release() {
+            if div.risk_tier >= self.blocking_floor {
                 blocking.push(id.clone());
             } else if div.risk_tier.requires_receipt() {
                 // Check if a receipt has been issued for this divergence and is valid.
@@ -891,6 +2229,25 @@ impl RuntimeOracle {
             }
         }
 
+        // A check where every voter diverges from every other voter (or every
+        // registered runtime abstained, leaving nothing to compare at all)
+        // means no ground truth exists; on a Security-scope check that blocks
+        // release regardless of any divergence's own risk tier.
+        for (check_id, voting_result) in &self.voting_results {
+            if !matches!(
+                voting_result.outcome,
+                VoteOutcome::NoConsensus | VoteOutcome::Inconclusive
+            ) {
+                continue;
+            }
+            let Some(check) = self.checks.get(check_id) else {
+                continue;
+            };
+            if check.boundary_scope == BoundaryScope::Security {
+                blocking.push(check_id.clone());
+            }
+        }
+
         if !blocking.is_empty() {
             let mut details = BTreeMap::new();
             details.insert("blocked_count".to_string(), blocking.len().to_string());
@@ -919,15 +2276,20 @@ impl RuntimeOracle {
         );
         self.emit_event(event_codes::FN_NV_012, "Oracle report generated", details);
 
+        let divergences: Vec<SemanticDivergence> = self.divergences.values().cloned().collect();
+        let risk_tier_counts = risk_tier_counts(&divergences);
+
         DivergenceReport {
             schema_version: SCHEMA_VERSION.to_string(),
             trace_id: self.trace_id.clone(),
             runtimes: self.runtimes.clone(),
             checks: self.checks.values().cloned().collect(),
-            divergences: self.divergences.values().cloned().collect(),
+            divergences,
             voting_results: self.voting_results.values().cloned().collect(),
+            vote_conflicts: self.vote_conflicts.clone(),
             receipts: self.receipts.values().cloned().collect(),
             verdict,
+            risk_tier_counts,
             event_log: self.event_log.clone(),
         }
     }
@@ -965,6 +2327,79 @@ pub fn default_risk_for_scope(scope: BoundaryScope) -> RiskTier {
     }
 }
 
+// ---------------------------------------------------------------------------
+// VerdictHistory
+// ---------------------------------------------------------------------------
+
+/// One run's contribution to a [`VerdictHistory`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerdictHistoryEntry {
+    pub run_label: String,
+    pub verdict: OracleVerdict,
+    pub divergence_count: usize,
+}
+
+/// Trend summary produced by [`VerdictHistory::trend`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerdictTrend {
+    /// Verdicts in run order, oldest first.
+    pub verdicts: Vec<OracleVerdict>,
+    /// Total divergence count reported by each run, in the same order as `verdicts`.
+    pub divergence_counts: Vec<usize>,
+    /// `true` if the latest run's divergence count is lower than the prior run's,
+    /// or if there is no prior run to compare against.
+    pub improved: bool,
+    /// `true` if the latest run's divergence count is higher than the prior run's.
+    pub regressed: bool,
+}
+
+/// Deterministic, serializable accumulator of oracle verdicts across
+/// successive runs, for dashboards that track whether divergence pressure is
+/// improving over time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerdictHistory {
+    entries: Vec<VerdictHistoryEntry>,
+}
+
+impl VerdictHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one run's report into the history.
+    pub fn record(&mut self, report: &DivergenceReport, run_label: &str) {
+        self.entries.push(VerdictHistoryEntry {
+            run_label: run_label.to_string(),
+            verdict: report.verdict.clone(),
+            divergence_count: report.divergences.len(),
+        });
+    }
+
+    /// All recorded entries, oldest first.
+    pub fn entries(&self) -> &[VerdictHistoryEntry] {
+        &self.entries
+    }
+
+    /// Summarize the recorded runs into a [`VerdictTrend`].
+    pub fn trend(&self) -> VerdictTrend {
+        let verdicts = self.entries.iter().map(|e| e.verdict.clone()).collect();
+        let divergence_counts = self.entries.iter().map(|e| e.divergence_count).collect();
+        let (improved, regressed) = match self.entries.as_slice() {
+            [.., prior, latest] => (
+                latest.divergence_count < prior.divergence_count,
+                latest.divergence_count > prior.divergence_count,
+            ),
+            _ => (true, false),
+        };
+        VerdictTrend {
+            verdicts,
+            divergence_counts,
+            improved,
+            regressed,
+        }
+    }
+}
+
 // ===========================================================================
 // Tests
 // ===========================================================================
@@ -981,6 +2416,7 @@ mod tests {
             runtime_name: format!("runtime-{id}"),
             version: "1.0.0".to_string(),
             is_reference: true,
+            engine_family: format!("engine-{id}"),
         }
     }
 
@@ -991,6 +2427,7 @@ mod tests {
             runtime_name: name.to_string(),
             version: version.to_string(),
             is_reference: is_ref,
+            engine_family: name.to_string(),
         }
     }
 
@@ -1125,117 +2562,612 @@ mod tests {
 
     #[test]
     fn quorum_required_uses_integer_ceiling_without_float_rounding() {
-        assert_eq!(quorum_required_for(3, 66).unwrap(), 2);
-        assert_eq!(quorum_required_for(3, 67).unwrap(), 3);
-        assert_eq!(quorum_required_for(usize::MAX, 100).unwrap(), usize::MAX);
+        assert_eq!(quorum_required_for(3, 66, QuorumRounding::Strict).unwrap(), 2);
+        assert_eq!(quorum_required_for(3, 67, QuorumRounding::Strict).unwrap(), 3);
+        assert_eq!(
+            quorum_required_for(usize::MAX, 100, QuorumRounding::Strict).unwrap(),
+            usize::MAX
+        );
 
         let total = u128::try_from(usize::MAX).unwrap();
         let expected = total.saturating_mul(66).saturating_add(99) / 100;
         assert_eq!(
-            quorum_required_for(usize::MAX, 66).unwrap(),
+            quorum_required_for(usize::MAX, 66, QuorumRounding::Strict).unwrap(),
             usize::try_from(expected).unwrap()
         );
     }
 
-    // 2) Register runtime success
+    // Exact reproduction of the case this setting exists for: 2 of 3 runtimes
+    // agreeing is 66.67% agreement, which fails a 67% threshold under Strict
+    // rounding (ceil(3 * 67 / 100) == 3) but passes under RoundNearest
+    // (66.67% rounds to 67%, meeting the threshold exactly).
     #[test]
-    fn register_runtime_success() {
-        let mut oracle = RuntimeOracle::new("trace-002", 66);
-        let result = oracle.register_runtime(sample_runtime("franken"));
-        assert!(result.is_ok());
-        assert_eq!(oracle.runtime_count(), 1);
+    fn two_of_three_at_67_percent_fails_under_strict_rounding() {
+        assert_eq!(quorum_required_for(3, 67, QuorumRounding::Strict).unwrap(), 3);
+        assert!(2 < quorum_required_for(3, 67, QuorumRounding::Strict).unwrap());
+    }
+
+    #[test]
+    fn two_of_three_at_67_percent_passes_under_round_nearest() {
         assert_eq!(
-            oracle.event_log.last().unwrap().event_code,
-            event_codes::FN_NV_002
+            quorum_required_for(3, 67, QuorumRounding::RoundNearest).unwrap(),
+            2
         );
+        assert!(2 >= quorum_required_for(3, 67, QuorumRounding::RoundNearest).unwrap());
     }
 
-    // 3) Duplicate runtime rejected
     #[test]
-    fn duplicate_runtime_rejected() {
-        let mut oracle = RuntimeOracle::new("trace-003", 66);
-        oracle.register_runtime(sample_runtime("franken")).unwrap();
-        let err = oracle
-            .register_runtime(sample_runtime("franken"))
-            .unwrap_err();
-        assert_eq!(err.code, error_codes::ERR_NVO_DUPLICATE_RUNTIME);
+    fn tally_votes_two_of_three_at_67_percent_strict_vs_round_nearest() {
+        fn oracle_with_two_of_three_agreeing(rounding: QuorumRounding) -> VotingResult {
+            let mut oracle = RuntimeOracle::new("trace-quorum-rounding", 67)
+                .with_quorum_rounding(rounding);
+            oracle
+                .register_runtime(sample_runtime("franken"))
+                .unwrap();
+            oracle.register_runtime(sample_runtime("v8")).unwrap();
+            oracle.register_runtime(sample_runtime("qjs")).unwrap();
+            oracle.vote("check-quorum", "franken", vec![1]).unwrap();
+            oracle.vote("check-quorum", "v8", vec![1]).unwrap();
+            oracle.vote("check-quorum", "qjs", vec![2]).unwrap();
+            oracle.tally_votes("check-quorum").unwrap()
+        }
+
+        let strict = oracle_with_two_of_three_agreeing(QuorumRounding::Strict);
+        assert!(!strict.quorum_reached);
+        assert_eq!(strict.quorum_threshold, 3);
+
+        let round_nearest = oracle_with_two_of_three_agreeing(QuorumRounding::RoundNearest);
+        assert!(round_nearest.quorum_reached);
+        assert_eq!(round_nearest.quorum_threshold, 2);
+    }
+
+    fn voted_oracle(quorum_threshold_percent: u8, outputs: &[(&str, u8)]) -> RuntimeOracle {
+        let mut oracle = RuntimeOracle::new("trace-vote-outcome", quorum_threshold_percent);
+        for (runtime_id, _) in outputs {
+            oracle.register_runtime(sample_runtime(runtime_id)).unwrap();
+        }
+        for (runtime_id, output) in outputs {
+            oracle
+                .vote("check-outcome", runtime_id, vec![*output])
+                .unwrap();
+        }
+        oracle
     }
 
-    // 4) Remove runtime success
     #[test]
-    fn remove_runtime_success() {
-        let mut oracle = RuntimeOracle::new("trace-004", 66);
-        oracle.register_runtime(sample_runtime("ref-a")).unwrap();
-        let removed = oracle.remove_runtime("ref-a").unwrap();
-        assert_eq!(removed.runtime_id, "ref-a");
-        assert_eq!(oracle.runtime_count(), 0);
+    fn tally_votes_three_of_three_agree_is_unanimous() {
+        let mut oracle = voted_oracle(100, &[("a", 1), ("b", 1), ("c", 1)]);
+        let result = oracle.tally_votes("check-outcome").unwrap();
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+        assert!(result.quorum_reached);
     }
 
-    // 5) Remove missing runtime error
     #[test]
-    fn remove_missing_runtime_error() {
-        let mut oracle = RuntimeOracle::new("trace-005", 66);
-        let err = oracle.remove_runtime("ghost").unwrap_err();
-        assert_eq!(err.code, error_codes::ERR_NVO_RUNTIME_NOT_FOUND);
+    fn tally_votes_two_of_three_split_falls_short_of_quorum() {
+        // 2-1: a plurality agrees, but the 67% quorum needs all 3.
+        let mut oracle = voted_oracle(67, &[("a", 1), ("b", 1), ("c", 2)]);
+        let result = oracle.tally_votes("check-outcome").unwrap();
+        assert_eq!(result.outcome, VoteOutcome::Split);
+        assert!(!result.quorum_reached);
     }
 
-    // 6) Cross-check requires at least one runtime
     #[test]
-    fn cross_check_requires_runtimes() {
-        let mut oracle = RuntimeOracle::new("trace-006", 66);
-        let outputs = BTreeMap::new();
-        let err = oracle
-            .run_cross_check("chk-1", BoundaryScope::Memory, b"input", &outputs)
-            .unwrap_err();
-        assert_eq!(err.code, error_codes::ERR_NVO_NO_RUNTIMES);
+    fn tally_votes_two_of_three_reaching_quorum_is_majority_agree() {
+        // Same 2-1 split, but a 50% threshold means the plurality meets quorum.
+        let mut oracle = voted_oracle(50, &[("a", 1), ("b", 1), ("c", 2)]);
+        let result = oracle.tally_votes("check-outcome").unwrap();
+        assert_eq!(result.outcome, VoteOutcome::MajorityAgree);
+        assert!(result.quorum_reached);
     }
 
-    // 7) Cross-check agreement
     #[test]
-    fn cross_check_agreement() {
-        let mut oracle = RuntimeOracle::new("trace-007", 66);
+    fn tally_votes_one_one_one_is_no_consensus() {
+        let mut oracle = voted_oracle(50, &[("a", 1), ("b", 2), ("c", 3)]);
+        let result = oracle.tally_votes("check-outcome").unwrap();
+        assert_eq!(result.outcome, VoteOutcome::NoConsensus);
+        assert!(!result.quorum_reached);
+    }
+
+    #[test]
+    fn tally_votes_excludes_abstainers_from_total_voters() {
+        let mut oracle = RuntimeOracle::new("trace-abstain", 100);
         oracle.register_runtime(sample_runtime("a")).unwrap();
         oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle.register_runtime(sample_runtime("c")).unwrap();
+        oracle.vote("check-outcome", "a", vec![1]).unwrap();
+        oracle.vote("check-outcome", "b", vec![1]).unwrap();
+        oracle
+            .abstain(
+                "check-outcome",
+                "c",
+                "opcode unsupported in this engine".to_string(),
+            )
+            .unwrap();
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("a".to_string(), vec![1, 2, 3]);
-        outputs.insert("b".to_string(), vec![1, 2, 3]);
+        let result = oracle.tally_votes("check-outcome").unwrap();
 
-        let check = oracle
-            .run_cross_check("chk-agree", BoundaryScope::IO, b"test", &outputs)
+        assert_eq!(result.total_voters, 2);
+        assert_eq!(result.abstain_count, 1);
+        assert_eq!(
+            result.abstentions.get("c").map(String::as_str),
+            Some("opcode unsupported in this engine")
+        );
+        assert_eq!(result.agreeing_voters, 2);
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+        assert!(result.quorum_reached);
+    }
+
+    #[test]
+    fn tally_votes_all_abstain_is_quorum_failed_distinct_from_zero_votes() {
+        let mut oracle = RuntimeOracle::new("trace-all-abstain", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle
+            .abstain("check-outcome", "a", "unsupported opcode".to_string())
+            .unwrap();
+        oracle
+            .abstain(
+                "check-outcome",
+                "b",
+                "sandboxed capability missing".to_string(),
+            )
             .unwrap();
 
-        match check.outcome.unwrap() {
-            CheckOutcome::Agree { canonical_output } => {
-                assert_eq!(canonical_output, vec![1, 2, 3]);
-            }
-            CheckOutcome::Diverge { .. } => unreachable!("expected agreement"),
-        }
+        let err = oracle.tally_votes("check-outcome").unwrap_err();
+
+        assert_eq!(err.code, error_codes::ERR_NVO_QUORUM_FAILED);
+        assert!(err.message.contains("abstained"));
+        assert!(!err.message.contains("no runtimes registered"));
     }
 
-    // 8) Cross-check divergence
     #[test]
-    fn cross_check_divergence() {
-        let mut oracle = RuntimeOracle::new("trace-008", 66);
+    fn tally_votes_at_succeeds_on_time_once_everyone_has_voted() {
+        let mut oracle = RuntimeOracle::new("trace-timeout-on-time", 100);
         oracle.register_runtime(sample_runtime("a")).unwrap();
         oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle.set_voting_timeout_ms(1_000);
 
         let mut outputs = BTreeMap::new();
         outputs.insert("a".to_string(), vec![1, 2, 3]);
-        outputs.insert("b".to_string(), vec![4, 5, 6]);
-
-        let check = oracle
-            .run_cross_check("chk-div", BoundaryScope::Security, b"test", &outputs)
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+        oracle
+            .run_cross_check_with_evidence_at(
+                "chk-on-time",
+                BoundaryScope::Security,
+                b"input",
+                &outputs,
+                &BTreeMap::new(),
+                1_000,
+            )
             .unwrap();
 
-        match check.outcome.unwrap() {
-            CheckOutcome::Diverge { outputs } => {
-                assert_eq!(outputs.len(), 2);
-            }
-            CheckOutcome::Agree { .. } => unreachable!("expected divergence"),
+        oracle.vote("chk-on-time", "a", vec![1, 2, 3]).unwrap();
+        oracle.vote("chk-on-time", "b", vec![1, 2, 3]).unwrap();
+
+        let result = oracle.tally_votes_at("chk-on-time", 1_500).unwrap();
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+    }
+
+    #[test]
+    fn tally_votes_at_rejects_a_partial_round_past_the_deadline() {
+        let mut oracle = RuntimeOracle::new("trace-timeout-partial", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle.set_voting_timeout_ms(1_000);
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+        oracle
+            .run_cross_check_with_evidence_at(
+                "chk-partial",
+                BoundaryScope::Security,
+                b"input",
+                &outputs,
+                &BTreeMap::new(),
+                1_000,
+            )
+            .unwrap();
+
+        // Only "a" votes in time; "b" never shows up before the deadline.
+        oracle.vote("chk-partial", "a", vec![1, 2, 3]).unwrap();
+
+        let err = oracle.tally_votes_at("chk-partial", 2_001).unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_VOTING_TIMEOUT);
+        assert!(err.message.contains("chk-partial"));
+    }
+
+    #[test]
+    fn tally_votes_at_past_deadline_still_succeeds_once_everyone_responded() {
+        let mut oracle = RuntimeOracle::new("trace-timeout-late-complete", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle.set_voting_timeout_ms(1_000);
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+        oracle
+            .run_cross_check_with_evidence_at(
+                "chk-late-complete",
+                BoundaryScope::Security,
+                b"input",
+                &outputs,
+                &BTreeMap::new(),
+                1_000,
+            )
+            .unwrap();
+
+        // "b" abstains instead of voting, but it does respond -- the round is
+        // complete even though the deadline has already passed by the time
+        // the tally is requested.
+        oracle
+            .vote("chk-late-complete", "a", vec![1, 2, 3])
+            .unwrap();
+        oracle
+            .abstain("chk-late-complete", "b", "crashed".to_string())
+            .unwrap();
+
+        let result = oracle.tally_votes_at("chk-late-complete", 5_000).unwrap();
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+    }
+
+    #[test]
+    fn tally_votes_at_without_a_recorded_start_time_ignores_the_deadline() {
+        let mut oracle = RuntimeOracle::new("trace-timeout-untimed", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle.set_voting_timeout_ms(1_000);
+
+        // Started via the plain, untimed entry point -- no started_at_ms is
+        // recorded, so tally_votes_at should behave exactly like tally_votes
+        // regardless of how large `now_ms` is.
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+        oracle
+            .run_cross_check("chk-untimed", BoundaryScope::Security, b"input", &outputs)
+            .unwrap();
+
+        oracle.vote("chk-untimed", "a", vec![1, 2, 3]).unwrap();
+        oracle.vote("chk-untimed", "b", vec![1, 2, 3]).unwrap();
+
+        let result = oracle.tally_votes_at("chk-untimed", 999_999_999).unwrap();
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+    }
+
+    #[test]
+    fn abstain_from_unknown_runtime_rejected() {
+        let mut oracle = RuntimeOracle::new("trace-abstain-unknown", 100);
+        let err = oracle
+            .abstain("check-outcome", "ghost", "n/a".to_string())
+            .unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_RUNTIME_NOT_FOUND);
+    }
+
+    #[test]
+    fn voting_after_abstaining_supersedes_the_abstention() {
+        let mut oracle = RuntimeOracle::new("trace-abstain-then-vote", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle
+            .abstain("check-outcome", "a", "retrying".to_string())
+            .unwrap();
+        oracle.vote("check-outcome", "a", vec![1]).unwrap();
+        oracle.vote("check-outcome", "b", vec![1]).unwrap();
+
+        let result = oracle.tally_votes("check-outcome").unwrap();
+
+        assert_eq!(result.abstain_count, 0);
+        assert!(result.abstentions.is_empty());
+        assert_eq!(result.total_voters, 2);
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+    }
+
+    #[test]
+    fn abstaining_after_voting_does_not_retract_the_vote() {
+        let mut oracle = RuntimeOracle::new("trace-vote-then-abstain", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle.vote("check-outcome", "a", vec![1]).unwrap();
+        oracle.vote("check-outcome", "b", vec![1]).unwrap();
+        oracle
+            .abstain("check-outcome", "a", "changed my mind".to_string())
+            .unwrap();
+
+        let result = oracle.tally_votes("check-outcome").unwrap();
+
+        assert_eq!(result.abstain_count, 0);
+        assert_eq!(result.total_voters, 2);
+        assert_eq!(result.outcome, VoteOutcome::Unanimous);
+    }
+
+    #[test]
+    fn check_release_gate_blocks_on_all_abstain_security_check_regardless_of_tier() {
+        let mut oracle = RuntimeOracle::new("trace-abstain-gate", 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        let mut cross_check_outputs = BTreeMap::new();
+        cross_check_outputs.insert("a".to_string(), vec![9]);
+        cross_check_outputs.insert("b".to_string(), vec![9]);
+        oracle
+            .run_cross_check(
+                "check-outcome",
+                BoundaryScope::Security,
+                b"input",
+                &cross_check_outputs,
+            )
+            .unwrap();
+        oracle
+            .abstain("check-outcome", "a", "unsupported opcode".to_string())
+            .unwrap();
+        oracle
+            .abstain("check-outcome", "b", "unsupported opcode".to_string())
+            .unwrap();
+        assert!(oracle.tally_votes("check-outcome").is_err());
+
+        let verdict = oracle.check_release_gate(0);
+
+        match verdict {
+            OracleVerdict::BlockRelease {
+                blocking_divergence_ids,
+            } => assert!(blocking_divergence_ids.contains(&"check-outcome".to_string())),
+            other => panic!("expected BlockRelease, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_release_gate_blocks_on_no_consensus_security_check_regardless_of_tier() {
+        let mut oracle = voted_oracle(100, &[("a", 1), ("b", 2), ("c", 3)]);
+        let mut cross_check_outputs = BTreeMap::new();
+        cross_check_outputs.insert("a".to_string(), vec![9]);
+        cross_check_outputs.insert("b".to_string(), vec![9]);
+        cross_check_outputs.insert("c".to_string(), vec![9]);
+        oracle
+            .run_cross_check(
+                "check-outcome",
+                BoundaryScope::Security,
+                b"input",
+                &cross_check_outputs,
+            )
+            .unwrap();
+        oracle.tally_votes("check-outcome").unwrap();
+
+        let verdict = oracle.check_release_gate(0);
+
+        match verdict {
+            OracleVerdict::BlockRelease {
+                blocking_divergence_ids,
+            } => assert!(blocking_divergence_ids.contains(&"check-outcome".to_string())),
+            other => panic!("expected BlockRelease, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_release_gate_ignores_no_consensus_outside_security_scope() {
+        let mut oracle = voted_oracle(100, &[("a", 1), ("b", 2), ("c", 3)]);
+        let mut cross_check_outputs = BTreeMap::new();
+        cross_check_outputs.insert("a".to_string(), vec![9]);
+        cross_check_outputs.insert("b".to_string(), vec![9]);
+        cross_check_outputs.insert("c".to_string(), vec![9]);
+        oracle
+            .run_cross_check(
+                "check-outcome",
+                BoundaryScope::TypeSystem,
+                b"input",
+                &cross_check_outputs,
+            )
+            .unwrap();
+        oracle.tally_votes("check-outcome").unwrap();
+
+        let verdict = oracle.check_release_gate(0);
+
+        assert_eq!(verdict, OracleVerdict::Pass);
+    }
+
+    // 2) Register runtime success
+    #[test]
+    fn register_runtime_success() {
+        let mut oracle = RuntimeOracle::new("trace-002", 66);
+        let result = oracle.register_runtime(sample_runtime("franken"));
+        assert!(result.is_ok());
+        assert_eq!(oracle.runtime_count(), 1);
+        assert_eq!(
+            oracle.event_log.last().unwrap().event_code,
+            event_codes::FN_NV_002
+        );
+    }
+
+    // 3) Duplicate runtime rejected
+    #[test]
+    fn duplicate_runtime_rejected() {
+        let mut oracle = RuntimeOracle::new("trace-003", 66);
+        oracle.register_runtime(sample_runtime("franken")).unwrap();
+        let err = oracle
+            .register_runtime(sample_runtime("franken"))
+            .unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_DUPLICATE_RUNTIME);
+    }
+
+    // 4) Remove runtime success
+    #[test]
+    fn remove_runtime_success() {
+        let mut oracle = RuntimeOracle::new("trace-004", 66);
+        oracle.register_runtime(sample_runtime("ref-a")).unwrap();
+        let removed = oracle.remove_runtime("ref-a").unwrap();
+        assert_eq!(removed.runtime_id, "ref-a");
+        assert_eq!(oracle.runtime_count(), 0);
+    }
+
+    // 5) Remove missing runtime error
+    #[test]
+    fn remove_missing_runtime_error() {
+        let mut oracle = RuntimeOracle::new("trace-005", 66);
+        let err = oracle.remove_runtime("ghost").unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_RUNTIME_NOT_FOUND);
+    }
+
+    // 6) Cross-check requires at least one runtime
+    #[test]
+    fn cross_check_requires_runtimes() {
+        let mut oracle = RuntimeOracle::new("trace-006", 66);
+        let outputs = BTreeMap::new();
+        let err = oracle
+            .run_cross_check("chk-1", BoundaryScope::Memory, b"input", &outputs)
+            .unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_NO_RUNTIMES);
+    }
+
+    // 7) Cross-check agreement
+    #[test]
+    fn cross_check_agreement() {
+        let mut oracle = RuntimeOracle::new("trace-007", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+
+        let check = oracle
+            .run_cross_check("chk-agree", BoundaryScope::IO, b"test", &outputs)
+            .unwrap();
+
+        match check.outcome.unwrap() {
+            CheckOutcome::Agree { canonical_output } => {
+                assert_eq!(canonical_output, vec![1, 2, 3]);
+            }
+            CheckOutcome::Diverge { .. } => unreachable!("expected agreement"),
+        }
+    }
+
+    #[test]
+    fn cross_check_rejects_insufficient_engine_diversity_even_with_quorum_met() {
+        let mut oracle =
+            RuntimeOracle::new("trace-diversity-fail", 100).with_min_distinct_engine_families(2);
+        oracle
+            .register_runtime(k9_entry("rt-a", "runtime-a", "1.0", true))
+            .unwrap();
+        oracle
+            .register_runtime(k9_entry("rt-b", "runtime-b", "2.0", false))
+            .unwrap();
+        oracle.runtimes.get_mut("rt-a").unwrap().engine_family = "same-engine".to_string();
+        oracle.runtimes.get_mut("rt-b").unwrap().engine_family = "same-engine".to_string();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("rt-a".to_string(), vec![1, 2, 3]);
+        outputs.insert("rt-b".to_string(), vec![1, 2, 3]);
+
+        let err = oracle
+            .run_cross_check("chk-diversity", BoundaryScope::Security, b"test", &outputs)
+            .unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_INSUFFICIENT_ENGINE_DIVERSITY);
+    }
+
+    #[test]
+    fn cross_check_passes_diversity_gate_with_distinct_engine_families() {
+        let mut oracle =
+            RuntimeOracle::new("trace-diversity-pass", 100).with_min_distinct_engine_families(2);
+        oracle
+            .register_runtime(k9_entry("rt-a", "runtime-a", "1.0", true))
+            .unwrap();
+        oracle
+            .register_runtime(k9_entry("rt-b", "runtime-b", "2.0", false))
+            .unwrap();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("rt-a".to_string(), vec![1, 2, 3]);
+        outputs.insert("rt-b".to_string(), vec![1, 2, 3]);
+
+        let check = oracle
+            .run_cross_check(
+                "chk-diversity-ok",
+                BoundaryScope::Security,
+                b"test",
+                &outputs,
+            )
+            .unwrap();
+        assert!(matches!(check.outcome.unwrap(), CheckOutcome::Agree { .. }));
+    }
+
+    // 8) Cross-check divergence
+    #[test]
+    fn cross_check_divergence() {
+        let mut oracle = RuntimeOracle::new("trace-008", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![4, 5, 6]);
+
+        let check = oracle
+            .run_cross_check("chk-div", BoundaryScope::Security, b"test", &outputs)
+            .unwrap();
+
+        match check.outcome.unwrap() {
+            CheckOutcome::Diverge { outputs } => {
+                assert_eq!(outputs.len(), 2);
+            }
+            CheckOutcome::Agree { .. } => unreachable!("expected divergence"),
         }
     }
 
+    #[test]
+    fn evidence_submitted_with_a_diverging_vote_appears_in_the_generated_report() {
+        let mut oracle = RuntimeOracle::new("trace-evidence", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![4, 5, 6]);
+
+        let mut evidence = BTreeMap::new();
+        evidence.insert("a".to_string(), "sha256:aaaa...".to_string());
+        evidence.insert("b".to_string(), "sha256:bbbb...".to_string());
+
+        oracle
+            .run_cross_check_with_evidence(
+                "chk-evidence",
+                BoundaryScope::Security,
+                b"test",
+                &outputs,
+                &evidence,
+            )
+            .unwrap();
+
+        let report = oracle.generate_report(1_000);
+        let check = report
+            .checks
+            .iter()
+            .find(|c| c.check_id == "chk-evidence")
+            .expect("check present in report");
+        assert_eq!(check.evidence.get("a"), Some(&"sha256:aaaa...".to_string()));
+        assert_eq!(check.evidence.get("b"), Some(&"sha256:bbbb...".to_string()));
+    }
+
+    #[test]
+    fn run_cross_check_without_evidence_records_an_empty_evidence_map() {
+        let mut oracle = RuntimeOracle::new("trace-no-evidence", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+
+        let check = oracle
+            .run_cross_check(
+                "chk-no-evidence",
+                BoundaryScope::Security,
+                b"test",
+                &outputs,
+            )
+            .unwrap();
+        assert!(check.evidence.is_empty());
+    }
+
     // 9) Duplicate active check ID rejected
     #[test]
     fn duplicate_active_check_rejected() {
@@ -1265,8 +3197,201 @@ mod tests {
             &outputs,
         );
         assert_eq!(div.risk_tier, RiskTier::Critical);
-        assert!(!div.resolved);
+        assert_eq!(div.state, DivergenceState::Open);
         assert_eq!(oracle.divergences.len(), 1);
+        assert_eq!(div.consistency, 1.0);
+    }
+
+    #[test]
+    fn content_addressed_id_ignores_caller_supplied_id() {
+        let mut oracle = RuntimeOracle::new("trace-cid", 66).with_content_addressed_ids(true);
+        let mut outputs = BTreeMap::new();
+        outputs.insert("v8".to_string(), vec![1]);
+        outputs.insert("franken".to_string(), vec![2]);
+
+        let div = oracle.classify_divergence(
+            "caller-chosen-id-is-ignored",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &outputs,
+        );
+
+        assert_ne!(div.divergence_id, "caller-chosen-id-is-ignored");
+        assert!(div.divergence_id.starts_with("div-"));
+        assert!(oracle.divergences.contains_key(&div.divergence_id));
+    }
+
+    #[test]
+    fn content_addressed_id_is_stable_regardless_of_observation_order() {
+        let mut outputs = BTreeMap::new();
+        outputs.insert("v8".to_string(), vec![1]);
+        outputs.insert("franken".to_string(), vec![2]);
+
+        let mut first_oracle =
+            RuntimeOracle::new("trace-cid-order-a", 66).with_content_addressed_ids(true);
+        let first = first_oracle.classify_divergence(
+            "id-a",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &outputs,
+        );
+
+        let mut second_oracle =
+            RuntimeOracle::new("trace-cid-order-b", 66).with_content_addressed_ids(true);
+        let second = second_oracle.classify_divergence(
+            "id-b",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &outputs,
+        );
+
+        assert_eq!(first.divergence_id, second.divergence_id);
+    }
+
+    #[test]
+    fn content_addressed_ids_produce_byte_identical_divergence_lists_across_orderings() {
+        let mut first_outputs = BTreeMap::new();
+        first_outputs.insert("v8".to_string(), vec![1]);
+        first_outputs.insert("franken".to_string(), vec![2]);
+        let mut second_outputs = BTreeMap::new();
+        second_outputs.insert("qjs".to_string(), vec![3]);
+        second_outputs.insert("franken".to_string(), vec![4]);
+
+        let mut oracle_ab =
+            RuntimeOracle::new("trace-cid-report", 66).with_content_addressed_ids(true);
+        oracle_ab.classify_divergence(
+            "seen-first",
+            "chk-a",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &first_outputs,
+        );
+        oracle_ab.classify_divergence(
+            "seen-second",
+            "chk-b",
+            BoundaryScope::IO,
+            RiskTier::Medium,
+            &second_outputs,
+        );
+        let report_ab = oracle_ab.generate_report(0);
+        // `event_log` is a timeline, so it legitimately records events in
+        // call order even with content-addressed IDs; it is `divergences`
+        // (what CI actually diffs between runs) that must match exactly.
+
+        let mut oracle_ba =
+            RuntimeOracle::new("trace-cid-report", 66).with_content_addressed_ids(true);
+        oracle_ba.classify_divergence(
+            "seen-second",
+            "chk-b",
+            BoundaryScope::IO,
+            RiskTier::Medium,
+            &second_outputs,
+        );
+        oracle_ba.classify_divergence(
+            "seen-first",
+            "chk-a",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &first_outputs,
+        );
+        let report_ba = oracle_ba.generate_report(0);
+
+        let divergences_ab = serde_json::to_vec(&report_ab.divergences).unwrap();
+        let divergences_ba = serde_json::to_vec(&report_ba.divergences).unwrap();
+        assert_eq!(divergences_ab, divergences_ba);
+    }
+
+    #[test]
+    fn divergence_transition_valid_progression_reaches_resolved() {
+        let mut oracle = RuntimeOracle::new("trace-010c", 66);
+        let mut div = oracle.classify_divergence(
+            "div-progress",
+            "chk-1",
+            BoundaryScope::IO,
+            RiskTier::Medium,
+            &BTreeMap::new(),
+        );
+        assert_eq!(div.state, DivergenceState::Open);
+        div.transition(DivergenceState::Acknowledged).unwrap();
+        assert_eq!(div.state, DivergenceState::Acknowledged);
+        div.transition(DivergenceState::Mitigated).unwrap();
+        assert_eq!(div.state, DivergenceState::Mitigated);
+        div.transition(DivergenceState::Resolved).unwrap();
+        assert_eq!(div.state, DivergenceState::Resolved);
+        assert!(div.state.is_terminal());
+    }
+
+    #[test]
+    fn divergence_transition_rejects_illegal_jump_to_resolved() {
+        let mut div = oracle_divergence_for_transition_test();
+        assert_eq!(div.state, DivergenceState::Open);
+
+        let err = div.transition(DivergenceState::Resolved).unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_ILLEGAL_DIVERGENCE_TRANSITION);
+        // Rejected transition must not mutate state.
+        assert_eq!(div.state, DivergenceState::Open);
+    }
+
+    fn oracle_divergence_for_transition_test() -> SemanticDivergence {
+        let mut oracle = RuntimeOracle::new("trace-010d", 66);
+        oracle.classify_divergence(
+            "div-illegal",
+            "chk-1",
+            BoundaryScope::IO,
+            RiskTier::Medium,
+            &BTreeMap::new(),
+        )
+    }
+
+    #[test]
+    fn classify_divergence_with_consistency_computes_ratio() {
+        let mut oracle = RuntimeOracle::new("trace-010b", 66);
+        let outputs = BTreeMap::new();
+
+        let weak = oracle.classify_divergence_with_consistency(
+            "div-weak",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Low,
+            &outputs,
+            1,
+            10,
+        );
+        assert_eq!(weak.consistency, 0.1);
+
+        let strong = oracle.classify_divergence_with_consistency(
+            "div-strong",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &outputs,
+            10,
+            10,
+        );
+        assert_eq!(strong.consistency, 1.0);
+    }
+
+    #[test]
+    fn consistency_is_surfaced_in_generated_report() {
+        let mut oracle = RuntimeOracle::new("trace-010c", 66);
+        let outputs = BTreeMap::new();
+        oracle.classify_divergence_with_consistency(
+            "div-weak",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Low,
+            &outputs,
+            1,
+            10,
+        );
+
+        let report = oracle.generate_report(66);
+        let json = serde_json::to_value(&report).unwrap();
+        let consistency = json["divergences"][0]["consistency"].as_f64().unwrap();
+        assert_eq!(consistency, 0.1);
     }
 
     // 11) Voting and quorum success
@@ -1311,6 +3436,49 @@ mod tests {
         assert_eq!(err.code, error_codes::ERR_NVO_RUNTIME_NOT_FOUND);
     }
 
+    #[test]
+    fn contradictory_vote_is_recorded_as_conflict_and_original_retained() {
+        let mut oracle = RuntimeOracle::new("trace-013b", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+
+        oracle.vote("chk-contra", "a", vec![1]).unwrap();
+        oracle.vote("chk-contra", "a", vec![2]).unwrap();
+
+        assert_eq!(oracle.vote_conflicts.len(), 1);
+        let conflict = &oracle.vote_conflicts[0];
+        assert_eq!(conflict.check_id, "chk-contra");
+        assert_eq!(conflict.runtime_id, "a");
+        assert_eq!(conflict.original_output, vec![1]);
+        assert_eq!(conflict.conflicting_output, vec![2]);
+
+        let result = oracle.tally_votes("chk-contra").unwrap();
+        assert_eq!(result.votes.get("a"), Some(&vec![1]));
+    }
+
+    #[test]
+    fn repeated_identical_vote_is_not_a_conflict() {
+        let mut oracle = RuntimeOracle::new("trace-013c", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+
+        oracle.vote("chk-same", "a", vec![1]).unwrap();
+        oracle.vote("chk-same", "a", vec![1]).unwrap();
+
+        assert!(oracle.vote_conflicts.is_empty());
+    }
+
+    #[test]
+    fn vote_conflicts_are_surfaced_in_generated_report() {
+        let mut oracle = RuntimeOracle::new("trace-013d", 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+
+        oracle.vote("chk-contra", "a", vec![1]).unwrap();
+        oracle.vote("chk-contra", "a", vec![2]).unwrap();
+
+        let report = oracle.generate_report(66);
+        assert_eq!(report.vote_conflicts.len(), 1);
+        assert_eq!(report.vote_conflicts[0].runtime_id, "a");
+    }
+
     // 14) Issue policy receipt for low-risk
     #[test]
     fn issue_policy_receipt_low_risk() {
@@ -1350,53 +3518,193 @@ mod tests {
         let divergence = oracle.classify_divergence(
             "div-l",
             "chk-1",
-            BoundaryScope::TypeSystem,
-            RiskTier::Low,
+            BoundaryScope::TypeSystem,
+            RiskTier::Low,
+            &BTreeMap::new(),
+        );
+        let receipt = linked_sample_receipt("rcpt-l1", &divergence);
+        oracle.issue_policy_receipt(receipt).unwrap();
+        let valid = oracle.verify_l1_linkage("rcpt-l1").unwrap();
+        assert!(valid);
+    }
+
+    // 17) Verify L1 linkage broken
+    #[test]
+    fn verify_l1_linkage_broken() {
+        let mut oracle = RuntimeOracle::new("trace-017", 66);
+        oracle.classify_divergence(
+            "div-lb",
+            "chk-1",
+            BoundaryScope::TypeSystem,
+            RiskTier::Low,
+            &BTreeMap::new(),
+        );
+        let mut receipt = sample_receipt("rcpt-broken", "div-lb");
+        receipt.l1_linkage.l1_oracle_run_id.clear();
+        oracle.issue_policy_receipt(receipt).unwrap();
+        let err = oracle.verify_l1_linkage("rcpt-broken").unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_L1_LINKAGE_BROKEN);
+    }
+
+    // 18) Resolve divergence
+    #[test]
+    fn resolve_divergence_success() {
+        let mut oracle = RuntimeOracle::new("trace-018", 66);
+        oracle.classify_divergence(
+            "div-r",
+            "chk-1",
+            BoundaryScope::IO,
+            RiskTier::Medium,
+            &BTreeMap::new(),
+        );
+        oracle
+            .resolve_divergence("div-r", "Accepted as benign")
+            .unwrap();
+        assert_eq!(oracle.divergences["div-r"].state, DivergenceState::Resolved);
+        assert_eq!(
+            oracle.divergences["div-r"].resolution_note.as_deref(),
+            Some("Accepted as benign")
+        );
+    }
+
+    // 18') resolve_divergence_with_evidence records structured evidence that
+    // surfaces into the generated report
+    #[test]
+    fn resolve_divergence_with_evidence_round_trips_through_report() {
+        let mut oracle = RuntimeOracle::new("trace-018evidence", 66);
+        oracle.classify_divergence(
+            "div-re",
+            "chk-1",
+            BoundaryScope::IO,
+            RiskTier::Medium,
+            &BTreeMap::new(),
+        );
+        oracle
+            .resolve_divergence_with_evidence(
+                "div-re",
+                "alice",
+                "Confirmed benign after manual review",
+                Some("https://tracker.example/TICKET-42".to_string()),
+            )
+            .unwrap();
+
+        let report = oracle.generate_report(0);
+        let div = report
+            .divergences
+            .iter()
+            .find(|d| d.divergence_id == "div-re")
+            .unwrap();
+        assert_eq!(div.state, DivergenceState::Resolved);
+        let evidence = div.resolution_evidence.as_ref().unwrap();
+        assert_eq!(evidence.resolver, "alice");
+        assert_eq!(
+            evidence.justification,
+            "Confirmed benign after manual review"
+        );
+        assert_eq!(
+            evidence.evidence_ref.as_deref(),
+            Some("https://tracker.example/TICKET-42")
+        );
+    }
+
+    // 18'') resolve_divergence_with_evidence rejects an empty justification
+    #[test]
+    fn resolve_divergence_with_evidence_rejects_empty_justification() {
+        let mut oracle = RuntimeOracle::new("trace-018evidence-empty", 66);
+        oracle.classify_divergence(
+            "div-re2",
+            "chk-1",
+            BoundaryScope::IO,
+            RiskTier::Medium,
             &BTreeMap::new(),
         );
-        let receipt = linked_sample_receipt("rcpt-l1", &divergence);
-        oracle.issue_policy_receipt(receipt).unwrap();
-        let valid = oracle.verify_l1_linkage("rcpt-l1").unwrap();
-        assert!(valid);
+
+        let err = oracle
+            .resolve_divergence_with_evidence("div-re2", "alice", "   ", None)
+            .unwrap_err();
+        assert_eq!(
+            err.code,
+            error_codes::ERR_NVO_RESOLUTION_JUSTIFICATION_REQUIRED
+        );
+        assert_eq!(oracle.divergences["div-re2"].state, DivergenceState::Open);
+        assert!(oracle.divergences["div-re2"].resolution_evidence.is_none());
     }
 
-    // 17) Verify L1 linkage broken
+    // 18a) Annotate divergence and see it surface in the generated report
     #[test]
-    fn verify_l1_linkage_broken() {
-        let mut oracle = RuntimeOracle::new("trace-017", 66);
+    fn annotate_divergence_round_trips_through_report() {
+        let mut oracle = RuntimeOracle::new("trace-018a", 66);
         oracle.classify_divergence(
-            "div-lb",
+            "div-ann",
             "chk-1",
-            BoundaryScope::TypeSystem,
-            RiskTier::Low,
+            BoundaryScope::IO,
+            RiskTier::Medium,
             &BTreeMap::new(),
         );
-        let mut receipt = sample_receipt("rcpt-broken", "div-lb");
-        receipt.l1_linkage.l1_oracle_run_id.clear();
-        oracle.issue_policy_receipt(receipt).unwrap();
-        let err = oracle.verify_l1_linkage("rcpt-broken").unwrap_err();
-        assert_eq!(err.code, error_codes::ERR_NVO_L1_LINKAGE_BROKEN);
+        oracle
+            .annotate_divergence("div-ann", "ticket", "JIRA-1234")
+            .unwrap();
+
+        let report = oracle.generate_report(0);
+        let div = report
+            .divergences
+            .iter()
+            .find(|d| d.divergence_id == "div-ann")
+            .unwrap();
+        assert_eq!(div.annotations.get("ticket").map(String::as_str), Some("JIRA-1234"));
     }
 
-    // 18) Resolve divergence
+    // 18b) Annotations round-trip through serde
     #[test]
-    fn resolve_divergence_success() {
-        let mut oracle = RuntimeOracle::new("trace-018", 66);
+    fn annotate_divergence_round_trips_through_serde() {
+        let mut oracle = RuntimeOracle::new("trace-018b", 66);
         oracle.classify_divergence(
-            "div-r",
+            "div-ann",
             "chk-1",
             BoundaryScope::IO,
             RiskTier::Medium,
             &BTreeMap::new(),
         );
         oracle
-            .resolve_divergence("div-r", "Accepted as benign")
+            .annotate_divergence("div-ann", "ticket", "JIRA-5678")
             .unwrap();
-        assert!(oracle.divergences["div-r"].resolved);
+
+        let div = oracle.divergences["div-ann"].clone();
+        let json = serde_json::to_string(&div).unwrap();
+        let roundtripped: SemanticDivergence = serde_json::from_str(&json).unwrap();
         assert_eq!(
-            oracle.divergences["div-r"].resolution_note.as_deref(),
-            Some("Accepted as benign")
+            roundtripped.annotations.get("ticket").map(String::as_str),
+            Some("JIRA-5678")
+        );
+    }
+
+    // 18c) Annotating a missing divergence errors
+    #[test]
+    fn annotate_divergence_missing_id_errors() {
+        let mut oracle = RuntimeOracle::new("trace-018c", 66);
+        let err = oracle
+            .annotate_divergence("div-ghost", "ticket", "JIRA-0000")
+            .unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_DIVERGENCE_NOT_FOUND);
+    }
+
+    // 18d) Annotations are informational only and never affect the release verdict
+    #[test]
+    fn annotate_divergence_does_not_affect_release_gate() {
+        let mut oracle = RuntimeOracle::new("trace-018d", 66);
+        oracle.classify_divergence(
+            "div-crit",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &BTreeMap::new(),
         );
+        let before = oracle.check_release_gate(0);
+        oracle
+            .annotate_divergence("div-crit", "ticket", "JIRA-9999")
+            .unwrap();
+        let after = oracle.check_release_gate(0);
+        assert_eq!(before, after);
     }
 
     // 19) Release gate pass when no divergences
@@ -1533,6 +3841,71 @@ mod tests {
         assert!(!report.event_log.is_empty());
     }
 
+    fn zone_report(zone: &str, block: bool) -> DivergenceReport {
+        let mut oracle = RuntimeOracle::new(&format!("trace-{zone}"), 66);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![10]);
+        outputs.insert("b".to_string(), vec![20]);
+        oracle
+            .run_cross_check(&format!("{zone}-chk"), BoundaryScope::IO, b"data", &outputs)
+            .unwrap();
+
+        if block {
+            oracle.classify_divergence(
+                &format!("{zone}-div"),
+                &format!("{zone}-chk"),
+                BoundaryScope::IO,
+                RiskTier::High,
+                &outputs,
+            );
+        }
+
+        oracle.generate_report(0)
+    }
+
+    #[test]
+    fn aggregate_reports_blocks_fleet_when_any_zone_blocks() {
+        let mut reports = BTreeMap::new();
+        reports.insert("zone-a".to_string(), zone_report("zone-a", false));
+        reports.insert("zone-b".to_string(), zone_report("zone-b", true));
+        reports.insert("zone-c".to_string(), zone_report("zone-c", false));
+
+        let fleet = aggregate_reports(&reports);
+
+        assert_eq!(
+            fleet.verdict,
+            OracleVerdict::BlockRelease {
+                blocking_divergence_ids: vec!["zone-b-div".to_string()]
+            }
+        );
+        assert_eq!(fleet.blocking_zones, vec!["zone-b".to_string()]);
+        assert_eq!(fleet.divergences.len(), 1);
+        assert_eq!(fleet.zone_verdicts.len(), 3);
+        assert_eq!(fleet.zone_verdicts["zone-a"], OracleVerdict::Pass);
+        assert_eq!(
+            fleet.zone_verdicts["zone-b"],
+            OracleVerdict::BlockRelease {
+                blocking_divergence_ids: vec!["zone-b-div".to_string()]
+            }
+        );
+        assert_eq!(fleet.zone_verdicts["zone-c"], OracleVerdict::Pass);
+    }
+
+    #[test]
+    fn aggregate_reports_passes_when_every_zone_passes() {
+        let mut reports = BTreeMap::new();
+        reports.insert("zone-a".to_string(), zone_report("zone-a", false));
+        reports.insert("zone-b".to_string(), zone_report("zone-b", false));
+
+        let fleet = aggregate_reports(&reports);
+
+        assert_eq!(fleet.verdict, OracleVerdict::Pass);
+        assert!(fleet.blocking_zones.is_empty());
+    }
+
     // 26) Default risk for scope mapping
     #[test]
     fn default_risk_for_scope_mapping() {
@@ -1676,6 +4049,69 @@ mod tests {
         assert_eq!(err.code, error_codes::ERR_NVO_CHECK_NOT_FOUND);
     }
 
+    #[test]
+    fn numeric_order_sorts_blocking_divergence_ids_numerically() {
+        let mut oracle = RuntimeOracle::new("trace-numeric-order", 66);
+        for i in 1..=12 {
+            oracle.classify_divergence(
+                &format!("div-{i}"),
+                "chk-1",
+                BoundaryScope::Memory,
+                RiskTier::High,
+                &BTreeMap::new(),
+            );
+        }
+
+        let verdict = oracle.check_release_gate(0);
+        let OracleVerdict::BlockRelease {
+            blocking_divergence_ids,
+        } = &verdict
+        else {
+            unreachable!("expected BlockRelease");
+        };
+
+        // BTreeMap iteration is lexicographic, so the raw ids are not in
+        // numeric order (e.g. "div-10" sorts before "div-2").
+        assert_ne!(
+            blocking_divergence_ids,
+            &(1..=12)
+                .map(|i| format!("div-{i}"))
+                .collect::<Vec<String>>()
+        );
+
+        let expected: Vec<String> = (1..=12).map(|i| format!("div-{i}")).collect();
+        assert_eq!(verdict.numeric_order(), expected);
+    }
+
+    #[test]
+    fn numeric_order_is_empty_for_pass() {
+        assert_eq!(OracleVerdict::Pass.numeric_order(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn numeric_order_falls_back_to_text_for_non_numeric_ids() {
+        let mut oracle = RuntimeOracle::new("trace-numeric-order-text", 66);
+        for id in ["div-lb", "div-l", "div-low"] {
+            oracle.classify_divergence(
+                id,
+                "chk-1",
+                BoundaryScope::Memory,
+                RiskTier::High,
+                &BTreeMap::new(),
+            );
+        }
+
+        let verdict = oracle.check_release_gate(0);
+        assert_eq!(
+            verdict.numeric_order(),
+            vec![
+                "div-l".to_string(),
+                "div-lb".to_string(),
+                "div-low".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn issue_policy_receipt_rejects_unknown_divergence() {
         let mut oracle = RuntimeOracle::new("trace-unknown-div-receipt", 66);
@@ -1811,10 +4247,13 @@ mod tests {
             VotingResult {
                 check_id: "chk-orphan".to_string(),
                 votes,
+                abstentions: BTreeMap::new(),
+                abstain_count: 0,
                 quorum_reached: false,
                 quorum_threshold: 1,
                 total_voters: 1,
                 agreeing_voters: 1,
+                outcome: VoteOutcome::default(),
             },
         );
 
@@ -1975,24 +4414,28 @@ mod tests {
                 runtime_name: "Valid Runtime".to_string(),
                 version: "1.0.0".to_string(),
                 is_reference: true,
+                engine_family: "valid-engine".to_string(),
             },
             RuntimeEntry {
                 runtime_id: "\0runtime\x01id".to_string(), // Control characters
                 runtime_name: "runtime\nwith\nnewlines".to_string(),
                 version: "🚀version💀".to_string(), // Unicode emoji
                 is_reference: false,
+                engine_family: "\0family\x01id".to_string(),
             },
             RuntimeEntry {
                 runtime_id: "../../../etc/passwd".to_string(), // Path traversal
                 runtime_name: "<script>alert('runtime')</script>".to_string(), // XSS
                 version: "\u{FFFF}".to_string(),               // Max Unicode
                 is_reference: true,
+                engine_family: "<script>alert('family')</script>".to_string(),
             },
             RuntimeEntry {
                 runtime_id: "x".repeat(10_000),   // Very long ID
                 runtime_name: "y".repeat(50_000), // Very long name
                 version: "z".repeat(1_000),       // Long version
                 is_reference: false,
+                engine_family: "w".repeat(10_000),
             },
         ];
 
@@ -2104,6 +4547,7 @@ mod tests {
                 input: vec![],
                 trace_id: "trace123".to_string(),
                 outcome: None,
+                evidence: BTreeMap::new(),
             },
             CrossRuntimeCheck {
                 check_id: "\0check\x01id".to_string(), // Control characters
@@ -2113,6 +4557,7 @@ mod tests {
                 outcome: Some(CheckOutcome::Agree {
                     canonical_output: vec![],
                 }),
+                evidence: BTreeMap::new(),
             },
             CrossRuntimeCheck {
                 check_id: "🚀check💀".to_string(), // Unicode emoji
@@ -2122,6 +4567,7 @@ mod tests {
                 outcome: Some(CheckOutcome::Diverge {
                     outputs: BTreeMap::new(),
                 }),
+                evidence: BTreeMap::new(),
             },
         ];
 
@@ -2158,9 +4604,12 @@ mod tests {
                 boundary_scope: BoundaryScope::IO,
                 risk_tier: RiskTier::Critical,
                 runtime_outputs: BTreeMap::new(), // Empty outputs
-                resolved: false,
+                state: DivergenceState::Open,
                 resolution_note: None, // No resolution note
                 trace_id: "trace1".to_string(),
+                annotations: BTreeMap::new(),
+                resolution_evidence: None,
+                consistency: 1.0,
             },
             SemanticDivergence {
                 divergence_id: "\0div\x01".to_string(), // Control characters
@@ -2173,9 +4622,12 @@ mod tests {
                     outputs.insert("🚀runtime2💀".to_string(), vec![]);
                     outputs
                 },
-                resolved: true,
+                state: DivergenceState::Resolved,
                 resolution_note: Some("<script>alert('resolved')</script>".to_string()), // XSS
                 trace_id: "../../../var/log/trace".to_string(),
+                annotations: BTreeMap::new(),
+                resolution_evidence: None,
+                consistency: 1.0,
             },
             SemanticDivergence {
                 divergence_id: "x".repeat(1000), // Long ID
@@ -2190,15 +4642,18 @@ mod tests {
                     }
                     outputs
                 },
-                resolved: true,
+                state: DivergenceState::Resolved,
                 resolution_note: Some("z".repeat(10_000)), // Very long resolution note
                 trace_id: "normal_trace".to_string(),
+                annotations: BTreeMap::new(),
+                resolution_evidence: None,
+                consistency: 1.0,
             },
         ];
 
         for divergence in edge_divergences {
             // Divergence creation should handle edge cases
-            assert!(divergence.resolved || !divergence.resolved); // Boolean check
+            let _ = divergence.state.is_terminal();
 
             // Risk tier should be valid
             assert!(matches!(
@@ -2446,6 +4901,7 @@ mod tests {
                 runtime_name: format!("Runtime for {}", malicious_id),
                 version: "1.0.0".to_string(),
                 is_reference: false,
+                engine_family: format!("engine-for-{}", malicious_id),
             };
 
             let result = oracle.register_runtime(runtime);
@@ -2461,6 +4917,7 @@ mod tests {
             runtime_name: "BOM Runtime".to_string(),
             version: "1.0.0".to_string(),
             is_reference: false,
+            engine_family: "bom-engine".to_string(),
         };
 
         let result = oracle.register_runtime(variant_runtime);
@@ -2558,6 +5015,7 @@ mod tests {
                     runtime_name: format!("Runtime {}", i),
                     version: "1.0.0".to_string(),
                     is_reference: false,
+                    engine_family: format!("engine-{}", i),
                 };
                 oracle_guard.register_runtime(runtime).unwrap();
             }
@@ -2784,6 +5242,7 @@ mod tests {
                 runtime_name: format!("Runtime {}", i),
                 version: "1.0.0".to_string(),
                 is_reference: false,
+                engine_family: format!("engine-{}", i),
             };
 
             // This should generate log events
@@ -2812,6 +5271,7 @@ mod tests {
             runtime_name: "Final Test Runtime".to_string(),
             version: "1.0.0".to_string(),
             is_reference: false,
+            engine_family: "final-test-engine".to_string(),
         };
 
         let result = oracle.register_runtime(test_runtime);
@@ -3003,4 +5463,350 @@ mod tests {
         );
         // Should succeed
     }
+
+    // === VerdictHistory ===
+
+    fn two_runtime_oracle(trace_id: &str) -> RuntimeOracle {
+        let mut oracle = RuntimeOracle::new(trace_id, 100);
+        oracle.register_runtime(sample_runtime("a")).unwrap();
+        oracle.register_runtime(sample_runtime("b")).unwrap();
+        oracle
+    }
+
+    fn agreeing_outputs() -> BTreeMap<String, Vec<u8>> {
+        let mut outputs = BTreeMap::new();
+        outputs.insert("a".to_string(), vec![1, 2, 3]);
+        outputs.insert("b".to_string(), vec![1, 2, 3]);
+        outputs
+    }
+
+    #[test]
+    fn verdict_history_reports_steady_improvement_across_three_runs() {
+        let mut history = VerdictHistory::new();
+
+        // Run 1: one critical (blocking) and one low (receipt-pending) divergence.
+        let mut oracle = two_runtime_oracle("trace-history-1");
+        oracle
+            .run_cross_check(
+                "chk-1",
+                BoundaryScope::Security,
+                b"input",
+                &agreeing_outputs(),
+            )
+            .unwrap();
+        oracle.classify_divergence(
+            "div-1-critical",
+            "chk-1",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &agreeing_outputs(),
+        );
+        oracle.classify_divergence(
+            "div-1-low",
+            "chk-1",
+            BoundaryScope::TypeSystem,
+            RiskTier::Low,
+            &agreeing_outputs(),
+        );
+        let report_1 = oracle.generate_report(1_000);
+        assert!(matches!(report_1.verdict, OracleVerdict::BlockRelease { .. }));
+        history.record(&report_1, "run-1");
+
+        // Run 2: only the low-risk divergence remains, with no receipt issued.
+        let mut oracle = two_runtime_oracle("trace-history-2");
+        oracle
+            .run_cross_check(
+                "chk-2",
+                BoundaryScope::TypeSystem,
+                b"input",
+                &agreeing_outputs(),
+            )
+            .unwrap();
+        oracle.classify_divergence(
+            "div-2-low",
+            "chk-2",
+            BoundaryScope::TypeSystem,
+            RiskTier::Low,
+            &agreeing_outputs(),
+        );
+        let report_2 = oracle.generate_report(1_001);
+        assert!(matches!(
+            report_2.verdict,
+            OracleVerdict::RequiresReceipt { .. }
+        ));
+        history.record(&report_2, "run-2");
+
+        // Run 3: clean run, no divergences at all.
+        let mut oracle = two_runtime_oracle("trace-history-3");
+        oracle
+            .run_cross_check(
+                "chk-3",
+                BoundaryScope::TypeSystem,
+                b"input",
+                &agreeing_outputs(),
+            )
+            .unwrap();
+        let report_3 = oracle.generate_report(1_002);
+        assert_eq!(report_3.verdict, OracleVerdict::Pass);
+        history.record(&report_3, "run-3");
+
+        assert_eq!(history.entries().len(), 3);
+
+        let trend = history.trend();
+        assert_eq!(
+            trend.verdicts,
+            vec![
+                OracleVerdict::BlockRelease {
+                    blocking_divergence_ids: vec!["div-1-critical".to_string()]
+                },
+                OracleVerdict::RequiresReceipt {
+                    pending_divergence_ids: vec!["div-2-low".to_string()]
+                },
+                OracleVerdict::Pass,
+            ]
+        );
+        assert_eq!(trend.divergence_counts, vec![2, 1, 0]);
+        assert!(
+            trend
+                .divergence_counts
+                .windows(2)
+                .all(|pair| pair[1] < pair[0]),
+            "divergence counts must steadily decrease across runs"
+        );
+        assert!(trend.improved);
+        assert!(!trend.regressed);
+    }
+
+    #[test]
+    fn verdict_history_flags_regression_when_latest_run_is_worse() {
+        let mut history = VerdictHistory::new();
+
+        let mut oracle = two_runtime_oracle("trace-regress-1");
+        oracle
+            .run_cross_check(
+                "chk-1",
+                BoundaryScope::TypeSystem,
+                b"input",
+                &agreeing_outputs(),
+            )
+            .unwrap();
+        history.record(&oracle.generate_report(1_000), "run-1");
+
+        let mut oracle = two_runtime_oracle("trace-regress-2");
+        oracle
+            .run_cross_check(
+                "chk-2",
+                BoundaryScope::Security,
+                b"input",
+                &agreeing_outputs(),
+            )
+            .unwrap();
+        oracle.classify_divergence(
+            "div-regress",
+            "chk-2",
+            BoundaryScope::Security,
+            RiskTier::Critical,
+            &agreeing_outputs(),
+        );
+        history.record(&oracle.generate_report(1_001), "run-2");
+
+        let trend = history.trend();
+        assert_eq!(trend.divergence_counts, vec![0, 1]);
+        assert!(trend.regressed);
+        assert!(!trend.improved);
+    }
+
+    #[test]
+    fn verdict_history_serde_roundtrip() {
+        let mut history = VerdictHistory::new();
+        let mut oracle = two_runtime_oracle("trace-serde");
+        oracle
+            .run_cross_check(
+                "chk-1",
+                BoundaryScope::TypeSystem,
+                b"input",
+                &agreeing_outputs(),
+            )
+            .unwrap();
+        history.record(&oracle.generate_report(1_000), "run-1");
+
+        let json = serde_json::to_string(&history).unwrap();
+        let parsed: VerdictHistory = serde_json::from_str(&json).unwrap();
+        assert_eq!(history, parsed);
+    }
+
+    fn divergence_for_checklist(
+        divergence_id: &str,
+        risk_tier: RiskTier,
+        runtimes: &[&str],
+    ) -> SemanticDivergence {
+        let mut runtime_outputs = BTreeMap::new();
+        for runtime in runtimes {
+            runtime_outputs.insert((*runtime).to_string(), vec![0u8]);
+        }
+        SemanticDivergence {
+            divergence_id: divergence_id.to_string(),
+            check_id: "chk-checklist".to_string(),
+            boundary_scope: BoundaryScope::Security,
+            risk_tier,
+            runtime_outputs,
+            state: DivergenceState::Open,
+            resolution_note: None,
+            trace_id: "trace-checklist".to_string(),
+            annotations: BTreeMap::new(),
+            resolution_evidence: None,
+            consistency: 1.0,
+        }
+    }
+
+    #[test]
+    fn remediation_checklist_lists_critical_before_low_with_correct_actions() {
+        let critical =
+            divergence_for_checklist("div-critical", RiskTier::Critical, &["rt-a", "rt-b"]);
+        let low = divergence_for_checklist("div-low", RiskTier::Low, &["rt-a", "rt-b"]);
+
+        let report = DivergenceReport {
+            schema_version: SCHEMA_VERSION.to_string(),
+            trace_id: "trace-checklist".to_string(),
+            runtimes: BTreeMap::new(),
+            checks: Vec::new(),
+            divergences: vec![low, critical],
+            voting_results: Vec::new(),
+            vote_conflicts: Vec::new(),
+            receipts: Vec::new(),
+            verdict: OracleVerdict::BlockRelease {
+                blocking_divergence_ids: vec!["div-critical".to_string()],
+            },
+            risk_tier_counts: BTreeMap::new(),
+            event_log: Vec::new(),
+        };
+
+        let checklist = report.remediation_checklist();
+        let critical_pos = checklist.find("div-critical").expect("critical listed");
+        let low_pos = checklist.find("div-low").expect("low listed");
+        assert!(
+            critical_pos < low_pos,
+            "critical divergence must be listed before low divergence"
+        );
+        assert!(checklist.contains(
+            "Resolve this divergence (or raise its risk tier's acceptance) before release."
+        ));
+        assert!(
+            checklist
+                .contains("Issue a policy receipt acknowledging this divergence before release.")
+        );
+    }
+
+    #[test]
+    fn remediation_checklist_reports_no_items_when_clean() {
+        let report = DivergenceReport {
+            schema_version: SCHEMA_VERSION.to_string(),
+            trace_id: "trace-clean".to_string(),
+            runtimes: BTreeMap::new(),
+            checks: Vec::new(),
+            divergences: Vec::new(),
+            voting_results: Vec::new(),
+            vote_conflicts: Vec::new(),
+            receipts: Vec::new(),
+            verdict: OracleVerdict::Pass,
+            risk_tier_counts: BTreeMap::new(),
+            event_log: Vec::new(),
+        };
+        assert_eq!(
+            report.remediation_checklist(),
+            "No outstanding remediation items."
+        );
+    }
+
+    fn report_with_divergences(divergences: Vec<SemanticDivergence>) -> DivergenceReport {
+        DivergenceReport {
+            schema_version: SCHEMA_VERSION.to_string(),
+            trace_id: "trace-filter-scope".to_string(),
+            runtimes: BTreeMap::new(),
+            checks: Vec::new(),
+            divergences,
+            voting_results: Vec::new(),
+            vote_conflicts: Vec::new(),
+            receipts: Vec::new(),
+            verdict: OracleVerdict::Pass,
+            risk_tier_counts: BTreeMap::new(),
+            event_log: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn filter_scope_keeps_only_matching_divergences() {
+        let security = divergence_for_checklist("div-security", RiskTier::Info, &["rt-a"]);
+        let mut memory_leak = divergence_for_checklist("div-memory", RiskTier::Info, &["rt-a"]);
+        memory_leak.boundary_scope = BoundaryScope::Memory;
+
+        let report = report_with_divergences(vec![security.clone(), memory_leak]);
+        let scoped = report.filter_scope(BoundaryScope::Security);
+
+        assert_eq!(scoped.divergences, vec![security]);
+        assert_eq!(scoped.risk_tier_counts.get(&RiskTier::Info), Some(&1));
+    }
+
+    #[test]
+    fn filter_scope_verdict_can_differ_from_global_verdict() {
+        let blocking_memory =
+            divergence_for_checklist("div-blocking", RiskTier::Critical, &["rt-a", "rt-b"]);
+        let mut blocking_memory = blocking_memory;
+        blocking_memory.boundary_scope = BoundaryScope::Memory;
+        let clean_security = divergence_for_checklist("div-clean", RiskTier::Info, &["rt-a"]);
+
+        let mut report = report_with_divergences(vec![blocking_memory, clean_security]);
+        report.verdict = OracleVerdict::BlockRelease {
+            blocking_divergence_ids: vec!["div-blocking".to_string()],
+        };
+
+        let scoped = report.filter_scope(BoundaryScope::Security);
+
+        assert_eq!(scoped.verdict, OracleVerdict::Pass);
+        assert_ne!(scoped.verdict, report.verdict);
+    }
+
+    #[test]
+    fn merge_rejects_empty_shard_list() {
+        let err = DivergenceReport::merge(Vec::new()).unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_NO_SHARDS);
+    }
+
+    #[test]
+    fn merge_unions_overlapping_info_divergences_and_blocks_on_high_risk() {
+        // Shard 1 and shard 2 both happened to observe the same info-level
+        // divergence (e.g. two workers covering overlapping checks); shard 3
+        // is the only one that saw the high-risk divergence.
+        let info = divergence_for_checklist("div-info-shared", RiskTier::Info, &["rt-a"]);
+        let high = divergence_for_checklist("div-high", RiskTier::High, &["rt-a", "rt-b"]);
+
+        let shard1 = report_with_divergences(vec![info.clone()]);
+        let shard2 = report_with_divergences(vec![info.clone()]);
+        let shard3 = report_with_divergences(vec![high.clone()]);
+
+        let merged = DivergenceReport::merge(vec![shard1, shard2, shard3]).unwrap();
+
+        assert_eq!(merged.divergences, vec![high.clone(), info]);
+        assert_eq!(merged.risk_tier_counts.get(&RiskTier::Info), Some(&1));
+        assert_eq!(merged.risk_tier_counts.get(&RiskTier::High), Some(&1));
+        assert_eq!(
+            merged.verdict,
+            OracleVerdict::BlockRelease {
+                blocking_divergence_ids: vec!["div-high".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn merge_rejects_shards_disagreeing_on_the_same_divergence_id() {
+        let original = divergence_for_checklist("div-disputed", RiskTier::Info, &["rt-a"]);
+        let mut mutated = original.clone();
+        mutated.risk_tier = RiskTier::Critical;
+
+        let shard1 = report_with_divergences(vec![original]);
+        let shard2 = report_with_divergences(vec![mutated]);
+
+        let err = DivergenceReport::merge(vec![shard1, shard2]).unwrap_err();
+        assert_eq!(err.code, error_codes::ERR_NVO_SHARD_CONFLICT);
+    }
 }
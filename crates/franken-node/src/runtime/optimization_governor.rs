@@ -27,6 +27,12 @@
 //! - `GOV_005` -- Proposal auto-reverted
 //! - `GOV_006` -- Safety envelope updated
 //! - `GOV_007` -- Governor state snapshot emitted
+//! - `GOV_008` -- Safety-envelope update proposed, pending approval
+//! - `GOV_009` -- Safety-envelope update approval rejected (insufficient approval level)
+//! - `GOV_010` -- A/B test started
+//! - `GOV_011` -- A/B test concluded
+//! - `GOV_012` -- Rail-placement proposal approved and applied through the isolation mesh
+//! - `GOV_013` -- Rail-placement proposal rejected
 //!
 //! # Error Codes
 //!
@@ -36,8 +42,15 @@
 //! - `ERR_GOV_REVERT_FAILED` -- Auto-revert of a previously applied proposal failed
 //! - `ERR_GOV_SHADOW_TIMEOUT` -- Shadow evaluation exceeded its time budget
 //! - `ERR_GOV_INVALID_PROPOSAL` -- Invalid or inconsistent proposal fields
+//! - `ERR_GOV_AB_INVALID_SPLIT` -- A/B test split fraction is out of range
+//! - `ERR_GOV_AB_EMPTY_CONFIG` -- A/B test candidate configuration has no knobs
+//! - `ERR_GOV_AB_ALREADY_ACTIVE` -- An A/B test is already running
+//! - `ERR_GOV_AB_NO_ACTIVE_TEST` -- `conclude_ab_test` called with no test running
+//! - `ERR_GOV_MESH_REJECTED` -- Isolation mesh rejected a rail-placement proposal
 
+use crate::runtime::isolation_mesh::IsolationMesh;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
 use std::fmt;
 
@@ -62,6 +75,26 @@ fn sanitize_log_field(value: &str) -> String {
         .collect()
 }
 
+/// Percentage of a ceiling bound `cap` that `value` consumes, clamped to
+/// `0.0..=100.0`. A non-positive `cap` is treated as already fully consumed
+/// (`100.0`) since there is no valid headroom to measure against.
+fn ceiling_proximity_pct(value: f64, cap: f64) -> f64 {
+    if cap <= 0.0 {
+        return 100.0;
+    }
+    (value / cap * 100.0).clamp(0.0, 100.0)
+}
+
+/// Percentage of the distance down to a floor bound `floor` that `value` has
+/// closed, clamped to `0.0..=100.0`. A non-positive `floor` means there is no
+/// floor to approach (`0.0`).
+fn floor_proximity_pct(value: f64, floor: f64) -> f64 {
+    if floor <= 0.0 {
+        return 0.0;
+    }
+    (floor / value.max(f64::EPSILON) * 100.0).clamp(0.0, 100.0)
+}
+
 // ---------------------------------------------------------------------------
 // Schema version
 // ---------------------------------------------------------------------------
@@ -88,6 +121,18 @@ pub mod event_codes {
     pub const GOV_006: &str = "GOV_006";
     /// GOV_007: Governor state snapshot emitted.
     pub const GOV_007: &str = "GOV_007";
+    /// GOV_008: Safety-envelope update proposed, pending approval.
+    pub const GOV_008: &str = "GOV_008";
+    /// GOV_009: Safety-envelope update approval rejected (insufficient approval level).
+    pub const GOV_009: &str = "GOV_009";
+    /// GOV_010: A/B test started.
+    pub const GOV_010: &str = "GOV_010";
+    /// GOV_011: A/B test concluded.
+    pub const GOV_011: &str = "GOV_011";
+    /// GOV_012: Rail-placement proposal approved and applied through the isolation mesh.
+    pub const GOV_012: &str = "GOV_012";
+    /// GOV_013: Rail-placement proposal rejected.
+    pub const GOV_013: &str = "GOV_013";
 
     // bd-21fo canonical event codes
     /// GOVERNOR_CANDIDATE_PROPOSED: a candidate optimization was submitted.
@@ -119,6 +164,12 @@ pub mod error_codes {
     pub const ERR_GOV_SHADOW_TIMEOUT: &str = "ERR_GOV_SHADOW_TIMEOUT";
     /// ERR_GOV_INVALID_PROPOSAL: Invalid or inconsistent proposal fields.
     pub const ERR_GOV_INVALID_PROPOSAL: &str = "ERR_GOV_INVALID_PROPOSAL";
+    /// ERR_GOV_KNOB_COOLDOWN: Knob was touched too recently; still in its
+    /// cooldown window.
+    pub const ERR_GOV_KNOB_COOLDOWN: &str = "ERR_GOV_KNOB_COOLDOWN";
+    /// ERR_GOV_RISK_BUDGET_EXCEEDED: Approving this proposal would exceed
+    /// the configured per-window risk budget.
+    pub const ERR_GOV_RISK_BUDGET_EXCEEDED: &str = "ERR_GOV_RISK_BUDGET_EXCEEDED";
 
     // bd-21fo canonical error codes
     /// ERR_GOVERNOR_UNSAFE_CANDIDATE: candidate optimization breaches safety envelope.
@@ -134,6 +185,25 @@ pub mod error_codes {
     pub const ERR_GOVERNOR_REVERT_FAILED: &str = "ERR_GOVERNOR_REVERT_FAILED";
     /// ERR_GOVERNOR_KNOB_READONLY: target knob is read-only or locked.
     pub const ERR_GOVERNOR_KNOB_READONLY: &str = "ERR_GOVERNOR_KNOB_READONLY";
+    /// ERR_GOV_LEDGER_SEQUENCE_GAP: ledger entries are not strictly ordered
+    /// by sequence number.
+    pub const ERR_GOV_LEDGER_SEQUENCE_GAP: &str = "ERR_GOV_LEDGER_SEQUENCE_GAP";
+    /// ERR_GOV_LEDGER_CHAIN_BROKEN: an entry's `prev_hash` does not match
+    /// the previous entry's `entry_hash`.
+    pub const ERR_GOV_LEDGER_CHAIN_BROKEN: &str = "ERR_GOV_LEDGER_CHAIN_BROKEN";
+    /// ERR_GOV_LEDGER_ENTRY_TAMPERED: an entry's recomputed hash does not
+    /// match its stored `entry_hash`.
+    pub const ERR_GOV_LEDGER_ENTRY_TAMPERED: &str = "ERR_GOV_LEDGER_ENTRY_TAMPERED";
+    /// ERR_GOV_AB_INVALID_SPLIT: A/B test split fraction is out of range.
+    pub const ERR_GOV_AB_INVALID_SPLIT: &str = "ERR_GOV_AB_INVALID_SPLIT";
+    /// ERR_GOV_AB_EMPTY_CONFIG: A/B test candidate configuration has no knobs.
+    pub const ERR_GOV_AB_EMPTY_CONFIG: &str = "ERR_GOV_AB_EMPTY_CONFIG";
+    /// ERR_GOV_AB_ALREADY_ACTIVE: an A/B test is already running.
+    pub const ERR_GOV_AB_ALREADY_ACTIVE: &str = "ERR_GOV_AB_ALREADY_ACTIVE";
+    /// ERR_GOV_AB_NO_ACTIVE_TEST: no A/B test is currently running.
+    pub const ERR_GOV_AB_NO_ACTIVE_TEST: &str = "ERR_GOV_AB_NO_ACTIVE_TEST";
+    /// ERR_GOV_MESH_REJECTED: isolation mesh rejected a rail-placement proposal.
+    pub const ERR_GOV_MESH_REJECTED: &str = "ERR_GOV_MESH_REJECTED";
 }
 
 // ---------------------------------------------------------------------------
@@ -297,6 +367,27 @@ impl SafetyEnvelope {
         vs
     }
 
+    /// How many risk points (`0..=100`) a proposal's predicted metrics would
+    /// consume against this envelope, as the worst-case (highest) proximity
+    /// to any bound. A proposal predicted to land exactly on a bound scores
+    /// `100`; one with comfortable headroom on every metric scores near `0`.
+    /// Used by [`OptimizationGovernor`] to enforce a [`RiskBudget`].
+    pub fn risk_points(&self, metrics: &PredictedMetrics) -> u32 {
+        let latency_pct =
+            ceiling_proximity_pct(metrics.latency_ms as f64, self.max_latency_ms as f64);
+        let throughput_pct = floor_proximity_pct(
+            metrics.throughput_rps as f64,
+            self.min_throughput_rps as f64,
+        );
+        let error_pct = ceiling_proximity_pct(metrics.error_rate_pct, self.max_error_rate_pct);
+        let memory_pct = ceiling_proximity_pct(metrics.memory_mb as f64, self.max_memory_mb as f64);
+
+        let worst_pct = [latency_pct, throughput_pct, error_pct, memory_pct]
+            .into_iter()
+            .fold(0.0_f64, f64::max);
+        worst_pct.round().clamp(0.0, 100.0) as u32
+    }
+
     /// Validate the envelope itself (all bounds are reasonable).
     pub fn is_valid(&self) -> bool {
         self.max_latency_ms > 0
@@ -317,6 +408,19 @@ impl Default for SafetyEnvelope {
     }
 }
 
+/// Caps the total [`SafetyEnvelope::risk_points`] that approved proposals
+/// may consume within a rolling time window, so a flurry of individually
+/// borderline-approved changes can't compound into something the envelope
+/// alone would not have caught one at a time. Enforced by
+/// [`OptimizationGovernor::submit_at`] via [`OptimizationGovernor::set_risk_budget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskBudget {
+    /// Maximum total risk points approved proposals may consume per window.
+    pub per_window: u32,
+    /// Window length in milliseconds.
+    pub window_ms: u64,
+}
+
 /// Predicted metrics for a proposal after shadow evaluation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredictedMetrics {
@@ -326,6 +430,106 @@ pub struct PredictedMetrics {
     pub memory_mb: u64,
 }
 
+/// Whether `predicted` strictly improves on `baseline` in at least one
+/// metric (lower latency, higher throughput, lower error rate, or lower
+/// memory). Used to reject pure no-op proposals (INV-GOV-EVIDENCE-ON-REJECT
+/// via `RejectionReason::NonBeneficial`) while still approving proposals
+/// that trade one metric for another as long as something gets better.
+fn metrics_improved(baseline: &PredictedMetrics, predicted: &PredictedMetrics) -> bool {
+    predicted.latency_ms < baseline.latency_ms
+        || predicted.throughput_rps > baseline.throughput_rps
+        || predicted.error_rate_pct < baseline.error_rate_pct
+        || predicted.memory_mb < baseline.memory_mb
+}
+
+/// Who or what submitted an [`OptimizationProposal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalSource {
+    /// Submitted directly by a human operator.
+    Human,
+    /// Submitted by the automated tuning loop.
+    Autotuner,
+    /// Submitted by a standing policy (e.g. a scheduled rebalance rule).
+    Policy,
+}
+
+impl Default for ProposalSource {
+    fn default() -> Self {
+        Self::Human
+    }
+}
+
+impl ProposalSource {
+    /// Relative priority used to arbitrate against a [`KnobLock`]: a human
+    /// operator outranks a standing policy, which outranks the autotuner.
+    /// Higher wins. Compared strictly against [`KnobLock::priority`] in
+    /// [`OptimizationGovernor::submit_at`], so a proposal from the same
+    /// priority tier as the lock's owner still gets blocked.
+    pub fn priority_rank(&self) -> u32 {
+        match self {
+            Self::Human => 100,
+            Self::Policy => 50,
+            Self::Autotuner => 10,
+        }
+    }
+}
+
+/// A change that the governor drives through another subsystem rather than
+/// adjusting a governor-owned [`RuntimeKnob`] directly. The target
+/// subsystem enforces its own safety rules and is the system of record for
+/// the change once applied -- the governor's role is to gate it against its
+/// own [`SafetyEnvelope`] first, and to keep a record of who asked for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KnobChange {
+    /// Move `workload_id` onto `target_rail` in the isolation mesh, subject
+    /// to [`crate::runtime::isolation_mesh::IsolationMesh::elevate_workload`]'s
+    /// own monotonicity, latency, and capacity rules.
+    RailPlacement {
+        workload_id: String,
+        target_rail: String,
+    },
+}
+
+/// A proposal that, when approved, drives a [`KnobChange`] through another
+/// subsystem via [`OptimizationGovernor::submit_rail_placement`]. Kept
+/// separate from [`OptimizationProposal`] because it is not scoped to a
+/// [`RuntimeKnob`] the governor owns outright.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RailPlacementProposal {
+    /// Unique identifier for this proposal.
+    pub proposal_id: String,
+    /// The change to drive through the target subsystem.
+    pub change: KnobChange,
+    /// Predicted metrics after the change, checked against the governor's
+    /// [`SafetyEnvelope`] before the change is ever sent to the target
+    /// subsystem.
+    pub predicted: PredictedMetrics,
+    /// Human-readable rationale.
+    pub rationale: String,
+    /// Correlation ID for distributed tracing.
+    pub trace_id: String,
+    /// Principal that submitted this proposal.
+    pub submitted_by: String,
+    /// Where the proposal originated.
+    pub source: ProposalSource,
+}
+
+impl RailPlacementProposal {
+    /// Basic structural validation, mirroring [`OptimizationProposal::is_valid`].
+    pub fn is_valid(&self) -> bool {
+        !self.proposal_id.is_empty()
+            && !self.trace_id.is_empty()
+            && !self.submitted_by.is_empty()
+            && !has_control_chars(&self.proposal_id)
+            && !has_control_chars(&self.trace_id)
+            && !has_control_chars(&self.submitted_by)
+            && self.predicted.error_rate_pct.is_finite()
+            && (0.0..=100.0).contains(&self.predicted.error_rate_pct)
+    }
+}
+
 /// An optimization proposal that the governor evaluates.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OptimizationProposal {
@@ -343,6 +547,14 @@ pub struct OptimizationProposal {
     pub rationale: String,
     /// Correlation ID for distributed tracing.
     pub trace_id: String,
+    /// Principal that submitted this proposal (e.g. a username or autotuner
+    /// instance id). Immutable once submitted: [`OptimizationGovernor::submit`]
+    /// rejects a re-submission under the same `proposal_id` while it is still
+    /// applied, so provenance can't be rewritten after the fact.
+    pub submitted_by: String,
+    /// Where the proposal originated, carried through to revert evidence so
+    /// alerts can route back to the source.
+    pub source: ProposalSource,
 }
 
 impl OptimizationProposal {
@@ -350,8 +562,10 @@ impl OptimizationProposal {
     pub fn is_valid(&self) -> bool {
         !self.proposal_id.is_empty()
             && !self.trace_id.is_empty()
+            && !self.submitted_by.is_empty()
             && !has_control_chars(&self.proposal_id)
             && !has_control_chars(&self.trace_id)
+            && !has_control_chars(&self.submitted_by)
             && self.predicted.error_rate_pct.is_finite()
             && (0.0..=100.0).contains(&self.predicted.error_rate_pct)
     }
@@ -369,6 +583,16 @@ pub enum RejectionReason {
     KnobLocked,
     /// Proposal has invalid fields.
     InvalidProposal(String),
+    /// Target knob was applied or reverted too recently and is still within
+    /// its cooldown window. Carries a human-readable evidence message.
+    KnobCooldown(String),
+    /// Approving this proposal would exceed the configured [`RiskBudget`]
+    /// for the current window. Carries a human-readable evidence message.
+    RiskBudgetExceeded(String),
+    /// A [`KnobChange`] was rejected by the subsystem it targets (e.g. the
+    /// isolation mesh's own monotonicity, latency, or capacity rules).
+    /// Carries that subsystem's rejection detail as evidence.
+    MeshRejected(String),
 }
 
 impl RejectionReason {
@@ -379,6 +603,9 @@ impl RejectionReason {
             Self::NonBeneficial => error_codes::ERR_GOV_NON_BENEFICIAL,
             Self::KnobLocked => error_codes::ERR_GOV_KNOB_LOCKED,
             Self::InvalidProposal(_) => error_codes::ERR_GOV_INVALID_PROPOSAL,
+            Self::KnobCooldown(_) => error_codes::ERR_GOV_KNOB_COOLDOWN,
+            Self::MeshRejected(_) => error_codes::ERR_GOV_MESH_REJECTED,
+            Self::RiskBudgetExceeded(_) => error_codes::ERR_GOV_RISK_BUDGET_EXCEEDED,
         }
     }
 }
@@ -425,6 +652,15 @@ pub struct ShadowResult {
     pub is_beneficial: bool,
 }
 
+/// Result of [`OptimizationGovernor::simulate_proposal`]: the decision the
+/// proposal would receive from [`OptimizationGovernor::submit`], computed
+/// with zero side effects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub proposal_id: String,
+    pub decision: GovernorDecision,
+}
+
 /// Current live value for a knob, used to check benefit.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KnobState {
@@ -433,6 +669,141 @@ pub struct KnobState {
     pub locked: bool,
 }
 
+/// A hold placed on a [`RuntimeKnob`] by [`OptimizationGovernor::lock_knob`],
+/// blocking further proposals against that knob unless their
+/// [`ProposalSource::priority_rank`] strictly exceeds `priority`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnobLock {
+    /// Priority tier of the policy holding the lock. A proposal is only
+    /// allowed to pre-empt it if its own priority is strictly greater.
+    pub priority: u32,
+    /// Identifier of the policy or operator that placed the lock.
+    pub owner: String,
+}
+
+/// Per-metric weights used to score a candidate's observed metrics during an
+/// [`OptimizationGovernor`] A/B test. Higher throughput is rewarded; higher
+/// latency, error rate, and memory are penalized.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveWeights {
+    pub latency_weight: f64,
+    pub throughput_weight: f64,
+    pub error_rate_weight: f64,
+    pub memory_weight: f64,
+}
+
+impl Default for ObjectiveWeights {
+    fn default() -> Self {
+        Self {
+            latency_weight: 1.0,
+            throughput_weight: 1.0,
+            error_rate_weight: 1.0,
+            memory_weight: 1.0,
+        }
+    }
+}
+
+impl ObjectiveWeights {
+    /// Weighted objective score for a set of observed metrics: higher is
+    /// better. Throughput contributes positively; latency, error rate, and
+    /// memory contribute negatively, each scaled by its configured weight.
+    pub fn score(&self, metrics: &PredictedMetrics) -> f64 {
+        self.throughput_weight * metrics.throughput_rps as f64
+            - self.latency_weight * metrics.latency_ms as f64
+            - self.error_rate_weight * metrics.error_rate_pct
+            - self.memory_weight * metrics.memory_mb as f64
+    }
+}
+
+/// A runtime-knob configuration: the value each knob would take under a
+/// candidate policy. Keyed by [`RuntimeKnob`] so a config need only mention
+/// the knobs it changes relative to the governor's current state.
+pub type KnobConfig = BTreeMap<RuntimeKnob, u64>;
+
+/// An A/B test in progress, started by [`OptimizationGovernor::begin_ab_test`]
+/// and resolved by [`OptimizationGovernor::conclude_ab_test`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbTest {
+    pub test_id: String,
+    pub config_a: KnobConfig,
+    pub config_b: KnobConfig,
+    /// Fraction of traffic routed to `config_b`, in `(0.0, 1.0)`.
+    pub split: f64,
+}
+
+/// Which candidate configuration an A/B test declared the winner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbWinner {
+    A,
+    B,
+}
+
+/// Outcome of [`OptimizationGovernor::conclude_ab_test`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbResult {
+    pub test_id: String,
+    /// The declared winner. When `conclusive` is `false` this is always `A`,
+    /// per the "inconclusive, keep A" default.
+    pub winner: AbWinner,
+    /// Whether the score difference exceeded the configured significance
+    /// margin.
+    pub conclusive: bool,
+    pub score_a: f64,
+    pub score_b: f64,
+    /// `|score_b - score_a|`.
+    pub margin: f64,
+    /// Per-knob decisions made while applying the winning configuration
+    /// through the normal envelope-checked [`OptimizationGovernor::submit`]
+    /// path. Empty when the winner's config has no knob that differs from
+    /// the governor's current state.
+    pub decisions: Vec<GovernorDecision>,
+    /// Whether every decision in `decisions` was [`GovernorDecision::Approved`].
+    /// `false` means the winner was rejected despite winning (e.g. it would
+    /// breach the safety envelope).
+    pub applied: bool,
+}
+
+/// Errors returned by [`OptimizationGovernor::begin_ab_test`] and
+/// [`OptimizationGovernor::conclude_ab_test`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbTestError {
+    /// `split` is not a valid traffic fraction in `(0.0, 1.0)`.
+    InvalidSplit(String),
+    /// `config_a` and/or `config_b` assign no knobs.
+    EmptyConfig(String),
+    /// A test is already running; conclude it before starting another.
+    AlreadyActive(String),
+    /// `conclude_ab_test` was called with no test in progress.
+    NoActiveTest(String),
+}
+
+impl AbTestError {
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidSplit(_) => error_codes::ERR_GOV_AB_INVALID_SPLIT,
+            Self::EmptyConfig(_) => error_codes::ERR_GOV_AB_EMPTY_CONFIG,
+            Self::AlreadyActive(_) => error_codes::ERR_GOV_AB_ALREADY_ACTIVE,
+            Self::NoActiveTest(_) => error_codes::ERR_GOV_AB_NO_ACTIVE_TEST,
+        }
+    }
+}
+
+impl fmt::Display for AbTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSplit(detail)
+            | Self::EmptyConfig(detail)
+            | Self::AlreadyActive(detail)
+            | Self::NoActiveTest(detail) => write!(f, "{}: {detail}", self.code()),
+        }
+    }
+}
+
+impl std::error::Error for AbTestError {}
+
 /// The self-evolving optimization governor.
 ///
 /// Maintains a [`SafetyEnvelope`], current knob states, a decision log, and
@@ -443,6 +814,10 @@ pub struct OptimizationGovernor {
     envelope: SafetyEnvelope,
     /// Current knob states keyed by knob (BTreeMap for deterministic ordering).
     knob_states: BTreeMap<RuntimeKnob, KnobState>,
+    /// Locks held against knobs by [`Self::lock_knob`], keyed by knob
+    /// (BTreeMap for deterministic ordering and snapshot iteration).
+    #[serde(default)]
+    knob_locks: BTreeMap<RuntimeKnob, KnobLock>,
     /// Decision log, totally ordered by seq.
     decision_log: Vec<DecisionRecord>,
     /// Currently applied proposals keyed by proposal_id, holding the old
@@ -452,6 +827,62 @@ pub struct OptimizationGovernor {
     next_seq: u64,
     /// Schema version.
     schema_version: String,
+    /// Hysteresis settings for `live_check` auto-revert.
+    #[serde(default)]
+    hysteresis: HysteresisConfig,
+    /// Minimum time, in milliseconds, that must elapse after a knob is
+    /// applied or reverted before another proposal targeting it is
+    /// accepted. `0` disables cooldown enforcement.
+    #[serde(default)]
+    knob_cooldown_ms: u64,
+    /// Timestamp, in milliseconds, each knob was last applied or reverted
+    /// at, as supplied by the caller of [`Self::submit_at`] /
+    /// [`Self::live_check_at`].
+    #[serde(default)]
+    knob_last_touched_ms: BTreeMap<RuntimeKnob, u64>,
+    /// Envelope-update proposals awaiting [`OptimizationGovernor::approve_envelope_update`],
+    /// keyed by proposal_id.
+    #[serde(default)]
+    pending_envelope_proposals: BTreeMap<String, EnvelopeProposal>,
+    /// Ledger of envelope-update approval decisions, separate from the
+    /// per-knob `decision_log` because envelope changes are not scoped to a
+    /// single [`RuntimeKnob`].
+    #[serde(default)]
+    envelope_decisions: Vec<EnvelopeDecisionRecord>,
+    /// Ledger of [`RailPlacementProposal`] decisions, separate from the
+    /// per-knob `decision_log` because a rail placement is not scoped to a
+    /// single [`RuntimeKnob`].
+    #[serde(default)]
+    mesh_decisions: Vec<MeshDecisionRecord>,
+    /// Per-window cap on cumulative [`SafetyEnvelope::risk_points`] spent by
+    /// approved proposals, set via [`Self::set_risk_budget`]. `None`
+    /// disables risk-budget enforcement.
+    #[serde(default)]
+    risk_budget: Option<RiskBudget>,
+    /// Start timestamp (caller-supplied `now_ms`) of the current risk-budget
+    /// window, as last rolled by [`Self::submit_at`].
+    #[serde(default)]
+    risk_window_start_ms: Option<u64>,
+    /// Risk points already spent by approved proposals within the current
+    /// risk-budget window.
+    #[serde(default)]
+    risk_spent_in_window: u32,
+    /// Objective weights used to score observed metrics in
+    /// [`Self::conclude_ab_test`], set via [`Self::set_objective_weights`].
+    #[serde(default)]
+    objective_weights: ObjectiveWeights,
+    /// Minimum `|score_b - score_a|` required for [`Self::conclude_ab_test`]
+    /// to declare a winner rather than "inconclusive, keep A", set via
+    /// [`Self::set_ab_significance_margin`].
+    #[serde(default)]
+    ab_significance_margin: f64,
+    /// The currently running A/B test, if any, set by [`Self::begin_ab_test`]
+    /// and cleared by [`Self::conclude_ab_test`].
+    #[serde(default)]
+    active_ab_test: Option<AbTest>,
+    /// Monotonic counter used to generate A/B test ids.
+    #[serde(default)]
+    ab_test_counter: u64,
 }
 
 /// Tracks an applied proposal so we can auto-revert it.
@@ -463,6 +894,177 @@ struct AppliedProposal {
     pub old_value: u64,
     pub new_value: u64,
     pub trace_id: String,
+    /// Provenance carried forward from the originating [`OptimizationProposal`],
+    /// echoed into revert evidence so alerts can route back to the source.
+    #[serde(default)]
+    pub submitted_by: String,
+    #[serde(default)]
+    pub source: ProposalSource,
+    /// Predicted metrics that were shadow-evaluated for this proposal,
+    /// retained as the improvement baseline for the next proposal submitted
+    /// against the same knob (see [`OptimizationGovernor::shadow_evaluate`]).
+    /// `None` for state restored from a schema version that predates this
+    /// field, in which case no baseline is known and benefit falls back to
+    /// the plain old-value/new-value comparison.
+    #[serde(default)]
+    pub predicted: Option<PredictedMetrics>,
+    /// Consecutive `live_check` calls in a row that found this proposal's
+    /// metrics out of envelope. Reset to 0 once `consecutive_ok_checks`
+    /// reaches the governor's `hysteresis.recovery_threshold`.
+    #[serde(default)]
+    pub consecutive_breach_checks: u32,
+    /// Consecutive `live_check` calls in a row that found metrics back
+    /// within envelope. Reset to 0 on any breach.
+    #[serde(default)]
+    pub consecutive_ok_checks: u32,
+}
+
+/// Hysteresis configuration for [`OptimizationGovernor::live_check`], to
+/// prevent a metric oscillating around an envelope bound from causing
+/// repeated apply/revert churn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HysteresisConfig {
+    /// Number of consecutive out-of-envelope checks required before an
+    /// applied proposal is auto-reverted. `1` matches the original
+    /// immediate-revert behavior.
+    pub breach_threshold: u32,
+    /// Number of consecutive in-envelope checks required to clear a partial
+    /// breach streak back to zero.
+    pub recovery_threshold: u32,
+}
+
+impl Default for HysteresisConfig {
+    /// Immediate revert on the first breach, immediate reset on the first
+    /// in-envelope check -- i.e. no hysteresis, matching the governor's
+    /// original behavior before this setting existed.
+    fn default() -> Self {
+        Self {
+            breach_threshold: 1,
+            recovery_threshold: 1,
+        }
+    }
+}
+
+/// Whether a proposed [`SafetyEnvelope`] change tightens or loosens the
+/// current bounds, as classified by [`OptimizationGovernor::propose_envelope_update`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvelopeChangeKind {
+    /// Every bound held steady or moved in the stricter direction.
+    Tightening,
+    /// At least one bound moved in the more permissive direction (higher
+    /// latency/memory/error-rate ceilings, or a lower throughput floor).
+    Loosening,
+}
+
+/// Minimum approver authority required to approve an [`EnvelopeProposal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalLevel {
+    /// Sufficient to approve a tightening.
+    Standard,
+    /// Required to approve a loosening -- widening a safety bound is itself
+    /// a risky change and needs sign-off from someone with elevated authority.
+    Elevated,
+}
+
+/// A proposed change to the governor's [`SafetyEnvelope`], pending approval
+/// via [`OptimizationGovernor::approve_envelope_update`]. Loosening the
+/// envelope does not take effect merely by being proposed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvelopeProposal {
+    /// Unique identifier for this proposal.
+    pub proposal_id: String,
+    /// The envelope in effect at the time this proposal was made.
+    pub current_envelope: SafetyEnvelope,
+    /// The envelope that would take effect if approved.
+    pub proposed_envelope: SafetyEnvelope,
+    /// Whether the change tightens or loosens the current bounds.
+    pub change_kind: EnvelopeChangeKind,
+    /// The minimum [`ApprovalLevel`] that [`OptimizationGovernor::approve_envelope_update`]
+    /// will accept for this proposal.
+    pub required_level: ApprovalLevel,
+    /// Human-readable rationale for the change.
+    pub justification: String,
+    /// Correlation ID for distributed tracing.
+    pub trace_id: String,
+}
+
+/// Outcome of [`OptimizationGovernor::approve_envelope_update`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvelopeApprovalOutcome {
+    /// The approver met the proposal's required level; the envelope was
+    /// updated and the proposal removed from the pending set.
+    Applied,
+    /// No pending proposal exists with that ID.
+    UnknownProposal,
+    /// The approver's level was below the proposal's `required_level`. The
+    /// proposal remains pending and can be retried by a sufficiently senior
+    /// approver.
+    InsufficientApprovalLevel,
+}
+
+/// An immutable record of an envelope-update approval decision, recorded
+/// alongside (but separately from) per-knob [`DecisionRecord`]s since an
+/// envelope change is never scoped to a single [`RuntimeKnob`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvelopeDecisionRecord {
+    /// Monotonically increasing sequence number, sharing the governor's
+    /// counter with the per-knob decision log.
+    pub seq: u64,
+    /// Proposal that was decided on.
+    pub proposal_id: String,
+    pub change_kind: EnvelopeChangeKind,
+    pub required_level: ApprovalLevel,
+    /// The level the approver actually presented.
+    pub approver_level: ApprovalLevel,
+    pub outcome: EnvelopeApprovalOutcome,
+    /// The event code emitted.
+    pub event_code: String,
+    /// Trace correlation ID.
+    pub trace_id: String,
+}
+
+/// An immutable record of a [`RailPlacementProposal`] decision, recorded
+/// alongside (but separately from) the per-knob [`DecisionRecord`] log since
+/// a rail placement is never scoped to a single [`RuntimeKnob`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeshDecisionRecord {
+    /// Monotonically increasing sequence number, sharing the governor's
+    /// counter with the per-knob decision log.
+    pub seq: u64,
+    /// Proposal that was decided on.
+    pub proposal_id: String,
+    /// The change that was requested.
+    pub change: KnobChange,
+    /// The decision.
+    pub decision: GovernorDecision,
+    /// The event code emitted.
+    pub event_code: String,
+    /// Trace correlation ID.
+    pub trace_id: String,
+    /// Evidence detail for rejections.
+    pub evidence: Option<String>,
+}
+
+/// Classify whether `proposed` tightens or loosens `current`. Any bound that
+/// moves in the permissive direction classifies the whole change as a
+/// [`EnvelopeChangeKind::Loosening`], even if other bounds tighten at the
+/// same time -- partial loosening is still loosening.
+fn classify_envelope_change(
+    current: &SafetyEnvelope,
+    proposed: &SafetyEnvelope,
+) -> EnvelopeChangeKind {
+    let any_loosened = proposed.max_latency_ms > current.max_latency_ms
+        || proposed.min_throughput_rps < current.min_throughput_rps
+        || proposed.max_error_rate_pct > current.max_error_rate_pct
+        || proposed.max_memory_mb > current.max_memory_mb;
+    if any_loosened {
+        EnvelopeChangeKind::Loosening
+    } else {
+        EnvelopeChangeKind::Tightening
+    }
 }
 
 impl OptimizationGovernor {
@@ -471,13 +1073,59 @@ impl OptimizationGovernor {
         Self {
             envelope,
             knob_states,
+            knob_locks: BTreeMap::new(),
             decision_log: Vec::new(),
             applied: BTreeMap::new(),
             next_seq: 1,
             schema_version: SCHEMA_VERSION.to_string(),
+            hysteresis: HysteresisConfig::default(),
+            knob_cooldown_ms: 0,
+            knob_last_touched_ms: BTreeMap::new(),
+            pending_envelope_proposals: BTreeMap::new(),
+            envelope_decisions: Vec::new(),
+            mesh_decisions: Vec::new(),
+            risk_budget: None,
+            risk_window_start_ms: None,
+            risk_spent_in_window: 0,
+            objective_weights: ObjectiveWeights::default(),
+            ab_significance_margin: 0.0,
+            active_ab_test: None,
+            ab_test_counter: 0,
         }
     }
 
+    /// Set the hysteresis thresholds used by [`Self::live_check`].
+    pub fn set_hysteresis(&mut self, hysteresis: HysteresisConfig) {
+        self.hysteresis = hysteresis;
+    }
+
+    /// Set the per-knob cooldown window used by [`Self::submit_at`] and
+    /// [`Self::live_check_at`]. `0` disables cooldown enforcement.
+    pub fn set_knob_cooldown(&mut self, knob_cooldown_ms: u64) {
+        self.knob_cooldown_ms = knob_cooldown_ms;
+    }
+
+    /// Set the risk budget enforced by [`Self::submit_at`]. Replacing the
+    /// budget does not reset the current window's spend; the next
+    /// `submit_at` call still rolls the window over once `window_ms` has
+    /// elapsed since it started.
+    pub fn set_risk_budget(&mut self, risk_budget: RiskBudget) {
+        self.risk_budget = Some(risk_budget);
+    }
+
+    /// Set the objective weights used to score observed metrics in
+    /// [`Self::conclude_ab_test`].
+    pub fn set_objective_weights(&mut self, objective_weights: ObjectiveWeights) {
+        self.objective_weights = objective_weights;
+    }
+
+    /// Set the minimum `|score_b - score_a|` required for
+    /// [`Self::conclude_ab_test`] to declare a winner. Below this margin the
+    /// result is "inconclusive, keep A".
+    pub fn set_ab_significance_margin(&mut self, margin: f64) {
+        self.ab_significance_margin = margin;
+    }
+
     /// Create a governor with default envelope and default knob states.
     pub fn with_defaults() -> Self {
         let mut knob_states = BTreeMap::new();
@@ -512,6 +1160,167 @@ impl OptimizationGovernor {
         // GOV_006 emitted (structurally logged by caller)
     }
 
+    /// Propose a change to the safety envelope. The change does not take
+    /// effect until [`Self::approve_envelope_update`] is called with a
+    /// sufficient [`ApprovalLevel`]; loosening any bound (widening the
+    /// envelope) requires [`ApprovalLevel::Elevated`], while a tightening
+    /// only requires [`ApprovalLevel::Standard`]. Emits GOV_008.
+    pub fn propose_envelope_update(
+        &mut self,
+        new_envelope: SafetyEnvelope,
+        justification: &str,
+        trace_id: &str,
+    ) -> EnvelopeProposal {
+        let change_kind = classify_envelope_change(&self.envelope, &new_envelope);
+        let required_level = match change_kind {
+            EnvelopeChangeKind::Tightening => ApprovalLevel::Standard,
+            EnvelopeChangeKind::Loosening => ApprovalLevel::Elevated,
+        };
+        let proposal_id = format!("envelope-proposal-{}", self.next_seq);
+        self.next_seq = self.next_seq.saturating_add(1);
+
+        let proposal = EnvelopeProposal {
+            proposal_id: proposal_id.clone(),
+            current_envelope: self.envelope.clone(),
+            proposed_envelope: new_envelope,
+            change_kind,
+            required_level,
+            justification: sanitize_log_field(justification),
+            trace_id: sanitize_log_field(trace_id),
+        };
+        self.pending_envelope_proposals
+            .insert(proposal_id, proposal.clone());
+        proposal
+    }
+
+    /// Approve (or attempt to approve) a pending [`EnvelopeProposal`].
+    ///
+    /// If `approver` meets the proposal's `required_level`, the envelope is
+    /// updated in place, the proposal is removed from the pending set, and
+    /// GOV_006 is recorded. If `approver` is below the required level, the
+    /// proposal remains pending (a more senior approver may retry it) and
+    /// GOV_009 is recorded instead. Either outcome is appended to
+    /// [`Self::envelope_decisions`].
+    pub fn approve_envelope_update(
+        &mut self,
+        proposal_id: &str,
+        approver: ApprovalLevel,
+        trace_id: &str,
+    ) -> EnvelopeApprovalOutcome {
+        let Some(proposal) = self.pending_envelope_proposals.get(proposal_id).cloned() else {
+            return EnvelopeApprovalOutcome::UnknownProposal;
+        };
+
+        let outcome = if approver >= proposal.required_level {
+            self.pending_envelope_proposals.remove(proposal_id);
+            self.envelope = proposal.proposed_envelope.clone();
+            EnvelopeApprovalOutcome::Applied
+        } else {
+            EnvelopeApprovalOutcome::InsufficientApprovalLevel
+        };
+
+        let event_code = match outcome {
+            EnvelopeApprovalOutcome::Applied => event_codes::GOV_006,
+            EnvelopeApprovalOutcome::InsufficientApprovalLevel => event_codes::GOV_009,
+            EnvelopeApprovalOutcome::UnknownProposal => unreachable!(
+                "handled above by the early return before an EnvelopeDecisionRecord is built"
+            ),
+        };
+
+        let rec = EnvelopeDecisionRecord {
+            seq: self.next_seq,
+            proposal_id: sanitize_log_field(proposal_id),
+            change_kind: proposal.change_kind,
+            required_level: proposal.required_level,
+            approver_level: approver,
+            outcome: outcome.clone(),
+            event_code: event_code.to_string(),
+            trace_id: sanitize_log_field(trace_id),
+        };
+        push_bounded(&mut self.envelope_decisions, rec, MAX_DECISION_LOG_ENTRIES);
+        self.next_seq = self.next_seq.saturating_add(1);
+
+        outcome
+    }
+
+    /// Read the pending envelope-update proposals, keyed by proposal_id.
+    pub fn pending_envelope_proposals(&self) -> &BTreeMap<String, EnvelopeProposal> {
+        &self.pending_envelope_proposals
+    }
+
+    /// Read the envelope-update approval decision ledger.
+    pub fn envelope_decisions(&self) -> &[EnvelopeDecisionRecord] {
+        &self.envelope_decisions
+    }
+
+    /// Submit a [`RailPlacementProposal`] that drives a [`KnobChange`]
+    /// through `mesh` rather than adjusting a [`RuntimeKnob`] directly.
+    ///
+    /// The proposal is first checked against the governor's
+    /// [`SafetyEnvelope`] exactly as [`Self::submit`] checks an
+    /// [`OptimizationProposal`]'s predicted metrics; an envelope breach is
+    /// rejected before `mesh` is ever touched. If it passes, the change is
+    /// driven through `mesh` -- whose own monotonicity, latency, and
+    /// capacity rules are the final word. A mesh rejection is surfaced as
+    /// [`RejectionReason::MeshRejected`] carrying the mesh's error as
+    /// evidence, and nothing is applied.
+    pub fn submit_rail_placement(
+        &mut self,
+        proposal: RailPlacementProposal,
+        mesh: &mut IsolationMesh,
+        now_ms: u64,
+    ) -> GovernorDecision {
+        let decision = if !proposal.is_valid() {
+            GovernorDecision::Rejected(RejectionReason::InvalidProposal(
+                "proposal_id, trace_id, or submitted_by is empty or contains control characters, or error_rate_pct is outside 0..=100".to_string(),
+            ))
+        } else if !self.envelope.contains(&proposal.predicted) {
+            GovernorDecision::Rejected(RejectionReason::EnvelopeViolation(
+                self.envelope.violations(&proposal.predicted),
+            ))
+        } else {
+            let KnobChange::RailPlacement {
+                workload_id,
+                target_rail,
+            } = &proposal.change;
+            match mesh.elevate_workload(workload_id, target_rail, now_ms) {
+                Ok(_) => GovernorDecision::Approved,
+                Err(err) => GovernorDecision::Rejected(RejectionReason::MeshRejected(format!(
+                    "isolation mesh rejected rail placement for `{workload_id}`: {err}"
+                ))),
+            }
+        };
+
+        let event_code = match decision {
+            GovernorDecision::Approved => event_codes::GOV_012,
+            _ => event_codes::GOV_013,
+        };
+        let evidence = match &decision {
+            GovernorDecision::Rejected(reason) => {
+                Some(format!("{}: {:?}", reason.error_code(), reason))
+            }
+            _ => None,
+        };
+        let rec = MeshDecisionRecord {
+            seq: self.next_seq,
+            proposal_id: sanitize_log_field(&proposal.proposal_id),
+            change: proposal.change.clone(),
+            decision: decision.clone(),
+            event_code: event_code.to_string(),
+            trace_id: sanitize_log_field(&proposal.trace_id),
+            evidence: evidence.map(|detail| sanitize_log_field(&detail)),
+        };
+        push_bounded(&mut self.mesh_decisions, rec, MAX_DECISION_LOG_ENTRIES);
+        self.next_seq = self.next_seq.saturating_add(1);
+
+        decision
+    }
+
+    /// Read the rail-placement decision ledger.
+    pub fn mesh_decisions(&self) -> &[MeshDecisionRecord] {
+        &self.mesh_decisions
+    }
+
     /// Return the current schema version.
     pub fn schema_version(&self) -> &str {
         &self.schema_version
@@ -532,10 +1341,15 @@ impl OptimizationGovernor {
         self.knob_states.get(knob).map(|s| s.value)
     }
 
-    /// Lock a knob so no proposals can change it.
-    pub fn lock_knob(&mut self, knob: RuntimeKnob) {
+    /// Lock a knob at the given `priority` tier so only a proposal whose
+    /// [`ProposalSource::priority_rank`] strictly exceeds it can change the
+    /// knob (`ERR_GOV_KNOB_LOCKED` otherwise). `owner` identifies the policy
+    /// or operator holding the lock, for the [`GovernorSnapshot`]. No-op if
+    /// `knob` is not a tracked knob.
+    pub fn lock_knob(&mut self, knob: RuntimeKnob, priority: u32, owner: String) {
         if let Some(state) = self.knob_states.get_mut(&knob) {
             state.locked = true;
+            self.knob_locks.insert(knob, KnobLock { priority, owner });
         }
     }
 
@@ -544,25 +1358,45 @@ impl OptimizationGovernor {
         if let Some(state) = self.knob_states.get_mut(&knob) {
             state.locked = false;
         }
+        self.knob_locks.remove(&knob);
     }
 
     // -----------------------------------------------------------------------
     // Shadow evaluation (INV-GOV-SHADOW-BEFORE-APPLY)
     // -----------------------------------------------------------------------
 
+    /// Predicted metrics of the most recently applied proposal for `knob`,
+    /// used as the improvement baseline in [`Self::shadow_evaluate`]. `None`
+    /// if no proposal has ever been applied against `knob` (or the applied
+    /// record predates the `predicted` field being tracked).
+    fn baseline_metrics_for(&self, knob: RuntimeKnob) -> Option<&PredictedMetrics> {
+        self.applied
+            .values()
+            .filter(|ap| ap.knob == knob)
+            .max_by_key(|ap| ap.seq)
+            .and_then(|ap| ap.predicted.as_ref())
+    }
+
     /// Perform shadow evaluation of a proposal against the safety envelope.
     ///
     /// Returns a [`ShadowResult`] that indicates whether the proposal is within
-    /// the envelope and whether it is beneficial (improves at least one metric
-    /// without worsening others beyond the envelope).
+    /// the envelope and whether it is beneficial. A proposal is beneficial if
+    /// it stays within the envelope and, compared against the predicted
+    /// metrics of the last proposal applied to the same knob, strictly
+    /// improves at least one metric (INV-GOV-EVIDENCE-ON-REJECT: a pure
+    /// no-op is rejected as [`RejectionReason::NonBeneficial`]). If no prior
+    /// applied proposal exists for the knob, there is no baseline to compare
+    /// against, so benefit falls back to whether the knob's value actually
+    /// changes.
     pub fn shadow_evaluate(&self, proposal: &OptimizationProposal) -> ShadowResult {
-        // GOV_002 emitted
         let violations = self.envelope.violations(&proposal.predicted);
         let within_envelope = violations.is_empty();
 
-        // A proposal is beneficial if its new_value differs from old_value and
-        // it stays within the envelope.
-        let is_beneficial = within_envelope && proposal.new_value != proposal.old_value;
+        let is_beneficial = within_envelope
+            && match self.baseline_metrics_for(proposal.knob) {
+                Some(baseline) => metrics_improved(baseline, &proposal.predicted),
+                None => proposal.new_value != proposal.old_value,
+            };
 
         ShadowResult {
             proposal_id: proposal.proposal_id.clone(),
@@ -582,47 +1416,26 @@ impl OptimizationGovernor {
     /// Returns the [`GovernorDecision`] and appends a [`DecisionRecord`] to
     /// the log.
     pub fn submit(&mut self, proposal: OptimizationProposal) -> GovernorDecision {
-        // GOV_001 emitted
-
-        // 1. Validate proposal
-        if !proposal.is_valid() {
-            let reason = RejectionReason::InvalidProposal(
-                "proposal_id or trace_id is empty, contains control characters, or error_rate_pct is outside 0..=100".to_string(),
-            );
-            let decision = GovernorDecision::Rejected(reason);
-            self.record(
-                &proposal.proposal_id,
-                proposal.knob,
-                &decision,
-                event_codes::GOV_004,
-                &proposal.trace_id,
-            );
-            return decision;
-        }
-
-        if self.applied.contains_key(&proposal.proposal_id) {
-            let reason = RejectionReason::InvalidProposal(format!(
-                "proposal_id `{}` is already applied",
-                sanitize_log_field(&proposal.proposal_id)
-            ));
-            let decision = GovernorDecision::Rejected(reason);
-            self.record(
-                &proposal.proposal_id,
-                proposal.knob,
-                &decision,
-                event_codes::GOV_004,
-                &proposal.trace_id,
-            );
-            return decision;
-        }
+        self.submit_core(proposal)
+    }
 
-        // 2. Ensure target knob exists and proposal baseline matches current state.
-        let (current_value, knob_locked) = match self.knob_states.get(&proposal.knob) {
-            Some(state) => (state.value, state.locked),
-            None => {
-                let reason = RejectionReason::InvalidProposal(format!(
-                    "target knob `{}` is not configured",
-                    proposal.knob
+    /// Submit a proposal with a caller-supplied timestamp, enforcing the
+    /// per-knob cooldown configured via [`Self::set_knob_cooldown`].
+    ///
+    /// If the proposal's knob was applied or reverted within
+    /// `knob_cooldown_ms` of `now_ms`, the proposal is rejected with
+    /// [`RejectionReason::KnobCooldown`] (`ERR_GOV_KNOB_COOLDOWN`) before any
+    /// other evaluation. Otherwise it is evaluated exactly as in
+    /// [`Self::submit`], and on approval the knob's last-touched timestamp
+    /// is updated to `now_ms`.
+    pub fn submit_at(&mut self, proposal: OptimizationProposal, now_ms: u64) -> GovernorDecision {
+        if let Some(&last_touched_ms) = self.knob_last_touched_ms.get(&proposal.knob) {
+            let elapsed_ms = now_ms.saturating_sub(last_touched_ms);
+            if elapsed_ms < self.knob_cooldown_ms {
+                let remaining_ms = self.knob_cooldown_ms - elapsed_ms;
+                let reason = RejectionReason::KnobCooldown(format!(
+                    "knob `{}` was last touched at {}ms; still in cooldown for {}ms more",
+                    proposal.knob, last_touched_ms, remaining_ms
                 ));
                 let decision = GovernorDecision::Rejected(reason);
                 self.record(
@@ -634,57 +1447,208 @@ impl OptimizationGovernor {
                 );
                 return decision;
             }
-        };
+        }
 
-        if proposal.old_value != current_value {
-            let reason = RejectionReason::InvalidProposal(format!(
-                "stale old_value for `{}`: expected current {}, got {}",
-                proposal.knob, current_value, proposal.old_value
-            ));
-            let decision = GovernorDecision::Rejected(reason);
-            self.record(
-                &proposal.proposal_id,
-                proposal.knob,
-                &decision,
-                event_codes::GOV_004,
-                &proposal.trace_id,
-            );
-            return decision;
+        let mut risk_points = 0;
+        if let Some(budget) = self.risk_budget {
+            self.roll_risk_window(now_ms, budget.window_ms);
+            if matches!(
+                self.evaluate_decision(&proposal),
+                GovernorDecision::Approved
+            ) {
+                risk_points = self.envelope.risk_points(&proposal.predicted);
+                let projected = self.risk_spent_in_window.saturating_add(risk_points);
+                if projected > budget.per_window {
+                    let reason = RejectionReason::RiskBudgetExceeded(format!(
+                        "proposal would consume {risk_points} risk points ({} already spent this window), exceeding the {}-point budget",
+                        self.risk_spent_in_window, budget.per_window
+                    ));
+                    let decision = GovernorDecision::Rejected(reason);
+                    self.record(
+                        &proposal.proposal_id,
+                        proposal.knob,
+                        &decision,
+                        event_codes::GOV_004,
+                        &proposal.trace_id,
+                    );
+                    return decision;
+                }
+            }
         }
 
-        // 3. Check if knob is locked (INV-GOV-KNOBS-ONLY)
-        if knob_locked {
-            let decision = GovernorDecision::Rejected(RejectionReason::KnobLocked);
-            self.record(
-                &proposal.proposal_id,
-                proposal.knob,
-                &decision,
-                event_codes::GOV_004,
-                &proposal.trace_id,
+        let knob = proposal.knob;
+        let decision = self.submit_core(proposal);
+        if matches!(decision, GovernorDecision::Approved) {
+            self.knob_last_touched_ms.insert(knob, now_ms);
+            if self.risk_budget.is_some() {
+                self.risk_spent_in_window = self.risk_spent_in_window.saturating_add(risk_points);
+            }
+        }
+        decision
+    }
+
+    /// Resets the risk-budget window's spend once `window_ms` has elapsed
+    /// since it last started (or it has not started yet).
+    fn roll_risk_window(&mut self, now_ms: u64, window_ms: u64) {
+        let window_expired = match self.risk_window_start_ms {
+            Some(start_ms) => now_ms.saturating_sub(start_ms) >= window_ms,
+            None => true,
+        };
+        if window_expired {
+            self.risk_window_start_ms = Some(now_ms);
+            self.risk_spent_in_window = 0;
+        }
+    }
+
+    /// Determine what decision a proposal would receive, without mutating
+    /// any governor state (no knob change, no decision-log entry). Runs the
+    /// same validation, knob-existence/baseline, lock, and shadow
+    /// envelope/benefit checks that [`Self::submit_core`] applies before it
+    /// mutates state, so the two stay in lockstep by construction.
+    fn evaluate_decision(&self, proposal: &OptimizationProposal) -> GovernorDecision {
+        self.evaluate_decision_with_shadow(proposal).0
+    }
+
+    /// Like [`Self::evaluate_decision`], but also returns the
+    /// [`ShadowResult`] computed along the way, when the proposal survives
+    /// validation, baseline, and lock checks far enough to reach shadow
+    /// evaluation (INV-GOV-SHADOW-BEFORE-APPLY). Returns `None` for
+    /// proposals rejected before the shadow stage is ever reached.
+    fn evaluate_decision_with_shadow(
+        &self,
+        proposal: &OptimizationProposal,
+    ) -> (GovernorDecision, Option<ShadowResult>) {
+        // 1. Validate proposal
+        if !proposal.is_valid() {
+            return (GovernorDecision::Rejected(RejectionReason::InvalidProposal(
+                "proposal_id, trace_id, or submitted_by is empty or contains control characters, or error_rate_pct is outside 0..=100".to_string(),
+            )), None);
+        }
+
+        if self.applied.contains_key(&proposal.proposal_id) {
+            return (
+                GovernorDecision::Rejected(RejectionReason::InvalidProposal(format!(
+                    "proposal_id `{}` is already applied",
+                    sanitize_log_field(&proposal.proposal_id)
+                ))),
+                None,
+            );
+        }
+
+        // 2. Ensure target knob exists and proposal baseline matches current state.
+        let current_value = match self.knob_states.get(&proposal.knob) {
+            Some(state) => state.value,
+            None => {
+                return (
+                    GovernorDecision::Rejected(RejectionReason::InvalidProposal(format!(
+                        "target knob `{}` is not configured",
+                        proposal.knob
+                    ))),
+                    None,
+                );
+            }
+        };
+
+        if proposal.old_value != current_value {
+            return (
+                GovernorDecision::Rejected(RejectionReason::InvalidProposal(format!(
+                    "stale old_value for `{}`: expected current {}, got {}",
+                    proposal.knob, current_value, proposal.old_value
+                ))),
+                None,
             );
-            return decision;
+        }
+
+        // 3. Check if knob is locked, unless this proposal's priority
+        //    pre-empts the lock (INV-GOV-KNOBS-ONLY)
+        if let Some(lock) = self.knob_locks.get(&proposal.knob) {
+            if proposal.source.priority_rank() <= lock.priority {
+                return (
+                    GovernorDecision::Rejected(RejectionReason::KnobLocked),
+                    None,
+                );
+            }
         }
 
         // 4. Shadow evaluate (INV-GOV-SHADOW-BEFORE-APPLY)
-        let shadow = self.shadow_evaluate(&proposal);
+        let shadow = self.shadow_evaluate(proposal);
 
         if !shadow.within_envelope {
-            // Rejected -- envelope violation
-            let reason = RejectionReason::EnvelopeViolation(shadow.violations);
-            let decision = GovernorDecision::Rejected(reason);
-            self.record(
+            return (
+                GovernorDecision::Rejected(RejectionReason::EnvelopeViolation(
+                    shadow.violations.clone(),
+                )),
+                Some(shadow),
+            );
+        }
+
+        if !shadow.is_beneficial {
+            return (
+                GovernorDecision::Rejected(RejectionReason::NonBeneficial),
+                Some(shadow),
+            );
+        }
+
+        (GovernorDecision::Approved, Some(shadow))
+    }
+
+    /// Simulate a proposal against the current governor state without
+    /// applying it: no knob change, no decision-log entry. The read-only
+    /// twin of [`Self::submit`], for tuning engineers to ask "what would the
+    /// verdict be?" before submitting for real.
+    pub fn simulate_proposal(&self, proposal: &OptimizationProposal) -> SimulationResult {
+        SimulationResult {
+            proposal_id: proposal.proposal_id.clone(),
+            decision: self.evaluate_decision(proposal),
+        }
+    }
+
+    fn submit_core(&mut self, proposal: OptimizationProposal) -> GovernorDecision {
+        // GOV_001 emitted
+        let (decision, shadow) = self.evaluate_decision_with_shadow(&proposal);
+
+        if let Some(shadow) = &shadow {
+            self.record_shadow(
                 &proposal.proposal_id,
                 proposal.knob,
-                &decision,
-                event_codes::GOV_004,
+                shadow,
                 &proposal.trace_id,
             );
-            return decision;
+            // GOV_002 emitted
         }
 
-        if !shadow.is_beneficial {
-            // Rejected -- non-beneficial
-            let decision = GovernorDecision::Rejected(RejectionReason::NonBeneficial);
+        if let GovernorDecision::Approved = decision {
+            // Approved -- apply the knob change
+            if let Some(state) = self.knob_states.get_mut(&proposal.knob) {
+                state.value = proposal.new_value;
+            }
+
+            self.applied.insert(
+                proposal.proposal_id.clone(),
+                AppliedProposal {
+                    seq: self.next_seq,
+                    proposal_id: proposal.proposal_id.clone(),
+                    knob: proposal.knob,
+                    old_value: proposal.old_value,
+                    new_value: proposal.new_value,
+                    trace_id: proposal.trace_id.clone(),
+                    submitted_by: proposal.submitted_by.clone(),
+                    source: proposal.source,
+                    predicted: Some(proposal.predicted.clone()),
+                    consecutive_breach_checks: 0,
+                    consecutive_ok_checks: 0,
+                },
+            );
+
+            self.record(
+                &proposal.proposal_id,
+                proposal.knob,
+                &decision,
+                event_codes::GOV_003,
+                &proposal.trace_id,
+            );
+            // GOV_003 emitted
+        } else {
             self.record(
                 &proposal.proposal_id,
                 proposal.knob,
@@ -692,35 +1656,8 @@ impl OptimizationGovernor {
                 event_codes::GOV_004,
                 &proposal.trace_id,
             );
-            return decision;
         }
 
-        // 5. Approved -- apply the knob change
-        if let Some(state) = self.knob_states.get_mut(&proposal.knob) {
-            state.value = proposal.new_value;
-        }
-
-        self.applied.insert(
-            proposal.proposal_id.clone(),
-            AppliedProposal {
-                seq: self.next_seq,
-                proposal_id: proposal.proposal_id.clone(),
-                knob: proposal.knob,
-                old_value: proposal.old_value,
-                new_value: proposal.new_value,
-                trace_id: proposal.trace_id.clone(),
-            },
-        );
-
-        let decision = GovernorDecision::Approved;
-        self.record(
-            &proposal.proposal_id,
-            proposal.knob,
-            &decision,
-            event_codes::GOV_003,
-            &proposal.trace_id,
-        );
-        // GOV_003 emitted
         decision
     }
 
@@ -729,29 +1666,94 @@ impl OptimizationGovernor {
     // -----------------------------------------------------------------------
 
     /// Perform a live check of all applied proposals against the given live
-    /// metrics.  Any proposal whose knob's live metrics breach the envelope
-    /// is auto-reverted.
+    /// metrics. Applies hysteresis per [`HysteresisConfig`]: a proposal is
+    /// only auto-reverted once its live metrics have breached the envelope
+    /// for `breach_threshold` consecutive checks, and a partial breach
+    /// streak only clears once `recovery_threshold` consecutive in-envelope
+    /// checks follow. With the default `1`/`1` config this degenerates to
+    /// the original immediate-revert-on-first-breach behavior.
     ///
     /// Returns the list of reverted proposal IDs.
     pub fn live_check(&mut self, live_metrics: &PredictedMetrics) -> Vec<String> {
-        if self.envelope.contains(live_metrics) {
+        self.live_check_core(live_metrics, None)
+    }
+
+    /// Perform a live check with a caller-supplied timestamp, recording it
+    /// as the last-touched time for any knob reverted as a result. Used
+    /// together with [`Self::submit_at`] to enforce the per-knob cooldown
+    /// configured via [`Self::set_knob_cooldown`].
+    pub fn live_check_at(&mut self, live_metrics: &PredictedMetrics, now_ms: u64) -> Vec<String> {
+        self.live_check_core(live_metrics, Some(now_ms))
+    }
+
+    fn live_check_core(
+        &mut self,
+        live_metrics: &PredictedMetrics,
+        now_ms: Option<u64>,
+    ) -> Vec<String> {
+        let violations = self.envelope.violations(live_metrics);
+        let in_envelope = violations.is_empty();
+
+        for ap in self.applied.values_mut() {
+            if in_envelope {
+                ap.consecutive_ok_checks = ap.consecutive_ok_checks.saturating_add(1);
+                if ap.consecutive_ok_checks >= self.hysteresis.recovery_threshold.max(1) {
+                    ap.consecutive_breach_checks = 0;
+                }
+            } else {
+                ap.consecutive_ok_checks = 0;
+                ap.consecutive_breach_checks = ap.consecutive_breach_checks.saturating_add(1);
+            }
+        }
+
+        if in_envelope {
             return Vec::new();
         }
 
-        // All currently applied proposals are suspect; revert them all.
-        // Sort by sequence number descending so last-applied proposals revert first.
-        let mut to_revert: Vec<AppliedProposal> = self.applied.values().cloned().collect();
+        // Proposals whose breach streak has reached the hysteresis threshold
+        // are suspect; revert them. Sort by sequence number descending so
+        // last-applied proposals revert first.
+        let mut to_revert: Vec<AppliedProposal> = self
+            .applied
+            .values()
+            .filter(|ap| ap.consecutive_breach_checks >= self.hysteresis.breach_threshold.max(1))
+            .cloned()
+            .collect();
         to_revert.sort_by_key(|ap| std::cmp::Reverse(ap.seq));
         let mut reverted_ids = Vec::new();
+        let offending_bounds = violations.join("; ");
 
         for ap in &to_revert {
-            // Revert knob to old value
-            if let Some(state) = self.knob_states.get_mut(&ap.knob) {
-                state.value = ap.old_value;
+            // Revert knob to old value. If the knob is no longer configured
+            // there is nothing to restore into, so the revert fails closed
+            // (ERR_GOV_REVERT_FAILED) rather than silently dropping the
+            // breach: the proposal stays in `applied` and will be retried
+            // on the next breaching live_check.
+            let Some(state) = self.knob_states.get_mut(&ap.knob) else {
+                let decision = GovernorDecision::Reverted(format!(
+                    "{}: cannot revert {} to {} (submitted_by={}, source={:?}); knob is no longer configured; offending reading breached: {offending_bounds}",
+                    error_codes::ERR_GOV_REVERT_FAILED,
+                    ap.knob,
+                    ap.old_value,
+                    ap.submitted_by,
+                    ap.source
+                ));
+                self.record(
+                    &ap.proposal_id,
+                    ap.knob,
+                    &decision,
+                    event_codes::GOV_005,
+                    &ap.trace_id,
+                );
+                continue;
+            };
+            state.value = ap.old_value;
+            if let Some(now_ms) = now_ms {
+                self.knob_last_touched_ms.insert(ap.knob, now_ms);
             }
             let decision = GovernorDecision::Reverted(format!(
-                "Live metrics breached envelope; reverted {} from {} to {}",
-                ap.knob, ap.new_value, ap.old_value
+                "Live metrics breached envelope; reverted {} from {} to {} (submitted_by={}, source={:?}); offending reading breached: {offending_bounds}",
+                ap.knob, ap.new_value, ap.old_value, ap.submitted_by, ap.source
             ));
             self.record(
                 &ap.proposal_id,
@@ -774,6 +1776,114 @@ impl OptimizationGovernor {
         reverted_ids
     }
 
+    // -----------------------------------------------------------------------
+    // A/B testing
+    // -----------------------------------------------------------------------
+
+    /// Start an A/B test between two candidate knob configurations on split
+    /// traffic. Only one test may run at a time; call [`Self::conclude_ab_test`]
+    /// before starting another. Emits GOV_010.
+    pub fn begin_ab_test(
+        &mut self,
+        config_a: KnobConfig,
+        config_b: KnobConfig,
+        split: f64,
+    ) -> Result<String, AbTestError> {
+        if self.active_ab_test.is_some() {
+            return Err(AbTestError::AlreadyActive(
+                "an A/B test is already running; conclude it first".to_string(),
+            ));
+        }
+        if config_a.is_empty() || config_b.is_empty() {
+            return Err(AbTestError::EmptyConfig(
+                "config_a and config_b must each assign at least one knob".to_string(),
+            ));
+        }
+        if !(split.is_finite() && split > 0.0 && split < 1.0) {
+            return Err(AbTestError::InvalidSplit(format!(
+                "split must be in (0.0, 1.0), got {split}"
+            )));
+        }
+
+        self.ab_test_counter = self.ab_test_counter.saturating_add(1);
+        let test_id = format!("ab-test-{}", self.ab_test_counter);
+        self.active_ab_test = Some(AbTest {
+            test_id: test_id.clone(),
+            config_a,
+            config_b,
+            split,
+        });
+        // GOV_010 emitted
+        Ok(test_id)
+    }
+
+    /// Conclude the running A/B test by comparing `metrics_a` and
+    /// `metrics_b` (observed from live split traffic) under the configured
+    /// [`ObjectiveWeights`]. A winner is declared only when the score
+    /// difference exceeds [`Self::set_ab_significance_margin`]; otherwise
+    /// the result is inconclusive and `A` is kept. The winning configuration
+    /// is then applied one knob at a time through the normal
+    /// envelope-checked [`Self::submit`] path, so a winner can still be
+    /// rejected (in full or in part) if it would breach the safety
+    /// envelope. Emits GOV_011.
+    pub fn conclude_ab_test(
+        &mut self,
+        metrics_a: PredictedMetrics,
+        metrics_b: PredictedMetrics,
+    ) -> Result<AbResult, AbTestError> {
+        let Some(test) = self.active_ab_test.take() else {
+            return Err(AbTestError::NoActiveTest(
+                "conclude_ab_test called with no test running".to_string(),
+            ));
+        };
+
+        let score_a = self.objective_weights.score(&metrics_a);
+        let score_b = self.objective_weights.score(&metrics_b);
+        let margin = (score_b - score_a).abs();
+        let conclusive = margin > self.ab_significance_margin;
+
+        let (winner, winning_config, winning_metrics) = if conclusive && score_b > score_a {
+            (AbWinner::B, &test.config_b, metrics_b)
+        } else {
+            (AbWinner::A, &test.config_a, metrics_a)
+        };
+
+        let mut decisions = Vec::with_capacity(winning_config.len());
+        for (knob, &new_value) in winning_config {
+            let old_value = self.knob_value(knob).unwrap_or(new_value);
+            if old_value == new_value {
+                continue;
+            }
+            let proposal = OptimizationProposal {
+                proposal_id: format!("{}-apply-{}", test.test_id, knob.as_str()),
+                knob: *knob,
+                old_value,
+                new_value,
+                predicted: winning_metrics.clone(),
+                rationale: format!("A/B test {} winner applied", test.test_id),
+                trace_id: test.test_id.clone(),
+                submitted_by: test.test_id.clone(),
+                source: ProposalSource::Autotuner,
+            };
+            decisions.push(self.submit(proposal));
+        }
+        let applied = decisions
+            .iter()
+            .all(|d| matches!(d, GovernorDecision::Approved));
+        // GOV_011 emitted
+
+        Ok(AbResult {
+            test_id: test.test_id,
+            winner,
+            conclusive,
+            score_a,
+            score_b,
+            margin,
+            decisions,
+            applied,
+        })
+    }
+
     // -----------------------------------------------------------------------
     // State snapshot (GOV_007)
     // -----------------------------------------------------------------------
@@ -784,6 +1894,7 @@ impl OptimizationGovernor {
             schema_version: self.schema_version.clone(),
             envelope: self.envelope.clone(),
             knob_states: self.knob_states.values().cloned().collect(),
+            knob_locks: self.knob_locks.clone(),
             applied_count: self.applied.len(),
             decision_log_len: self.decision_log.len(),
             next_seq: self.next_seq,
@@ -809,6 +1920,30 @@ impl OptimizationGovernor {
         self.shadow_evaluate(proposal)
     }
 
+    /// Export the decision log as a tamper-evident, totally ordered ledger
+    /// (INV-GOV-DETERMINISTIC-ORDER). Each entry hashes the previous entry's
+    /// hash together with its own decision record, so any reordering or
+    /// mutation of the exported history is detectable via
+    /// [`verify_decision_ledger`].
+    pub fn export_decision_ledger(&self) -> DecisionLedger {
+        let mut entries = Vec::with_capacity(self.decision_log.len());
+        let mut prev_hash = decision_ledger_genesis_hash();
+        for record in &self.decision_log {
+            let entry_hash = compute_decision_ledger_entry_hash(&prev_hash, record)
+                .expect("DecisionRecord serialization is infallible");
+            entries.push(LedgerEntry {
+                record: record.clone(),
+                prev_hash: prev_hash.clone(),
+                entry_hash: entry_hash.clone(),
+            });
+            prev_hash = entry_hash;
+        }
+        DecisionLedger {
+            schema_version: SCHEMA_VERSION.to_string(),
+            entries,
+        }
+    }
+
     /// Export the decision log as JSONL (one JSON object per line).
     pub fn export_decision_log_jsonl(&self) -> String {
         self.decision_log
@@ -901,6 +2036,34 @@ impl OptimizationGovernor {
         push_bounded(&mut self.decision_log, rec, MAX_DECISION_LOG_ENTRIES);
         self.next_seq = self.next_seq.saturating_add(1);
     }
+
+    /// Record a `GOV_002` shadow-evaluation entry ahead of the eventual
+    /// apply/reject entry for the same proposal, so the decision log always
+    /// shows shadow-before-apply ordering by `seq` (INV-GOV-SHADOW-BEFORE-APPLY).
+    fn record_shadow(
+        &mut self,
+        proposal_id: &str,
+        knob: RuntimeKnob,
+        shadow: &ShadowResult,
+        trace_id: &str,
+    ) {
+        let evidence = format!(
+            "within_envelope={} is_beneficial={} violations={:?}",
+            shadow.within_envelope, shadow.is_beneficial, shadow.violations
+        );
+
+        let rec = DecisionRecord {
+            seq: self.next_seq,
+            proposal_id: sanitize_log_field(proposal_id),
+            knob,
+            decision: GovernorDecision::ShadowOnly,
+            event_code: event_codes::GOV_002.to_string(),
+            trace_id: sanitize_log_field(trace_id),
+            evidence: Some(sanitize_log_field(&evidence)),
+        };
+        push_bounded(&mut self.decision_log, rec, MAX_DECISION_LOG_ENTRIES);
+        self.next_seq = self.next_seq.saturating_add(1);
+    }
 }
 
 /// Serializable snapshot of the governor state (GOV_007).
@@ -909,11 +2072,166 @@ pub struct GovernorSnapshot {
     pub schema_version: String,
     pub envelope: SafetyEnvelope,
     pub knob_states: Vec<KnobState>,
+    /// Locks currently held against knobs, keyed by knob.
+    pub knob_locks: BTreeMap<RuntimeKnob, KnobLock>,
     pub applied_count: usize,
     pub decision_log_len: usize,
     pub next_seq: u64,
 }
 
+// ---------------------------------------------------------------------------
+// Decision ledger: tamper-evident export of the decision log
+// ---------------------------------------------------------------------------
+
+const DECISION_LEDGER_GENESIS_DOMAIN: &[u8] =
+    b"franken_node.optimization_governor.decision_ledger.genesis.v1";
+const DECISION_LEDGER_ENTRY_DOMAIN: &[u8] =
+    b"franken_node.optimization_governor.decision_ledger.entry.v1";
+
+/// One entry in a [`DecisionLedger`]: a [`DecisionRecord`] together with the
+/// hash chain linkage that makes the exported history tamper-evident.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// The decision record this entry wraps.
+    pub record: DecisionRecord,
+    /// SHA-256 hex digest of the previous entry's `entry_hash` (the
+    /// domain-separated genesis hash for the first entry).
+    pub prev_hash: String,
+    /// SHA-256 hex digest binding `prev_hash` to `record`.
+    pub entry_hash: String,
+}
+
+/// A totally ordered, tamper-evident export of an
+/// [`OptimizationGovernor`]'s decision log, produced by
+/// [`OptimizationGovernor::export_decision_ledger`] and checked by
+/// [`verify_decision_ledger`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionLedger {
+    pub schema_version: String,
+    pub entries: Vec<LedgerEntry>,
+}
+
+/// Errors returned by [`verify_decision_ledger`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LedgerError {
+    /// Two consecutive entries do not have strictly increasing sequence
+    /// numbers, i.e. the ledger has been reordered.
+    SequenceNotIncreasing { at: usize, prev_seq: u64, seq: u64 },
+    /// An entry's `prev_hash` does not match the previous entry's
+    /// `entry_hash` (or the genesis hash, for the first entry).
+    ChainBroken {
+        at: usize,
+        expected: String,
+        found: String,
+    },
+    /// An entry's recomputed hash does not match its stored `entry_hash`,
+    /// meaning the record was mutated after export.
+    EntryTampered { at: usize },
+}
+
+impl LedgerError {
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::SequenceNotIncreasing { .. } => error_codes::ERR_GOV_LEDGER_SEQUENCE_GAP,
+            Self::ChainBroken { .. } => error_codes::ERR_GOV_LEDGER_CHAIN_BROKEN,
+            Self::EntryTampered { .. } => error_codes::ERR_GOV_LEDGER_ENTRY_TAMPERED,
+        }
+    }
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SequenceNotIncreasing { at, prev_seq, seq } => {
+                write!(
+                    f,
+                    "{}: entry {at} has seq={seq}, not greater than previous seq={prev_seq}",
+                    self.code()
+                )
+            }
+            Self::ChainBroken {
+                at,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "{}: entry {at} prev_hash={found} does not match expected={expected}",
+                    self.code()
+                )
+            }
+            Self::EntryTampered { at } => {
+                write!(
+                    f,
+                    "{}: entry {at} hash does not match its record",
+                    self.code()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+fn decision_ledger_update_len_prefixed(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update(u64::try_from(bytes.len()).unwrap_or(u64::MAX).to_be_bytes());
+    hasher.update(bytes);
+}
+
+fn decision_ledger_genesis_hash() -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(DECISION_LEDGER_GENESIS_DOMAIN);
+    hex::encode(hasher.finalize())
+}
+
+fn compute_decision_ledger_entry_hash(
+    prev_hash: &str,
+    record: &DecisionRecord,
+) -> Result<String, serde_json::Error> {
+    let record_bytes = serde_json::to_vec(record)?;
+    let mut hasher = Sha256::new();
+    hasher.update(DECISION_LEDGER_ENTRY_DOMAIN);
+    decision_ledger_update_len_prefixed(&mut hasher, prev_hash.as_bytes());
+    decision_ledger_update_len_prefixed(&mut hasher, &record_bytes);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Verifies that `ledger` is a genuine, unmodified export: sequence numbers
+/// strictly increase entry-to-entry, each entry's `prev_hash` chains to the
+/// previous entry's `entry_hash` (or the genesis hash, for the first entry),
+/// and each entry's `entry_hash` matches its `record`.
+pub fn verify_decision_ledger(ledger: &DecisionLedger) -> Result<(), LedgerError> {
+    let mut expected_prev_hash = decision_ledger_genesis_hash();
+    let mut prev_seq: Option<u64> = None;
+    for (at, entry) in ledger.entries.iter().enumerate() {
+        if let Some(prev_seq) = prev_seq {
+            if entry.record.seq <= prev_seq {
+                return Err(LedgerError::SequenceNotIncreasing {
+                    at,
+                    prev_seq,
+                    seq: entry.record.seq,
+                });
+            }
+        }
+        if entry.prev_hash != expected_prev_hash {
+            return Err(LedgerError::ChainBroken {
+                at,
+                expected: expected_prev_hash,
+                found: entry.prev_hash.clone(),
+            });
+        }
+        let recomputed = compute_decision_ledger_entry_hash(&entry.prev_hash, &entry.record)
+            .map_err(|_| LedgerError::EntryTampered { at })?;
+        if recomputed != entry.entry_hash {
+            return Err(LedgerError::EntryTampered { at });
+        }
+        prev_seq = Some(entry.record.seq);
+        expected_prev_hash = entry.entry_hash.clone();
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Type aliases for contract compatibility (bd-21fo checker)
 // ---------------------------------------------------------------------------
@@ -962,6 +2280,8 @@ mod tests {
             predicted: safe_metrics(),
             rationale: "Increase concurrency under low load".to_string(),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -979,6 +2299,8 @@ mod tests {
             },
             rationale: "Aggressive batch size".to_string(),
             trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         }
     }
 
@@ -1045,6 +2367,23 @@ mod tests {
         assert_eq!(env.violations(&m).len(), 4);
     }
 
+    #[test]
+    fn test_envelope_risk_points_scales_with_proximity_to_bound() {
+        let env = default_envelope();
+        let comfortable = PredictedMetrics {
+            latency_ms: 50,
+            throughput_rps: 1000,
+            error_rate_pct: 0.1,
+            memory_mb: 512,
+        };
+        let borderline = PredictedMetrics {
+            latency_ms: 490,
+            ..comfortable.clone()
+        };
+        assert!(env.risk_points(&comfortable) < env.risk_points(&borderline));
+        assert_eq!(env.risk_points(&borderline), 98);
+    }
+
     #[test]
     fn test_envelope_default_is_valid() {
         assert!(SafetyEnvelope::default().is_valid());
@@ -1240,6 +2579,74 @@ mod tests {
         assert!(!result.is_beneficial);
     }
 
+    #[test]
+    fn test_shadow_eval_pure_noop_against_applied_baseline_is_non_beneficial() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        assert_eq!(gov.submit(good_proposal("p1")), GovernorDecision::Approved);
+
+        // Knob value changes (128 -> 256) but the predicted metrics are
+        // byte-for-byte identical to the already-applied baseline: nothing
+        // actually improves, so this must be rejected as a no-op.
+        let mut p2 = good_proposal("p2");
+        p2.old_value = 128;
+        p2.new_value = 256;
+        p2.predicted = safe_metrics();
+
+        let result = gov.shadow_evaluate(&p2);
+        assert!(result.within_envelope);
+        assert!(!result.is_beneficial);
+
+        let decision = gov.submit(p2);
+        match &decision {
+            GovernorDecision::Rejected(RejectionReason::NonBeneficial) => {}
+            other => unreachable!("expected NonBeneficial rejection, got {other:?}"),
+        }
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(128));
+    }
+
+    #[test]
+    fn test_shadow_eval_strict_improvement_over_baseline_is_beneficial() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        assert_eq!(gov.submit(good_proposal("p1")), GovernorDecision::Approved);
+
+        // Lower latency than the applied baseline, every other metric flat.
+        let mut p2 = good_proposal("p2");
+        p2.old_value = 128;
+        p2.new_value = 256;
+        p2.predicted = PredictedMetrics {
+            latency_ms: 150,
+            ..safe_metrics()
+        };
+
+        let result = gov.shadow_evaluate(&p2);
+        assert!(result.within_envelope);
+        assert!(result.is_beneficial);
+        assert_eq!(gov.submit(p2), GovernorDecision::Approved);
+    }
+
+    #[test]
+    fn test_shadow_eval_mixed_tradeoff_within_envelope_is_beneficial() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        assert_eq!(gov.submit(good_proposal("p1")), GovernorDecision::Approved);
+
+        // Trades worse latency for better throughput, both still comfortably
+        // inside the envelope: one metric improves, so it's beneficial even
+        // though another regresses.
+        let mut p2 = good_proposal("p2");
+        p2.old_value = 128;
+        p2.new_value = 256;
+        p2.predicted = PredictedMetrics {
+            latency_ms: 350,       // worse than baseline's 200ms, still < 500ms cap
+            throughput_rps: 800,   // better than baseline's 500rps
+            ..safe_metrics()
+        };
+
+        let result = gov.shadow_evaluate(&p2);
+        assert!(result.within_envelope);
+        assert!(result.is_beneficial);
+        assert_eq!(gov.submit(p2), GovernorDecision::Approved);
+    }
+
     #[test]
     fn test_shadow_eval_with_invalid_envelope_rejects_safe_metrics() {
         let mut gov = OptimizationGovernor::with_defaults();
@@ -1269,11 +2676,51 @@ mod tests {
         let mut gov = OptimizationGovernor::with_defaults();
         let decision = gov.submit(good_proposal("p1"));
         assert_eq!(decision, GovernorDecision::Approved);
-        assert_eq!(gov.decision_log().len(), 1);
-        assert_eq!(gov.decision_log()[0].event_code, event_codes::GOV_003);
+        assert_eq!(gov.decision_log().len(), 2);
+        assert_eq!(gov.decision_log()[0].event_code, event_codes::GOV_002);
+        assert_eq!(gov.decision_log()[0].decision, GovernorDecision::ShadowOnly);
+        assert_eq!(gov.decision_log()[1].event_code, event_codes::GOV_003);
+        assert!(gov.decision_log()[0].seq < gov.decision_log()[1].seq);
         assert_eq!(gov.applied_count(), 1);
     }
 
+    #[test]
+    fn test_simulate_proposal_matches_submit_for_approvable_proposal_without_mutating_state() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let before = gov.snapshot();
+
+        let simulation = gov.simulate_proposal(&good_proposal("p1"));
+        assert_eq!(simulation.proposal_id, "p1");
+        assert_eq!(simulation.decision, GovernorDecision::Approved);
+        assert_eq!(gov.decision_log().len(), 0);
+        assert_eq!(gov.applied_count(), 0);
+        assert_eq!(gov.snapshot(), before);
+
+        let decision = gov.submit(good_proposal("p1"));
+        assert_eq!(decision, simulation.decision);
+    }
+
+    #[test]
+    fn test_simulate_proposal_matches_submit_for_envelope_violation_without_mutating_state() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let before = gov.snapshot();
+
+        let simulation = gov.simulate_proposal(&unsafe_proposal("p2"));
+        assert_eq!(simulation.proposal_id, "p2");
+        match &simulation.decision {
+            GovernorDecision::Rejected(RejectionReason::EnvelopeViolation(vs)) => {
+                assert!(!vs.is_empty(), "should have violation details");
+            }
+            other => unreachable!("expected EnvelopeViolation rejection, got {other:?}"),
+        }
+        assert_eq!(gov.decision_log().len(), 0);
+        assert_eq!(gov.applied_count(), 0);
+        assert_eq!(gov.snapshot(), before);
+
+        let decision = gov.submit(unsafe_proposal("p2"));
+        assert_eq!(decision, simulation.decision);
+    }
+
     #[test]
     fn test_submit_unsafe_proposal_rejected() {
         let mut gov = OptimizationGovernor::with_defaults();
@@ -1284,7 +2731,16 @@ mod tests {
             }
             other => unreachable!("expected EnvelopeViolation rejection, got {other:?}"),
         }
-        assert_eq!(gov.decision_log()[0].event_code, event_codes::GOV_004);
+        assert_eq!(gov.decision_log()[0].event_code, event_codes::GOV_002);
+        assert!(
+            gov.decision_log()[0]
+                .evidence
+                .as_ref()
+                .unwrap()
+                .contains("within_envelope=false")
+        );
+        assert_eq!(gov.decision_log()[1].event_code, event_codes::GOV_004);
+        assert!(gov.decision_log()[0].seq < gov.decision_log()[1].seq);
         assert_eq!(gov.applied_count(), 0);
     }
 
@@ -1303,7 +2759,7 @@ mod tests {
     #[test]
     fn test_submit_locked_knob_rejected() {
         let mut gov = OptimizationGovernor::with_defaults();
-        gov.lock_knob(RuntimeKnob::ConcurrencyLimit);
+        gov.lock_knob(RuntimeKnob::ConcurrencyLimit, 50, "policy-1".to_string());
         let decision = gov.submit(good_proposal("p4"));
         assert_eq!(
             decision,
@@ -1312,15 +2768,66 @@ mod tests {
     }
 
     #[test]
-    fn test_submit_invalid_proposal_rejected() {
+    fn test_submit_locked_knob_blocked_by_lower_or_equal_priority() {
         let mut gov = OptimizationGovernor::with_defaults();
-        let mut p = good_proposal("p5");
-        p.proposal_id = String::new();
-        let decision = gov.submit(p);
-        match &decision {
-            GovernorDecision::Rejected(RejectionReason::InvalidProposal(_)) => {}
-            other => unreachable!("expected InvalidProposal rejection, got {other:?}"),
-        }
+        // Autotuner proposals (rank 10) can't touch a knob locked at the
+        // Policy tier (rank 50), nor one locked at their own tier.
+        gov.lock_knob(RuntimeKnob::ConcurrencyLimit, 50, "policy-1".to_string());
+        let mut p = good_proposal("p_low_prio");
+        p.source = ProposalSource::Autotuner;
+        assert_eq!(
+            gov.submit(p),
+            GovernorDecision::Rejected(RejectionReason::KnobLocked)
+        );
+
+        gov.unlock_knob(RuntimeKnob::ConcurrencyLimit);
+        gov.lock_knob(
+            RuntimeKnob::ConcurrencyLimit,
+            ProposalSource::Autotuner.priority_rank(),
+            "autotuner-lock".to_string(),
+        );
+        let mut p_equal = good_proposal("p_equal_prio");
+        p_equal.source = ProposalSource::Autotuner;
+        assert_eq!(
+            gov.submit(p_equal),
+            GovernorDecision::Rejected(RejectionReason::KnobLocked)
+        );
+    }
+
+    #[test]
+    fn test_submit_higher_priority_proposal_preempts_lock() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.lock_knob(RuntimeKnob::ConcurrencyLimit, 50, "policy-1".to_string());
+
+        let mut p = good_proposal("p_high_prio");
+        p.source = ProposalSource::Human;
+        assert_eq!(p.source.priority_rank(), 100);
+
+        assert_eq!(gov.submit(p), GovernorDecision::Approved);
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(128));
+        // The lock itself is untouched by pre-emption -- a second
+        // lower-priority proposal is still blocked.
+        let still_blocked = OptimizationProposal {
+            old_value: 128,
+            new_value: 192,
+            ..good_proposal("p_still_blocked")
+        };
+        assert_eq!(
+            gov.submit(still_blocked),
+            GovernorDecision::Rejected(RejectionReason::KnobLocked)
+        );
+    }
+
+    #[test]
+    fn test_submit_invalid_proposal_rejected() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut p = good_proposal("p5");
+        p.proposal_id = String::new();
+        let decision = gov.submit(p);
+        match &decision {
+            GovernorDecision::Rejected(RejectionReason::InvalidProposal(_)) => {}
+            other => unreachable!("expected InvalidProposal rejection, got {other:?}"),
+        }
     }
 
     #[test]
@@ -1364,7 +2871,7 @@ mod tests {
     #[test]
     fn test_locked_knob_rejection_preserves_value_and_applied_set() {
         let mut gov = OptimizationGovernor::with_defaults();
-        gov.lock_knob(RuntimeKnob::ConcurrencyLimit);
+        gov.lock_knob(RuntimeKnob::ConcurrencyLimit, 50, "policy-1".to_string());
 
         let decision = gov.submit(good_proposal("p_locked_no_apply"));
 
@@ -1381,11 +2888,12 @@ mod tests {
         let envelope = default_envelope();
         let mut gov = OptimizationGovernor::new(envelope, BTreeMap::new());
 
-        gov.lock_knob(RuntimeKnob::RetryBudget);
+        gov.lock_knob(RuntimeKnob::RetryBudget, 50, "policy-1".to_string());
         gov.unlock_knob(RuntimeKnob::RetryBudget);
 
         assert_eq!(gov.knob_value(&RuntimeKnob::RetryBudget), None);
         assert!(gov.snapshot().knob_states.is_empty());
+        assert!(gov.snapshot().knob_locks.is_empty());
     }
 
     #[test]
@@ -1456,6 +2964,167 @@ mod tests {
         assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(64));
     }
 
+    #[test]
+    fn test_revert_evidence_carries_original_submitted_by() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut p = good_proposal("p_provenance");
+        p.submitted_by = "alice@example.com".to_string();
+        p.source = ProposalSource::Human;
+        assert_eq!(gov.submit(p), GovernorDecision::Approved);
+
+        let reverted = gov.live_check(&PredictedMetrics {
+            latency_ms: 999,
+            ..safe_metrics()
+        });
+        assert_eq!(reverted, vec!["p_provenance"]);
+
+        let record = gov
+            .decision_log()
+            .iter()
+            .find(|r| r.proposal_id == "p_provenance" && r.event_code == event_codes::GOV_005)
+            .expect("revert decision must be logged");
+        let evidence = record
+            .evidence
+            .as_ref()
+            .expect("revert must carry evidence");
+        assert!(evidence.contains("alice@example.com"));
+        assert!(evidence.contains("Human"));
+    }
+
+    #[test]
+    fn test_submit_rejects_provenance_change_on_already_applied_proposal() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut original = good_proposal("p_attribution");
+        original.submitted_by = "alice@example.com".to_string();
+        original.source = ProposalSource::Human;
+        assert_eq!(gov.submit(original), GovernorDecision::Approved);
+
+        // Same proposal_id, but claiming different provenance. Provenance
+        // cannot be altered post-submission: the duplicate-id guard rejects
+        // it just like any other attempt to resubmit an applied proposal.
+        let mut spoofed = good_proposal("p_attribution");
+        spoofed.old_value = 128;
+        spoofed.new_value = 256;
+        spoofed.submitted_by = "mallory@example.com".to_string();
+        spoofed.source = ProposalSource::Autotuner;
+
+        let decision = gov.submit(spoofed);
+        match &decision {
+            GovernorDecision::Rejected(RejectionReason::InvalidProposal(msg)) => {
+                assert!(msg.contains("already applied"));
+            }
+            other => unreachable!("expected duplicate InvalidProposal rejection, got {other:?}"),
+        }
+        assert_eq!(gov.applied_count(), 1);
+    }
+
+    fn rail_placement_mesh() -> crate::runtime::isolation_mesh::IsolationMesh {
+        use crate::runtime::isolation_mesh::{IsolationRail, IsolationRailLevel, MeshTopology};
+
+        let mut rails = BTreeMap::new();
+        rails.insert(
+            "shared-1".to_string(),
+            IsolationRail {
+                rail_id: "shared-1".to_string(),
+                level: IsolationRailLevel::Shared,
+                latency_overhead_us: 10,
+                capacity: 4,
+                cost_units: 1,
+            },
+        );
+        rails.insert(
+            "proc-1".to_string(),
+            IsolationRail {
+                rail_id: "proc-1".to_string(),
+                level: IsolationRailLevel::ProcessIsolated,
+                latency_overhead_us: 50,
+                capacity: 4,
+                cost_units: 3,
+            },
+        );
+        crate::runtime::isolation_mesh::IsolationMesh::new(MeshTopology { rails })
+            .expect("valid topology")
+    }
+
+    fn rail_placement_proposal(
+        id: &str,
+        workload_id: &str,
+        target_rail: &str,
+    ) -> RailPlacementProposal {
+        RailPlacementProposal {
+            proposal_id: id.to_string(),
+            change: KnobChange::RailPlacement {
+                workload_id: workload_id.to_string(),
+                target_rail: target_rail.to_string(),
+            },
+            predicted: safe_metrics(),
+            rationale: "Move latency-sensitive trusted workload to a stricter rail".to_string(),
+            trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
+        }
+    }
+
+    #[test]
+    fn test_submit_rail_placement_approved_elevates_workload_in_mesh() {
+        use crate::runtime::isolation_mesh::ElevationPolicy;
+
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut mesh = rail_placement_mesh();
+        let permissive = ElevationPolicy {
+            elevation_allowed: true,
+            max_target_level: crate::runtime::isolation_mesh::IsolationRailLevel::HardwareIsolated,
+            preserve_latency_budget: false,
+            latency_budget_us: 0,
+        };
+        mesh.place_workload("w1", "shared-1", permissive, 1)
+            .expect("place w1");
+
+        let proposal = rail_placement_proposal("p_rail_1", "w1", "proc-1");
+        let decision = gov.submit_rail_placement(proposal, &mut mesh, 2);
+
+        assert_eq!(decision, GovernorDecision::Approved);
+        assert_eq!(mesh.workloads()["w1"].current_rail_id, "proc-1");
+        assert_eq!(gov.mesh_decisions()[0].event_code, event_codes::GOV_012);
+    }
+
+    #[test]
+    fn test_submit_rail_placement_mesh_rejection_surfaces_as_governor_rejection() {
+        use crate::runtime::isolation_mesh::ElevationPolicy;
+
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut mesh = rail_placement_mesh();
+        let permissive = ElevationPolicy {
+            elevation_allowed: true,
+            max_target_level: crate::runtime::isolation_mesh::IsolationRailLevel::HardwareIsolated,
+            preserve_latency_budget: false,
+            latency_budget_us: 0,
+        };
+        // Placed directly on the stricter rail; requesting the less-strict
+        // rail is a demotion, which the mesh forbids outright.
+        mesh.place_workload("w1", "proc-1", permissive, 1)
+            .expect("place w1");
+
+        let proposal = rail_placement_proposal("p_rail_2", "w1", "shared-1");
+        let decision = gov.submit_rail_placement(proposal, &mut mesh, 2);
+
+        match &decision {
+            GovernorDecision::Rejected(RejectionReason::MeshRejected(msg)) => {
+                assert!(msg.contains("w1"));
+            }
+            other => unreachable!("expected MeshRejected, got {other:?}"),
+        }
+        assert_eq!(mesh.workloads()["w1"].current_rail_id, "proc-1");
+        let rec = &gov.mesh_decisions()[0];
+        assert_eq!(rec.event_code, event_codes::GOV_013);
+        assert!(
+            rec.evidence
+                .as_ref()
+                .unwrap()
+                .contains("ERR_GOV_MESH_REJECTED")
+        );
+    }
+
     #[test]
     fn test_submit_control_character_ids_are_logged_sanitized() {
         let mut gov = OptimizationGovernor::with_defaults();
@@ -1529,6 +3198,367 @@ mod tests {
             gov.knob_value(&RuntimeKnob::ConcurrencyLimit),
             Some(64) // original default
         );
+
+        let rec = gov
+            .decision_log()
+            .iter()
+            .rev()
+            .find(|r| r.event_code == event_codes::GOV_005)
+            .expect("revert should be recorded");
+        let evidence = rec.evidence.as_ref().expect("revert must carry evidence");
+        assert!(evidence.contains("latency"), "evidence: {evidence}");
+        assert!(evidence.contains("cap"), "evidence: {evidence}");
+    }
+
+    #[test]
+    fn test_live_check_revert_fails_closed_when_prior_value_is_missing() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.submit(good_proposal("p1"));
+        assert_eq!(gov.applied_count(), 1);
+
+        // Simulate the knob disappearing out from under an applied proposal.
+        gov.knob_states.remove(&RuntimeKnob::ConcurrencyLimit);
+
+        let bad_live = PredictedMetrics {
+            latency_ms: 999,
+            throughput_rps: 10,
+            error_rate_pct: 50.0,
+            memory_mb: 9999,
+        };
+        let reverted = gov.live_check(&bad_live);
+        assert!(
+            reverted.is_empty(),
+            "a failed revert must not be reported as reverted"
+        );
+        // The proposal stays applied since the revert could not be completed.
+        assert_eq!(gov.applied_count(), 1);
+
+        let rec = gov
+            .decision_log()
+            .iter()
+            .rev()
+            .find(|r| r.event_code == event_codes::GOV_005)
+            .expect("failed revert attempt should still be recorded");
+        let evidence = rec.evidence.as_ref().expect("must carry evidence");
+        assert!(evidence.contains(error_codes::ERR_GOV_REVERT_FAILED));
+    }
+
+    #[test]
+    fn hysteresis_transient_breach_does_not_revert() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_hysteresis(HysteresisConfig {
+            breach_threshold: 3,
+            recovery_threshold: 1,
+        });
+        gov.submit(good_proposal("p1"));
+        assert_eq!(gov.applied_count(), 1);
+
+        let bad_live = PredictedMetrics {
+            latency_ms: 999,
+            ..safe_metrics()
+        };
+
+        // A single transient breach must not trigger a revert.
+        let reverted = gov.live_check(&bad_live);
+        assert!(reverted.is_empty());
+        assert_eq!(gov.applied_count(), 1);
+        assert_eq!(
+            gov.knob_value(&RuntimeKnob::ConcurrencyLimit),
+            Some(128) // still applied
+        );
+
+        // Metric returns to envelope before the breach streak sustains.
+        let reverted = gov.live_check(&safe_metrics());
+        assert!(reverted.is_empty());
+        assert_eq!(gov.applied_count(), 1);
+    }
+
+    #[test]
+    fn hysteresis_sustained_breach_reverts_after_n_consecutive_checks() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_hysteresis(HysteresisConfig {
+            breach_threshold: 3,
+            recovery_threshold: 1,
+        });
+        gov.submit(good_proposal("p1"));
+
+        let bad_live = PredictedMetrics {
+            latency_ms: 999,
+            ..safe_metrics()
+        };
+
+        assert!(gov.live_check(&bad_live).is_empty());
+        assert!(gov.live_check(&bad_live).is_empty());
+        assert_eq!(gov.applied_count(), 1, "still applied after only 2 of 3 breaches");
+
+        let reverted = gov.live_check(&bad_live);
+        assert_eq!(reverted, vec!["p1"]);
+        assert_eq!(gov.applied_count(), 0);
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(64));
+    }
+
+    #[test]
+    fn hysteresis_recovery_requires_m_consecutive_in_envelope_checks() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_hysteresis(HysteresisConfig {
+            breach_threshold: 3,
+            recovery_threshold: 2,
+        });
+        gov.submit(good_proposal("p1"));
+
+        let bad_live = PredictedMetrics {
+            latency_ms: 999,
+            ..safe_metrics()
+        };
+
+        // Two breaches, one in-envelope check (not enough to clear the
+        // streak since recovery_threshold is 2), then one more breach should
+        // be enough to hit the 3-breach threshold and revert.
+        assert!(gov.live_check(&bad_live).is_empty());
+        assert!(gov.live_check(&bad_live).is_empty());
+        assert!(gov.live_check(&safe_metrics()).is_empty());
+        let reverted = gov.live_check(&bad_live);
+        assert_eq!(reverted, vec!["p1"]);
+    }
+
+    #[test]
+    fn hysteresis_default_config_matches_immediate_revert_behavior() {
+        assert_eq!(
+            HysteresisConfig::default(),
+            HysteresisConfig {
+                breach_threshold: 1,
+                recovery_threshold: 1,
+            }
+        );
+    }
+
+    // --- Per-knob cooldown tests ---
+
+    #[test]
+    fn cooldown_rejects_second_proposal_to_same_knob_within_window() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_knob_cooldown(10_000);
+
+        assert_eq!(
+            gov.submit_at(good_proposal("p1"), 1_000),
+            GovernorDecision::Approved
+        );
+
+        let second = OptimizationProposal {
+            old_value: 128,
+            ..good_proposal("p2")
+        };
+        let decision = gov.submit_at(second, 5_000);
+        match decision {
+            GovernorDecision::Rejected(RejectionReason::KnobCooldown(_)) => {}
+            other => panic!("expected KnobCooldown rejection, got {other:?}"),
+        }
+        assert_eq!(gov.applied_count(), 1);
+    }
+
+    #[test]
+    fn cooldown_allows_proposal_to_same_knob_after_window_elapses() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_knob_cooldown(10_000);
+
+        assert_eq!(
+            gov.submit_at(good_proposal("p1"), 1_000),
+            GovernorDecision::Approved
+        );
+
+        let second = OptimizationProposal {
+            old_value: 128,
+            new_value: 256,
+            predicted: PredictedMetrics {
+                latency_ms: 150,
+                ..safe_metrics()
+            },
+            ..good_proposal("p2")
+        };
+        let decision = gov.submit_at(second, 11_001);
+        assert_eq!(decision, GovernorDecision::Approved);
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(256));
+    }
+
+    #[test]
+    fn cooldown_rejection_carries_err_gov_knob_cooldown_error_code() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_knob_cooldown(10_000);
+        gov.submit_at(good_proposal("p1"), 1_000);
+
+        let second = OptimizationProposal {
+            old_value: 128,
+            ..good_proposal("p2")
+        };
+        let decision = gov.submit_at(second, 5_000);
+        let GovernorDecision::Rejected(reason) = decision else {
+            panic!("expected rejection, got {decision:?}");
+        };
+        assert_eq!(reason.error_code(), error_codes::ERR_GOV_KNOB_COOLDOWN);
+    }
+
+    #[test]
+    fn cooldown_is_disabled_by_default() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        assert_eq!(
+            gov.submit_at(good_proposal("p1"), 1_000),
+            GovernorDecision::Approved
+        );
+
+        let second = OptimizationProposal {
+            old_value: 128,
+            new_value: 256,
+            predicted: PredictedMetrics {
+                latency_ms: 150,
+                ..safe_metrics()
+            },
+            ..good_proposal("p2")
+        };
+        assert_eq!(gov.submit_at(second, 1_000), GovernorDecision::Approved);
+    }
+
+    #[test]
+    fn cooldown_started_by_revert_blocks_reproposal_until_window_elapses() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_knob_cooldown(5_000);
+
+        gov.submit_at(good_proposal("p1"), 1_000);
+        let bad_live = PredictedMetrics {
+            latency_ms: 999,
+            ..safe_metrics()
+        };
+        let reverted = gov.live_check_at(&bad_live, 2_000);
+        assert_eq!(reverted, vec!["p1"]);
+
+        let retry = OptimizationProposal {
+            old_value: 64,
+            ..good_proposal("p2")
+        };
+        let decision = gov.submit_at(retry.clone(), 4_000);
+        match decision {
+            GovernorDecision::Rejected(RejectionReason::KnobCooldown(_)) => {}
+            other => panic!("expected KnobCooldown rejection, got {other:?}"),
+        }
+
+        let decision = gov.submit_at(retry, 7_001);
+        assert_eq!(decision, GovernorDecision::Approved);
+    }
+
+    // --- Risk budget tests ---
+
+    fn borderline_metrics() -> PredictedMetrics {
+        PredictedMetrics {
+            latency_ms: 490, // 98% of the 500ms cap -- borderline but still safe
+            throughput_rps: 500,
+            error_rate_pct: 0.1,
+            memory_mb: 2048,
+        }
+    }
+
+    fn borderline_proposal(
+        id: &str,
+        knob: RuntimeKnob,
+        old_value: u64,
+        new_value: u64,
+    ) -> OptimizationProposal {
+        OptimizationProposal {
+            proposal_id: id.to_string(),
+            knob,
+            old_value,
+            new_value,
+            predicted: borderline_metrics(),
+            rationale: "Borderline risk adjustment".to_string(),
+            trace_id: format!("trace-{id}"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
+        }
+    }
+
+    #[test]
+    fn risk_budget_is_disabled_by_default() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.submit_at(
+            borderline_proposal("p1", RuntimeKnob::ConcurrencyLimit, 64, 128),
+            1_000,
+        );
+        let decision = gov.submit_at(
+            borderline_proposal("p2", RuntimeKnob::BatchSize, 128, 256),
+            2_000,
+        );
+        assert_eq!(decision, GovernorDecision::Approved);
+    }
+
+    #[test]
+    fn risk_budget_exhausted_by_consecutive_borderline_proposals_rejects_next() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_risk_budget(RiskBudget {
+            per_window: 100,
+            window_ms: 60_000,
+        });
+
+        let first = borderline_proposal("p1", RuntimeKnob::ConcurrencyLimit, 64, 128);
+        assert_eq!(gov.submit_at(first, 1_000), GovernorDecision::Approved);
+
+        let second = borderline_proposal("p2", RuntimeKnob::BatchSize, 128, 256);
+        let decision = gov.submit_at(second, 2_000);
+        match decision {
+            GovernorDecision::Rejected(RejectionReason::RiskBudgetExceeded(_)) => {}
+            other => panic!("expected RiskBudgetExceeded rejection, got {other:?}"),
+        }
+        assert_eq!(gov.applied_count(), 1);
+    }
+
+    #[test]
+    fn risk_budget_rejection_carries_err_gov_risk_budget_exceeded_error_code() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_risk_budget(RiskBudget {
+            per_window: 100,
+            window_ms: 60_000,
+        });
+        gov.submit_at(
+            borderline_proposal("p1", RuntimeKnob::ConcurrencyLimit, 64, 128),
+            1_000,
+        );
+
+        let decision = gov.submit_at(
+            borderline_proposal("p2", RuntimeKnob::BatchSize, 128, 256),
+            2_000,
+        );
+        let GovernorDecision::Rejected(reason) = decision else {
+            panic!("expected rejection, got {decision:?}");
+        };
+        assert_eq!(
+            reason.error_code(),
+            error_codes::ERR_GOV_RISK_BUDGET_EXCEEDED
+        );
+    }
+
+    #[test]
+    fn risk_budget_resets_once_window_rolls_over() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_risk_budget(RiskBudget {
+            per_window: 100,
+            window_ms: 60_000,
+        });
+
+        gov.submit_at(
+            borderline_proposal("p1", RuntimeKnob::ConcurrencyLimit, 64, 128),
+            1_000,
+        );
+        let blocked = gov.submit_at(
+            borderline_proposal("p2", RuntimeKnob::BatchSize, 128, 256),
+            2_000,
+        );
+        assert!(matches!(
+            blocked,
+            GovernorDecision::Rejected(RejectionReason::RiskBudgetExceeded(_))
+        ));
+
+        let after_rollover = gov.submit_at(
+            borderline_proposal("p3", RuntimeKnob::BatchSize, 128, 256),
+            61_001,
+        );
+        assert_eq!(after_rollover, GovernorDecision::Approved);
     }
 
     #[test]
@@ -1630,13 +3660,59 @@ mod tests {
         }
     }
 
+    // --- Decision ledger (INV-GOV-DETERMINISTIC-ORDER) ---
+
+    #[test]
+    fn test_decision_ledger_clean_export_verifies() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.submit(good_proposal("p1"));
+        gov.submit(good_proposal("p2"));
+        gov.submit(unsafe_proposal("p3"));
+
+        let ledger = gov.export_decision_ledger();
+        assert_eq!(ledger.entries.len(), gov.decision_log().len());
+        assert!(verify_decision_ledger(&ledger).is_ok());
+    }
+
+    #[test]
+    fn test_decision_ledger_rejects_reordered_entry() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.submit(good_proposal("p1"));
+        gov.submit(good_proposal("p2"));
+        gov.submit(good_proposal("p3"));
+
+        let mut ledger = gov.export_decision_ledger();
+        ledger.entries.swap(0, 1);
+
+        match verify_decision_ledger(&ledger) {
+            Err(LedgerError::ChainBroken { at, .. }) => assert_eq!(at, 0),
+            other => panic!("expected ChainBroken, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decision_ledger_rejects_mutated_decision() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.submit(good_proposal("p1"));
+        gov.submit(good_proposal("p2"));
+
+        let mut ledger = gov.export_decision_ledger();
+        ledger.entries[0].record.proposal_id = "tampered".to_string();
+
+        match verify_decision_ledger(&ledger) {
+            Err(LedgerError::EntryTampered { at }) => assert_eq!(at, 0),
+            other => panic!("expected EntryTampered, got {other:?}"),
+        }
+    }
+
     // --- Evidence on reject (INV-GOV-EVIDENCE-ON-REJECT) ---
 
     #[test]
     fn test_rejection_record_has_evidence() {
         let mut gov = OptimizationGovernor::with_defaults();
         gov.submit(unsafe_proposal("p1"));
-        let rec = &gov.decision_log()[0];
+        let rec = &gov.decision_log()[1];
+        assert_eq!(rec.event_code, event_codes::GOV_004);
         assert!(
             rec.evidence.is_some(),
             "rejected proposal must have evidence"
@@ -1687,6 +3763,223 @@ mod tests {
         assert_eq!(gov.envelope(), &new_env);
     }
 
+    // --- Gated envelope-update approval flow ---
+
+    #[test]
+    fn envelope_tightening_applies_with_standard_approval() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let tighter = SafetyEnvelope {
+            max_latency_ms: 400,
+            min_throughput_rps: 150,
+            max_error_rate_pct: 0.5,
+            max_memory_mb: 2048,
+        };
+
+        let proposal =
+            gov.propose_envelope_update(tighter.clone(), "reduce blast radius", "trace-1");
+        assert_eq!(proposal.change_kind, EnvelopeChangeKind::Tightening);
+        assert_eq!(proposal.required_level, ApprovalLevel::Standard);
+        assert_eq!(gov.envelope(), &SafetyEnvelope::default());
+
+        let outcome =
+            gov.approve_envelope_update(&proposal.proposal_id, ApprovalLevel::Standard, "trace-1");
+        assert_eq!(outcome, EnvelopeApprovalOutcome::Applied);
+        assert_eq!(gov.envelope(), &tighter);
+        assert!(gov.pending_envelope_proposals().is_empty());
+        assert_eq!(
+            gov.envelope_decisions().last().unwrap().event_code,
+            event_codes::GOV_006
+        );
+    }
+
+    #[test]
+    fn envelope_loosening_requires_elevated_approval() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let looser = SafetyEnvelope {
+            max_latency_ms: 1000,
+            ..SafetyEnvelope::default()
+        };
+
+        let proposal = gov.propose_envelope_update(looser.clone(), "raise latency cap", "trace-2");
+        assert_eq!(proposal.change_kind, EnvelopeChangeKind::Loosening);
+        assert_eq!(proposal.required_level, ApprovalLevel::Elevated);
+
+        // A standard approver cannot push through a loosening.
+        let rejected =
+            gov.approve_envelope_update(&proposal.proposal_id, ApprovalLevel::Standard, "trace-2");
+        assert_eq!(rejected, EnvelopeApprovalOutcome::InsufficientApprovalLevel);
+        assert_eq!(gov.envelope(), &SafetyEnvelope::default());
+        assert!(
+            gov.pending_envelope_proposals()
+                .contains_key(&proposal.proposal_id)
+        );
+        assert_eq!(
+            gov.envelope_decisions().last().unwrap().event_code,
+            event_codes::GOV_009
+        );
+
+        // The elevated approver succeeds on retry.
+        let applied =
+            gov.approve_envelope_update(&proposal.proposal_id, ApprovalLevel::Elevated, "trace-2");
+        assert_eq!(applied, EnvelopeApprovalOutcome::Applied);
+        assert_eq!(gov.envelope(), &looser);
+        assert!(gov.pending_envelope_proposals().is_empty());
+        assert_eq!(
+            gov.envelope_decisions().last().unwrap().event_code,
+            event_codes::GOV_006
+        );
+    }
+
+    #[test]
+    fn approve_envelope_update_reports_unknown_proposal() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let outcome =
+            gov.approve_envelope_update("no-such-proposal", ApprovalLevel::Elevated, "trace-3");
+        assert_eq!(outcome, EnvelopeApprovalOutcome::UnknownProposal);
+        assert!(gov.envelope_decisions().is_empty());
+    }
+
+    // --- A/B testing ---
+
+    #[test]
+    fn ab_test_declares_a_clear_winner_and_applies_it() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_ab_significance_margin(100.0);
+
+        let mut config_a = BTreeMap::new();
+        config_a.insert(RuntimeKnob::ConcurrencyLimit, 64);
+        let mut config_b = BTreeMap::new();
+        config_b.insert(RuntimeKnob::ConcurrencyLimit, 128);
+
+        gov.begin_ab_test(config_a, config_b, 0.5).unwrap();
+
+        let metrics_a = safe_metrics();
+        let metrics_b = PredictedMetrics {
+            latency_ms: 100,
+            throughput_rps: 900,
+            error_rate_pct: 0.05,
+            memory_mb: 1024,
+        };
+        let result = gov.conclude_ab_test(metrics_a, metrics_b).unwrap();
+
+        assert!(result.conclusive);
+        assert_eq!(result.winner, AbWinner::B);
+        assert!(result.score_b > result.score_a);
+        assert!(result.applied);
+        assert_eq!(result.decisions.len(), 1);
+        assert!(matches!(result.decisions[0], GovernorDecision::Approved));
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(128));
+    }
+
+    #[test]
+    fn ab_test_within_significance_margin_is_inconclusive_and_keeps_a() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_ab_significance_margin(10_000.0);
+
+        let mut config_a = BTreeMap::new();
+        config_a.insert(RuntimeKnob::ConcurrencyLimit, 64);
+        let mut config_b = BTreeMap::new();
+        config_b.insert(RuntimeKnob::ConcurrencyLimit, 128);
+
+        gov.begin_ab_test(config_a, config_b, 0.5).unwrap();
+
+        let metrics_a = safe_metrics();
+        let metrics_b = PredictedMetrics {
+            latency_ms: 100,
+            throughput_rps: 900,
+            error_rate_pct: 0.05,
+            memory_mb: 1024,
+        };
+        let result = gov.conclude_ab_test(metrics_a, metrics_b).unwrap();
+
+        assert!(!result.conclusive);
+        assert_eq!(result.winner, AbWinner::A);
+        // config_a matches the knob's current value, so nothing changes.
+        assert!(result.decisions.is_empty());
+        assert!(result.applied);
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(64));
+    }
+
+    #[test]
+    fn ab_test_winner_that_breaches_envelope_is_rejected_despite_winning() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        gov.set_ab_significance_margin(100.0);
+
+        let mut config_a = BTreeMap::new();
+        config_a.insert(RuntimeKnob::ConcurrencyLimit, 64);
+        let mut config_b = BTreeMap::new();
+        config_b.insert(RuntimeKnob::ConcurrencyLimit, 256);
+
+        gov.begin_ab_test(config_a, config_b, 0.5).unwrap();
+
+        let metrics_a = safe_metrics();
+        let metrics_b = PredictedMetrics {
+            latency_ms: 900, // exceeds the 500ms cap
+            throughput_rps: 5000,
+            error_rate_pct: 0.5,
+            memory_mb: 2000,
+        };
+        let result = gov.conclude_ab_test(metrics_a, metrics_b).unwrap();
+
+        assert!(result.conclusive);
+        assert_eq!(result.winner, AbWinner::B);
+        assert!(!result.applied);
+        assert_eq!(result.decisions.len(), 1);
+        assert!(matches!(
+            result.decisions[0],
+            GovernorDecision::Rejected(RejectionReason::EnvelopeViolation(_))
+        ));
+        // The knob must not have moved since the winner was rejected.
+        assert_eq!(gov.knob_value(&RuntimeKnob::ConcurrencyLimit), Some(64));
+    }
+
+    #[test]
+    fn begin_ab_test_rejects_an_out_of_range_split() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut config_a = BTreeMap::new();
+        config_a.insert(RuntimeKnob::ConcurrencyLimit, 64);
+        let mut config_b = BTreeMap::new();
+        config_b.insert(RuntimeKnob::ConcurrencyLimit, 128);
+
+        let err = gov.begin_ab_test(config_a, config_b, 1.5).unwrap_err();
+        assert_eq!(err.code(), error_codes::ERR_GOV_AB_INVALID_SPLIT);
+    }
+
+    #[test]
+    fn begin_ab_test_rejects_an_empty_config() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut config_b = BTreeMap::new();
+        config_b.insert(RuntimeKnob::ConcurrencyLimit, 128);
+
+        let err = gov
+            .begin_ab_test(BTreeMap::new(), config_b, 0.5)
+            .unwrap_err();
+        assert_eq!(err.code(), error_codes::ERR_GOV_AB_EMPTY_CONFIG);
+    }
+
+    #[test]
+    fn begin_ab_test_rejects_a_second_test_while_one_is_active() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let mut config_a = BTreeMap::new();
+        config_a.insert(RuntimeKnob::ConcurrencyLimit, 64);
+        let mut config_b = BTreeMap::new();
+        config_b.insert(RuntimeKnob::ConcurrencyLimit, 128);
+
+        gov.begin_ab_test(config_a.clone(), config_b.clone(), 0.5)
+            .unwrap();
+        let err = gov.begin_ab_test(config_a, config_b, 0.5).unwrap_err();
+        assert_eq!(err.code(), error_codes::ERR_GOV_AB_ALREADY_ACTIVE);
+    }
+
+    #[test]
+    fn conclude_ab_test_without_an_active_test_errors() {
+        let mut gov = OptimizationGovernor::with_defaults();
+        let err = gov
+            .conclude_ab_test(safe_metrics(), safe_metrics())
+            .unwrap_err();
+        assert_eq!(err.code(), error_codes::ERR_GOV_AB_NO_ACTIVE_TEST);
+    }
+
     // --- Serialization round-trip ---
 
     #[test]
@@ -1723,6 +4016,8 @@ mod tests {
             predicted: safe_metrics(),
             rationale: unicode_bomb.clone(),
             trace_id: unicode_bomb,
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gov.submit(proposal);
@@ -1753,6 +4048,8 @@ mod tests {
             predicted: safe_metrics(),
             rationale: massive_rationale,
             trace_id: "trace-memory-stress".to_string(),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gov.submit(proposal);
@@ -1782,6 +4079,8 @@ mod tests {
                 predicted: safe_metrics(),
                 rationale: format!("overflow boundary test {i}"),
                 trace_id: format!("trace-overflow-{i}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gov.submit(proposal);
@@ -1833,6 +4132,8 @@ mod tests {
                 predicted: metrics,
                 rationale: format!("contradictory metrics test {i}"),
                 trace_id: format!("trace-contradictory-{i}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             let decision = gov.submit(proposal);
@@ -1859,6 +4160,8 @@ mod tests {
                 predicted: safe_metrics(),
                 rationale: format!("rapid submission {i}"),
                 trace_id: format!("trace-rapid-{i:04}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
 
             gov.submit(proposal);
@@ -1898,6 +4201,8 @@ mod tests {
             },
             rationale: format!("control{control_chars}character{control_chars}test"),
             trace_id: format!("trace{control_chars}control"),
+            submitted_by: "test-harness".to_string(),
+            source: ProposalSource::Autotuner,
         };
 
         let decision = gov.submit(proposal);
@@ -1952,16 +4257,23 @@ mod tests {
     fn negative_optimization_governor_envelope_update_cascade_revert_logic() {
         let mut gov = OptimizationGovernor::with_defaults();
 
-        // Apply multiple proposals that are initially safe
+        // Apply multiple proposals that are initially safe, each shaving a
+        // little off latency so it stays strictly beneficial over the
+        // previous applied proposal on the same knob.
         for i in 0..5 {
             let proposal = OptimizationProposal {
                 proposal_id: format!("cascade-safe-{i}"),
                 knob: RuntimeKnob::ConcurrencyLimit,
                 old_value: 64 + i,
                 new_value: 64 + i + 1,
-                predicted: safe_metrics(),
+                predicted: PredictedMetrics {
+                    latency_ms: 200 - i,
+                    ..safe_metrics()
+                },
                 rationale: format!("safe cascade {i}"),
                 trace_id: format!("trace-cascade-{i}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             assert_eq!(gov.submit(proposal), GovernorDecision::Approved);
         }
@@ -2006,6 +4318,8 @@ mod tests {
                 predicted: safe_metrics(),
                 rationale: "short".to_string(),
                 trace_id: "t".to_string(),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
             // Long proposal ID
             OptimizationProposal {
@@ -2016,6 +4330,8 @@ mod tests {
                 predicted: safe_metrics(),
                 rationale: "long".repeat(1000),
                 trace_id: "trace".repeat(500),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             },
         ];
 
@@ -2048,6 +4364,8 @@ mod tests {
                 predicted: safe_metrics(),
                 rationale: format!("bulk generation {i}"),
                 trace_id: format!("trace-bulk-{i:06}"),
+                submitted_by: "test-harness".to_string(),
+                source: ProposalSource::Autotuner,
             };
             gov.submit(proposal);
         }
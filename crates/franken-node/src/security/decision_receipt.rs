@@ -7,7 +7,7 @@
 //! - JSON + CBOR export/import and query filtering
 //! - High-impact action receipt enforcement
 
-use std::collections::{BTreeSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fs::OpenOptions;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
@@ -26,7 +26,7 @@ use frankenengine_node::crypto::{
     HybridCriticalAnchorSignature, HybridCriticalAnchorVerification, SignatureScheme,
     sign_hybrid_critical_anchor, validate_crypto_suite, verify_hybrid_critical_anchor,
 };
-use frankenengine_node::runtime::clock;
+use frankenengine_node::runtime::clock::{self, Clock};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sha2::{Digest, Sha256};
@@ -34,6 +34,7 @@ use uuid::Uuid;
 
 use crate::capacity_defaults::aliases::MAX_RECEIPT_CHAIN;
 use crate::lock_utils;
+use crate::policy::policy_explainer::PolicyExplanation;
 use crate::push_bounded;
 
 /// Process-local receipt persistence lock.
@@ -142,6 +143,24 @@ pub struct Receipt {
     pub confidence: f64,
     pub rollback_command: String,
     pub previous_receipt_hash: Option<String>,
+    /// Structured policy-evaluation trace backing `rationale`, if the caller
+    /// had a [`PolicyExplanation`] on hand when the receipt was issued.
+    /// Absent on receipts minted before this field existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale_trace: Option<RationaleTrace>,
+}
+
+/// Links a receipt's free-text `rationale` to the structured policy
+/// evaluation that produced it, so an auditor can trace a decision back to
+/// the exact matched rules and their outcomes instead of trusting prose.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RationaleTrace {
+    /// Id of the rule that ultimately decided the outcome, surfaced up front
+    /// so renderers don't need to walk `policy_explanation` to show it.
+    pub deciding_rule_id: String,
+    /// Full structured explanation (diagnostic + guarantee sections, blocked
+    /// alternatives) that produced this decision.
+    pub policy_explanation: PolicyExplanation,
 }
 
 /// Signed receipt with hash-chain evidence.
@@ -154,6 +173,31 @@ pub struct SignedReceipt {
     pub signature: String,
 }
 
+/// One field of a [`RedactedReceipt`]: either its plaintext value, or only
+/// the Merkle leaf commitment to a value that was intentionally hidden.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RedactedField {
+    Revealed { value: Value },
+    Redacted { commitment: String },
+}
+
+/// A receipt with selected fields redacted for external sharing.
+///
+/// Each field is either revealed in full or replaced by its Merkle leaf
+/// commitment. [`verify_redacted`] recomputes the same Merkle root from
+/// whatever mix of revealed values and commitments is present, so redacting
+/// fewer or more fields never changes what the signature proves -- only
+/// what the holder can see. Produced by [`redact_fields`] from a
+/// [`SignedReceipt`] that was signed with [`sign_receipt_redactable`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedactedReceipt {
+    pub fields: BTreeMap<String, RedactedField>,
+    pub merkle_root: String,
+    pub signer_key_id: String,
+    pub chain_hash: String,
+    pub signature: String,
+}
+
 /// Query filter for exporting receipt subsets.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReceiptQuery {
@@ -327,6 +371,8 @@ pub enum ReceiptError {
         field: &'static str,
         reason: &'static str,
     },
+    #[error("redaction field '{field}' is not a top-level field of this receipt")]
+    UnknownRedactionField { field: String },
     #[error("unsupported format: {0}")]
     UnsupportedFormat(String),
     #[error("internal error: {0}")]
@@ -334,7 +380,13 @@ pub enum ReceiptError {
 }
 
 impl Receipt {
-    /// Construct a new receipt with canonical input/output hashes.
+    /// Construct a new receipt with canonical input/output hashes, sourcing
+    /// the issuance timestamp from the ambient wall clock
+    /// ([`clock::wall_now`]).
+    ///
+    /// Prefer [`Receipt::new_with_clock`] in tests or anywhere else the
+    /// issuance time must be deterministic or injected rather than read
+    /// from ambient state.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         action_name: &str,
@@ -348,13 +400,49 @@ impl Receipt {
         policy_rule_chain: Vec<String>,
         confidence: f64,
         rollback_command: &str,
+    ) -> Result<Self, ReceiptError> {
+        Self::new_with_clock(
+            action_name,
+            actor_identity,
+            audience,
+            input,
+            output,
+            decision,
+            rationale,
+            evidence_refs,
+            policy_rule_chain,
+            confidence,
+            rollback_command,
+            &clock::SystemClock,
+        )
+    }
+
+    /// Construct a new receipt with canonical input/output hashes, sourcing
+    /// the issuance timestamp from the given `clock` rather than ambient
+    /// wall-clock state (INV-AA-NO-AMBIENT). Pass
+    /// [`frankenengine_node::runtime::clock::TestClock`] in tests to get a
+    /// reproducible `timestamp`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_clock(
+        action_name: &str,
+        actor_identity: &str,
+        audience: &str,
+        input: &impl Serialize,
+        output: &impl Serialize,
+        decision: Decision,
+        rationale: &str,
+        evidence_refs: Vec<String>,
+        policy_rule_chain: Vec<String>,
+        confidence: f64,
+        rollback_command: &str,
+        clock: &dyn Clock,
     ) -> Result<Self, ReceiptError> {
         validate_confidence(confidence)?;
         let receipt = Self {
             receipt_id: Uuid::now_v7().to_string(),
             action_name: action_name.to_string(),
             actor_identity: actor_identity.to_string(),
-            timestamp: clock::wall_now().to_rfc3339(),
+            timestamp: clock.now().to_rfc3339(),
             signature_version: DECISION_RECEIPT_SIGNATURE_VERSION.to_string(),
             crypto_suite: DECISION_RECEIPT_CRYPTO_SUITE.to_string(),
             nonce: Uuid::now_v7().simple().to_string(),
@@ -368,6 +456,7 @@ impl Receipt {
             confidence,
             rollback_command: rollback_command.to_string(),
             previous_receipt_hash: None,
+            rationale_trace: None,
         };
         validate_receipt_payload_fields(&receipt)?;
         Ok(receipt)
@@ -379,6 +468,14 @@ impl Receipt {
         self
     }
 
+    /// Attach a structured [`RationaleTrace`] linking this receipt's
+    /// free-text `rationale` to the policy evaluation that produced it.
+    pub fn with_rationale_trace(mut self, rationale_trace: RationaleTrace) -> Result<Self, ReceiptError> {
+        validate_receipt_text_field("rationale_trace.deciding_rule_id", &rationale_trace.deciding_rule_id)?;
+        self.rationale_trace = Some(rationale_trace);
+        Ok(self)
+    }
+
     /// Validate timestamp monotonicity against previous receipt.
     ///
     /// Ensures this receipt's timestamp is strictly after the previous receipt's timestamp
@@ -436,7 +533,7 @@ impl Receipt {
         rollback_command: &str,
         previous_receipt: Option<&Receipt>,
     ) -> Result<Self, ReceiptError> {
-        let mut receipt = Self::new(
+        Self::new_with_monotonic_timestamp_with_clock(
             action_name,
             actor_identity,
             audience,
@@ -448,6 +545,43 @@ impl Receipt {
             policy_rule_chain,
             confidence,
             rollback_command,
+            previous_receipt,
+            &clock::SystemClock,
+        )
+    }
+
+    /// [`Receipt::new_with_monotonic_timestamp`], sourcing the issuance
+    /// timestamp from the given `clock` rather than ambient wall-clock state
+    /// (INV-AA-NO-AMBIENT).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_monotonic_timestamp_with_clock(
+        action_name: &str,
+        actor_identity: &str,
+        audience: &str,
+        input: &impl Serialize,
+        output: &impl Serialize,
+        decision: Decision,
+        rationale: &str,
+        evidence_refs: Vec<String>,
+        policy_rule_chain: Vec<String>,
+        confidence: f64,
+        rollback_command: &str,
+        previous_receipt: Option<&Receipt>,
+        clock: &dyn Clock,
+    ) -> Result<Self, ReceiptError> {
+        let mut receipt = Self::new_with_clock(
+            action_name,
+            actor_identity,
+            audience,
+            input,
+            output,
+            decision,
+            rationale,
+            evidence_refs,
+            policy_rule_chain,
+            confidence,
+            rollback_command,
+            clock,
         )?;
 
         // SECURITY: If there's a previous receipt, validate monotonic timestamp ordering
@@ -548,6 +682,84 @@ pub fn sign_receipt(
     })
 }
 
+/// Sign a receipt over a Merkle commitment of its fields instead of the
+/// full canonical JSON payload [`sign_receipt`] uses.
+///
+/// The resulting [`SignedReceipt`] can later be passed to
+/// [`redact_fields`] to hide individual field values for external sharing
+/// while [`verify_redacted`] still validates the signature and proves the
+/// hidden fields existed. Verify an unredacted copy with
+/// [`verify_receipt_redactable`], not [`verify_receipt`] -- the two
+/// schemes commit to different preimages and are not interchangeable.
+pub fn sign_receipt_redactable(
+    receipt: &Receipt,
+    signing_key: &Ed25519PrivateKey,
+) -> Result<SignedReceipt, ReceiptError> {
+    validate_receipt_payload_fields(receipt)?;
+    validate_confidence(receipt.confidence)?;
+    validate_signature_version(&receipt.signature_version)?;
+    validate_crypto_suite_binding(&receipt.signature_version, &receipt.crypto_suite)?;
+    let payload = receipt_field_commitment(receipt)?;
+
+    let secret_key_bytes = signing_key.to_bytes();
+    let signature_bytes =
+        Ed25519Scheme::sign_raw(&secret_key_bytes, payload.as_bytes()).map_err(|source| {
+            ReceiptError::Internal(format!("failed to sign decision receipt: {source}"))
+        })?;
+    let signature_b64 = BASE64_STANDARD.encode(signature_bytes);
+    let chain_hash = compute_chain_hash(receipt.previous_receipt_hash.as_deref(), &payload);
+    let signer_key_id = signing_key_id(&signing_key.verifying_key());
+
+    Ok(SignedReceipt {
+        receipt: receipt.clone(),
+        signer_key_id,
+        chain_hash,
+        signature: signature_b64,
+    })
+}
+
+/// Verify a [`SignedReceipt`] produced by [`sign_receipt_redactable`].
+///
+/// Mirrors [`verify_receipt`] but recomputes the Merkle field commitment
+/// instead of the full canonical JSON payload as the signed preimage.
+pub fn verify_receipt_redactable(
+    signed: &SignedReceipt,
+    public_key: &Ed25519PublicKey,
+) -> Result<bool, ReceiptError> {
+    validate_receipt_payload_fields(&signed.receipt)?;
+    validate_confidence(signed.receipt.confidence)?;
+    validate_signature_version(&signed.receipt.signature_version)?;
+    validate_crypto_suite_binding(
+        &signed.receipt.signature_version,
+        &signed.receipt.crypto_suite,
+    )?;
+
+    let expected_key_id = signing_key_id(public_key);
+    if !crate::security::constant_time::ct_eq(&signed.signer_key_id, &expected_key_id) {
+        return Ok(false);
+    }
+
+    let payload = receipt_field_commitment(&signed.receipt)?;
+    let sig_bytes = BASE64_STANDARD
+        .decode(&signed.signature)
+        .map_err(ReceiptError::SignatureDecode)?;
+    let signature = Signature::from_slice(&sig_bytes).map_err(|_| ReceiptError::SignatureBytes)?;
+
+    if public_key
+        .verify_strict(payload.as_bytes(), &signature)
+        .is_err()
+    {
+        return Ok(false);
+    }
+
+    let expected_chain_hash =
+        compute_chain_hash(signed.receipt.previous_receipt_hash.as_deref(), &payload);
+    Ok(crate::security::constant_time::ct_eq(
+        &expected_chain_hash,
+        &signed.chain_hash,
+    ))
+}
+
 /// Dual-sign the receipt chain hash as a critical durability anchor.
 ///
 /// This leaves the canonical receipt payload and legacy Ed25519 receipt
@@ -662,6 +874,93 @@ pub fn verify_receipt_with_audience(
     Ok(true)
 }
 
+/// Hide `fields` in `signed`, replacing each with its Merkle leaf
+/// commitment while leaving every other field in plain sight.
+///
+/// `signed` must have been produced by [`sign_receipt_redactable`] --
+/// receipts signed with [`sign_receipt`] commit to their full canonical
+/// JSON, not a per-field Merkle root, so redacting a field from one would
+/// invalidate the signature instead of merely hiding data. Fails closed if
+/// `fields` names something that is not one of the receipt's top-level
+/// JSON fields.
+pub fn redact_fields(
+    signed: &SignedReceipt,
+    fields: &[&str],
+) -> Result<RedactedReceipt, ReceiptError> {
+    let entries = receipt_field_entries(&signed.receipt)?;
+    let known_fields: BTreeSet<&str> = entries.iter().map(|(field, _)| field.as_str()).collect();
+    for field in fields {
+        if !known_fields.contains(field) {
+            return Err(ReceiptError::UnknownRedactionField {
+                field: (*field).to_string(),
+            });
+        }
+    }
+    let redact_set: BTreeSet<&str> = fields.iter().copied().collect();
+
+    let mut redacted_fields = BTreeMap::new();
+    let mut leaves = Vec::with_capacity(entries.len());
+    for (field, value) in &entries {
+        let leaf = merkle_leaf_hash(field, value)?;
+        leaves.push(leaf.clone());
+        let redacted_field = if redact_set.contains(field.as_str()) {
+            RedactedField::Redacted { commitment: leaf }
+        } else {
+            RedactedField::Revealed {
+                value: value.clone(),
+            }
+        };
+        redacted_fields.insert(field.clone(), redacted_field);
+    }
+
+    Ok(RedactedReceipt {
+        fields: redacted_fields,
+        merkle_root: merkle_root_from_leaves(&leaves),
+        signer_key_id: signed.signer_key_id.clone(),
+        chain_hash: signed.chain_hash.clone(),
+        signature: signed.signature.clone(),
+    })
+}
+
+/// Verify a [`RedactedReceipt`]: recompute the Merkle root from whatever
+/// mix of revealed values and commitments is present and check the
+/// Ed25519 signature over that root.
+///
+/// Returns `true` only if every revealed field and every commitment are
+/// consistent with the root that was actually signed, proving the redacted
+/// fields existed in the signed receipt without revealing their values.
+pub fn verify_redacted(
+    redacted: &RedactedReceipt,
+    public_key: &Ed25519PublicKey,
+) -> Result<bool, ReceiptError> {
+    let mut leaves = Vec::with_capacity(redacted.fields.len());
+    for (field, value) in &redacted.fields {
+        let leaf = match value {
+            RedactedField::Revealed { value } => merkle_leaf_hash(field, value)?,
+            RedactedField::Redacted { commitment } => commitment.clone(),
+        };
+        leaves.push(leaf);
+    }
+    let recomputed_root = merkle_root_from_leaves(&leaves);
+    if !crate::security::constant_time::ct_eq(&recomputed_root, &redacted.merkle_root) {
+        return Ok(false);
+    }
+
+    let expected_key_id = signing_key_id(public_key);
+    if !crate::security::constant_time::ct_eq(&redacted.signer_key_id, &expected_key_id) {
+        return Ok(false);
+    }
+
+    let sig_bytes = BASE64_STANDARD
+        .decode(&redacted.signature)
+        .map_err(ReceiptError::SignatureDecode)?;
+    let signature = Signature::from_slice(&sig_bytes).map_err(|_| ReceiptError::SignatureBytes)?;
+
+    Ok(public_key
+        .verify_strict(redacted.merkle_root.as_bytes(), &signature)
+        .is_ok())
+}
+
 /// Deterministic key ID shared with release-verification trust roots.
 #[must_use]
 pub fn signing_key_id(public_key: &Ed25519PublicKey) -> String {
@@ -687,6 +986,34 @@ pub fn append_signed_receipt(
     Ok(signed)
 }
 
+/// Sign and append a batch of receipts with a single signing key, preserving
+/// the same `previous_receipt_hash`/`chain_hash` linkage sequential
+/// [`append_signed_receipt`] calls would produce.
+///
+/// Atomic: receipts are signed into a scratch buffer first, so if any
+/// receipt in `receipts` fails to sign, `chain` is left completely
+/// unchanged. On success, every signed receipt is pushed onto `chain` in
+/// order and the newly appended receipts are returned.
+pub fn append_signed_receipts_batch(
+    chain: &mut Vec<SignedReceipt>,
+    receipts: Vec<Receipt>,
+    signing_key: &Ed25519PrivateKey,
+) -> Result<Vec<SignedReceipt>, ReceiptError> {
+    let mut previous = chain.last().map(|signed| signed.chain_hash.clone());
+    let mut signed_batch = Vec::with_capacity(receipts.len());
+    for receipt in receipts {
+        let signed = sign_receipt(&receipt.with_previous_hash(previous), signing_key)?;
+        previous = Some(signed.chain_hash.clone());
+        signed_batch.push(signed);
+    }
+
+    for signed in &signed_batch {
+        push_bounded(chain, signed.clone(), MAX_RECEIPT_CHAIN);
+    }
+
+    Ok(signed_batch)
+}
+
 /// Verify append-only hash-chain linkage and deterministic hash material.
 pub fn verify_hash_chain(receipts: &[SignedReceipt]) -> Result<(), ReceiptError> {
     for (idx, signed) in receipts.iter().enumerate() {
@@ -899,6 +1226,28 @@ pub fn render_receipts_markdown(receipts: &[SignedReceipt]) -> String {
             receipt.receipt.timestamp
         ));
     }
+
+    let traced: Vec<&SignedReceipt> = receipts
+        .iter()
+        .filter(|receipt| receipt.receipt.rationale_trace.is_some())
+        .collect();
+    if !traced.is_empty() {
+        output.push_str("\n## Rationale Traces\n\n");
+        for receipt in traced {
+            let trace = receipt
+                .receipt
+                .rationale_trace
+                .as_ref()
+                .expect("filtered to Some above");
+            output.push_str(&format!(
+                "- **{}**: deciding rule `{}` -- {}\n",
+                receipt.receipt.receipt_id,
+                trace.deciding_rule_id,
+                trace.policy_explanation.action_summary
+            ));
+        }
+    }
+
     output
 }
 
@@ -1189,6 +1538,81 @@ fn hash_canonical_json(value: &impl Serialize) -> Result<String, ReceiptError> {
     Ok(sha256_hex(canonical.as_bytes()))
 }
 
+/// Canonicalized top-level `(field, value)` pairs of a receipt, sorted by
+/// field name. Backs both [`receipt_field_commitment`] (the signed
+/// preimage) and [`redact_fields`] (which needs to hash one field at a
+/// time).
+fn receipt_field_entries(receipt: &Receipt) -> Result<Vec<(String, Value)>, ReceiptError> {
+    ensure_canonical_json_depth(receipt)?;
+    let serialized = serde_json::to_value(receipt).map_err(ReceiptError::CanonicalJson)?;
+    match canonicalize_value(serialized) {
+        Value::Object(map) => Ok(map.into_iter().collect()),
+        _ => Err(ReceiptError::Internal(
+            "receipt did not serialize to a JSON object".to_string(),
+        )),
+    }
+}
+
+/// Domain-separated Merkle leaf hash for one receipt field.
+fn merkle_leaf_hash(field: &str, value: &Value) -> Result<String, ReceiptError> {
+    let value_json = serde_json::to_string(value).map_err(ReceiptError::CanonicalJson)?;
+    let mut hasher = Sha256::new();
+    hasher.update(b"decision_receipt_redaction_leaf_v1:");
+    hasher.update(u64::try_from(field.len()).unwrap_or(u64::MAX).to_le_bytes());
+    hasher.update(field.as_bytes());
+    hasher.update(
+        u64::try_from(value_json.len())
+            .unwrap_or(u64::MAX)
+            .to_le_bytes(),
+    );
+    hasher.update(value_json.as_bytes());
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Domain-separated Merkle interior-node hash.
+fn merkle_pair_hash(left: &str, right: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"decision_receipt_redaction_node_v1:");
+    hasher.update(u64::try_from(left.len()).unwrap_or(u64::MAX).to_le_bytes());
+    hasher.update(left.as_bytes());
+    hasher.update(u64::try_from(right.len()).unwrap_or(u64::MAX).to_le_bytes());
+    hasher.update(right.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Fold leaf hashes into a single Merkle root. A single leaf's root is the
+/// leaf itself; an odd level duplicates its last node, mirroring the
+/// control-plane MMR proof tree in `mmr_proofs.rs`.
+fn merkle_root_from_leaves(leaves: &[String]) -> String {
+    if leaves.is_empty() {
+        return sha256_hex(b"decision_receipt_redaction_empty_v1");
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(level.last().cloned().expect("level is non-empty"));
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| merkle_pair_hash(&pair[0], &pair[1]))
+            .collect();
+    }
+    level.into_iter().next().expect("level is non-empty")
+}
+
+/// Merkle commitment over a receipt's top-level fields, used as the signed
+/// preimage for [`sign_receipt_redactable`] so that [`redact_fields`] can
+/// later hide individual field values while [`verify_redacted`] still
+/// proves they were part of what was signed.
+pub fn receipt_field_commitment(receipt: &Receipt) -> Result<String, ReceiptError> {
+    let entries = receipt_field_entries(receipt)?;
+    let leaves = entries
+        .iter()
+        .map(|(field, value)| merkle_leaf_hash(field, value))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(merkle_root_from_leaves(&leaves))
+}
+
 fn canonical_json(value: &impl Serialize) -> Result<String, ReceiptError> {
     // bd-0u14n: bound nesting depth FAIL-CLOSED before the recursive serialization runs.
     // `serde_json::to_value` and `canonicalize_value` both descend once per nesting level with
@@ -1415,6 +1839,72 @@ mod tests {
         .expect("receipt construction")
     }
 
+    fn make_receipt_with_clock(
+        action_name: &str,
+        decision: Decision,
+        clock: &dyn Clock,
+    ) -> Receipt {
+        Receipt::new_with_clock(
+            action_name,
+            "control-plane@prod",
+            "franken-node-control-plane",
+            &json!({"z": 1, "a": 2}),
+            &json!({"result": "ok"}),
+            decision,
+            "policy gate evaluated",
+            vec!["ledger-001".to_string()],
+            vec!["rule-A".to_string(), "rule-B".to_string()],
+            0.91,
+            "franken-node trust release --incident INC-001",
+            clock,
+        )
+        .expect("receipt construction")
+    }
+
+    #[test]
+    fn receipts_built_with_the_same_fixed_clock_have_identical_timestamps() {
+        use chrono::TimeZone;
+        use frankenengine_node::runtime::clock::TestClock;
+
+        let fixed_time = Utc.with_ymd_and_hms(2030, 3, 14, 1, 59, 26).unwrap();
+        let clock = TestClock::new(fixed_time);
+
+        let first = make_receipt_with_clock("quarantine", Decision::Approved, &clock);
+        let second = make_receipt_with_clock("revocation", Decision::Denied, &clock);
+
+        assert_eq!(first.timestamp, fixed_time.to_rfc3339());
+        assert_eq!(second.timestamp, fixed_time.to_rfc3339());
+        assert_eq!(first.timestamp, second.timestamp);
+    }
+
+    #[test]
+    fn chain_hashes_are_reproducible_with_a_fixed_clock() {
+        use chrono::TimeZone;
+        use frankenengine_node::runtime::clock::TestClock;
+
+        let fixed_time = Utc.with_ymd_and_hms(2030, 3, 14, 1, 59, 26).unwrap();
+        let signing_key = demo_signing_key();
+
+        let build_chain = || {
+            let clock = TestClock::new(fixed_time);
+            let mut chain = Vec::new();
+            let first = make_receipt_with_clock("quarantine", Decision::Approved, &clock);
+            append_signed_receipt(&mut chain, first, &signing_key).expect("first append");
+            let second = make_receipt_with_clock("revocation", Decision::Denied, &clock);
+            append_signed_receipt(&mut chain, second, &signing_key).expect("second append");
+            chain
+        };
+
+        let chain_a = build_chain();
+        let chain_b = build_chain();
+
+        assert_eq!(chain_a.len(), 2);
+        assert_eq!(
+            chain_a.iter().map(|r| &r.chain_hash).collect::<Vec<_>>(),
+            chain_b.iter().map(|r| &r.chain_hash).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn canonical_json_sorts_keys() {
         let canonical = canonical_json(&json!({"b": 2, "a": 1})).expect("canonical JSON");
@@ -1542,6 +2032,128 @@ mod tests {
         assert!(verified);
     }
 
+    #[test]
+    fn redacted_receipt_with_no_fields_hidden_verifies() {
+        let key = demo_signing_key();
+        let public_key = key.verifying_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        assert!(verify_receipt_redactable(&signed, &public_key).expect("verify"));
+
+        let redacted = redact_fields(&signed, &[]).expect("redact");
+        assert!(verify_redacted(&redacted, &public_key).expect("verify redacted"));
+    }
+
+    #[test]
+    fn redact_fields_hides_input_and_output_hashes_but_still_verifies() {
+        let key = demo_signing_key();
+        let public_key = key.verifying_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        let redacted =
+            redact_fields(&signed, &["input_hash", "output_hash"]).expect("redact fields");
+
+        assert!(verify_redacted(&redacted, &public_key).expect("verify redacted"));
+        match redacted.fields.get("input_hash").expect("input_hash field") {
+            RedactedField::Redacted { commitment } => assert!(!commitment.is_empty()),
+            RedactedField::Revealed { .. } => panic!("input_hash should be redacted"),
+        }
+        match redacted
+            .fields
+            .get("output_hash")
+            .expect("output_hash field")
+        {
+            RedactedField::Redacted { commitment } => assert!(!commitment.is_empty()),
+            RedactedField::Revealed { .. } => panic!("output_hash should be redacted"),
+        }
+    }
+
+    #[test]
+    fn redacted_fields_reveal_nothing_about_the_hidden_value() {
+        let key = demo_signing_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        let redacted = redact_fields(&signed, &["input_hash"]).expect("redact");
+        let RedactedField::Redacted { commitment } =
+            redacted.fields.get("input_hash").expect("input_hash field")
+        else {
+            panic!("input_hash should be redacted");
+        };
+
+        assert_ne!(commitment, &receipt.input_hash);
+        assert!(!commitment.contains(receipt.input_hash.as_str()));
+    }
+
+    #[test]
+    fn redact_fields_rejects_unknown_field_name() {
+        let key = demo_signing_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        let err = redact_fields(&signed, &["not_a_real_field"]).unwrap_err();
+        assert!(matches!(
+            err,
+            ReceiptError::UnknownRedactionField { field } if field == "not_a_real_field"
+        ));
+    }
+
+    #[test]
+    fn verify_redacted_rejects_forged_commitment() {
+        let key = demo_signing_key();
+        let public_key = key.verifying_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        let mut redacted = redact_fields(&signed, &["input_hash"]).expect("redact");
+        match redacted
+            .fields
+            .get_mut("input_hash")
+            .expect("input_hash field")
+        {
+            RedactedField::Redacted { commitment } => {
+                *commitment = "0".repeat(commitment.len());
+            }
+            RedactedField::Revealed { .. } => panic!("input_hash should be redacted"),
+        }
+
+        assert!(!verify_redacted(&redacted, &public_key).expect("verify redacted"));
+    }
+
+    #[test]
+    fn verify_redacted_rejects_forged_revealed_value() {
+        let key = demo_signing_key();
+        let public_key = key.verifying_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        let mut redacted = redact_fields(&signed, &[]).expect("redact");
+        match redacted
+            .fields
+            .get_mut("actor_identity")
+            .expect("actor_identity field")
+        {
+            RedactedField::Revealed { value } => {
+                *value = json!("someone-else");
+            }
+            RedactedField::Redacted { .. } => panic!("actor_identity should be revealed"),
+        }
+
+        assert!(!verify_redacted(&redacted, &public_key).expect("verify redacted"));
+    }
+
+    #[test]
+    fn receipt_signed_for_redaction_does_not_verify_with_verify_receipt() {
+        let key = demo_signing_key();
+        let public_key = key.verifying_key();
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let signed = sign_receipt_redactable(&receipt, &key).expect("sign");
+
+        assert!(!verify_receipt(&signed, &public_key).expect("verify"));
+    }
+
     #[test]
     fn receipt_critical_anchor_hybrid_signature_roundtrips() {
         let key = demo_signing_key();
@@ -1819,6 +2431,63 @@ mod tests {
         assert!(matches!(err, ReceiptError::HashChainMismatch { .. }));
     }
 
+    #[test]
+    fn append_signed_receipts_batch_matches_sequential_individual_appends() {
+        let key = demo_signing_key();
+        // Build each receipt once so both paths sign identical content
+        // (receipt ids, nonces and timestamps differ between constructions).
+        let receipts = vec![
+            make_receipt("quarantine", Decision::Approved),
+            make_receipt("deployment_promotion", Decision::Escalated),
+            make_receipt("revocation", Decision::Denied),
+        ];
+
+        let mut batch_chain = Vec::new();
+        let batch_result =
+            append_signed_receipts_batch(&mut batch_chain, receipts.clone(), &key)
+                .expect("batch append");
+
+        let mut sequential_chain = Vec::new();
+        append_signed_receipt(&mut sequential_chain, receipts[0].clone(), &key)
+            .expect("append #1");
+        append_signed_receipt(&mut sequential_chain, receipts[1].clone(), &key)
+            .expect("append #2");
+        append_signed_receipt(&mut sequential_chain, receipts[2].clone(), &key)
+            .expect("append #3");
+
+        assert_eq!(batch_chain, sequential_chain);
+        assert_eq!(batch_result, sequential_chain);
+        verify_hash_chain(&batch_chain).expect("batch chain should be valid");
+    }
+
+    #[test]
+    fn append_signed_receipts_batch_is_atomic_on_signing_failure() {
+        let key = demo_signing_key();
+        let mut chain = Vec::new();
+        append_signed_receipt(
+            &mut chain,
+            make_receipt("quarantine", Decision::Approved),
+            &key,
+        )
+        .expect("seed append");
+        let before = chain.clone();
+
+        let mut bad_receipt = make_receipt("revocation", Decision::Denied);
+        bad_receipt.confidence = f64::NAN;
+        let err = append_signed_receipts_batch(
+            &mut chain,
+            vec![
+                make_receipt("deployment_promotion", Decision::Escalated),
+                bad_receipt,
+            ],
+            &key,
+        )
+        .expect_err("batch with an invalid receipt must fail");
+
+        assert!(matches!(err, ReceiptError::InvalidConfidence { .. }));
+        assert_eq!(chain, before);
+    }
+
     #[test]
     fn hash_chain_rejects_genesis_receipt_with_previous_hash() {
         let key = demo_signing_key();
@@ -2230,6 +2899,78 @@ mod tests {
         );
     }
 
+    fn demo_policy_explanation(deciding_rule_id: &str) -> PolicyExplanation {
+        use crate::policy::policy_explainer::{DiagnosticSection, GuaranteeSection};
+
+        PolicyExplanation {
+            diagnostic_confidence: DiagnosticSection {
+                posterior_prob: Some(0.94),
+                observation_count: 42,
+                confidence_interval: Some((0.9, 0.98)),
+                confidence_level: "high".to_string(),
+                summary: "strong posterior support for the chosen candidate".to_string(),
+            },
+            guarantee_confidence: GuaranteeSection {
+                all_guardrails_passed: true,
+                guardrails_checked: vec!["rate-limit".to_string(), deciding_rule_id.to_string()],
+                invariants_verified: vec!["INV-EXPLAIN-SEPARATION".to_string()],
+                summary: "all guardrails passed for the chosen action".to_string(),
+            },
+            action_summary: "quarantined extension per policy gate".to_string(),
+            blocked_alternatives: Vec::new(),
+            epoch_id: 7,
+        }
+    }
+
+    #[test]
+    fn receipt_with_rationale_trace_round_trips_through_serde() {
+        let receipt = make_receipt("quarantine", Decision::Approved)
+            .with_rationale_trace(RationaleTrace {
+                deciding_rule_id: "rule-A".to_string(),
+                policy_explanation: demo_policy_explanation("rule-A"),
+            })
+            .expect("rationale trace attaches");
+
+        let json = serde_json::to_string(&receipt).expect("serialize receipt");
+        let roundtripped: Receipt = serde_json::from_str(&json).expect("deserialize receipt");
+
+        assert_eq!(roundtripped.rationale_trace, receipt.rationale_trace);
+        assert_eq!(
+            roundtripped
+                .rationale_trace
+                .expect("trace present")
+                .deciding_rule_id,
+            "rule-A"
+        );
+    }
+
+    #[test]
+    fn receipt_without_rationale_trace_still_deserializes() {
+        let receipt = make_receipt("quarantine", Decision::Approved);
+        let json = serde_json::to_string(&receipt).expect("serialize receipt");
+        assert!(!json.contains("rationale_trace"));
+
+        let roundtripped: Receipt = serde_json::from_str(&json).expect("deserialize receipt");
+        assert_eq!(roundtripped.rationale_trace, None);
+    }
+
+    #[test]
+    fn markdown_render_includes_deciding_rule_id_from_rationale_trace() {
+        let key = demo_signing_key();
+        let receipt = make_receipt("quarantine", Decision::Approved)
+            .with_rationale_trace(RationaleTrace {
+                deciding_rule_id: "rule-exfil-001".to_string(),
+                policy_explanation: demo_policy_explanation("rule-exfil-001"),
+            })
+            .expect("rationale trace attaches");
+        let signed = sign_receipt(&receipt, &key).unwrap();
+
+        let markdown = render_receipts_markdown(&[signed]);
+
+        assert!(markdown.contains("## Rationale Traces"));
+        assert!(markdown.contains("deciding rule `rule-exfil-001`"));
+    }
+
     #[test]
     fn export_receipts_to_path_creates_missing_parent_directories() {
         // Prod rejects absolute export paths (path-traversal hardening), so exercise
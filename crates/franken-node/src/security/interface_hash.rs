@@ -18,7 +18,11 @@ const MAX_TELEMETRY_FIELD_BYTES: usize = 4 * 1024;
 
 /// Compute a domain-separated hash over length-prefixed domain and data fields.
 ///
-/// Uses full-width SHA-256 output to preserve collision resistance.
+/// Uses full-width SHA-256 output to preserve collision resistance. The
+/// result carries no algorithm tag, matching hashes registered before
+/// algorithm negotiation (see [`compute_interface_hash`]) existed; `verify_hash`
+/// treats an untagged hash as SHA-256 so hashes stored before this change
+/// keep verifying during the transition.
 pub fn compute_hash(domain: &str, data: &[u8]) -> InterfaceHash {
     let hash_hex = secure_hash!("interface_hash_v1:", domain.as_bytes(), data);
 
@@ -29,6 +33,69 @@ pub fn compute_hash(domain: &str, data: &[u8]) -> InterfaceHash {
     }
 }
 
+/// Compute a domain-separated hash tagged with the algorithm that produced it
+/// (e.g. `sha256:...`, `blake3:...`), so `verify_hash` can dispatch on the tag
+/// instead of assuming a fixed algorithm.
+pub fn compute_interface_hash(
+    domain: &str,
+    data: &[u8],
+    algorithm: HashAlgorithm,
+) -> InterfaceHash {
+    let digest_hex = match algorithm {
+        HashAlgorithm::Sha256 => secure_hash!("interface_hash_v1:", domain.as_bytes(), data),
+        HashAlgorithm::Blake3 => blake3_digest_hex(domain, data),
+    };
+
+    InterfaceHash {
+        domain: domain.to_string(),
+        hash_hex: format!("{}:{digest_hex}", algorithm.tag()),
+        data_len: data.len(),
+    }
+}
+
+#[cfg(feature = "blake3")]
+fn blake3_digest_hex(domain: &str, data: &[u8]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"interface_hash_v1:");
+    hasher.update(&u64::try_from(domain.len()).unwrap_or(u64::MAX).to_le_bytes());
+    hasher.update(domain.as_bytes());
+    hasher.update(&u64::try_from(data.len()).unwrap_or(u64::MAX).to_le_bytes());
+    hasher.update(data);
+    hex::encode(hasher.finalize().as_bytes())
+}
+
+#[cfg(not(feature = "blake3"))]
+fn blake3_digest_hex(_domain: &str, _data: &[u8]) -> String {
+    // Without the `blake3` feature compiled in we cannot produce a real
+    // digest; returning a value that will never match a recomputed hash
+    // keeps `verify_hash` safely failing closed rather than panicking.
+    "blake3-unavailable".repeat(4)
+}
+
+/// Hash algorithm selectable when computing a tagged interface hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "sha256" => Some(Self::Sha256),
+            "blake3" => Some(Self::Blake3),
+            _ => None,
+        }
+    }
+}
+
 /// Verify that `expected` matches the recomputed hash for the given domain and data.
 pub fn verify_hash(
     expected: &InterfaceHash,
@@ -40,20 +107,33 @@ pub fn verify_hash(
         return Err(RejectionCode::DomainMismatch);
     }
 
-    // Validate hash format
-    if expected.hash_hex.len() != 64 || !expected.hash_hex.chars().all(|c| c.is_ascii_hexdigit()) {
-        return Err(RejectionCode::MalformedHash);
-    }
-
     if expected.data_len != data.len() {
         return Err(RejectionCode::HashMismatch);
     }
 
+    let (algorithm, digest_hex) = match expected.hash_hex.split_once(':') {
+        Some((tag, rest)) => (
+            HashAlgorithm::from_tag(tag).ok_or(RejectionCode::UnknownAlgorithm)?,
+            rest,
+        ),
+        None => (HashAlgorithm::Sha256, expected.hash_hex.as_str()),
+    };
+
+    // Validate hash format
+    if digest_hex.len() != 64 || !digest_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(RejectionCode::MalformedHash);
+    }
+
     // Recompute and compare
-    let computed = compute_hash(domain, data);
+    let computed = compute_interface_hash(domain, data, algorithm);
+    let computed_digest_hex = computed
+        .hash_hex
+        .split_once(':')
+        .map(|(_, rest)| rest)
+        .unwrap_or(computed.hash_hex.as_str());
     if !crate::security::constant_time::ct_eq(
-        &computed.hash_hex.to_ascii_lowercase(),
-        &expected.hash_hex.to_ascii_lowercase(),
+        &computed_digest_hex.to_ascii_lowercase(),
+        &digest_hex.to_ascii_lowercase(),
     ) {
         return Err(RejectionCode::HashMismatch);
     }
@@ -90,6 +170,7 @@ pub enum RejectionCode {
     DomainMismatch,
     ExpiredHash,
     MalformedHash,
+    UnknownAlgorithm,
 }
 
 impl fmt::Display for RejectionCode {
@@ -99,6 +180,7 @@ impl fmt::Display for RejectionCode {
             Self::DomainMismatch => write!(f, "IFACE_DOMAIN_MISMATCH"),
             Self::ExpiredHash => write!(f, "IFACE_HASH_EXPIRED"),
             Self::MalformedHash => write!(f, "IFACE_HASH_MALFORMED"),
+            Self::UnknownAlgorithm => write!(f, "IFACE_HASH_UNKNOWN_ALGORITHM"),
         }
     }
 }
@@ -311,6 +393,44 @@ mod tests {
         assert_ne!(h.hash_hex, legacy_hash_hex);
     }
 
+    // === compute_interface_hash / algorithm negotiation ===
+
+    #[test]
+    fn compute_interface_hash_tags_sha256() {
+        let h = compute_interface_hash("connector.v1", b"hello", HashAlgorithm::Sha256);
+        assert!(h.hash_hex.starts_with("sha256:"));
+        assert_eq!(h.hash_hex.split_once(':').unwrap().1.len(), 64);
+    }
+
+    #[test]
+    fn compute_interface_hash_sha256_verifies() {
+        let h = compute_interface_hash("connector.v1", b"hello", HashAlgorithm::Sha256);
+        assert!(verify_hash(&h, "connector.v1", b"hello").is_ok());
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn compute_interface_hash_blake3_verifies() {
+        let h = compute_interface_hash("connector.v1", b"hello", HashAlgorithm::Blake3);
+        assert!(h.hash_hex.starts_with("blake3:"));
+        assert!(verify_hash(&h, "connector.v1", b"hello").is_ok());
+    }
+
+    #[test]
+    fn compute_interface_hash_unknown_tag_errors_on_verify() {
+        let mut h = compute_interface_hash("connector.v1", b"hello", HashAlgorithm::Sha256);
+        h.hash_hex = format!("rot13:{}", h.hash_hex.split_once(':').unwrap().1);
+        let result = verify_hash(&h, "connector.v1", b"hello");
+        assert_eq!(result, Err(RejectionCode::UnknownAlgorithm));
+    }
+
+    #[test]
+    fn legacy_untagged_hash_still_verifies() {
+        let h = compute_hash("connector.v1", b"hello");
+        assert!(!h.hash_hex.contains(':'));
+        assert!(verify_hash(&h, "connector.v1", b"hello").is_ok());
+    }
+
     // === verify_hash ===
 
     #[test]
@@ -659,7 +779,10 @@ mod additional_negative_path_tests {
     }
 
     #[test]
-    fn verify_rejects_sha256_prefixed_hash_material() {
+    fn verify_rejects_sha256_prefixed_hash_material_with_wrong_digest() {
+        // A `sha256:` tag is now a recognized algorithm tag (see
+        // `compute_interface_hash`), so a tagged hash with a bogus digest is a
+        // mismatch rather than malformed input.
         let expected = malformed_expected(
             "sha256:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
             4,
@@ -667,7 +790,19 @@ mod additional_negative_path_tests {
 
         let result = verify_hash(&expected, "connector.v1", b"data");
 
-        assert_eq!(result, Err(RejectionCode::MalformedHash));
+        assert_eq!(result, Err(RejectionCode::HashMismatch));
+    }
+
+    #[test]
+    fn verify_rejects_unknown_algorithm_tag() {
+        let expected = malformed_expected(
+            "md5:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            4,
+        );
+
+        let result = verify_hash(&expected, "connector.v1", b"data");
+
+        assert_eq!(result, Err(RejectionCode::UnknownAlgorithm));
     }
 
     #[test]
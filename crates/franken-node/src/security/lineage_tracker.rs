@@ -12,6 +12,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+use crate::observability::metrics::MetricsRegistry;
 use crate::push_bounded;
 use hmac::{Hmac, KeyInit, Mac};
 use serde::{Deserialize, Serialize};
@@ -48,6 +49,11 @@ pub const EVENT_FLOW_LEDGER_SNAPSHOT_EXPORTED: &str = "FN-IFL-015";
 pub const EVENT_TRANSFORM_PROPAGATED: &str = "FN-IFL-016";
 pub const EVENT_DECLASSIFICATION_RECEIPT_REGISTERED: &str = "FN-IFL-017";
 pub const EVENT_SINK_ENFORCED: &str = "FN-IFL-018";
+/// Note: the request that introduced [`LineageGraph::release_edge`] asked
+/// for this to be `FN-IFL-013`, but that code is already
+/// [`EVENT_SIGNED_LINEAGE_BUILT`]; `FN-IFL-019` is the next free slot after
+/// [`EVENT_SINK_ENFORCED`].
+pub const EVENT_EDGE_RELEASED: &str = "FN-IFL-019";
 
 // Product-level acceptance transcript events for the information-flow lane.
 pub const EVENT_FLOW_SOURCE_REGISTERED: &str = "FN-FLOW-001";
@@ -82,6 +88,9 @@ pub const ERR_IFL_SENSITIVE_SOURCE_INVALID: &str = "ERR_IFL_SENSITIVE_SOURCE_INV
 pub const ERR_IFL_SENSITIVE_SOURCE_CONFLICT: &str = "ERR_IFL_SENSITIVE_SOURCE_CONFLICT";
 pub const ERR_IFL_DECLASSIFICATION_INVALID: &str = "ERR_IFL_DECLASSIFICATION_INVALID";
 pub const ERR_IFL_SINK_POLICY_INVALID: &str = "ERR_IFL_SINK_POLICY_INVALID";
+pub const ERR_IFL_TAINT_SET_OVERFLOW: &str = "ERR_IFL_TAINT_SET_OVERFLOW";
+pub const ERR_IFL_EDGE_NOT_FOUND: &str = "ERR_IFL_EDGE_NOT_FOUND";
+pub const ERR_IFL_NOT_QUARANTINED: &str = "ERR_IFL_NOT_QUARANTINED";
 
 // Canonical error codes required by bd-2iyk acceptance criteria.
 pub const ERR_LINEAGE_TAG_MISSING: &str = "ERR_LINEAGE_TAG_MISSING";
@@ -188,6 +197,14 @@ pub struct TaintLabel {
     pub description: String,
     /// Severity level (higher = more sensitive).
     pub severity: u32,
+    /// Optional expiry, in epoch milliseconds, after which
+    /// [`TaintSet::expire`] drops this label from any taint set that
+    /// carries it. `None` (the default) means the label never expires,
+    /// which is required to keep [`INV_LABEL_PERSIST`] intact for labels
+    /// that were never meant to be expirable in the first place --
+    /// expiry is opt-in per label, not a blanket TTL.
+    #[serde(default)]
+    pub expires_at_ms: Option<u64>,
 }
 
 /// Ordered set of active taint labels on a datum.
@@ -245,6 +262,12 @@ impl TaintSet {
 
     /// Merge labels from another set into this one.
     ///
+    /// Since a `TaintSet` only stores label ids, not their full
+    /// [`TaintLabel`] metadata, a label's expiry is carried through a merge
+    /// for free: whichever set later calls [`Self::expire`] resolves the id
+    /// against the same shared registry, so the merged copy expires at
+    /// exactly the same `now_ms` the original would have.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -296,6 +319,61 @@ impl TaintSet {
     pub fn len(&self) -> usize {
         self.labels.len()
     }
+
+    /// Drop labels whose *registered* [`TaintLabel::expires_at_ms`] is at or
+    /// before `now_ms`. `registry` is the label-id to [`TaintLabel`] map a
+    /// label was registered into (see [`LineageGraph::register_label`]);
+    /// `TaintSet` itself only stores label ids, so expiry has to be looked
+    /// up there rather than carried on each member.
+    ///
+    /// Labels with `expires_at_ms: None` (the default), or whose id is not
+    /// found in `registry` at all, are left untouched -- this is what keeps
+    /// expiry an explicit opt-in per label rather than a blanket TTL that
+    /// would violate [`INV_LABEL_PERSIST`] for every other label.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{TaintLabel, TaintSet};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut registry = BTreeMap::new();
+    /// registry.insert(
+    ///     "SESSION".to_string(),
+    ///     TaintLabel {
+    ///         id: "SESSION".to_string(),
+    ///         description: "Short-lived session data".to_string(),
+    ///         severity: 40,
+    ///         expires_at_ms: Some(1_000),
+    ///     },
+    /// );
+    ///
+    /// let mut taints = TaintSet::new();
+    /// taints.insert("SESSION");
+    /// taints.expire(1_000, &registry);
+    /// assert!(!taints.contains("SESSION"));
+    /// ```
+    pub fn expire(&mut self, now_ms: u64, registry: &BTreeMap<String, TaintLabel>) {
+        self.labels.retain(|label_id| {
+            let Some(label) = registry.get(label_id) else {
+                return true;
+            };
+            match label.expires_at_ms {
+                Some(expires_at_ms) => now_ms < expires_at_ms,
+                None => true,
+            }
+        });
+    }
+}
+
+/// Per-label propagation statistics reported by [`LineageGraph::label_spread`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelSpread {
+    /// Number of distinct datums whose current taint set carries this label.
+    pub datum_count: usize,
+    /// Number of edges whose `taint_set` carried this label when it was
+    /// appended (i.e. edges that propagated the label).
+    pub edge_count: usize,
 }
 
 impl Default for TaintSet {
@@ -310,12 +388,33 @@ pub struct FlowEdge {
     pub edge_id: String,
     pub source: String,
     pub sink: String,
+    /// Explicit zone the `source` datum belongs to. `None` falls back to
+    /// matching `source` itself against a boundary's `from_zone` (see
+    /// [`TaintBoundary::crosses_edge`]).
+    #[serde(default)]
+    pub source_zone: Option<String>,
+    /// Explicit zone the `sink` datum belongs to. `None` falls back to
+    /// matching `sink` itself against a boundary's `to_zone`. See
+    /// `source_zone`.
+    #[serde(default)]
+    pub sink_zone: Option<String>,
     pub operation: String,
     pub taint_set: TaintSet,
     pub timestamp_ms: u64,
     pub quarantined: bool,
 }
 
+/// A human triage note attached to a [`FlowEdge`] via
+/// [`LineageGraph::annotate_edge`]. Annotations live in a side channel keyed
+/// by `edge_id`, so the edge record itself stays append-only and
+/// unmutated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EdgeAnnotation {
+    pub note: String,
+    pub author: String,
+    pub timestamp_ms: u64,
+}
+
 /// Runtime transform classes that deterministically propagate lineage labels.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -356,6 +455,52 @@ impl LineageTransformKind {
     }
 }
 
+/// Coarse taxonomy of `FlowEdge.operation` free-form strings, so boundary
+/// policy can distinguish "this boundary only matters for Export operations"
+/// from "this boundary matters for every operation."
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationClass {
+    Read,
+    Copy,
+    Transform,
+    Export,
+    Other,
+}
+
+impl OperationClass {
+    /// Classify a free-form `FlowEdge.operation` string.
+    ///
+    /// Matching is case-insensitive substring matching against the operation
+    /// string, checked in the order below; an operation matching none of the
+    /// known keywords classifies as `Other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::OperationClass;
+    ///
+    /// assert_eq!(OperationClass::parse("export"), OperationClass::Export);
+    /// assert_eq!(OperationClass::parse("read_file"), OperationClass::Read);
+    /// assert_eq!(OperationClass::parse("frobnicate"), OperationClass::Other);
+    /// ```
+    #[must_use]
+    pub fn parse(operation: &str) -> Self {
+        let op = operation.to_ascii_lowercase();
+        if op.contains("export") {
+            Self::Export
+        } else if op.contains("copy") || op.contains("clone") || op.contains("duplicate") {
+            Self::Copy
+        } else if op.contains("transform") || op.contains("convert") || op.contains("map") {
+            Self::Transform
+        } else if op.contains("read") || op.contains("fetch") || op.contains("get") {
+            Self::Read
+        } else {
+            Self::Other
+        }
+    }
+}
+
 /// Taint boundary: policy rule defining allowed/denied taint crossings.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaintBoundary {
@@ -366,6 +511,12 @@ pub struct TaintBoundary {
     pub denied_labels: BTreeSet<String>,
     /// If true, *all* labels are denied (deny-all rule).
     pub deny_all: bool,
+    /// If set, this boundary only applies to edges whose operation classifies
+    /// into one of these classes; edges of other classes pass through this
+    /// boundary untouched regardless of taint. `None` applies to every
+    /// operation, matching the boundary's pre-taxonomy behavior.
+    #[serde(default)]
+    pub operation_restriction: Option<BTreeSet<OperationClass>>,
 }
 
 impl TaintBoundary {
@@ -384,6 +535,7 @@ impl TaintBoundary {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
+    ///     operation_restriction: None,
     /// };
     /// let mut taints = TaintSet::new();
     /// taints.insert("SECRET");
@@ -415,6 +567,7 @@ impl TaintBoundary {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::new(),
     ///     deny_all: true,
+    ///     operation_restriction: None,
     /// };
     ///
     /// assert!(boundary.validate().is_ok());
@@ -431,9 +584,67 @@ impl TaintBoundary {
         Ok(())
     }
 
-    fn crosses_edge(&self, edge: &FlowEdge) -> bool {
-        node_matches_zone(&edge.source, &self.from_zone)
-            && node_matches_zone(&edge.sink, &self.to_zone)
+    /// Whether `edge` crosses this boundary.
+    ///
+    /// If the edge carries an explicit [`FlowEdge::source_zone`] /
+    /// [`FlowEdge::sink_zone`], those are compared against `from_zone` /
+    /// `to_zone` by exact equality -- the caller said what zone the datum is
+    /// in, so there's nothing to infer. Otherwise the zone is inferred from
+    /// the node name itself, via `node_matches_zone`'s delimiter-aware
+    /// prefix match, or (when `legacy_substring_zones` is set) via a raw
+    /// substring match kept only for migrating callers off of it.
+    fn crosses_edge(&self, edge: &FlowEdge, legacy_substring_zones: bool) -> bool {
+        zone_matches(
+            edge.source_zone.as_deref(),
+            &edge.source,
+            &self.from_zone,
+            legacy_substring_zones,
+        ) && zone_matches(
+            edge.sink_zone.as_deref(),
+            &edge.sink,
+            &self.to_zone,
+            legacy_substring_zones,
+        ) && self.applies_to_operation(edge)
+    }
+
+    /// Whether this boundary's `operation_restriction` (if any) covers the
+    /// given edge's operation class. A boundary with no restriction applies
+    /// to every operation.
+    fn applies_to_operation(&self, edge: &FlowEdge) -> bool {
+        match &self.operation_restriction {
+            None => true,
+            Some(classes) => classes.contains(&OperationClass::parse(&edge.operation)),
+        }
+    }
+}
+
+/// A rule that escalates the verdict when several labels co-occur on the same
+/// edge, since some label combinations (e.g. `NAME` + `SSN`) are more
+/// dangerous together than any one of them evaluated in isolation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompositeRule {
+    pub rule_id: String,
+    pub required_labels: BTreeSet<String>,
+    pub escalated_verdict: FlowVerdict,
+}
+
+impl CompositeRule {
+    /// Validate that the rule is well-formed.
+    pub fn validate(&self) -> Result<(), LineageError> {
+        if self.rule_id.is_empty() || self.required_labels.len() < 2 {
+            return Err(LineageError::BoundaryInvalid {
+                detail: format!(
+                    "{}: composite rule '{}' must have a rule_id and at least 2 required_labels",
+                    ERR_IFL_BOUNDARY_INVALID, self.rule_id
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// True when the taint set carries *every* required label.
+    fn matches(&self, taint_set: &TaintSet) -> bool {
+        self.required_labels.is_subset(&taint_set.labels)
     }
 }
 
@@ -450,6 +661,21 @@ fn node_matches_zone(node: &str, zone: &str) -> bool {
     matches!(suffix.chars().next(), Some(ch) if !ch.is_ascii_alphanumeric())
 }
 
+/// Resolve whether a node is in `zone`, preferring the explicit
+/// `node_zone` (set on the [`FlowEdge`]) when present.
+fn zone_matches(
+    node_zone: Option<&str>,
+    node: &str,
+    zone: &str,
+    legacy_substring_zones: bool,
+) -> bool {
+    match node_zone {
+        Some(node_zone) => node_zone == zone,
+        None if legacy_substring_zones => !zone.is_empty() && node.contains(zone),
+        None => node_matches_zone(node, zone),
+    }
+}
+
 /// Per-edge pass/quarantine/alert decision.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -486,6 +712,13 @@ pub struct ExfiltrationAlert {
     pub taint_labels: BTreeSet<String>,
     pub verdict: FlowVerdict,
     pub timestamp_ms: u64,
+    /// Count of additional violating edges, sharing this alert's
+    /// `(violated_boundary, taint_labels)` key, that arrived within
+    /// [`SentinelConfig::alert_cooldown_ms`] of this alert and were folded
+    /// into it instead of raising an alert of their own. `0` means every
+    /// matching edge so far got its own alert.
+    #[serde(default)]
+    pub suppressed_count: u32,
     pub detail: String,
 }
 
@@ -500,6 +733,18 @@ pub struct ContainmentReceipt {
     pub success: bool,
 }
 
+/// Proof that a previously quarantined edge was released after human review.
+/// Produced by [`LineageGraph::release_edge`], analogous to how a
+/// [`ContainmentReceipt`] documents the original quarantine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseReceipt {
+    pub receipt_id: String,
+    pub edge_id: String,
+    pub justification: String,
+    pub released_by: String,
+    pub release_timestamp_ms: u64,
+}
+
 pub const DECLASSIFICATION_SCHEMA_VERSION: &str = "declassification-v1.0";
 
 /// Sensitive sink classes that require declassification for forbidden labels.
@@ -898,6 +1143,24 @@ pub struct SentinelConfig {
     pub recall_threshold_pct: u32,
     pub precision_threshold_pct: u32,
     pub schema_version: String,
+    /// Maximum number of distinct labels a single datum's taint set may
+    /// hold. Guards against runaway propagation accumulating unbounded
+    /// labels on one datum; does not affect labels already present
+    /// (INV-IFL-LABEL-PERSIST still holds for them).
+    #[serde(default = "default_max_taint_set_size")]
+    pub max_taint_set_size: usize,
+    /// When `true`, boundary crossing falls back to raw substring matching
+    /// (`node.contains(zone)`) for edges that don't carry an explicit
+    /// [`FlowEdge::source_zone`]/[`FlowEdge::sink_zone`] -- the pre-structured
+    /// behavior this flag exists to let operators migrate off of. Defaults to
+    /// `false`, in which case such edges fall back to the stricter
+    /// `node_matches_zone` prefix match instead.
+    #[serde(default)]
+    pub legacy_substring_zones: bool,
+}
+
+fn default_max_taint_set_size() -> usize {
+    1_024
 }
 
 impl Default for SentinelConfig {
@@ -909,6 +1172,8 @@ impl Default for SentinelConfig {
             recall_threshold_pct: 95,
             precision_threshold_pct: 90,
             schema_version: SCHEMA_VERSION.to_string(),
+            max_taint_set_size: default_max_taint_set_size(),
+            legacy_substring_zones: false,
         }
     }
 }
@@ -933,6 +1198,14 @@ impl SentinelConfig {
                 ),
             });
         }
+        if self.max_taint_set_size == 0 {
+            return Err(LineageError::ConfigRejected {
+                detail: format!(
+                    "{}: max_taint_set_size must be > 0",
+                    ERR_IFL_CONFIG_REJECTED
+                ),
+            });
+        }
         if self.recall_threshold_pct > 100 || self.precision_threshold_pct > 100 {
             return Err(LineageError::ConfigRejected {
                 detail: format!("{}: thresholds must be <= 100", ERR_IFL_CONFIG_REJECTED),
@@ -1004,6 +1277,10 @@ pub struct LineageSnapshot {
     pub edges: Vec<FlowEdge>,
     pub labels: BTreeMap<String, TaintLabel>,
     pub schema_version: String,
+    /// Human triage notes keyed by `edge_id`, as attached via
+    /// [`LineageGraph::annotate_edge`].
+    #[serde(default)]
+    pub annotations: BTreeMap<String, Vec<EdgeAnnotation>>,
 }
 
 /// Schema version for sensitive-source FlowLedger commitments.
@@ -1268,6 +1545,7 @@ impl FlowLedger {
                     id: label_id.clone(),
                     description: Self::label_description(existing),
                     severity: existing.descriptor.severity,
+                    expires_at_ms: None,
                 });
             }
             graph.assign_taint(datum_id, &label_id)?;
@@ -1279,6 +1557,7 @@ impl FlowLedger {
             id: label_id.clone(),
             description: Self::label_description(&commitment),
             severity: commitment.descriptor.severity,
+            expires_at_ms: None,
         });
         graph.assign_taint(datum_id, &label_id)?;
         self.record_binding(datum_id, &label_id);
@@ -1781,6 +2060,9 @@ pub enum LineageError {
     SensitiveSourceConflict { detail: String },
     DeclassificationInvalid { detail: String },
     SinkPolicyInvalid { detail: String },
+    TaintSetOverflow { detail: String },
+    EdgeNotFound { detail: String },
+    NotQuarantined { detail: String },
 }
 
 impl fmt::Display for LineageError {
@@ -1800,12 +2082,35 @@ impl fmt::Display for LineageError {
             Self::SensitiveSourceConflict { detail } => write!(f, "{}", detail),
             Self::DeclassificationInvalid { detail } => write!(f, "{}", detail),
             Self::SinkPolicyInvalid { detail } => write!(f, "{}", detail),
+            Self::TaintSetOverflow { detail } => write!(f, "{}", detail),
+            Self::EdgeNotFound { detail } => write!(f, "{}", detail),
+            Self::NotQuarantined { detail } => write!(f, "{}", detail),
         }
     }
 }
 
 impl std::error::Error for LineageError {}
 
+/// One edge's failure within a [`LineageGraph::append_edges`] batch, tagged
+/// with its index in the input `Vec` so callers can correlate failures back
+/// to the edges they submitted.
+#[derive(Debug)]
+pub struct BatchAppendFailure {
+    pub index: usize,
+    pub error: LineageError,
+}
+
+/// Result of a batched [`LineageGraph::append_edges`] call.
+#[derive(Debug)]
+pub struct BatchAppendResult {
+    /// Assigned edge ids for edges that appended successfully, in input order.
+    pub appended: Vec<String>,
+    /// Failures, each tagged with its index in the input `Vec`. A
+    /// `GraphFull` failure halts ingestion, so no later index appears
+    /// in `appended` or `failures`.
+    pub failures: Vec<BatchAppendFailure>,
+}
+
 // ---------------------------------------------------------------------------
 // LineageGraph
 // ---------------------------------------------------------------------------
@@ -1823,6 +2128,27 @@ pub struct LineageGraph {
     config: SentinelConfig,
     /// Monotonic edge counter for generating edge IDs.
     edge_counter: u64,
+    /// Optional namespace prefixed onto auto-generated edge ids
+    /// (`{namespace}-edge-{n}`), so edges from independently-constructed
+    /// graphs stay globally unique ahead of a future merge. `None` keeps
+    /// the bare `edge-{n}` scheme used by [`LineageGraph::new`].
+    #[serde(default)]
+    edge_namespace: Option<String>,
+    /// Human triage notes attached to edges via [`LineageGraph::annotate_edge`],
+    /// keyed by `edge_id`. Kept separate from `edges` so the edge record
+    /// itself stays append-only.
+    #[serde(default)]
+    annotations: BTreeMap<String, Vec<EdgeAnnotation>>,
+    /// [`ReleaseReceipt`]s for edges un-quarantined via
+    /// [`LineageGraph::release_edge`], keyed by `edge_id`. Consulted by
+    /// [`invariants::verify_quarantine_receipt`] so a released edge's
+    /// now-stale [`ContainmentReceipt`] doesn't fail that invariant once
+    /// the edge is no longer quarantined.
+    #[serde(default)]
+    released_edges: BTreeMap<String, ReleaseReceipt>,
+    /// Monotonic counter for generating [`ReleaseReceipt`] ids.
+    #[serde(default)]
+    release_counter: u64,
 }
 
 impl LineageGraph {
@@ -1843,7 +2169,49 @@ impl LineageGraph {
             datum_taints: BTreeMap::new(),
             config,
             edge_counter: 0,
+            edge_namespace: None,
+            annotations: BTreeMap::new(),
+            released_edges: BTreeMap::new(),
+            release_counter: 0,
+        }
+    }
+
+    /// Create a new lineage graph whose auto-generated edge ids are
+    /// prefixed with `namespace` (`{namespace}-edge-{n}`), so edges from
+    /// independently-constructed graphs never collide once merged.
+    /// `namespace` must be non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{LineageGraph, SentinelConfig};
+    ///
+    /// let graph = LineageGraph::new_with_namespace(SentinelConfig::default(), "runtime-a").unwrap();
+    /// assert_eq!(graph.edge_count(), 0);
+    /// ```
+    pub fn new_with_namespace(
+        config: SentinelConfig,
+        namespace: &str,
+    ) -> Result<Self, LineageError> {
+        if namespace.is_empty() {
+            return Err(LineageError::ConfigRejected {
+                detail: format!(
+                    "{}: edge id namespace must not be empty",
+                    ERR_IFL_CONFIG_REJECTED
+                ),
+            });
         }
+        Ok(Self {
+            edges: BTreeMap::new(),
+            labels: BTreeMap::new(),
+            datum_taints: BTreeMap::new(),
+            config,
+            edge_counter: 0,
+            edge_namespace: Some(namespace.to_string()),
+            annotations: BTreeMap::new(),
+            released_edges: BTreeMap::new(),
+            release_counter: 0,
+        })
     }
 
     /// Register a taint label. Event: FN-IFL-001.
@@ -1860,6 +2228,7 @@ impl LineageGraph {
     ///     id: "PII".to_string(),
     ///     description: "Personally identifiable information".to_string(),
     ///     severity: 80,
+    ///     expires_at_ms: None,
     /// });
     ///
     /// assert_eq!(label_id, "PII");
@@ -1874,6 +2243,10 @@ impl LineageGraph {
     /// Assign a taint label to a datum. Event: FN-IFL-001.
     /// INV-IFL-LABEL-PERSIST: labels are never removed from a taint set.
     ///
+    /// Assigning a label the datum already carries is always a no-op
+    /// success, even at the cap; only a genuinely new label can trigger
+    /// `TaintSetOverflow` once the set holds `max_taint_set_size` labels.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -1886,6 +2259,7 @@ impl LineageGraph {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive data".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     ///
     /// graph.assign_taint("datum-1", "SECRET").unwrap();
@@ -1902,6 +2276,17 @@ impl LineageGraph {
             });
         }
         let taint_set = self.datum_taints.entry(datum_id.to_string()).or_default();
+        if !taint_set.contains(label_id) && taint_set.len() >= self.config.max_taint_set_size {
+            return Err(LineageError::TaintSetOverflow {
+                detail: format!(
+                    "{}: datum '{}' taint set already holds {} labels (max {})",
+                    ERR_IFL_TAINT_SET_OVERFLOW,
+                    datum_id,
+                    taint_set.len(),
+                    self.config.max_taint_set_size
+                ),
+            });
+        }
         taint_set.insert(label_id);
         Ok(())
     }
@@ -1920,6 +2305,7 @@ impl LineageGraph {
     ///     id: "INTERNAL".to_string(),
     ///     description: "Internal-only".to_string(),
     ///     severity: 20,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("datum-1", "INTERNAL").unwrap();
     ///
@@ -1930,6 +2316,35 @@ impl LineageGraph {
         self.datum_taints.get(datum_id)
     }
 
+    /// Drop expired labels (see [`TaintSet::expire`]) from `datum_id`'s
+    /// taint set, using this graph's label registry. A no-op, not an error,
+    /// if the datum has no taint set yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     LineageGraph, SentinelConfig, TaintLabel,
+    /// };
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.register_label(TaintLabel {
+    ///     id: "SESSION".to_string(),
+    ///     description: "Short-lived session data".to_string(),
+    ///     severity: 40,
+    ///     expires_at_ms: Some(1_000),
+    /// });
+    /// graph.assign_taint("datum-1", "SESSION").unwrap();
+    ///
+    /// graph.expire_taints("datum-1", 1_000);
+    /// assert!(!graph.get_taint_set("datum-1").unwrap().contains("SESSION"));
+    /// ```
+    pub fn expire_taints(&mut self, datum_id: &str, now_ms: u64) {
+        if let Some(taint_set) = self.datum_taints.get_mut(datum_id) {
+            taint_set.expire(now_ms, &self.labels);
+        }
+    }
+
     /// Append a flow edge. Event: FN-IFL-002.
     /// INV-IFL-EDGE-APPEND-ONLY: edges are never deleted.
     ///
@@ -1949,6 +2364,8 @@ impl LineageGraph {
     ///     taint_set: TaintSet::new(),
     ///     timestamp_ms: 42,
     ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
     /// }).unwrap();
     ///
     /// assert!(graph.get_edge(&edge_id).is_some());
@@ -1969,7 +2386,10 @@ impl LineageGraph {
 
         if edge.edge_id.is_empty() {
             self.edge_counter = self.edge_counter.saturating_add(1);
-            edge.edge_id = format!("edge-{}", self.edge_counter);
+            edge.edge_id = match &self.edge_namespace {
+                Some(namespace) => format!("{namespace}-edge-{}", self.edge_counter),
+                None => format!("edge-{}", self.edge_counter),
+            };
         }
 
         if self.edges.contains_key(&edge.edge_id) {
@@ -1987,9 +2407,74 @@ impl LineageGraph {
         Ok(edge_id)
     }
 
+    /// Append many edges in one call, reporting per-index failures instead
+    /// of aborting the whole batch on the first one. Event: FN-IFL-002 (once
+    /// per edge that appends successfully).
+    ///
+    /// Edges are appended in order. A `GraphFull` failure halts ingestion
+    /// immediately, since no later edge in the batch can succeed either;
+    /// every edge appended before that point (including ones after a
+    /// non-fatal failure such as `DuplicateEdge`) stays in the graph per
+    /// INV-IFL-EDGE-APPEND-ONLY.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     FlowEdge, LineageGraph, SentinelConfig, TaintSet,
+    /// };
+    ///
+    /// fn edge(id: &str) -> FlowEdge {
+    ///     FlowEdge {
+    ///         edge_id: id.to_string(),
+    ///         source: "internal:db".to_string(),
+    ///         sink: "internal:cache".to_string(),
+    ///         operation: "replicate".to_string(),
+    ///         taint_set: TaintSet::new(),
+    ///         timestamp_ms: 42,
+    ///         quarantined: false,
+    ///         source_zone: None,
+    ///         sink_zone: None,
+    ///     }
+    /// }
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// let result = graph.append_edges(vec![edge("e1"), edge("e1"), edge("e2")]);
+    ///
+    /// assert_eq!(result.appended, vec!["e1".to_string(), "e2".to_string()]);
+    /// assert_eq!(result.failures.len(), 1);
+    /// assert_eq!(result.failures[0].index, 1);
+    /// ```
+    pub fn append_edges(&mut self, edges: Vec<FlowEdge>) -> BatchAppendResult {
+        let mut result = BatchAppendResult {
+            appended: Vec::with_capacity(edges.len()),
+            failures: Vec::new(),
+        };
+
+        for (index, edge) in edges.into_iter().enumerate() {
+            match self.append_edge(edge) {
+                Ok(edge_id) => result.appended.push(edge_id),
+                Err(error) => {
+                    let is_graph_full = matches!(error, LineageError::GraphFull { .. });
+                    result.failures.push(BatchAppendFailure { index, error });
+                    if is_graph_full {
+                        break;
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
     /// Propagate taint from source to sink datum through an operation.
     /// Event: FN-IFL-003, FN-IFL-011 (on merge).
     ///
+    /// If merging in the source datum's labels would push the sink's
+    /// taint set past `max_taint_set_size`, the merge is rejected with
+    /// `TaintSetOverflow` and the sink's existing labels are left
+    /// untouched (INV-IFL-LABEL-PERSIST is never violated to make room).
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -2002,6 +2487,7 @@ impl LineageGraph {
     ///     id: "PII".to_string(),
     ///     description: "Personally identifiable information".to_string(),
     ///     severity: 80,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:source", "PII").unwrap();
     ///
@@ -2028,9 +2514,30 @@ impl LineageGraph {
             .unwrap_or_default();
 
         // Merge taint sets (INV-IFL-LABEL-PERSIST: labels only grow)
+        let had_labels = self
+            .datum_taints
+            .get(sink_datum)
+            .map(TaintSet::len)
+            .unwrap_or(0);
+        let mut merged = self
+            .datum_taints
+            .get(sink_datum)
+            .cloned()
+            .unwrap_or_default();
+        merged.merge(&source_taint);
+        if merged.len() > self.config.max_taint_set_size {
+            return Err(LineageError::TaintSetOverflow {
+                detail: format!(
+                    "{}: propagating to '{}' would grow taint set to {} labels (max {})",
+                    ERR_IFL_TAINT_SET_OVERFLOW,
+                    sink_datum,
+                    merged.len(),
+                    self.config.max_taint_set_size
+                ),
+            });
+        }
         let sink_taint = self.datum_taints.entry(sink_datum.to_string()).or_default();
-        let had_labels = sink_taint.len();
-        sink_taint.merge(&source_taint);
+        *sink_taint = merged;
         if sink_taint.len() > had_labels {
             let _event_merge = EVENT_TAINT_MERGE;
         }
@@ -2043,6 +2550,8 @@ impl LineageGraph {
             taint_set: source_taint,
             timestamp_ms,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
 
         self.append_edge(edge)
@@ -2138,6 +2647,8 @@ impl LineageGraph {
                 taint_set: source_taint,
                 timestamp_ms,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
             edge_ids.push(self.append_edge(edge)?);
         }
@@ -2159,6 +2670,7 @@ impl LineageGraph {
     ///     id: "PII".to_string(),
     ///     description: "Personally identifiable information".to_string(),
     ///     severity: 80,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:source", "PII").unwrap();
     /// graph
@@ -2215,6 +2727,89 @@ impl LineageGraph {
         Ok(results)
     }
 
+    /// Walk edges forward from `source`, following `source -> sink` links,
+    /// up to `max_depth` hops (further capped by `config.max_graph_depth`),
+    /// and return every sink datum reachable that way.
+    ///
+    /// Traversal is a breadth-first search guarded by a `BTreeSet` of
+    /// visited nodes, so cycles are followed exactly once and never cause
+    /// an infinite loop. If the frontier is still non-empty once the depth
+    /// cap is reached, the traversal was truncated. Event: FN-IFL-010.
+    ///
+    /// Nodes are returned in deterministic sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{LineageGraph, SentinelConfig};
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.propagate_taint("A", "B", "copy", 100).unwrap();
+    /// graph.propagate_taint("B", "C", "copy", 200).unwrap();
+    ///
+    /// assert_eq!(graph.reachable_sinks("A", 10), vec!["B".to_string(), "C".to_string()]);
+    /// ```
+    pub fn reachable_sinks(&self, source: &str, max_depth: usize) -> Vec<String> {
+        self.walk_reachable(source, max_depth, true)
+    }
+
+    /// Walk edges backward from `sink`, following `sink -> source` links
+    /// (the reverse of `source -> sink`), up to `max_depth` hops (further
+    /// capped by `config.max_graph_depth`), and return every source datum
+    /// that can eventually reach `sink`.
+    ///
+    /// Symmetric to [`Self::reachable_sinks`]; see it for the traversal,
+    /// cycle-safety, ordering, and event semantics shared by both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{LineageGraph, SentinelConfig};
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.propagate_taint("A", "B", "copy", 100).unwrap();
+    /// graph.propagate_taint("B", "C", "copy", 200).unwrap();
+    ///
+    /// assert_eq!(graph.reachable_sources("C", 10), vec!["A".to_string(), "B".to_string()]);
+    /// ```
+    pub fn reachable_sources(&self, sink: &str, max_depth: usize) -> Vec<String> {
+        self.walk_reachable(sink, max_depth, false)
+    }
+
+    /// Shared BFS for [`Self::reachable_sinks`] (`forward = true`) and
+    /// [`Self::reachable_sources`] (`forward = false`).
+    fn walk_reachable(&self, start: &str, max_depth: usize, forward: bool) -> Vec<String> {
+        let depth_cap = max_depth.min(self.config.max_graph_depth);
+        let mut visited: BTreeSet<String> = BTreeSet::new();
+        let mut frontier: Vec<String> = vec![start.to_string()];
+
+        for _ in 0..depth_cap {
+            if frontier.is_empty() {
+                break;
+            }
+            let mut next = Vec::new();
+            for node in &frontier {
+                for edge in self.edges.values() {
+                    let (from, to) = if forward {
+                        (edge.source.as_str(), edge.sink.as_str())
+                    } else {
+                        (edge.sink.as_str(), edge.source.as_str())
+                    };
+                    if from == node.as_str() && visited.insert(to.to_string()) {
+                        next.push(to.to_string());
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        if !frontier.is_empty() {
+            let _event = EVENT_DEPTH_LIMIT;
+        }
+
+        visited.into_iter().collect()
+    }
+
     /// Export a snapshot. Event: FN-IFL-008.
     /// INV-IFL-SNAPSHOT-FAITHFUL.
     ///
@@ -2240,6 +2835,7 @@ impl LineageGraph {
             edges: self.edges.values().cloned().collect(),
             labels: self.labels.clone(),
             schema_version: SCHEMA_VERSION.to_string(),
+            annotations: self.annotations.clone(),
         }
     }
 
@@ -2271,6 +2867,7 @@ impl LineageGraph {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     ///
     /// assert_eq!(graph.label_count(), 1);
@@ -2279,6 +2876,143 @@ impl LineageGraph {
         self.labels.len()
     }
 
+    /// Report, per registered label, how widely it has spread through the
+    /// graph: the number of distinct datums currently carrying it and the
+    /// number of edges that propagated it.
+    ///
+    /// A label present on many datums is a data-minimization concern for
+    /// privacy reviews. The result is keyed by label id and computed
+    /// deterministically from the current graph state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     LineageGraph, SentinelConfig, TaintLabel,
+    /// };
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.register_label(TaintLabel {
+    ///     id: "PII".to_string(),
+    ///     description: "Personally identifiable information".to_string(),
+    ///     severity: 80,
+    ///     expires_at_ms: None,
+    /// });
+    /// graph.assign_taint("datum-a", "PII").unwrap();
+    /// graph
+    ///     .propagate_taint("datum-a", "datum-b", "copy", 100)
+    ///     .unwrap();
+    ///
+    /// let spread = graph.label_spread();
+    /// assert_eq!(spread["PII"].datum_count, 2);
+    /// assert_eq!(spread["PII"].edge_count, 1);
+    /// ```
+    pub fn label_spread(&self) -> BTreeMap<String, LabelSpread> {
+        let mut spread = BTreeMap::new();
+        for label_id in self.labels.keys() {
+            let datum_count = self
+                .datum_taints
+                .values()
+                .filter(|taints| taints.contains(label_id))
+                .count();
+            let edge_count = self
+                .edges
+                .values()
+                .filter(|edge| edge.taint_set.contains(label_id))
+                .count();
+            spread.insert(
+                label_id.clone(),
+                LabelSpread {
+                    datum_count,
+                    edge_count,
+                },
+            );
+        }
+        spread
+    }
+
+    /// Extract a bounded BFS neighborhood around `datum_id`, covering both
+    /// upstream and downstream edges within `hops` hops.
+    ///
+    /// Returns a faithful snapshot of the induced subgraph: constructing a
+    /// fresh [`LineageGraph`] from exactly the collected edges and the
+    /// labels they reference satisfies
+    /// [`invariants::verify_snapshot_faithful`] relative to that subgraph.
+    /// The BFS tracks visited datums, so cycles in the graph cannot cause
+    /// non-termination or duplicate edges in the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{LineageGraph, SentinelConfig};
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.propagate_taint("a", "b", "copy", 100).unwrap();
+    /// graph.propagate_taint("b", "c", "copy", 200).unwrap();
+    ///
+    /// let snapshot = graph.neighborhood("b", 1);
+    /// assert_eq!(snapshot.edge_count, 2);
+    /// ```
+    pub fn neighborhood(&self, datum_id: &str, hops: usize) -> LineageSnapshot {
+        let mut visited_datums: BTreeSet<String> = BTreeSet::new();
+        visited_datums.insert(datum_id.to_string());
+        let mut frontier: Vec<String> = vec![datum_id.to_string()];
+        let mut included_edge_ids: BTreeSet<String> = BTreeSet::new();
+
+        for _ in 0..hops {
+            if frontier.is_empty() {
+                break;
+            }
+            let mut next_frontier = Vec::new();
+            for node in &frontier {
+                for edge in self.edges.values() {
+                    if &edge.source == node && included_edge_ids.insert(edge.edge_id.clone()) {
+                        if visited_datums.insert(edge.sink.clone()) {
+                            next_frontier.push(edge.sink.clone());
+                        }
+                    }
+                    if &edge.sink == node && included_edge_ids.insert(edge.edge_id.clone()) {
+                        if visited_datums.insert(edge.source.clone()) {
+                            next_frontier.push(edge.source.clone());
+                        }
+                    }
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        let sub_edges: BTreeMap<String, FlowEdge> = self
+            .edges
+            .iter()
+            .filter(|(edge_id, _)| included_edge_ids.contains(*edge_id))
+            .map(|(edge_id, edge)| (edge_id.clone(), edge.clone()))
+            .collect();
+
+        let mut sub_labels: BTreeMap<String, TaintLabel> = BTreeMap::new();
+        for edge in sub_edges.values() {
+            for label_id in &edge.taint_set.labels {
+                if let Some(label) = self.labels.get(label_id) {
+                    sub_labels
+                        .entry(label_id.clone())
+                        .or_insert_with(|| label.clone());
+                }
+            }
+        }
+
+        let subgraph = LineageGraph {
+            edges: sub_edges,
+            labels: sub_labels,
+            datum_taints: BTreeMap::new(),
+            config: self.config.clone(),
+            edge_counter: 0,
+            edge_namespace: None,
+            annotations: BTreeMap::new(),
+            released_edges: BTreeMap::new(),
+            release_counter: 0,
+        };
+        subgraph.snapshot(&format!("neighborhood-{datum_id}-{hops}"), 0)
+    }
+
     /// Get an edge by ID.
     ///
     /// # Examples
@@ -2297,6 +3031,8 @@ impl LineageGraph {
     ///     taint_set: TaintSet::new(),
     ///     timestamp_ms: 1,
     ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
     /// }).unwrap();
     ///
     /// assert_eq!(graph.get_edge(&edge_id).unwrap().operation, "copy");
@@ -2305,12 +3041,70 @@ impl LineageGraph {
         self.edges.get(edge_id)
     }
 
-    /// Mark an edge as quarantined (internal helper).
-    fn quarantine_edge(&mut self, edge_id: &str) -> Result<(), LineageError> {
-        if let Some(edge) = self.edges.get_mut(edge_id) {
-            if edge.quarantined {
-                return Err(LineageError::AlreadyQuarantined {
-                    detail: format!(
+    /// Attach a human triage note to `edge_id` without mutating the edge
+    /// record itself. Notes accumulate in append order in a side-channel
+    /// map, keeping [`FlowEdge`] append-only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{LineageGraph, SentinelConfig};
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// let edge_id = graph.propagate_taint("a", "b", "copy", 100).unwrap();
+    /// graph
+    ///     .annotate_edge(&edge_id, "confirmed benign, test data", "alice", 200)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(graph.edge_annotations(&edge_id).len(), 1);
+    /// ```
+    pub fn annotate_edge(
+        &mut self,
+        edge_id: &str,
+        note: &str,
+        author: &str,
+        now_ms: u64,
+    ) -> Result<(), LineageError> {
+        if !self.edges.contains_key(edge_id) {
+            return Err(LineageError::EdgeNotFound {
+                detail: format!("{}: edge '{}' not found", ERR_IFL_EDGE_NOT_FOUND, edge_id),
+            });
+        }
+        self.annotations
+            .entry(edge_id.to_string())
+            .or_default()
+            .push(EdgeAnnotation {
+                note: note.to_string(),
+                author: author.to_string(),
+                timestamp_ms: now_ms,
+            });
+        Ok(())
+    }
+
+    /// Triage notes attached to `edge_id`, in the order they were added.
+    /// Returns an empty slice if the edge has no annotations (or does not
+    /// exist).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{LineageGraph, SentinelConfig};
+    ///
+    /// let graph = LineageGraph::new(SentinelConfig::default());
+    /// assert!(graph.edge_annotations("missing").is_empty());
+    /// ```
+    pub fn edge_annotations(&self, edge_id: &str) -> &[EdgeAnnotation] {
+        self.annotations
+            .get(edge_id)
+            .map_or(&[], |notes| notes.as_slice())
+    }
+
+    /// Mark an edge as quarantined (internal helper).
+    fn quarantine_edge(&mut self, edge_id: &str) -> Result<(), LineageError> {
+        if let Some(edge) = self.edges.get_mut(edge_id) {
+            if edge.quarantined {
+                return Err(LineageError::AlreadyQuarantined {
+                    detail: format!(
                         "{}: edge '{}' already quarantined",
                         ERR_IFL_ALREADY_QUARANTINED, edge_id
                     ),
@@ -2327,6 +3121,173 @@ impl LineageGraph {
             })
         }
     }
+
+    /// Release a previously quarantined edge after human review, clearing
+    /// `quarantined` and producing a [`ReleaseReceipt`] documenting who
+    /// authorized it and why. This is the sanctioned alternative to
+    /// operators editing the graph out of band to un-quarantine an edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LineageError::EdgeNotFound`] if `edge_id` does not exist,
+    /// or [`LineageError::NotQuarantined`] if the edge exists but was never
+    /// quarantined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    ///
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     ExfiltrationSentinel, FlowEdge, FlowVerdict, LineageGraph, SentinelConfig, TaintBoundary,
+    ///     TaintLabel, TaintSet,
+    /// };
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.register_label(TaintLabel {
+    ///     id: "SECRET".to_string(),
+    ///     description: "Sensitive".to_string(),
+    ///     severity: 100,
+    ///     expires_at_ms: None,
+    /// });
+    /// let mut taints = TaintSet::new();
+    /// taints.insert("SECRET");
+    /// let edge_id = graph.append_edge(FlowEdge {
+    ///     edge_id: String::new(),
+    ///     source: "internal:db".to_string(),
+    ///     sink: "external:api".to_string(),
+    ///     operation: "export".to_string(),
+    ///     taint_set: taints,
+    ///     timestamp_ms: 7,
+    ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
+    /// }).unwrap();
+    /// let edge = graph.get_edge(&edge_id).unwrap().clone();
+    ///
+    /// let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// sentinel.add_boundary(TaintBoundary {
+    ///     boundary_id: "b-1".to_string(),
+    ///     from_zone: "internal".to_string(),
+    ///     to_zone: "external".to_string(),
+    ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
+    ///     deny_all: false,
+    ///     operation_restriction: None,
+    /// }).unwrap();
+    /// let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+    /// assert_eq!(verdict, FlowVerdict::Quarantine);
+    ///
+    /// let receipt = graph
+    ///     .release_edge(&edge_id, "reviewed, false positive", "alice", 200)
+    ///     .unwrap();
+    /// assert_eq!(receipt.edge_id, edge_id);
+    /// ```
+    pub fn release_edge(
+        &mut self,
+        edge_id: &str,
+        justification: &str,
+        released_by: &str,
+        now_ms: u64,
+    ) -> Result<ReleaseReceipt, LineageError> {
+        let edge = self
+            .edges
+            .get_mut(edge_id)
+            .ok_or_else(|| LineageError::EdgeNotFound {
+                detail: format!("{}: edge '{}' not found", ERR_IFL_EDGE_NOT_FOUND, edge_id),
+            })?;
+        if !edge.quarantined {
+            return Err(LineageError::NotQuarantined {
+                detail: format!(
+                    "{}: edge '{}' is not quarantined",
+                    ERR_IFL_NOT_QUARANTINED, edge_id
+                ),
+            });
+        }
+        edge.quarantined = false;
+        let _event = EVENT_EDGE_RELEASED;
+
+        self.release_counter = self.release_counter.saturating_add(1);
+        let receipt = ReleaseReceipt {
+            receipt_id: format!("release-{}", self.release_counter),
+            edge_id: edge_id.to_string(),
+            justification: justification.to_string(),
+            released_by: released_by.to_string(),
+            release_timestamp_ms: now_ms,
+        };
+        self.released_edges
+            .insert(edge_id.to_string(), receipt.clone());
+        Ok(receipt)
+    }
+
+    /// The [`ReleaseReceipt`] produced for `edge_id` by
+    /// [`LineageGraph::release_edge`], if it has been released.
+    pub fn release_receipt(&self, edge_id: &str) -> Option<&ReleaseReceipt> {
+        self.released_edges.get(edge_id)
+    }
+}
+
+/// Schema version for [`SentinelPolicyDoc`].
+pub const SENTINEL_POLICY_SCHEMA_VERSION: &str = "sentinel-policy-v1.0";
+
+/// A reviewable, versioned snapshot of an [`ExfiltrationSentinel`]'s full
+/// policy: every taint boundary and composite escalation rule, serialized as
+/// one JSON document so it can be diffed and checked into version control
+/// instead of only existing as programmatically-built in-memory state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SentinelPolicyDoc {
+    pub schema_version: String,
+    pub boundaries: Vec<TaintBoundary>,
+    pub composite_rules: Vec<CompositeRule>,
+}
+
+/// One edge flagged by a [`ExfiltrationSentinel::simulate_boundary`] dry run,
+/// and the verdict the candidate boundary alone would have produced for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulatedFlag {
+    pub edge_id: String,
+    pub verdict: FlowVerdict,
+}
+
+/// Result of [`ExfiltrationSentinel::simulate_boundary`]: which existing
+/// edges a candidate boundary would have flagged, without quarantining
+/// anything or registering any alert/receipt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationReport {
+    pub boundary_id: String,
+    pub edges_evaluated: usize,
+    pub flagged: Vec<SimulatedFlag>,
+}
+
+/// A containment receipt bundled with the alert it was issued for and the
+/// flow edge that alert was raised against, so an auditor can walk the full
+/// violation-to-receipt chain in one lookup instead of three. See
+/// [`ExfiltrationSentinel::forensic_trace`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForensicTrace {
+    pub receipt: ContainmentReceipt,
+    pub alert: ExfiltrationAlert,
+    pub edge: FlowEdge,
+}
+
+/// External side effect to trigger when the sentinel quarantines a flow edge
+/// (e.g. killing a connection, revoking a token).
+///
+/// Invoked deterministically, exactly once, after the [`ContainmentReceipt`]
+/// for the quarantine has been issued. A hook that returns `Err` does not
+/// abort evaluation; the sentinel records the failure in
+/// [`ExfiltrationSentinel::hook_failures`] and continues.
+pub trait QuarantineHook {
+    fn on_quarantine(&self, alert: &ExfiltrationAlert, edge: &FlowEdge) -> Result<(), String>;
+}
+
+/// Default hook that performs no side effect, preserving the sentinel's
+/// behavior when no hook is configured.
+struct NoOpQuarantineHook;
+
+impl QuarantineHook for NoOpQuarantineHook {
+    fn on_quarantine(&self, _alert: &ExfiltrationAlert, _edge: &FlowEdge) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -2337,6 +3298,8 @@ impl LineageGraph {
 pub struct ExfiltrationSentinel {
     /// Taint boundaries (keyed by boundary_id).
     boundaries: BTreeMap<String, TaintBoundary>,
+    /// Composite escalation rules (keyed by rule_id).
+    composite_rules: BTreeMap<String, CompositeRule>,
     /// Alert history (keyed by alert_id).
     alerts: BTreeMap<String, ExfiltrationAlert>,
     /// Containment receipts (keyed by receipt_id).
@@ -2347,6 +3310,15 @@ pub struct ExfiltrationSentinel {
     receipt_counter: u64,
     /// Configuration reference.
     config: SentinelConfig,
+    /// Side-effect hook invoked after each containment receipt is issued.
+    quarantine_hook: Box<dyn QuarantineHook>,
+    /// Count of [`QuarantineHook::on_quarantine`] calls that returned `Err`.
+    hook_failures: u64,
+    /// Last alert timestamp and alert id raised per `(violated_boundary,
+    /// taint_labels)` key, used by [`Self::evaluate_edge`] to suppress
+    /// repeat alerts for the same violation within
+    /// [`SentinelConfig::alert_cooldown_ms`].
+    alert_cooldowns: BTreeMap<(String, BTreeSet<String>), (u64, String)>,
 }
 
 impl ExfiltrationSentinel {
@@ -2363,12 +3335,176 @@ impl ExfiltrationSentinel {
     pub fn new(config: SentinelConfig) -> Self {
         Self {
             boundaries: BTreeMap::new(),
+            composite_rules: BTreeMap::new(),
             alerts: BTreeMap::new(),
             receipts: BTreeMap::new(),
             alert_counter: 0,
             receipt_counter: 0,
             config,
+            quarantine_hook: Box::new(NoOpQuarantineHook),
+            hook_failures: 0,
+            alert_cooldowns: BTreeMap::new(),
+        }
+    }
+
+    /// Configures the side-effect hook invoked after each containment
+    /// receipt is issued. Replaces the no-op default installed by
+    /// [`ExfiltrationSentinel::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     ExfiltrationAlert, ExfiltrationSentinel, FlowEdge, QuarantineHook, SentinelConfig,
+    /// };
+    ///
+    /// struct LoggingHook;
+    /// impl QuarantineHook for LoggingHook {
+    ///     fn on_quarantine(&self, _alert: &ExfiltrationAlert, _edge: &FlowEdge) -> Result<(), String> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let sentinel =
+    ///     ExfiltrationSentinel::new(SentinelConfig::default()).with_quarantine_hook(Box::new(LoggingHook));
+    /// assert_eq!(sentinel.hook_failures(), 0);
+    /// ```
+    #[must_use]
+    pub fn with_quarantine_hook(mut self, hook: Box<dyn QuarantineHook>) -> Self {
+        self.quarantine_hook = hook;
+        self
+    }
+
+    /// Number of [`QuarantineHook::on_quarantine`] calls that returned `Err`.
+    pub fn hook_failures(&self) -> u64 {
+        self.hook_failures
+    }
+
+    /// Renders the sentinel's counters in Prometheus text exposition
+    /// format, for scraping by a monitoring stack.
+    ///
+    /// Emits, in deterministic order (via [`MetricsRegistry::render_prometheus`]):
+    /// - `franken_lineage_alerts_total`: total alerts raised.
+    /// - `franken_lineage_receipts_total`: total containment receipts issued.
+    /// - `franken_lineage_boundary_violations_total{boundary="..."}`: alert
+    ///   count per violated boundary.
+    /// - `franken_lineage_graph_boundaries`: number of configured taint
+    ///   boundaries (edges of the boundary graph).
+    /// - `franken_lineage_graph_labels`: number of distinct labels denied
+    ///   by at least one configured boundary.
+    #[must_use]
+    pub fn prometheus_metrics(&self) -> String {
+        let mut registry = MetricsRegistry::new();
+
+        registry
+            .record_counter(
+                "franken_lineage_alerts_total",
+                "Total exfiltration alerts raised by the sentinel",
+                self.alerts.len() as f64,
+                &[],
+            )
+            .expect("static metric name/help are valid");
+        registry
+            .record_counter(
+                "franken_lineage_receipts_total",
+                "Total containment receipts issued by the sentinel",
+                self.receipts.len() as f64,
+                &[],
+            )
+            .expect("static metric name/help are valid");
+
+        let mut violations_by_boundary: BTreeMap<&str, u64> = BTreeMap::new();
+        for alert in self.alerts.values() {
+            *violations_by_boundary
+                .entry(alert.violated_boundary.as_str())
+                .or_insert(0) += 1;
+        }
+        for (boundary, count) in &violations_by_boundary {
+            registry
+                .record_counter(
+                    "franken_lineage_boundary_violations_total",
+                    "Total alerts raised per violated taint boundary",
+                    *count as f64,
+                    &[("boundary", *boundary)],
+                )
+                .expect("boundary ids are valid label values");
+        }
+
+        let distinct_labels: BTreeSet<&str> = self
+            .boundaries
+            .values()
+            .flat_map(|boundary| boundary.denied_labels.iter().map(String::as_str))
+            .collect();
+        registry
+            .record_gauge(
+                "franken_lineage_graph_boundaries",
+                "Number of configured taint boundaries (edges of the boundary graph)",
+                self.boundaries.len() as f64,
+                &[],
+            )
+            .expect("static metric name/help are valid");
+        registry
+            .record_gauge(
+                "franken_lineage_graph_labels",
+                "Number of distinct labels denied by at least one configured boundary",
+                distinct_labels.len() as f64,
+                &[],
+            )
+            .expect("static metric name/help are valid");
+
+        registry.render_prometheus()
+    }
+
+    /// Invokes the configured quarantine hook, recording a failure without
+    /// propagating it.
+    fn fire_quarantine_hook(&mut self, alert: &ExfiltrationAlert, edge: &FlowEdge) {
+        if let Err(_err) = self.quarantine_hook.on_quarantine(alert, edge) {
+            self.hook_failures = self.hook_failures.saturating_add(1);
+        }
+    }
+
+    /// Register a composite escalation rule.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    ///
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     CompositeRule, ExfiltrationSentinel, FlowVerdict, SentinelConfig,
+    /// };
+    ///
+    /// let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// sentinel
+    ///     .add_composite_rule(CompositeRule {
+    ///         rule_id: "cr-1".to_string(),
+    ///         required_labels: BTreeSet::from(["NAME".to_string(), "SSN".to_string()]),
+    ///         escalated_verdict: FlowVerdict::Alert,
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn add_composite_rule(&mut self, rule: CompositeRule) -> Result<(), LineageError> {
+        rule.validate()?;
+        if self.composite_rules.contains_key(&rule.rule_id) {
+            return Err(LineageError::BoundaryInvalid {
+                detail: format!(
+                    "{}: composite rule '{}' already registered",
+                    ERR_IFL_BOUNDARY_INVALID, rule.rule_id
+                ),
+            });
         }
+        self.composite_rules.insert(rule.rule_id.clone(), rule);
+        Ok(())
+    }
+
+    /// Strongest verdict escalated by any composite rule whose required
+    /// labels are all present in `taint_set`, if any.
+    fn composite_escalation(&self, taint_set: &TaintSet) -> Option<FlowVerdict> {
+        self.composite_rules
+            .values()
+            .filter(|rule| rule.matches(taint_set))
+            .map(|rule| rule.escalated_verdict)
+            .reduce(strongest_flow_verdict)
     }
 
     /// Register a taint boundary.
@@ -2389,6 +3525,7 @@ impl ExfiltrationSentinel {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
+    ///     operation_restriction: None,
     /// }).unwrap();
     ///
     /// assert_eq!(sentinel.alert_count(), 0);
@@ -2408,9 +3545,81 @@ impl ExfiltrationSentinel {
         Ok(())
     }
 
-    /// Evaluate a flow edge against all boundaries.
-    /// Returns the verdict and any alerts raised.
-    /// INV-IFL-BOUNDARY-ENFORCED, INV-IFL-DETERMINISTIC.
+    /// Export the full set of boundaries and composite rules as a
+    /// reviewable, versioned policy document.
+    #[must_use]
+    pub fn export_policy(&self) -> SentinelPolicyDoc {
+        SentinelPolicyDoc {
+            schema_version: SENTINEL_POLICY_SCHEMA_VERSION.to_string(),
+            boundaries: self.boundaries.values().cloned().collect(),
+            composite_rules: self.composite_rules.values().cloned().collect(),
+        }
+    }
+
+    /// Replace this sentinel's boundaries and composite rules with the
+    /// contents of `doc`.
+    ///
+    /// Every boundary and composite rule in `doc` is validated, and checked
+    /// for duplicate IDs, before any of them are applied
+    /// (INV-IFL-POLICY-ALL-OR-NOTHING): a single malformed entry aborts the
+    /// whole load and leaves the sentinel's existing policy untouched.
+    pub fn load_policy(&mut self, doc: &SentinelPolicyDoc) -> Result<(), LineageError> {
+        if doc.schema_version != SENTINEL_POLICY_SCHEMA_VERSION {
+            return Err(LineageError::BoundaryInvalid {
+                detail: format!(
+                    "{}: unsupported sentinel policy schema_version '{}', expected '{}'",
+                    ERR_IFL_BOUNDARY_INVALID, doc.schema_version, SENTINEL_POLICY_SCHEMA_VERSION
+                ),
+            });
+        }
+
+        let mut boundaries = BTreeMap::new();
+        for boundary in &doc.boundaries {
+            boundary.validate()?;
+            if boundaries
+                .insert(boundary.boundary_id.clone(), boundary.clone())
+                .is_some()
+            {
+                return Err(LineageError::BoundaryInvalid {
+                    detail: format!(
+                        "{}: duplicate boundary_id '{}' in policy document",
+                        ERR_IFL_BOUNDARY_INVALID, boundary.boundary_id
+                    ),
+                });
+            }
+        }
+
+        let mut composite_rules = BTreeMap::new();
+        for rule in &doc.composite_rules {
+            rule.validate()?;
+            if composite_rules
+                .insert(rule.rule_id.clone(), rule.clone())
+                .is_some()
+            {
+                return Err(LineageError::BoundaryInvalid {
+                    detail: format!(
+                        "{}: duplicate rule_id '{}' in policy document",
+                        ERR_IFL_BOUNDARY_INVALID, rule.rule_id
+                    ),
+                });
+            }
+        }
+
+        self.boundaries = boundaries;
+        self.composite_rules = composite_rules;
+        Ok(())
+    }
+
+    /// Dry-run a candidate boundary against every edge currently in `graph`.
+    ///
+    /// Unlike [`Self::evaluate_edge`], this neither mutates `graph` (no
+    /// quarantine) nor registers alerts or receipts on `self` -- it reports
+    /// which edges `boundary` *would* have flagged, so a security engineer
+    /// can test a new rule against historical edges before deploying it.
+    /// `boundary` does not need to be registered via [`Self::add_boundary`]
+    /// first. Composite-rule escalation is intentionally not applied: the
+    /// report reflects `boundary` in isolation, not the sentinel's full
+    /// registered policy.
     ///
     /// # Examples
     ///
@@ -2427,6 +3636,7 @@ impl ExfiltrationSentinel {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     /// let mut taints = TaintSet::new();
     /// taints.insert("SECRET");
@@ -2438,55 +3648,175 @@ impl ExfiltrationSentinel {
     ///     taint_set: taints,
     ///     timestamp_ms: 7,
     ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
     /// }).unwrap();
-    /// let edge = graph.get_edge(&edge_id).unwrap().clone();
     ///
-    /// let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
-    /// sentinel.add_boundary(TaintBoundary {
+    /// let sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// let boundary = TaintBoundary {
     ///     boundary_id: "b-1".to_string(),
     ///     from_zone: "internal".to_string(),
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
-    /// }).unwrap();
+    ///     operation_restriction: None,
+    /// };
     ///
-    /// let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
-    /// assert_eq!(verdict, FlowVerdict::Quarantine);
+    /// let report = sentinel.simulate_boundary(&boundary, &graph);
+    /// assert_eq!(report.flagged.len(), 1);
+    /// assert_eq!(report.flagged[0].edge_id, edge_id);
+    /// assert!(!graph.get_edge(&edge_id).unwrap().quarantined);
     /// ```
-    pub fn evaluate_edge(
-        &mut self,
-        edge: &FlowEdge,
-        graph: &mut LineageGraph,
-    ) -> Result<FlowVerdict, LineageError> {
-        let _inv_boundary = INV_BOUNDARY_ENFORCED;
-        let _inv_det = INV_DETERMINISTIC;
+    #[must_use]
+    pub fn simulate_boundary(
+        &self,
+        boundary: &TaintBoundary,
+        graph: &LineageGraph,
+    ) -> SimulationReport {
+        let flagged = graph
+            .edges
+            .values()
+            .filter(|edge| {
+                boundary.crosses_edge(edge, self.config.legacy_substring_zones)
+                    && boundary.is_violated_by(&edge.taint_set)
+            })
+            .map(|edge| SimulatedFlag {
+                edge_id: edge.edge_id.clone(),
+                verdict: FlowVerdict::Quarantine,
+            })
+            .collect();
 
-        if graph
-            .get_edge(&edge.edge_id)
-            .is_some_and(|stored_edge| stored_edge.quarantined)
-        {
-            return Err(LineageError::AlreadyQuarantined {
-                detail: format!(
-                    "{}: edge '{}' already quarantined",
-                    ERR_IFL_ALREADY_QUARANTINED, edge.edge_id
-                ),
-            });
+        SimulationReport {
+            boundary_id: boundary.boundary_id.clone(),
+            edges_evaluated: graph.edges.len(),
+            flagged,
         }
+    }
 
-        let mut worst_verdict = FlowVerdict::Pass;
-        let mut edge_quarantined = false;
-
-        for boundary in self.boundaries.values() {
-            // Check if this edge crosses this boundary
-            let crosses = boundary.crosses_edge(edge);
-
-            if !crosses {
-                continue;
-            }
+    /// Evaluate a flow edge against all boundaries.
+    /// Returns the verdict and any alerts raised.
+    /// INV-IFL-BOUNDARY-ENFORCED, INV-IFL-DETERMINISTIC.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    ///
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     ExfiltrationSentinel, FlowEdge, FlowVerdict, LineageGraph, SentinelConfig, TaintBoundary,
+    ///     TaintLabel, TaintSet,
+    /// };
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.register_label(TaintLabel {
+    ///     id: "SECRET".to_string(),
+    ///     description: "Sensitive".to_string(),
+    ///     severity: 100,
+    ///     expires_at_ms: None,
+    /// });
+    /// let mut taints = TaintSet::new();
+    /// taints.insert("SECRET");
+    /// let edge_id = graph.append_edge(FlowEdge {
+    ///     edge_id: String::new(),
+    ///     source: "internal:db".to_string(),
+    ///     sink: "external:api".to_string(),
+    ///     operation: "export".to_string(),
+    ///     taint_set: taints,
+    ///     timestamp_ms: 7,
+    ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
+    /// }).unwrap();
+    /// let edge = graph.get_edge(&edge_id).unwrap().clone();
+    ///
+    /// let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// sentinel.add_boundary(TaintBoundary {
+    ///     boundary_id: "b-1".to_string(),
+    ///     from_zone: "internal".to_string(),
+    ///     to_zone: "external".to_string(),
+    ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
+    ///     deny_all: false,
+    ///     operation_restriction: None,
+    /// }).unwrap();
+    ///
+    /// let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+    /// assert_eq!(verdict, FlowVerdict::Quarantine);
+    /// ```
+    pub fn evaluate_edge(
+        &mut self,
+        edge: &FlowEdge,
+        graph: &mut LineageGraph,
+    ) -> Result<FlowVerdict, LineageError> {
+        let _inv_boundary = INV_BOUNDARY_ENFORCED;
+        let _inv_det = INV_DETERMINISTIC;
+
+        if graph
+            .get_edge(&edge.edge_id)
+            .is_some_and(|stored_edge| stored_edge.quarantined)
+        {
+            return Err(LineageError::AlreadyQuarantined {
+                detail: format!(
+                    "{}: edge '{}' already quarantined",
+                    ERR_IFL_ALREADY_QUARANTINED, edge.edge_id
+                ),
+            });
+        }
+
+        let mut worst_verdict = FlowVerdict::Pass;
+        let mut edge_quarantined = false;
+
+        for boundary in self.boundaries.values() {
+            // Check if this edge crosses this boundary
+            let crosses = boundary.crosses_edge(edge, self.config.legacy_substring_zones);
+
+            if !crosses {
+                continue;
+            }
 
             let _event = EVENT_BOUNDARY_CROSSING;
 
-            if boundary.is_violated_by(&edge.taint_set) {
+            // Composite rules evaluate alongside boundaries: an edge carrying
+            // every label of a registered composite rule while crossing a
+            // boundary escalates to that rule's verdict, even if no single
+            // label on its own violates the boundary.
+            let escalation = self.composite_escalation(&edge.taint_set);
+            let boundary_violated = boundary.is_violated_by(&edge.taint_set);
+
+            if boundary_violated || escalation.is_some() {
+                let verdict = match (boundary_violated, escalation) {
+                    (true, Some(escalated)) => strongest_flow_verdict(FlowVerdict::Quarantine, escalated),
+                    (true, None) => FlowVerdict::Quarantine,
+                    (false, Some(escalated)) => escalated,
+                    (false, None) => unreachable!("checked above"),
+                };
+
+                let cooldown_key = (boundary.boundary_id.clone(), edge.taint_set.labels.clone());
+                let suppressed =
+                    self.alert_cooldowns
+                        .get(&cooldown_key)
+                        .is_some_and(|(last_alert_ms, _)| {
+                            edge.timestamp_ms.saturating_sub(*last_alert_ms)
+                                < self.config.alert_cooldown_ms
+                        });
+
+                if suppressed {
+                    if let Some(alert) = self
+                        .alert_cooldowns
+                        .get(&cooldown_key)
+                        .and_then(|(_, alert_id)| self.alerts.get_mut(alert_id))
+                    {
+                        alert.suppressed_count = alert.suppressed_count.saturating_add(1);
+                    }
+                    if !edge_quarantined && verdict == FlowVerdict::Quarantine {
+                        let _inv_receipt = INV_QUARANTINE_RECEIPT;
+                        let _event_quarantine = EVENT_FLOW_QUARANTINED;
+                        graph.quarantine_edge(&edge.edge_id)?;
+                        edge_quarantined = true;
+                    }
+                    worst_verdict = strongest_flow_verdict(worst_verdict, verdict);
+                    continue;
+                }
+
                 // Raise an alert
                 self.alert_counter = self.alert_counter.saturating_add(1);
                 let alert_id = format!("alert-{}", self.alert_counter);
@@ -2497,8 +3827,9 @@ impl ExfiltrationSentinel {
                     edge_id: edge.edge_id.clone(),
                     violated_boundary: boundary.boundary_id.clone(),
                     taint_labels: edge.taint_set.labels.clone(),
-                    verdict: FlowVerdict::Quarantine,
+                    verdict,
                     timestamp_ms: edge.timestamp_ms,
+                    suppressed_count: 0,
                     detail: format!(
                         "Taint labels {:?} crossed boundary '{}' ({} -> {})",
                         edge.taint_set.labels,
@@ -2507,11 +3838,13 @@ impl ExfiltrationSentinel {
                         boundary.to_zone,
                     ),
                 };
-                self.alerts.insert(alert_id, alert);
+                self.alerts.insert(alert_id.clone(), alert);
+                self.alert_cooldowns
+                    .insert(cooldown_key, (edge.timestamp_ms, alert_id));
 
                 // Auto-contain: quarantine the edge
                 // INV-IFL-QUARANTINE-RECEIPT
-                if !edge_quarantined {
+                if !edge_quarantined && verdict == FlowVerdict::Quarantine {
                     let _inv_receipt = INV_QUARANTINE_RECEIPT;
                     let _event_quarantine = EVENT_FLOW_QUARANTINED;
                     graph.quarantine_edge(&edge.edge_id)?;
@@ -2530,10 +3863,13 @@ impl ExfiltrationSentinel {
                         success: true,
                     };
                     self.receipts.insert(receipt_id, receipt);
+                    let quarantine_alert =
+                        self.alerts[&format!("alert-{}", self.alert_counter)].clone();
+                    self.fire_quarantine_hook(&quarantine_alert, edge);
                     edge_quarantined = true;
                 }
 
-                worst_verdict = FlowVerdict::Quarantine;
+                worst_verdict = strongest_flow_verdict(worst_verdict, verdict);
             }
         }
 
@@ -2611,6 +3947,7 @@ impl ExfiltrationSentinel {
             taint_labels: required_labels.clone(),
             verdict: FlowVerdict::Quarantine,
             timestamp_ms,
+            suppressed_count: 0,
             detail: format!(
                 "Forbidden labels {:?} reached sink '{}' ({}) without a valid scoped declassification receipt",
                 required_labels,
@@ -2627,13 +3964,15 @@ impl ExfiltrationSentinel {
         let _event_receipt = EVENT_CONTAINMENT_RECEIPT;
         let receipt = ContainmentReceipt {
             receipt_id: receipt_id.clone(),
-            alert_id,
-            edge_id: edge.edge_id,
+            alert_id: alert_id.clone(),
+            edge_id: edge.edge_id.clone(),
             quarantine_timestamp_ms: timestamp_ms,
             containment_action: "quarantine_sink_flow".to_string(),
             success: true,
         };
         self.receipts.insert(receipt_id, receipt);
+        let quarantine_alert = self.alerts[&alert_id].clone();
+        self.fire_quarantine_hook(&quarantine_alert, &edge);
 
         Ok(FlowVerdict::Quarantine)
     }
@@ -2652,6 +3991,91 @@ impl ExfiltrationSentinel {
         &self.alerts
     }
 
+    /// Deterministically re-derives the verdict for a previously recorded
+    /// alert, for dispute resolution: proving a quarantine was correct given
+    /// the boundary policy in force at the time, or checking what the
+    /// decision would be under a relaxed policy.
+    ///
+    /// Re-evaluates the alert's recorded `violated_boundary` (looked up in
+    /// `boundaries_at_time`, not this sentinel's *current* boundaries)
+    /// against the alert's recorded `taint_labels`, combined with any
+    /// composite-rule escalation those labels still trigger. Returns `None`
+    /// if `alert_id` is not in this sentinel's alert history.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use std::collections::BTreeSet;
+    ///
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     ExfiltrationSentinel, FlowEdge, FlowVerdict, LineageGraph, SentinelConfig, TaintBoundary,
+    ///     TaintLabel, TaintSet,
+    /// };
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.register_label(TaintLabel {
+    ///     id: "SECRET".to_string(),
+    ///     description: "Sensitive".to_string(),
+    ///     severity: 100,
+    ///     expires_at_ms: None,
+    /// });
+    /// let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// let boundary = TaintBoundary {
+    ///     boundary_id: "b-1".to_string(),
+    ///     from_zone: "internal".to_string(),
+    ///     to_zone: "external".to_string(),
+    ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
+    ///     deny_all: false,
+    ///     operation_restriction: None,
+    /// };
+    /// sentinel.add_boundary(boundary.clone()).unwrap();
+    /// let mut taints = TaintSet::new();
+    /// taints.insert("SECRET");
+    /// let edge = FlowEdge {
+    ///     edge_id: "e-1".to_string(),
+    ///     source: "internal:db".to_string(),
+    ///     sink: "external:api".to_string(),
+    ///     operation: "export".to_string(),
+    ///     taint_set: taints,
+    ///     timestamp_ms: 1,
+    ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
+    /// };
+    /// sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+    /// let alert_id = sentinel.alerts().keys().next().unwrap().clone();
+    ///
+    /// let boundaries_at_time = BTreeMap::from([(boundary.boundary_id.clone(), boundary)]);
+    /// assert_eq!(
+    ///     sentinel.replay_alert(&alert_id, &boundaries_at_time),
+    ///     Some(FlowVerdict::Quarantine)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn replay_alert(
+        &self,
+        alert_id: &str,
+        boundaries_at_time: &BTreeMap<String, TaintBoundary>,
+    ) -> Option<FlowVerdict> {
+        let alert = self.alerts.get(alert_id)?;
+
+        let mut taint_set = TaintSet::new();
+        taint_set.labels = alert.taint_labels.clone();
+
+        let boundary_violated = boundaries_at_time
+            .get(&alert.violated_boundary)
+            .is_some_and(|boundary| boundary.is_violated_by(&taint_set));
+        let escalation = self.composite_escalation(&taint_set);
+
+        Some(match (boundary_violated, escalation) {
+            (true, Some(escalated)) => strongest_flow_verdict(FlowVerdict::Quarantine, escalated),
+            (true, None) => FlowVerdict::Quarantine,
+            (false, Some(escalated)) => escalated,
+            (false, None) => FlowVerdict::Pass,
+        })
+    }
+
     /// Get all containment receipts.
     ///
     /// # Examples
@@ -2666,6 +4090,71 @@ impl ExfiltrationSentinel {
         &self.receipts
     }
 
+    /// Look up the containment receipt issued for a given alert, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{ExfiltrationSentinel, SentinelConfig};
+    ///
+    /// let sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// assert!(sentinel.receipt_for_alert("alert-unknown").is_none());
+    /// ```
+    pub fn receipt_for_alert(&self, alert_id: &str) -> Option<&ContainmentReceipt> {
+        self.receipts
+            .values()
+            .find(|receipt| receipt.alert_id == alert_id)
+    }
+
+    /// Look up the alert that triggered a given containment receipt, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{ExfiltrationSentinel, SentinelConfig};
+    ///
+    /// let sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// assert!(sentinel.alert_for_receipt("receipt-unknown").is_none());
+    /// ```
+    pub fn alert_for_receipt(&self, receipt_id: &str) -> Option<&ExfiltrationAlert> {
+        let receipt = self.receipts.get(receipt_id)?;
+        self.alerts.get(&receipt.alert_id)
+    }
+
+    /// Bundle a containment receipt with the alert it was issued for and the
+    /// flow edge that alert was raised against, for one-call audit
+    /// traversal. See [`ForensicTrace`].
+    ///
+    /// # Parameters
+    /// - `receipt_id`: receipt to trace back from.
+    /// - `graph`: lineage graph expected to still hold the flow edge the
+    ///   alert refers to.
+    ///
+    /// # Returns
+    /// `None` if the receipt, its alert, or the alert's edge cannot be found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     ExfiltrationSentinel, LineageGraph, SentinelConfig,
+    /// };
+    ///
+    /// let sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// let graph = LineageGraph::new(SentinelConfig::default());
+    /// assert!(sentinel.forensic_trace("receipt-unknown", &graph).is_none());
+    /// ```
+    pub fn forensic_trace(&self, receipt_id: &str, graph: &LineageGraph) -> Option<ForensicTrace> {
+        let receipt = self.receipts.get(receipt_id)?;
+        let alert = self.alerts.get(&receipt.alert_id)?;
+        let edge = graph.get_edge(&alert.edge_id)?;
+        Some(ForensicTrace {
+            receipt: receipt.clone(),
+            alert: alert.clone(),
+            edge: edge.clone(),
+        })
+    }
+
     /// Get alert count.
     ///
     /// # Examples
@@ -2767,6 +4256,7 @@ impl ExfiltrationSentinel {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:db", "SECRET").unwrap();
     /// graph
@@ -2780,6 +4270,7 @@ impl ExfiltrationSentinel {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
+    ///     operation_restriction: None,
     /// }).unwrap();
     ///
     /// let scan = sentinel.scan_graph(&mut graph).unwrap();
@@ -2833,6 +4324,81 @@ impl ExfiltrationSentinel {
         })
     }
 
+    /// Build a zone-to-zone flow matrix over every edge in `graph`: for each
+    /// `(from_zone, to_zone)` pair crossed by at least one registered
+    /// [`TaintBoundary`], counts how many edges crossed it and how many of
+    /// those crossings violated a boundary covering that pair. An edge
+    /// crossing several boundaries that share the same zone pair is counted
+    /// once per pair, not once per boundary.
+    ///
+    /// Unlike [`Self::evaluate_edge`] and [`Self::scan_graph`], this is a
+    /// read-only report: it never raises alerts, issues receipts, or
+    /// quarantines edges. The returned `BTreeMap` orders cells by
+    /// `(from_zone, to_zone)`, so the heatmap is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    ///
+    /// use frankenengine_node::security::lineage_tracker::{
+    ///     ExfiltrationSentinel, LineageGraph, SentinelConfig, TaintBoundary, TaintLabel,
+    /// };
+    ///
+    /// let mut graph = LineageGraph::new(SentinelConfig::default());
+    /// graph.register_label(TaintLabel {
+    ///     id: "SECRET".to_string(),
+    ///     description: "Sensitive".to_string(),
+    ///     severity: 100,
+    ///     expires_at_ms: None,
+    /// });
+    /// graph.assign_taint("internal:db", "SECRET").unwrap();
+    /// graph
+    ///     .propagate_taint("internal:db", "external:api", "export", 10)
+    ///     .unwrap();
+    ///
+    /// let mut sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
+    /// sentinel.add_boundary(TaintBoundary {
+    ///     boundary_id: "b-1".to_string(),
+    ///     from_zone: "internal".to_string(),
+    ///     to_zone: "external".to_string(),
+    ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
+    ///     deny_all: false,
+    ///     operation_restriction: None,
+    /// }).unwrap();
+    ///
+    /// let heatmap = sentinel.flow_heatmap(&graph);
+    /// let cell = &heatmap[&("internal".to_string(), "external".to_string())];
+    /// assert_eq!(cell.crossings, 1);
+    /// assert_eq!(cell.violations, 1);
+    /// ```
+    pub fn flow_heatmap(&self, graph: &LineageGraph) -> BTreeMap<(String, String), FlowCell> {
+        let mut heatmap: BTreeMap<(String, String), FlowCell> = BTreeMap::new();
+
+        for edge in graph.edges.values() {
+            let mut crossed: BTreeMap<(String, String), bool> = BTreeMap::new();
+            for boundary in self.boundaries.values() {
+                if !boundary.crosses_edge(edge, self.config.legacy_substring_zones) {
+                    continue;
+                }
+                let key = (boundary.from_zone.clone(), boundary.to_zone.clone());
+                let violated = boundary.is_violated_by(&edge.taint_set);
+                let entry = crossed.entry(key).or_insert(false);
+                *entry = *entry || violated;
+            }
+
+            for (key, violated) in crossed {
+                let cell = heatmap.entry(key).or_default();
+                cell.crossings = cell.crossings.saturating_add(1);
+                if violated {
+                    cell.violations = cell.violations.saturating_add(1);
+                }
+            }
+        }
+
+        heatmap
+    }
+
     /// Evaluate recall and precision against ground-truth labels.
     /// INV-SENTINEL-RECALL-THRESHOLD, INV-SENTINEL-PRECISION-THRESHOLD.
     ///
@@ -2908,6 +4474,7 @@ impl ExfiltrationSentinel {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:db", "SECRET").unwrap();
     /// for ts in 1..=3 {
@@ -2974,6 +4541,7 @@ impl ExfiltrationSentinel {
     ///     id: "PII".to_string(),
     ///     description: "Personally identifiable information".to_string(),
     ///     severity: 80,
+    ///     expires_at_ms: None,
     /// });
     /// let sentinel = ExfiltrationSentinel::new(SentinelConfig::default());
     ///
@@ -3017,6 +4585,7 @@ impl ExfiltrationSentinel {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:db", "SECRET").unwrap();
     ///
@@ -3027,6 +4596,7 @@ impl ExfiltrationSentinel {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
+    ///     operation_restriction: None,
     /// }).unwrap();
     ///
     /// let verdict = sentinel
@@ -3100,6 +4670,15 @@ pub struct SentinelScanResult {
     pub exfiltrations_contained: u64,
 }
 
+/// One cell of the [`ExfiltrationSentinel::flow_heatmap`] zone-to-zone flow
+/// matrix: how many edges crossed a `(from_zone, to_zone)` pair, and how many
+/// of those crossings violated a boundary covering that pair.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowCell {
+    pub crossings: u64,
+    pub violations: u64,
+}
+
 /// Recall/precision metrics from sentinel evaluation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SentinelMetrics {
@@ -3184,6 +4763,11 @@ pub mod invariants {
 
     /// Verify INV-IFL-QUARANTINE-RECEIPT: quarantined edges have receipts.
     ///
+    /// Edges un-quarantined via [`LineageGraph::release_edge`] are excluded
+    /// from the receipted side of the comparison, so a released edge's
+    /// now-stale [`ContainmentReceipt`] doesn't fail this invariant once the
+    /// edge is no longer quarantined.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -3199,6 +4783,7 @@ pub mod invariants {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:db", "SECRET").unwrap();
     /// graph
@@ -3212,6 +4797,7 @@ pub mod invariants {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
+    ///     operation_restriction: None,
     /// }).unwrap();
     /// sentinel.scan_graph(&mut graph).unwrap();
     ///
@@ -3231,7 +4817,7 @@ pub mod invariants {
         let receipted_edges: BTreeSet<String> = sentinel
             .receipts
             .values()
-            .filter(|r| r.success)
+            .filter(|r| r.success && !graph.released_edges.contains_key(&r.edge_id))
             .map(|r| r.edge_id.clone())
             .collect();
 
@@ -3260,6 +4846,8 @@ pub mod invariants {
     ///     taint_set: taints,
     ///     timestamp_ms: 1,
     ///     quarantined: false,
+    ///     source_zone: None,
+    ///     sink_zone: None,
     /// };
     /// let boundaries = BTreeMap::from([(
     ///     "b-1".to_string(),
@@ -3269,6 +4857,7 @@ pub mod invariants {
     ///         to_zone: "external".to_string(),
     ///         denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///         deny_all: false,
+    ///         operation_restriction: None,
     ///     },
     /// )]);
     ///
@@ -3289,7 +4878,7 @@ pub mod invariants {
         boundaries: &BTreeMap<String, TaintBoundary>,
     ) -> FlowVerdict {
         for boundary in boundaries.values() {
-            let crosses = boundary.crosses_edge(edge);
+            let crosses = boundary.crosses_edge(edge, false);
             if crosses && boundary.is_violated_by(&edge.taint_set) {
                 return FlowVerdict::Quarantine;
             }
@@ -3337,6 +4926,7 @@ pub mod invariants {
     ///     id: "SECRET".to_string(),
     ///     description: "Sensitive".to_string(),
     ///     severity: 100,
+    ///     expires_at_ms: None,
     /// });
     /// graph.assign_taint("internal:db", "SECRET").unwrap();
     /// graph
@@ -3350,6 +4940,7 @@ pub mod invariants {
     ///     to_zone: "external".to_string(),
     ///     denied_labels: BTreeSet::from(["SECRET".to_string()]),
     ///     deny_all: false,
+    ///     operation_restriction: None,
     /// };
     /// sentinel.add_boundary(boundary.clone()).unwrap();
     /// sentinel.scan_graph(&mut graph).unwrap();
@@ -3363,7 +4954,7 @@ pub mod invariants {
     ) -> bool {
         for edge in graph.edges.values() {
             for boundary in boundaries.values() {
-                let crosses = boundary.crosses_edge(edge);
+                let crosses = boundary.crosses_edge(edge, false);
                 if crosses && boundary.is_violated_by(&edge.taint_set) && !edge.quarantined {
                     return false;
                 }
@@ -3456,6 +5047,7 @@ mod tests {
             id: id.to_string(),
             description: format!("{} label", id),
             severity,
+            expires_at_ms: None,
         }
     }
 
@@ -3466,6 +5058,7 @@ mod tests {
             to_zone: to.to_string(),
             denied_labels: denied.iter().map(|s| s.to_string()).collect(),
             deny_all: false,
+            operation_restriction: None,
         }
     }
 
@@ -3505,6 +5098,8 @@ mod tests {
             taint_set: TaintSet::new(),
             timestamp_ms,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         }
     }
 
@@ -3927,6 +5522,59 @@ mod tests {
         assert_eq!(ts1.len(), 2);
     }
 
+    #[test]
+    fn test_taint_set_expire_drops_only_expired_labels() {
+        let mut registry = BTreeMap::new();
+        registry.insert(
+            "PII".to_string(),
+            TaintLabel {
+                id: "PII".to_string(),
+                description: "Permanent classification".to_string(),
+                severity: 80,
+                expires_at_ms: None,
+            },
+        );
+        registry.insert(
+            "SESSION".to_string(),
+            TaintLabel {
+                id: "SESSION".to_string(),
+                description: "Short-lived session data".to_string(),
+                severity: 20,
+                expires_at_ms: Some(1_000),
+            },
+        );
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+        ts.insert("SESSION");
+
+        ts.expire(999, &registry);
+        assert!(
+            ts.contains("SESSION"),
+            "expiry is exclusive of now_ms reaching the deadline"
+        );
+
+        ts.expire(1_000, &registry);
+        assert!(!ts.contains("SESSION"), "expired label must be dropped");
+        assert!(
+            ts.contains("PII"),
+            "non-expiring label must survive regardless of now_ms"
+        );
+    }
+
+    #[test]
+    fn test_taint_set_expire_ignores_labels_missing_from_the_registry() {
+        let registry = BTreeMap::new();
+        let mut ts = TaintSet::new();
+        ts.insert("UNREGISTERED");
+
+        ts.expire(u64::MAX, &registry);
+        assert!(
+            ts.contains("UNREGISTERED"),
+            "a label the registry no longer knows about is not assumed expired"
+        );
+    }
+
     #[test]
     fn test_register_label() {
         let mut graph = LineageGraph::new(default_config());
@@ -3952,23 +5600,96 @@ mod tests {
     }
 
     #[test]
-    fn test_append_edge_success() {
-        let mut graph = LineageGraph::new(default_config());
-        let edge = FlowEdge {
-            edge_id: String::new(),
-            source: "node-a".to_string(),
-            sink: "node-b".to_string(),
-            operation: "copy".to_string(),
-            taint_set: TaintSet::new(),
-            timestamp_ms: 1000,
-            quarantined: false,
+    fn test_assign_taint_beyond_cap_errors_and_preserves_existing_labels() {
+        let config = SentinelConfig {
+            max_taint_set_size: 2,
+            ..default_config()
         };
-        let id = graph.append_edge(edge).unwrap();
-        assert_eq!(id, "edge-1");
-        assert_eq!(graph.edge_count(), 1);
-    }
+        let mut graph = LineageGraph::new(config);
+        graph.register_label(make_label("A", 10));
+        graph.register_label(make_label("B", 10));
+        graph.register_label(make_label("C", 10));
 
-    #[test]
+        graph.assign_taint("datum-1", "A").unwrap();
+        graph.assign_taint("datum-1", "B").unwrap();
+
+        let err = graph.assign_taint("datum-1", "C").unwrap_err();
+        assert!(err.to_string().contains(ERR_IFL_TAINT_SET_OVERFLOW));
+
+        let ts = graph.get_taint_set("datum-1").unwrap();
+        assert!(ts.contains("A"));
+        assert!(ts.contains("B"));
+        assert!(!ts.contains("C"));
+        assert_eq!(ts.len(), 2);
+    }
+
+    #[test]
+    fn test_assign_taint_of_existing_label_at_cap_is_a_no_op_success() {
+        let config = SentinelConfig {
+            max_taint_set_size: 1,
+            ..default_config()
+        };
+        let mut graph = LineageGraph::new(config);
+        graph.register_label(make_label("A", 10));
+
+        graph.assign_taint("datum-1", "A").unwrap();
+        // Re-assigning an already-held label must not error even though
+        // the set is already at its cap.
+        assert!(graph.assign_taint("datum-1", "A").is_ok());
+        assert_eq!(graph.get_taint_set("datum-1").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_expire_taints_drops_expired_label_but_keeps_permanent_one() {
+        let mut graph = LineageGraph::new(default_config());
+        graph.register_label(make_label("PII", 80));
+        graph.register_label(TaintLabel {
+            id: "SESSION".to_string(),
+            description: "Short-lived session data".to_string(),
+            severity: 20,
+            expires_at_ms: Some(1_000),
+        });
+        graph.assign_taint("datum-1", "PII").unwrap();
+        graph.assign_taint("datum-1", "SESSION").unwrap();
+
+        graph.expire_taints("datum-1", 1_000);
+
+        let taints = graph.get_taint_set("datum-1").unwrap();
+        assert!(!taints.contains("SESSION"), "expired label must be dropped");
+        assert!(
+            taints.contains("PII"),
+            "INV-IFL-LABEL-PERSIST: non-expiring label must survive"
+        );
+    }
+
+    #[test]
+    fn test_expire_taints_on_datum_with_no_taint_set_is_a_no_op() {
+        let mut graph = LineageGraph::new(default_config());
+        // Must not panic even though "datum-1" was never tainted.
+        graph.expire_taints("datum-1", 1_000);
+        assert!(graph.get_taint_set("datum-1").is_none());
+    }
+
+    #[test]
+    fn test_append_edge_success() {
+        let mut graph = LineageGraph::new(default_config());
+        let edge = FlowEdge {
+            edge_id: String::new(),
+            source: "node-a".to_string(),
+            sink: "node-b".to_string(),
+            operation: "copy".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 1000,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        let id = graph.append_edge(edge).unwrap();
+        assert_eq!(id, "edge-1");
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
     fn test_append_edge_duplicate() {
         let mut graph = LineageGraph::new(default_config());
         let edge = FlowEdge {
@@ -3979,6 +5700,8 @@ mod tests {
             taint_set: TaintSet::new(),
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
         let err = graph.append_edge(edge).unwrap_err();
@@ -3998,6 +5721,8 @@ mod tests {
             taint_set: TaintSet::new(),
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(e1).unwrap();
         let e2 = FlowEdge {
@@ -4008,11 +5733,141 @@ mod tests {
             taint_set: TaintSet::new(),
             timestamp_ms: 2,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         let err = graph.append_edge(e2).unwrap_err();
         assert!(err.to_string().contains(ERR_IFL_GRAPH_FULL));
     }
 
+    #[test]
+    fn append_edges_reports_duplicate_without_losing_the_rest_of_the_batch() {
+        let mut graph = LineageGraph::new(default_config());
+        let edge = |id: &str| FlowEdge {
+            edge_id: id.to_string(),
+            source: "a".to_string(),
+            sink: "b".to_string(),
+            operation: "op".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 1,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge("e1")).unwrap();
+
+        let result = graph.append_edges(vec![edge("e2"), edge("e1"), edge("e3")]);
+
+        assert_eq!(result.appended, vec!["e2".to_string(), "e3".to_string()]);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].index, 1);
+        assert!(
+            result.failures[0]
+                .error
+                .to_string()
+                .contains(ERR_IFL_DUPLICATE_EDGE)
+        );
+        assert_eq!(graph.edge_count(), 3);
+    }
+
+    #[test]
+    fn append_edges_halts_cleanly_at_graph_full_but_keeps_prior_successes() {
+        let mut config = default_config();
+        config.max_graph_edges = 2;
+        let mut graph = LineageGraph::new(config);
+        let edge = |id: &str| FlowEdge {
+            edge_id: id.to_string(),
+            source: "a".to_string(),
+            sink: "b".to_string(),
+            operation: "op".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 1,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+
+        let result = graph.append_edges(vec![edge("e1"), edge("e2"), edge("e3")]);
+
+        assert_eq!(result.appended, vec!["e1".to_string(), "e2".to_string()]);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].index, 2);
+        assert!(
+            result.failures[0]
+                .error
+                .to_string()
+                .contains(ERR_IFL_GRAPH_FULL)
+        );
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn new_with_namespace_rejects_empty_namespace() {
+        let err = LineageGraph::new_with_namespace(default_config(), "").unwrap_err();
+        assert!(err.to_string().contains(ERR_IFL_CONFIG_REJECTED));
+    }
+
+    #[test]
+    fn namespaced_auto_ids_are_prefixed_and_explicit_ids_still_work() {
+        let mut graph = LineageGraph::new_with_namespace(default_config(), "runtime-a").unwrap();
+
+        let auto_edge = FlowEdge {
+            edge_id: String::new(),
+            source: "node-a".to_string(),
+            sink: "node-b".to_string(),
+            operation: "copy".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 1000,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        let auto_id = graph.append_edge(auto_edge).unwrap();
+        assert_eq!(auto_id, "runtime-a-edge-1");
+
+        let explicit_edge = FlowEdge {
+            edge_id: "explicit-edge".to_string(),
+            source: "node-c".to_string(),
+            sink: "node-d".to_string(),
+            operation: "copy".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 1001,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        let explicit_id = graph.append_edge(explicit_edge).unwrap();
+        assert_eq!(explicit_id, "explicit-edge");
+        assert!(graph.get_edge("explicit-edge").is_some());
+    }
+
+    #[test]
+    fn two_namespaced_graphs_never_produce_colliding_auto_ids() {
+        let mut graph_a = LineageGraph::new_with_namespace(default_config(), "runtime-a").unwrap();
+        let mut graph_b = LineageGraph::new_with_namespace(default_config(), "runtime-b").unwrap();
+
+        let mut ids = std::collections::HashSet::new();
+        for i in 0..5 {
+            let edge = FlowEdge {
+                edge_id: String::new(),
+                source: format!("src-{i}"),
+                sink: format!("sink-{i}"),
+                operation: "copy".to_string(),
+                taint_set: TaintSet::new(),
+                timestamp_ms: i as u64,
+                quarantined: false,
+                source_zone: None,
+                sink_zone: None,
+            };
+            let id_a = graph_a.append_edge(edge.clone()).unwrap();
+            let id_b = graph_b.append_edge(edge).unwrap();
+            assert_ne!(id_a, id_b);
+            assert!(ids.insert(id_a));
+            assert!(ids.insert(id_b));
+        }
+        assert_eq!(ids.len(), 10);
+    }
+
     #[test]
     fn test_propagate_taint() {
         let mut graph = LineageGraph::new(default_config());
@@ -4026,6 +5881,173 @@ mod tests {
         assert!(dst_taint.contains("PII"));
     }
 
+    #[test]
+    fn test_propagate_taint_carries_expiry_metadata_through_the_merge() {
+        let mut graph = LineageGraph::new(default_config());
+        graph.register_label(TaintLabel {
+            id: "SESSION".to_string(),
+            description: "Short-lived session data".to_string(),
+            severity: 20,
+            expires_at_ms: Some(1_000),
+        });
+        graph.assign_taint("src", "SESSION").unwrap();
+
+        graph.propagate_taint("src", "dst", "copy", 100).unwrap();
+        assert!(graph.get_taint_set("dst").unwrap().contains("SESSION"));
+
+        // The propagated copy expires at exactly the same deadline as the
+        // original, since both resolve "SESSION" against the same registry.
+        graph.expire_taints("dst", 1_000);
+        assert!(!graph.get_taint_set("dst").unwrap().contains("SESSION"));
+    }
+
+    #[test]
+    fn test_propagate_taint_beyond_cap_errors_and_preserves_existing_labels() {
+        let config = SentinelConfig {
+            max_taint_set_size: 1,
+            ..default_config()
+        };
+        let mut graph = LineageGraph::new(config);
+        graph.register_label(make_label("A", 10));
+        graph.register_label(make_label("B", 10));
+
+        graph.assign_taint("src", "A").unwrap();
+        graph.assign_taint("dst", "B").unwrap();
+
+        let err = graph
+            .propagate_taint("src", "dst", "transform", 100)
+            .unwrap_err();
+        assert!(err.to_string().contains(ERR_IFL_TAINT_SET_OVERFLOW));
+
+        // dst must keep its pre-existing label untouched.
+        let dst_taint = graph.get_taint_set("dst").unwrap();
+        assert!(dst_taint.contains("B"));
+        assert!(!dst_taint.contains("A"));
+        assert_eq!(dst_taint.len(), 1);
+    }
+
+    #[test]
+    fn reachable_sinks_and_sources_walk_a_diamond_shaped_flow_graph() {
+        // A -> B -> D
+        // A -> C -> D
+        let mut graph = LineageGraph::new(default_config());
+        graph.propagate_taint("A", "B", "copy", 100).unwrap();
+        graph.propagate_taint("A", "C", "copy", 100).unwrap();
+        graph.propagate_taint("B", "D", "copy", 200).unwrap();
+        graph.propagate_taint("C", "D", "copy", 200).unwrap();
+
+        assert_eq!(
+            graph.reachable_sinks("A", 10),
+            vec!["B".to_string(), "C".to_string(), "D".to_string()]
+        );
+        assert_eq!(
+            graph.reachable_sources("D", 10),
+            vec!["A".to_string(), "B".to_string(), "C".to_string()]
+        );
+        // A leaf sink has no further sinks downstream of it.
+        assert!(graph.reachable_sinks("D", 10).is_empty());
+    }
+
+    #[test]
+    fn reachable_sinks_does_not_infinite_loop_on_a_cycle() {
+        // A -> B -> C -> A
+        let mut graph = LineageGraph::new(default_config());
+        graph.propagate_taint("A", "B", "copy", 100).unwrap();
+        graph.propagate_taint("B", "C", "copy", 200).unwrap();
+        graph.propagate_taint("C", "A", "copy", 300).unwrap();
+
+        // A is reachable from itself through the cycle, and every node in
+        // the cycle is discovered exactly once.
+        assert_eq!(
+            graph.reachable_sinks("A", 10),
+            vec!["A".to_string(), "B".to_string(), "C".to_string()]
+        );
+    }
+
+    #[test]
+    fn reachable_sinks_clamps_to_configured_max_graph_depth() {
+        // A -> B -> C -> D, a straight chain four hops deep.
+        let config = SentinelConfig {
+            max_graph_depth: 1,
+            ..default_config()
+        };
+        let mut graph = LineageGraph::new(config);
+        graph.propagate_taint("A", "B", "copy", 100).unwrap();
+        graph.propagate_taint("B", "C", "copy", 200).unwrap();
+        graph.propagate_taint("C", "D", "copy", 300).unwrap();
+
+        // Even though the caller asked for depth 10, the graph's own
+        // max_graph_depth of 1 caps the walk to a single hop.
+        assert_eq!(graph.reachable_sinks("A", 10), vec!["B".to_string()]);
+    }
+
+    #[test]
+    fn label_spread_grows_as_pii_propagates_through_a_chain() {
+        let mut graph = LineageGraph::new(default_config());
+        graph.register_label(make_label("PII", 10));
+        graph.assign_taint("datum-a", "PII").unwrap();
+
+        let spread = graph.label_spread();
+        assert_eq!(spread["PII"].datum_count, 1);
+        assert_eq!(spread["PII"].edge_count, 0);
+
+        graph
+            .propagate_taint("datum-a", "datum-b", "copy", 100)
+            .unwrap();
+        graph
+            .propagate_taint("datum-b", "datum-c", "copy", 200)
+            .unwrap();
+
+        let spread = graph.label_spread();
+        assert_eq!(spread["PII"].datum_count, 3);
+        assert_eq!(spread["PII"].edge_count, 2);
+    }
+
+    #[test]
+    fn label_spread_reports_zero_for_an_unused_label() {
+        let mut graph = LineageGraph::new(default_config());
+        graph.register_label(make_label("PII", 10));
+        graph.register_label(make_label("SECRET", 90));
+        graph.assign_taint("datum-a", "PII").unwrap();
+
+        let spread = graph.label_spread();
+        assert_eq!(
+            spread["SECRET"],
+            LabelSpread {
+                datum_count: 0,
+                edge_count: 0
+            }
+        );
+    }
+
+    #[test]
+    fn neighborhood_one_hop_around_middle_node_contains_only_its_immediate_edges() {
+        let mut graph = LineageGraph::new(default_config());
+        let e1 = graph.propagate_taint("a", "b", "copy", 100).unwrap();
+        let e2 = graph.propagate_taint("b", "c", "copy", 200).unwrap();
+        let e3 = graph.propagate_taint("c", "d", "copy", 300).unwrap();
+
+        let snapshot = graph.neighborhood("b", 1);
+        let edge_ids: BTreeSet<String> = snapshot.edges.iter().map(|e| e.edge_id.clone()).collect();
+        assert_eq!(edge_ids, BTreeSet::from([e1, e2]));
+        assert!(!edge_ids.contains(&e3));
+        assert_eq!(snapshot.edge_count, 2);
+
+        let subgraph = LineageGraph::new(default_config());
+        let subgraph = {
+            let mut sub_edges = BTreeMap::new();
+            for edge in &snapshot.edges {
+                sub_edges.insert(edge.edge_id.clone(), edge.clone());
+            }
+            LineageGraph {
+                edges: sub_edges,
+                labels: snapshot.labels.clone(),
+                ..subgraph
+            }
+        };
+        assert!(invariants::verify_snapshot_faithful(&subgraph, &snapshot));
+    }
+
     #[test]
     fn lineage_transform_kind_operation_names_are_stable() {
         let cases = [
@@ -4186,6 +6208,73 @@ mod tests {
         assert_eq!(sentinel.alert_count(), 1);
     }
 
+    fn make_export_restricted_boundary(id: &str, from: &str, to: &str, denied: &[&str]) -> TaintBoundary {
+        TaintBoundary {
+            boundary_id: id.to_string(),
+            from_zone: from.to_string(),
+            to_zone: to.to_string(),
+            denied_labels: denied.iter().map(|s| s.to_string()).collect(),
+            deny_all: false,
+            operation_restriction: Some(BTreeSet::from([OperationClass::Export])),
+        }
+    }
+
+    #[test]
+    fn operation_restricted_boundary_blocks_export_of_same_taint() {
+        let mut graph = LineageGraph::new(default_config());
+        graph.register_label(make_label("SECRET", 90));
+        graph.assign_taint("internal:secret", "SECRET").unwrap();
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        sentinel
+            .add_boundary(make_export_restricted_boundary(
+                "b1",
+                "internal",
+                "external",
+                &["SECRET"],
+            ))
+            .unwrap();
+
+        let verdict = sentinel
+            .track_flow(&mut graph, "internal:secret", "external:api", "export", 10)
+            .unwrap();
+
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.alert_count(), 1);
+    }
+
+    #[test]
+    fn operation_restricted_boundary_allows_read_of_same_taint() {
+        let mut graph = LineageGraph::new(default_config());
+        graph.register_label(make_label("SECRET", 90));
+        graph.assign_taint("internal:secret", "SECRET").unwrap();
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        sentinel
+            .add_boundary(make_export_restricted_boundary(
+                "b1",
+                "internal",
+                "external",
+                &["SECRET"],
+            ))
+            .unwrap();
+
+        let verdict = sentinel
+            .track_flow(&mut graph, "internal:secret", "external:api", "read", 10)
+            .unwrap();
+
+        assert_eq!(verdict, FlowVerdict::Pass);
+        assert_eq!(sentinel.alert_count(), 0);
+    }
+
+    #[test]
+    fn operation_class_parse_classifies_known_and_unknown_operations() {
+        assert_eq!(OperationClass::parse("export"), OperationClass::Export);
+        assert_eq!(OperationClass::parse("Export-To-S3"), OperationClass::Export);
+        assert_eq!(OperationClass::parse("copy_file"), OperationClass::Copy);
+        assert_eq!(OperationClass::parse("transform"), OperationClass::Transform);
+        assert_eq!(OperationClass::parse("read_file"), OperationClass::Read);
+        assert_eq!(OperationClass::parse("frobnicate"), OperationClass::Other);
+    }
+
     #[test]
     fn evaluate_sink_quarantines_forbidden_label_without_declassification() {
         let mut graph = LineageGraph::new(default_config());
@@ -4351,6 +6440,8 @@ mod tests {
             taint_set: TaintSet::new(),
             timestamp_ms: 42,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
         let snap = graph.snapshot("snap-1", 100);
@@ -4361,36 +6452,103 @@ mod tests {
     }
 
     #[test]
-    fn test_query_by_source() {
+    fn annotate_edge_does_not_mutate_the_edge_record() {
         let mut graph = LineageGraph::new(default_config());
-        let e1 = FlowEdge {
-            edge_id: "e1".to_string(),
-            source: "nodeA".to_string(),
-            sink: "nodeB".to_string(),
-            operation: "op".to_string(),
-            taint_set: TaintSet::new(),
-            timestamp_ms: 1,
-            quarantined: false,
-        };
-        let e2 = FlowEdge {
-            edge_id: "e2".to_string(),
-            source: "nodeC".to_string(),
-            sink: "nodeD".to_string(),
-            operation: "op".to_string(),
-            taint_set: TaintSet::new(),
-            timestamp_ms: 2,
-            quarantined: false,
-        };
-        graph.append_edge(e1).unwrap();
-        graph.append_edge(e2).unwrap();
-        let q = LineageQuery {
-            source: Some("nodeA".to_string()),
-            ..Default::default()
-        };
-        let results = graph.query(&q).unwrap();
-        assert_eq!(results.len(), 1);
-        assert_eq!(results[0].source, "nodeA");
-    }
+        let edge_id = graph.propagate_taint("a", "b", "copy", 100).unwrap();
+        let before = graph.get_edge(&edge_id).unwrap().clone();
+
+        graph
+            .annotate_edge(&edge_id, "confirmed benign, test data", "alice", 200)
+            .unwrap();
+
+        assert_eq!(graph.get_edge(&edge_id).unwrap(), &before);
+    }
+
+    #[test]
+    fn annotate_edge_accumulates_multiple_notes_in_order() {
+        let mut graph = LineageGraph::new(default_config());
+        let edge_id = graph.propagate_taint("a", "b", "copy", 100).unwrap();
+
+        graph
+            .annotate_edge(&edge_id, "first note", "alice", 200)
+            .unwrap();
+        graph
+            .annotate_edge(&edge_id, "second note", "bob", 300)
+            .unwrap();
+
+        let notes = graph.edge_annotations(&edge_id);
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].note, "first note");
+        assert_eq!(notes[0].author, "alice");
+        assert_eq!(notes[1].note, "second note");
+        assert_eq!(notes[1].author, "bob");
+    }
+
+    #[test]
+    fn annotate_edge_rejects_unknown_edge_id() {
+        let mut graph = LineageGraph::new(default_config());
+        let err = graph
+            .annotate_edge("missing", "note", "alice", 200)
+            .unwrap_err();
+        assert!(matches!(err, LineageError::EdgeNotFound { .. }));
+    }
+
+    #[test]
+    fn edge_annotations_survive_snapshot_round_trip() {
+        let mut graph = LineageGraph::new(default_config());
+        let edge_id = graph.propagate_taint("a", "b", "copy", 100).unwrap();
+        graph
+            .annotate_edge(&edge_id, "confirmed benign, test data", "alice", 200)
+            .unwrap();
+
+        let snapshot = graph.snapshot("snap-annotations", 300);
+        let json = serde_json::to_string(&snapshot).expect("snapshot should serialize");
+        let restored: LineageSnapshot =
+            serde_json::from_str(&json).expect("snapshot should deserialize");
+
+        assert_eq!(restored.annotations[&edge_id].len(), 1);
+        assert_eq!(
+            restored.annotations[&edge_id][0].note,
+            "confirmed benign, test data"
+        );
+        assert_eq!(restored.annotations[&edge_id][0].author, "alice");
+    }
+
+    #[test]
+    fn test_query_by_source() {
+        let mut graph = LineageGraph::new(default_config());
+        let e1 = FlowEdge {
+            edge_id: "e1".to_string(),
+            source: "nodeA".to_string(),
+            sink: "nodeB".to_string(),
+            operation: "op".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 1,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        let e2 = FlowEdge {
+            edge_id: "e2".to_string(),
+            source: "nodeC".to_string(),
+            sink: "nodeD".to_string(),
+            operation: "op".to_string(),
+            taint_set: TaintSet::new(),
+            timestamp_ms: 2,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(e1).unwrap();
+        graph.append_edge(e2).unwrap();
+        let q = LineageQuery {
+            source: Some("nodeA".to_string()),
+            ..Default::default()
+        };
+        let results = graph.query(&q).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source, "nodeA");
+    }
 
     #[test]
     fn test_query_invalid_timestamp_range() {
@@ -4428,68 +6586,642 @@ mod tests {
             to_zone: "public".to_string(),
             denied_labels: BTreeSet::new(),
             deny_all: true,
+            operation_restriction: None,
+        };
+        let mut ts = TaintSet::new();
+        ts.insert("ANY");
+        assert!(boundary.is_violated_by(&ts));
+        assert!(!boundary.is_violated_by(&TaintSet::new()));
+    }
+
+    #[test]
+    fn test_sentinel_evaluate_and_quarantine() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+
+        let edge = FlowEdge {
+            edge_id: "exfil-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 500,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.alert_count(), 1);
+        assert_eq!(sentinel.receipt_count(), 1);
+
+        // Verify the edge is quarantined in the graph
+        let quarantined_edge = graph.get_edge("exfil-1").unwrap();
+        assert!(quarantined_edge.quarantined);
+    }
+
+    struct RecordingHook {
+        calls: std::sync::Mutex<Vec<(String, String)>>,
+    }
+
+    impl RecordingHook {
+        fn new() -> Self {
+            Self {
+                calls: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl QuarantineHook for std::sync::Arc<RecordingHook> {
+        fn on_quarantine(&self, alert: &ExfiltrationAlert, edge: &FlowEdge) -> Result<(), String> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((alert.alert_id.clone(), edge.edge_id.clone()));
+            Ok(())
+        }
+    }
+
+    struct FailingHook;
+
+    impl QuarantineHook for FailingHook {
+        fn on_quarantine(
+            &self,
+            _alert: &ExfiltrationAlert,
+            _edge: &FlowEdge,
+        ) -> Result<(), String> {
+            Err("hook exploded".to_string())
+        }
+    }
+
+    fn quarantine_edge(sentinel: &mut ExfiltrationSentinel, graph: &mut LineageGraph) -> FlowEdge {
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+
+        let edge = FlowEdge {
+            edge_id: "exfil-hook-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 500,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+        sentinel.evaluate_edge(&edge, graph).unwrap();
+        edge
+    }
+
+    #[test]
+    fn recording_quarantine_hook_is_invoked_once_per_quarantine() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let hook = std::sync::Arc::new(RecordingHook::new());
+
+        let mut sentinel =
+            ExfiltrationSentinel::new(config).with_quarantine_hook(Box::new(hook.clone()));
+        let edge = quarantine_edge(&mut sentinel, &mut graph);
+
+        let calls = hook.calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].1, edge.edge_id);
+        assert_eq!(sentinel.hook_failures(), 0);
+    }
+
+    #[test]
+    fn failing_quarantine_hook_increments_counter_without_aborting() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel =
+            ExfiltrationSentinel::new(config).with_quarantine_hook(Box::new(FailingHook));
+
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+        let edge = FlowEdge {
+            edge_id: "exfil-hook-2".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 500,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.hook_failures(), 1);
+    }
+
+    #[test]
+    fn replay_alert_reproduces_quarantine_against_same_boundaries() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        let edge = quarantine_edge(&mut sentinel, &mut graph);
+
+        let alert_id = sentinel
+            .alerts()
+            .iter()
+            .find(|(_, alert)| alert.edge_id == edge.edge_id)
+            .map(|(id, _)| id.clone())
+            .expect("evaluate_edge recorded an alert for the quarantined edge");
+
+        let boundaries_at_time = BTreeMap::from([(
+            "b1".to_string(),
+            make_boundary("b1", "internal", "external", &["PII"]),
+        )]);
+
+        assert_eq!(
+            sentinel.replay_alert(&alert_id, &boundaries_at_time),
+            Some(FlowVerdict::Quarantine)
+        );
+    }
+
+    #[test]
+    fn replay_alert_against_relaxed_boundaries_yields_pass() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        let edge = quarantine_edge(&mut sentinel, &mut graph);
+
+        let alert_id = sentinel
+            .alerts()
+            .iter()
+            .find(|(_, alert)| alert.edge_id == edge.edge_id)
+            .map(|(id, _)| id.clone())
+            .expect("evaluate_edge recorded an alert for the quarantined edge");
+
+        // The boundary in force at replay time no longer denies "PII".
+        let relaxed_boundary = make_boundary("b1", "internal", "external", &[]);
+        let boundaries_at_time = BTreeMap::from([("b1".to_string(), relaxed_boundary)]);
+
+        assert_eq!(
+            sentinel.replay_alert(&alert_id, &boundaries_at_time),
+            Some(FlowVerdict::Pass)
+        );
+    }
+
+    #[test]
+    fn replay_alert_returns_none_for_unknown_alert_id() {
+        let sentinel = ExfiltrationSentinel::new(default_config());
+        assert_eq!(
+            sentinel.replay_alert("does-not-exist", &BTreeMap::new()),
+            None
+        );
+    }
+
+    /// Parses Prometheus text exposition format just enough to validate
+    /// shape (every non-comment, non-blank line is `name{labels} value` or
+    /// `name value`) and to pull out a metric's value by name and label.
+    fn parse_prometheus_metric(output: &str, name: &str, label: Option<(&str, &str)>) -> f64 {
+        for line in output.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (metric_part, value_part) = line
+                .rsplit_once(' ')
+                .expect("every exposition line has a trailing value");
+            let value: f64 = value_part.parse().expect("metric value must parse as f64");
+            let matches_name = metric_part == name || metric_part.starts_with(&format!("{name}{{"));
+            if !matches_name {
+                continue;
+            }
+            match label {
+                None => return value,
+                Some((key, expected)) => {
+                    if metric_part.contains(&format!("{key}=\"{expected}\"")) {
+                        return value;
+                    }
+                }
+            }
+        }
+        panic!("metric `{name}` with label {label:?} not found in:\n{output}");
+    }
+
+    #[test]
+    fn prometheus_metrics_parses_and_matches_state_after_quarantine() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        quarantine_edge(&mut sentinel, &mut graph);
+
+        let output = sentinel.prometheus_metrics();
+
+        // Every non-comment line parses as `name{labels...} value` or
+        // `name value`, with a finite f64 value -- i.e. valid exposition
+        // format.
+        let mut data_lines = 0usize;
+        for line in output.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (_, value_part) = line
+                .rsplit_once(' ')
+                .expect("every exposition line has a trailing value");
+            let value: f64 = value_part.parse().expect("metric value must parse as f64");
+            assert!(value.is_finite());
+            data_lines += 1;
+        }
+        assert!(data_lines > 0);
+
+        assert_eq!(
+            parse_prometheus_metric(&output, "franken_lineage_alerts_total", None),
+            sentinel.alerts.len() as f64
+        );
+        assert_eq!(
+            parse_prometheus_metric(&output, "franken_lineage_receipts_total", None),
+            sentinel.receipts.len() as f64
+        );
+        assert_eq!(
+            parse_prometheus_metric(
+                &output,
+                "franken_lineage_boundary_violations_total",
+                Some(("boundary", "b1")),
+            ),
+            1.0
+        );
+        assert_eq!(
+            parse_prometheus_metric(&output, "franken_lineage_graph_boundaries", None),
+            1.0
+        );
+        assert_eq!(
+            parse_prometheus_metric(&output, "franken_lineage_graph_labels", None),
+            1.0
+        );
+    }
+
+    #[test]
+    fn forensic_trace_resolves_receipt_alert_and_edge() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+
+        let edge = FlowEdge {
+            edge_id: "exfil-trace-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 500,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
+        graph.append_edge(edge.clone()).unwrap();
+        sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+
+        let alert = sentinel.alerts().values().next().unwrap().clone();
+        let receipt = sentinel.receipts().values().next().unwrap().clone();
+
+        assert_eq!(sentinel.receipt_for_alert(&alert.alert_id), Some(&receipt));
+        assert_eq!(
+            sentinel.alert_for_receipt(&receipt.receipt_id),
+            Some(&alert)
+        );
+
+        let trace = sentinel
+            .forensic_trace(&receipt.receipt_id, &graph)
+            .expect("full trace resolves");
+        assert_eq!(trace.receipt, receipt);
+        assert_eq!(trace.alert, alert);
+        assert_eq!(trace.edge.edge_id, "exfil-trace-1");
+    }
+
+    #[test]
+    fn forensic_trace_lookups_return_none_for_unknown_ids() {
+        let config = default_config();
+        let graph = LineageGraph::new(config.clone());
+        let sentinel = ExfiltrationSentinel::new(config);
+
+        assert_eq!(sentinel.receipt_for_alert("no-such-alert"), None);
+        assert_eq!(sentinel.alert_for_receipt("no-such-receipt"), None);
+        assert_eq!(sentinel.forensic_trace("no-such-receipt", &graph), None);
+    }
+
+    #[test]
+    fn test_sentinel_pass_when_no_violation() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["SECRET"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PUBLIC");
+
+        let edge = FlowEdge {
+            edge_id: "safe-1".to_string(),
+            source: "internal-svc".to_string(),
+            sink: "external-cdn".to_string(),
+            operation: "publish".to_string(),
+            taint_set: ts,
+            timestamp_ms: 600,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Pass);
+        assert_eq!(sentinel.alert_count(), 0);
+    }
+
+    #[test]
+    fn simulate_boundary_flags_exactly_the_pii_crossing_edges_without_mutation() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let sentinel = ExfiltrationSentinel::new(config);
+
+        let mut pii = TaintSet::new();
+        pii.insert("PII");
+        let pii_edge = FlowEdge {
+            edge_id: "exfil-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: pii,
+            timestamp_ms: 500,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(pii_edge).unwrap();
+
+        let mut public = TaintSet::new();
+        public.insert("PUBLIC");
+        let safe_edge = FlowEdge {
+            edge_id: "safe-1".to_string(),
+            source: "internal-svc".to_string(),
+            sink: "external-cdn".to_string(),
+            operation: "publish".to_string(),
+            taint_set: public,
+            timestamp_ms: 600,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(safe_edge).unwrap();
+
+        let graph_before = graph.clone();
+        let deny_pii = make_boundary("candidate-b1", "internal", "external", &["PII"]);
+        let report = sentinel.simulate_boundary(&deny_pii, &graph);
+
+        assert_eq!(report.boundary_id, "candidate-b1");
+        assert_eq!(report.edges_evaluated, 2);
+        assert_eq!(report.flagged.len(), 1);
+        assert_eq!(report.flagged[0].edge_id, "exfil-1");
+        assert_eq!(report.flagged[0].verdict, FlowVerdict::Quarantine);
+
+        // Read-only: no mutation, no alerts/receipts, boundary never registered.
+        assert_eq!(graph.edges, graph_before.edges);
+        assert!(!graph.get_edge("exfil-1").unwrap().quarantined);
+        assert_eq!(sentinel.alert_count(), 0);
+        assert_eq!(sentinel.receipt_count(), 0);
+        assert!(sentinel.export_policy().boundaries.is_empty());
+    }
+
+    #[test]
+    fn test_multiple_boundary_violations_emit_all_alerts_but_single_receipt() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+        sentinel
+            .add_boundary(make_boundary("b2", "internal", "external", &["SECRET"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+        ts.insert("SECRET");
+
+        let edge = FlowEdge {
+            edge_id: "multi-boundary-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 605,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.alert_count(), 2);
+        assert_eq!(sentinel.receipt_count(), 1);
+        assert!(graph.get_edge("multi-boundary-1").unwrap().quarantined);
+    }
+
+    #[test]
+    fn test_repeated_identical_exfil_within_cooldown_is_suppressed_not_reported() {
+        let config = SentinelConfig {
+            alert_cooldown_ms: 1_000,
+            ..default_config()
+        };
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+
+        let first = FlowEdge {
+            edge_id: "retry-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 1_000,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(first.clone()).unwrap();
+        let verdict = sentinel.evaluate_edge(&first, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.alert_count(), 1);
+        assert_eq!(sentinel.receipt_count(), 1);
+
+        // A second, identical-violation edge arrives 500ms later (inside the
+        // 1000ms cooldown): quarantined, but folded into the existing alert
+        // instead of raising a new one or minting a new receipt.
+        let second = FlowEdge {
+            edge_id: "retry-2".to_string(),
+            timestamp_ms: 1_500,
+            ..first
+        };
+        graph.append_edge(second.clone()).unwrap();
+        let verdict = sentinel.evaluate_edge(&second, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.alert_count(), 1);
+        assert_eq!(sentinel.receipt_count(), 1);
+        assert!(graph.get_edge("retry-2").unwrap().quarantined);
+
+        let alert = sentinel
+            .alerts()
+            .values()
+            .find(|a| a.violated_boundary == "b1")
+            .unwrap();
+        assert_eq!(alert.suppressed_count, 1);
+    }
+
+    #[test]
+    fn test_repeated_identical_exfil_just_outside_cooldown_raises_a_fresh_alert() {
+        let config = SentinelConfig {
+            alert_cooldown_ms: 1_000,
+            ..default_config()
+        };
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .unwrap();
+
         let mut ts = TaintSet::new();
-        ts.insert("ANY");
-        assert!(boundary.is_violated_by(&ts));
-        assert!(!boundary.is_violated_by(&TaintSet::new()));
+        ts.insert("PII");
+
+        let first = FlowEdge {
+            edge_id: "retry-1".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
+            taint_set: ts,
+            timestamp_ms: 1_000,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(first.clone()).unwrap();
+        sentinel.evaluate_edge(&first, &mut graph).unwrap();
+
+        // Arrives exactly 1000ms later, i.e. just outside (not within) the
+        // cooldown window, so it should get its own alert and receipt.
+        let second = FlowEdge {
+            edge_id: "retry-2".to_string(),
+            timestamp_ms: 2_000,
+            ..first
+        };
+        graph.append_edge(second.clone()).unwrap();
+        let verdict = sentinel.evaluate_edge(&second, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(sentinel.alert_count(), 2);
+        assert_eq!(sentinel.receipt_count(), 2);
+
+        assert!(
+            sentinel.alerts().values().all(|a| a.suppressed_count == 0),
+            "neither alert should carry suppressed occurrences of the other"
+        );
     }
 
     #[test]
-    fn test_sentinel_evaluate_and_quarantine() {
+    fn test_composite_rule_escalates_when_all_labels_present() {
         let config = default_config();
         let mut graph = LineageGraph::new(config.clone());
         let mut sentinel = ExfiltrationSentinel::new(config);
 
         sentinel
-            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .add_boundary(make_boundary("b1", "internal", "external", &[]))
+            .unwrap();
+        sentinel
+            .add_composite_rule(CompositeRule {
+                rule_id: "cr-name-ssn".to_string(),
+                required_labels: BTreeSet::from(["NAME".to_string(), "SSN".to_string()]),
+                escalated_verdict: FlowVerdict::Alert,
+            })
             .unwrap();
 
         let mut ts = TaintSet::new();
-        ts.insert("PII");
+        ts.insert("NAME");
+        ts.insert("SSN");
 
         let edge = FlowEdge {
-            edge_id: "exfil-1".to_string(),
+            edge_id: "composite-1".to_string(),
             source: "internal-db".to_string(),
             sink: "external-api".to_string(),
             operation: "export".to_string(),
             taint_set: ts,
-            timestamp_ms: 500,
+            timestamp_ms: 700,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
         let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
-        assert_eq!(verdict, FlowVerdict::Quarantine);
+        assert_eq!(verdict, FlowVerdict::Alert);
         assert_eq!(sentinel.alert_count(), 1);
-        assert_eq!(sentinel.receipt_count(), 1);
-
-        // Verify the edge is quarantined in the graph
-        let quarantined_edge = graph.get_edge("exfil-1").unwrap();
-        assert!(quarantined_edge.quarantined);
     }
 
     #[test]
-    fn test_sentinel_pass_when_no_violation() {
+    fn test_composite_rule_does_not_escalate_with_only_one_label() {
         let config = default_config();
         let mut graph = LineageGraph::new(config.clone());
         let mut sentinel = ExfiltrationSentinel::new(config);
 
         sentinel
-            .add_boundary(make_boundary("b1", "internal", "external", &["SECRET"]))
+            .add_boundary(make_boundary("b1", "internal", "external", &[]))
+            .unwrap();
+        sentinel
+            .add_composite_rule(CompositeRule {
+                rule_id: "cr-name-ssn".to_string(),
+                required_labels: BTreeSet::from(["NAME".to_string(), "SSN".to_string()]),
+                escalated_verdict: FlowVerdict::Alert,
+            })
             .unwrap();
 
         let mut ts = TaintSet::new();
-        ts.insert("PUBLIC");
+        ts.insert("NAME");
 
         let edge = FlowEdge {
-            edge_id: "safe-1".to_string(),
-            source: "internal-svc".to_string(),
-            sink: "external-cdn".to_string(),
-            operation: "publish".to_string(),
+            edge_id: "composite-2".to_string(),
+            source: "internal-db".to_string(),
+            sink: "external-api".to_string(),
+            operation: "export".to_string(),
             taint_set: ts,
-            timestamp_ms: 600,
+            timestamp_ms: 701,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
@@ -4499,38 +7231,154 @@ mod tests {
     }
 
     #[test]
-    fn test_multiple_boundary_violations_emit_all_alerts_but_single_receipt() {
+    fn test_composite_rule_escalates_to_quarantine_when_boundary_also_violated() {
         let config = default_config();
         let mut graph = LineageGraph::new(config.clone());
         let mut sentinel = ExfiltrationSentinel::new(config);
 
         sentinel
-            .add_boundary(make_boundary("b1", "internal", "external", &["PII"]))
+            .add_boundary(make_boundary("b1", "internal", "external", &["SSN"]))
             .unwrap();
         sentinel
-            .add_boundary(make_boundary("b2", "internal", "external", &["SECRET"]))
+            .add_composite_rule(CompositeRule {
+                rule_id: "cr-name-ssn".to_string(),
+                required_labels: BTreeSet::from(["NAME".to_string(), "SSN".to_string()]),
+                escalated_verdict: FlowVerdict::Alert,
+            })
             .unwrap();
 
         let mut ts = TaintSet::new();
-        ts.insert("PII");
-        ts.insert("SECRET");
+        ts.insert("NAME");
+        ts.insert("SSN");
 
         let edge = FlowEdge {
-            edge_id: "multi-boundary-1".to_string(),
+            edge_id: "composite-3".to_string(),
             source: "internal-db".to_string(),
             sink: "external-api".to_string(),
             operation: "export".to_string(),
             taint_set: ts,
-            timestamp_ms: 605,
+            timestamp_ms: 702,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
         let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
         assert_eq!(verdict, FlowVerdict::Quarantine);
-        assert_eq!(sentinel.alert_count(), 2);
-        assert_eq!(sentinel.receipt_count(), 1);
-        assert!(graph.get_edge("multi-boundary-1").unwrap().quarantined);
+        assert!(graph.get_edge("composite-3").unwrap().quarantined);
+    }
+
+    #[test]
+    fn composite_rule_rejects_fewer_than_two_required_labels() {
+        let rule = CompositeRule {
+            rule_id: "cr-single".to_string(),
+            required_labels: BTreeSet::from(["NAME".to_string()]),
+            escalated_verdict: FlowVerdict::Alert,
+        };
+        assert!(rule.validate().is_err());
+    }
+
+    #[test]
+    fn add_composite_rule_rejects_duplicate_rule_id() {
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        let rule = CompositeRule {
+            rule_id: "cr-1".to_string(),
+            required_labels: BTreeSet::from(["NAME".to_string(), "SSN".to_string()]),
+            escalated_verdict: FlowVerdict::Alert,
+        };
+        sentinel.add_composite_rule(rule.clone()).unwrap();
+        assert!(sentinel.add_composite_rule(rule).is_err());
+    }
+
+    #[test]
+    fn export_policy_round_trips_through_load_policy() {
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        sentinel
+            .add_boundary(make_boundary("b1", "internal", "external", &["SECRET"]))
+            .unwrap();
+        sentinel
+            .add_boundary(make_boundary("b2", "internal", "partner", &["SSN", "NAME"]))
+            .unwrap();
+        sentinel
+            .add_composite_rule(CompositeRule {
+                rule_id: "cr-name-ssn".to_string(),
+                required_labels: BTreeSet::from(["NAME".to_string(), "SSN".to_string()]),
+                escalated_verdict: FlowVerdict::Alert,
+            })
+            .unwrap();
+
+        let doc = sentinel.export_policy();
+        assert_eq!(doc.schema_version, SENTINEL_POLICY_SCHEMA_VERSION);
+        assert_eq!(doc.boundaries.len(), 2);
+        assert_eq!(doc.composite_rules.len(), 1);
+
+        let json = serde_json::to_string(&doc).expect("serialize policy doc");
+        let reloaded: SentinelPolicyDoc =
+            serde_json::from_str(&json).expect("deserialize policy doc");
+        assert_eq!(reloaded, doc);
+
+        let mut fresh = ExfiltrationSentinel::new(default_config());
+        fresh.load_policy(&reloaded).expect("load policy");
+        assert_eq!(fresh.export_policy(), doc);
+    }
+
+    #[test]
+    fn load_policy_rejects_unknown_schema_version() {
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        let doc = SentinelPolicyDoc {
+            schema_version: "some-other-version".to_string(),
+            boundaries: Vec::new(),
+            composite_rules: Vec::new(),
+        };
+        assert!(sentinel.load_policy(&doc).is_err());
+    }
+
+    #[test]
+    fn load_policy_aborts_all_or_nothing_on_malformed_boundary() {
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        sentinel
+            .add_boundary(make_boundary("existing", "internal", "external", &["SECRET"]))
+            .unwrap();
+        let baseline = sentinel.export_policy();
+
+        let doc = SentinelPolicyDoc {
+            schema_version: SENTINEL_POLICY_SCHEMA_VERSION.to_string(),
+            boundaries: vec![
+                make_boundary("ok-1", "internal", "external", &["SECRET"]),
+                TaintBoundary {
+                    boundary_id: String::new(),
+                    from_zone: "internal".to_string(),
+                    to_zone: "external".to_string(),
+                    denied_labels: BTreeSet::new(),
+                    deny_all: false,
+                    operation_restriction: None,
+                },
+            ],
+            composite_rules: Vec::new(),
+        };
+
+        let err = sentinel
+            .load_policy(&doc)
+            .expect_err("malformed boundary must abort the whole load");
+        assert!(matches!(err, LineageError::BoundaryInvalid { .. }));
+        // The existing policy must be untouched -- no partial apply.
+        assert_eq!(sentinel.export_policy(), baseline);
+    }
+
+    #[test]
+    fn load_policy_aborts_on_duplicate_boundary_id() {
+        let mut sentinel = ExfiltrationSentinel::new(default_config());
+        let doc = SentinelPolicyDoc {
+            schema_version: SENTINEL_POLICY_SCHEMA_VERSION.to_string(),
+            boundaries: vec![
+                make_boundary("dup", "internal", "external", &["SECRET"]),
+                make_boundary("dup", "internal", "partner", &["SSN"]),
+            ],
+            composite_rules: Vec::new(),
+        };
+        assert!(sentinel.load_policy(&doc).is_err());
+        assert_eq!(sentinel.export_policy().boundaries.len(), 0);
     }
 
     #[test]
@@ -4554,6 +7402,8 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 601,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
@@ -4584,6 +7434,8 @@ mod tests {
             taint_set: ts.clone(),
             timestamp_ms: 700,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
         sentinel.evaluate_edge(&edge, &mut graph).unwrap();
@@ -4612,6 +7464,8 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 701,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
 
         let err = sentinel
@@ -4643,6 +7497,8 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 702,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
         sentinel.evaluate_edge(&edge, &mut graph).unwrap();
@@ -4728,6 +7584,7 @@ mod tests {
             to_zone: "ext".to_string(),
             denied_labels: BTreeSet::new(),
             deny_all: false,
+            operation_restriction: None,
         };
         assert!(boundary.validate().is_err());
     }
@@ -4740,6 +7597,7 @@ mod tests {
             to_zone: String::new(),
             denied_labels: BTreeSet::new(),
             deny_all: false,
+            operation_restriction: None,
         };
 
         let err = boundary
@@ -4757,6 +7615,7 @@ mod tests {
             to_zone: "external".to_string(),
             denied_labels: BTreeSet::new(),
             deny_all: false,
+            operation_restriction: None,
         };
 
         let err = boundary
@@ -4791,6 +7650,8 @@ mod tests {
             taint_set: taint,
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
@@ -4860,13 +7721,77 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 999,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+        sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+
+        assert!(invariants::verify_quarantine_receipt(&graph, &sentinel));
+    }
+
+    #[test]
+    fn release_edge_clears_quarantine_and_keeps_the_receipt_invariant_satisfied() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+
+        sentinel
+            .add_boundary(make_boundary("b1", "in", "out", &["PII"]))
+            .unwrap();
+
+        let mut ts = TaintSet::new();
+        ts.insert("PII");
+
+        let edge = FlowEdge {
+            edge_id: "release-test".to_string(),
+            source: "in-svc".to_string(),
+            sink: "out-svc".to_string(),
+            operation: "leak".to_string(),
+            taint_set: ts,
+            timestamp_ms: 999,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
         sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert!(graph.get_edge("release-test").unwrap().quarantined);
+
+        let receipt = graph
+            .release_edge("release-test", "reviewed, false positive", "alice", 1_500)
+            .unwrap();
+        assert_eq!(receipt.edge_id, "release-test");
+        assert_eq!(receipt.released_by, "alice");
+        assert_eq!(receipt.justification, "reviewed, false positive");
+        assert_eq!(receipt.release_timestamp_ms, 1_500);
+        assert_eq!(graph.release_receipt("release-test"), Some(&receipt));
 
+        assert!(!graph.get_edge("release-test").unwrap().quarantined);
         assert!(invariants::verify_quarantine_receipt(&graph, &sentinel));
     }
 
+    #[test]
+    fn release_edge_rejects_an_edge_that_was_never_quarantined() {
+        let mut graph = LineageGraph::new(default_config());
+        let edge_id = graph.propagate_taint("a", "b", "copy", 100).unwrap();
+
+        let err = graph
+            .release_edge(&edge_id, "no review happened", "alice", 200)
+            .unwrap_err();
+        assert!(matches!(err, LineageError::NotQuarantined { .. }));
+        assert!(err.to_string().contains(ERR_IFL_NOT_QUARANTINED));
+    }
+
+    #[test]
+    fn release_edge_rejects_an_unknown_edge_id() {
+        let mut graph = LineageGraph::new(default_config());
+        let err = graph
+            .release_edge("missing", "no review happened", "alice", 200)
+            .unwrap_err();
+        assert!(matches!(err, LineageError::EdgeNotFound { .. }));
+    }
+
     #[test]
     fn test_invariant_boundary_enforced_fails_for_unquarantined_violation() {
         let mut graph = LineageGraph::new(default_config());
@@ -4887,6 +7812,8 @@ mod tests {
                 taint_set: ts,
                 timestamp_ms: 1001,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             })
             .unwrap();
 
@@ -4912,6 +7839,8 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
 
         assert!(invariants::verify_deterministic(&edge, &boundaries));
@@ -4944,6 +7873,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: i as u64,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
             graph.append_edge(e).unwrap();
         }
@@ -4975,6 +7906,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: i as u64,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
             graph.append_edge(e).unwrap();
         }
@@ -5074,6 +8007,8 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
 
@@ -5106,6 +8041,8 @@ mod tests {
             taint_set: ts,
             timestamp_ms: 2,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
 
@@ -5116,6 +8053,82 @@ mod tests {
         assert_eq!(sentinel.receipt_count(), 1);
     }
 
+    #[test]
+    fn test_flow_heatmap_counts_crossings_and_violations_per_zone_pair() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        sentinel
+            .add_boundary(make_boundary("b1", "priv", "pub", &["PII"]))
+            .unwrap();
+        sentinel
+            .add_boundary(make_boundary("b2", "internal", "external", &["SECRET"]))
+            .unwrap();
+
+        let mut pii = TaintSet::new();
+        pii.insert("PII");
+        graph
+            .append_edge(FlowEdge {
+                edge_id: "heat-1".to_string(),
+                source: "priv-svc".to_string(),
+                sink: "pub-cdn".to_string(),
+                operation: "export".to_string(),
+                taint_set: pii.clone(),
+                timestamp_ms: 1,
+                quarantined: false,
+                source_zone: None,
+                sink_zone: None,
+            })
+            .unwrap();
+
+        // Same zone pair as heat-1, but carries no denied label: a crossing
+        // without a violation.
+        graph
+            .append_edge(FlowEdge {
+                edge_id: "heat-2".to_string(),
+                source: "priv-svc".to_string(),
+                sink: "pub-cdn".to_string(),
+                operation: "export".to_string(),
+                taint_set: TaintSet::new(),
+                timestamp_ms: 2,
+                quarantined: false,
+                source_zone: None,
+                sink_zone: None,
+            })
+            .unwrap();
+
+        let mut secret = TaintSet::new();
+        secret.insert("SECRET");
+        graph
+            .append_edge(FlowEdge {
+                edge_id: "heat-3".to_string(),
+                source: "internal-svc".to_string(),
+                sink: "external-api".to_string(),
+                operation: "export".to_string(),
+                taint_set: secret,
+                timestamp_ms: 3,
+                quarantined: false,
+                source_zone: None,
+                sink_zone: None,
+            })
+            .unwrap();
+
+        let heatmap = sentinel.flow_heatmap(&graph);
+        assert_eq!(heatmap.len(), 2);
+
+        let priv_to_pub = &heatmap[&("priv".to_string(), "pub".to_string())];
+        assert_eq!(priv_to_pub.crossings, 2);
+        assert_eq!(priv_to_pub.violations, 1);
+
+        let internal_to_external = &heatmap[&("internal".to_string(), "external".to_string())];
+        assert_eq!(internal_to_external.crossings, 1);
+        assert_eq!(internal_to_external.violations, 1);
+
+        // flow_heatmap is read-only: no alerts or receipts are raised.
+        assert_eq!(sentinel.alert_count(), 0);
+        assert_eq!(sentinel.receipt_count(), 0);
+    }
+
     #[test]
     fn test_evaluate_metrics_above_threshold() {
         let config = default_config();
@@ -5167,6 +8180,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: i as u64,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
             graph.append_edge(e).unwrap();
         }
@@ -5191,6 +8206,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: i as u64,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
             graph.append_edge(e).unwrap();
         }
@@ -5214,6 +8231,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: i as u64,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
             graph.append_edge(e).unwrap();
         }
@@ -5445,6 +8464,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: 1,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             })
             .unwrap();
 
@@ -5473,6 +8494,8 @@ mod tests {
             taint_set: taint,
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
@@ -5526,6 +8549,8 @@ mod tests {
             taint_set: taint,
             timestamp_ms: 1,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge.clone()).unwrap();
 
@@ -5536,6 +8561,103 @@ mod tests {
         assert_eq!(verdict, FlowVerdict::Pass);
     }
 
+    #[test]
+    fn test_printer_service_does_not_trip_an_int_to_ext_boundary_by_default() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        sentinel
+            .add_boundary(make_boundary("b-int-ext", "int", "ext", &["PII"]))
+            .unwrap();
+
+        let mut taint = TaintSet::new();
+        taint.insert("PII");
+        let edge = FlowEdge {
+            edge_id: "printer-edge".to_string(),
+            source: "printer-service".to_string(),
+            sink: "ext-archive".to_string(),
+            operation: "export".to_string(),
+            taint_set: taint,
+            timestamp_ms: 1,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        // "printer-service" contains the substring "int" (prINTer), but does
+        // not start with the zone "int", so the default (non-legacy) zone
+        // match must not treat it as crossing the boundary.
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Pass);
+    }
+
+    #[test]
+    fn test_legacy_substring_zones_reintroduces_the_printer_service_false_positive() {
+        let config = SentinelConfig {
+            legacy_substring_zones: true,
+            ..default_config()
+        };
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        sentinel
+            .add_boundary(make_boundary("b-int-ext", "int", "ext", &["PII"]))
+            .unwrap();
+
+        let mut taint = TaintSet::new();
+        taint.insert("PII");
+        let edge = FlowEdge {
+            edge_id: "printer-edge".to_string(),
+            source: "printer-service".to_string(),
+            sink: "ext-archive".to_string(),
+            operation: "export".to_string(),
+            taint_set: taint,
+            timestamp_ms: 1,
+            quarantined: false,
+            source_zone: None,
+            sink_zone: None,
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        // With the legacy flag set, an untagged edge falls back to raw
+        // substring matching, which does reproduce the original bug report.
+        // This is why the flag defaults to off and exists only for migration.
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Quarantine);
+    }
+
+    #[test]
+    fn test_explicit_source_zone_is_matched_exactly_even_under_a_deceptive_node_name() {
+        let config = default_config();
+        let mut graph = LineageGraph::new(config.clone());
+        let mut sentinel = ExfiltrationSentinel::new(config);
+        sentinel
+            .add_boundary(make_boundary("b-int-ext", "int", "ext", &["PII"]))
+            .unwrap();
+
+        let mut taint = TaintSet::new();
+        taint.insert("PII");
+        // The node name itself looks internal, but the caller explicitly
+        // tags it as belonging to the "ext" zone; the explicit tag wins.
+        let edge = FlowEdge {
+            edge_id: "relabeled-edge".to_string(),
+            source: "internal-looking-host".to_string(),
+            sink: "external-looking-host".to_string(),
+            operation: "export".to_string(),
+            taint_set: taint,
+            timestamp_ms: 1,
+            quarantined: false,
+            source_zone: Some("ext".to_string()),
+            sink_zone: Some("ext".to_string()),
+        };
+        graph.append_edge(edge.clone()).unwrap();
+
+        // source_zone "ext" does not match the boundary's from_zone "int",
+        // so this is not a crossing even though the name says "internal".
+        let verdict = sentinel.evaluate_edge(&edge, &mut graph).unwrap();
+        assert_eq!(verdict, FlowVerdict::Pass);
+    }
+
     #[test]
     fn test_track_flow_from_untagged_source_does_not_create_false_quarantine() {
         let config = default_config();
@@ -5681,26 +8803,31 @@ mod tests {
                 id: "".to_string(), // Empty ID
                 description: "Empty ID label".to_string(),
                 severity: 5,
+                expires_at_ms: None,
             },
             TaintLabel {
                 id: "\0null\x01control\x7f".to_string(), // Control characters
                 description: "label\nwith\nnewlines".to_string(),
                 severity: 3,
+                expires_at_ms: None,
             },
             TaintLabel {
                 id: "🚀emoji🔥label💀".to_string(),            // Unicode emoji
                 description: "\u{FFFF}\u{10FFFF}".to_string(), // Max Unicode
                 severity: u32::MAX,                            // Maximum severity
+                expires_at_ms: None,
             },
             TaintLabel {
                 id: "../../../etc/passwd".to_string(), // Path traversal
                 description: "<script>alert('xss')</script>".to_string(), // XSS
                 severity: 0,                           // Zero severity
+                expires_at_ms: None,
             },
             TaintLabel {
                 id: "x".repeat(10_000),          // Very long ID
                 description: "y".repeat(50_000), // Very long description
                 severity: 1,
+                expires_at_ms: None,
             },
         ];
 
@@ -5798,6 +8925,8 @@ mod tests {
                 taint_set,
                 timestamp_ms: timestamp,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             };
 
             // Edge creation should handle extreme timestamps
@@ -5821,6 +8950,8 @@ mod tests {
             taint_set: TaintSet::new(),
             timestamp_ms: 1000,
             quarantined: true,
+            source_zone: None,
+            sink_zone: None,
         };
 
         let serialized =
@@ -5841,6 +8972,7 @@ mod tests {
                 to_zone: "valid_zone".to_string(),
                 denied_labels: BTreeSet::new(),
                 deny_all: false,
+                operation_restriction: None,
             },
             TaintBoundary {
                 boundary_id: "empty_to_zone".to_string(),
@@ -5848,6 +8980,7 @@ mod tests {
                 to_zone: "".to_string(), // Empty to_zone
                 denied_labels: BTreeSet::new(),
                 deny_all: false,
+                operation_restriction: None,
             },
             TaintBoundary {
                 boundary_id: "both_empty".to_string(),
@@ -5855,6 +8988,7 @@ mod tests {
                 to_zone: "".to_string(),
                 denied_labels: BTreeSet::new(),
                 deny_all: false,
+                operation_restriction: None,
             },
         ];
 
@@ -5877,6 +9011,7 @@ mod tests {
             to_zone: "🚀zone💀".to_string(),     // Unicode emoji
             denied_labels: BTreeSet::new(),
             deny_all: true, // Deny all labels
+            operation_restriction: None,
         };
 
         // Should validate successfully (non-empty zones)
@@ -5920,6 +9055,7 @@ mod tests {
             to_zone: "sink_zone".to_string(),
             denied_labels: BTreeSet::new(),
             deny_all: false,
+            operation_restriction: None,
         };
 
         // Create edges with problematic node names
@@ -5932,6 +9068,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: 1000,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             },
             FlowEdge {
                 edge_id: "edge2".to_string(),
@@ -5941,6 +9079,8 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: 1000,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             },
             FlowEdge {
                 edge_id: "edge3".to_string(),
@@ -5950,12 +9090,14 @@ mod tests {
                 taint_set: TaintSet::new(),
                 timestamp_ms: 1000,
                 quarantined: false,
+                source_zone: None,
+                sink_zone: None,
             },
         ];
 
         for edge in problematic_edges {
             // Should not panic when checking if boundary crosses edge
-            let _crosses = boundary.crosses_edge(&edge);
+            let _crosses = boundary.crosses_edge(&edge, false);
             // Result may vary based on implementation, just verify no panic
         }
     }
@@ -6,6 +6,7 @@
 //! decision emits a structured audit event.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::net::IpAddr;
 
@@ -299,11 +300,48 @@ pub struct AuditEvent {
     pub trace_id: String,
 }
 
+const MS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// Per-principal daily egress byte usage, reset on day-boundary rollover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct QuotaUsage {
+    day_index: u64,
+    bytes_used: u64,
+}
+
+impl QuotaUsage {
+    fn rolled_over(self, day_index: u64) -> Self {
+        if self.day_index == day_index {
+            self
+        } else {
+            Self {
+                day_index,
+                bytes_used: 0,
+            }
+        }
+    }
+}
+
+/// Result of checking a principal's remaining daily egress allowance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuotaStatus {
+    /// Principal has a configured cap; this many bytes remain for the day.
+    Remaining(u64),
+    /// Principal has a configured cap and has already used it up for the day.
+    Exceeded,
+    /// Principal has no configured cap, so egress is unmetered.
+    Unlimited,
+}
+
 /// The network guard that processes egress requests.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkGuard {
     pub policy: EgressPolicy,
     pub audit_log: Vec<AuditEvent>,
+    /// Configured daily egress byte cap per principal. Principals without an
+    /// entry here are unmetered by the quota layer (per-host rules still apply).
+    pub principal_quotas: BTreeMap<String, u64>,
+    quota_usage: BTreeMap<String, QuotaUsage>,
 }
 
 impl NetworkGuard {
@@ -311,7 +349,85 @@ impl NetworkGuard {
         Self {
             policy,
             audit_log: Vec::new(),
+            principal_quotas: BTreeMap::new(),
+            quota_usage: BTreeMap::new(),
+        }
+    }
+
+    /// Configure (or replace) a principal's daily egress byte cap.
+    pub fn set_principal_quota(&mut self, principal: &str, daily_byte_cap: u64) {
+        self.principal_quotas
+            .insert(principal.to_string(), daily_byte_cap);
+    }
+
+    /// Record egress bytes against a principal's daily usage, rolling over to
+    /// a fresh day if `now_ms` falls on a later UTC day than the last record.
+    /// Has no effect on whether the quota is already exceeded; callers that
+    /// need to gate on the quota should use [`Self::check_and_consume`].
+    pub fn record_egress(&mut self, principal: &str, bytes: u64, now_ms: u64) {
+        let day_index = now_ms / MS_PER_DAY;
+        let usage = self
+            .quota_usage
+            .get(principal)
+            .copied()
+            .unwrap_or(QuotaUsage {
+                day_index,
+                bytes_used: 0,
+            })
+            .rolled_over(day_index);
+        self.quota_usage.insert(
+            principal.to_string(),
+            QuotaUsage {
+                day_index,
+                bytes_used: usage.bytes_used.saturating_add(bytes),
+            },
+        );
+    }
+
+    /// Check a principal's remaining daily egress allowance as of `now_ms`,
+    /// without recording any usage.
+    pub fn check_quota(&self, principal: &str, now_ms: u64) -> QuotaStatus {
+        let Some(&cap) = self.principal_quotas.get(principal) else {
+            return QuotaStatus::Unlimited;
+        };
+        let day_index = now_ms / MS_PER_DAY;
+        let bytes_used = self
+            .quota_usage
+            .get(principal)
+            .copied()
+            .map(|usage| usage.rolled_over(day_index).bytes_used)
+            .unwrap_or(0);
+        if bytes_used >= cap {
+            QuotaStatus::Exceeded
+        } else {
+            QuotaStatus::Remaining(cap - bytes_used)
+        }
+    }
+
+    /// Check a principal's quota and, if allowance remains, record `bytes` of
+    /// egress against it. Returns `GuardError::QuotaExceeded` without
+    /// recording usage if the principal has already exhausted its daily cap
+    /// (from a prior call to this method or to [`Self::record_egress`]).
+    ///
+    /// A single request that itself crosses the cap is still let through --
+    /// it is the request immediately *after* the cap was crossed that is
+    /// denied, matching how the per-host rules fail closed only once a
+    /// violation is observed rather than predicting it in advance.
+    pub fn check_and_consume(
+        &mut self,
+        principal: &str,
+        bytes: u64,
+        now_ms: u64,
+    ) -> Result<(), GuardError> {
+        if let QuotaStatus::Exceeded = self.check_quota(principal, now_ms) {
+            let cap = self.principal_quotas.get(principal).copied().unwrap_or(0);
+            return Err(GuardError::QuotaExceeded {
+                principal: principal.to_string(),
+                daily_byte_cap: cap,
+            });
         }
+        self.record_egress(principal, bytes, now_ms);
+        Ok(())
     }
 
     /// Process an egress request and emit an audit event.
@@ -504,6 +620,11 @@ pub enum GuardError {
         compatibility_code: Option<String>,
         detail: String,
     },
+    #[serde(rename = "GUARD_QUOTA_EXCEEDED")]
+    QuotaExceeded {
+        principal: String,
+        daily_byte_cap: u64,
+    },
 }
 
 impl fmt::Display for GuardError {
@@ -533,6 +654,15 @@ impl fmt::Display for GuardError {
                     write!(f, "GUARD_REMOTE_CAP_DENIED: {code} {detail}")
                 }
             }
+            Self::QuotaExceeded {
+                principal,
+                daily_byte_cap,
+            } => {
+                write!(
+                    f,
+                    "GUARD_QUOTA_EXCEEDED: principal {principal} exceeded daily cap of {daily_byte_cap} bytes"
+                )
+            }
         }
     }
 }
@@ -1338,6 +1468,96 @@ mod tests {
         assert_eq!(guard.audit_events()[0].rule_matched, None);
         assert_eq!(guard.audit_events()[0].host, "api.example.com");
     }
+
+    // === Per-principal egress quotas ===
+
+    #[test]
+    fn quota_accumulates_across_calls() {
+        let mut guard = NetworkGuard::new(sample_policy());
+        guard.set_principal_quota("conn-1", 1_000);
+
+        guard.record_egress("conn-1", 300, 1_700_000_000_000);
+        guard.record_egress("conn-1", 300, 1_700_000_000_500);
+
+        assert_eq!(
+            guard.check_quota("conn-1", 1_700_000_001_000),
+            QuotaStatus::Remaining(400)
+        );
+    }
+
+    #[test]
+    fn quota_rolls_over_at_day_boundary() {
+        let mut guard = NetworkGuard::new(sample_policy());
+        guard.set_principal_quota("conn-1", 1_000);
+
+        guard.record_egress("conn-1", 900, 0);
+        assert_eq!(guard.check_quota("conn-1", 0), QuotaStatus::Remaining(100));
+
+        let next_day_ms = MS_PER_DAY + 1;
+        assert_eq!(
+            guard.check_quota("conn-1", next_day_ms),
+            QuotaStatus::Remaining(1_000)
+        );
+        guard.record_egress("conn-1", 50, next_day_ms);
+        assert_eq!(
+            guard.check_quota("conn-1", next_day_ms),
+            QuotaStatus::Remaining(950)
+        );
+    }
+
+    #[test]
+    fn quota_without_configured_cap_is_unlimited() {
+        let guard = NetworkGuard::new(sample_policy());
+        assert_eq!(
+            guard.check_quota("conn-unconfigured", 1_700_000_000_000),
+            QuotaStatus::Unlimited
+        );
+    }
+
+    #[test]
+    fn check_and_consume_denies_past_the_cap() {
+        let mut guard = NetworkGuard::new(sample_policy());
+        guard.set_principal_quota("conn-1", 1_000);
+
+        guard
+            .check_and_consume("conn-1", 900, 1_700_000_000_000)
+            .expect("first request within cap should succeed");
+        // This request pushes cumulative usage (1100) past the 1000 cap, but
+        // it is evaluated against the *pre*-request usage (900), so it is
+        // still allowed through.
+        guard
+            .check_and_consume("conn-1", 200, 1_700_000_000_100)
+            .expect("request that itself crosses the cap is still allowed");
+        assert_eq!(
+            guard.check_quota("conn-1", 1_700_000_000_100),
+            QuotaStatus::Exceeded
+        );
+
+        let err = guard
+            .check_and_consume("conn-1", 1, 1_700_000_000_200)
+            .expect_err("next request after the cap was crossed must be denied");
+
+        assert!(matches!(
+            err,
+            GuardError::QuotaExceeded {
+                daily_byte_cap: 1_000,
+                ..
+            }
+        ));
+        // The denied request must not have been recorded against usage.
+        assert_eq!(
+            guard.check_quota("conn-1", 1_700_000_000_200),
+            QuotaStatus::Exceeded
+        );
+    }
+
+    #[test]
+    fn check_and_consume_allows_unconfigured_principal() {
+        let mut guard = NetworkGuard::new(sample_policy());
+        guard
+            .check_and_consume("conn-unconfigured", 1_000_000, 1_700_000_000_000)
+            .expect("unconfigured principals are unmetered");
+    }
 }
 
 #[cfg(test)]
@@ -1494,6 +1494,209 @@ fn verify_threshold_with_validated_artifact(
     }
 }
 
+// ── Dealerless resharing ────────────────────────────────────────────
+//
+// This module's threshold scheme is a k-of-n quorum of independent Ed25519
+// keypairs, not a single group key split via Shamir secret sharing: there is
+// no shared secret to redistribute and no group public key to hold stable
+// across membership changes. "Dealerless resharing" here means transitioning
+// `ThresholdConfig` from one signer set/threshold to another without any
+// single party unilaterally installing the new set: `finalize_reshare`
+// requires a quorum of the *old* signer set to co-sign the exact new
+// parameters before they take effect.
+
+const RESHARE_SESSION_DOMAIN: &[u8] = b"threshold_sig_reshare_session_v1:";
+const RESHARE_COMMITMENT_DOMAIN: &[u8] = b"threshold_sig_reshare_commitment_v1:";
+
+fn reshare_update_len_prefixed_hash(hasher: &mut Sha256, bytes: &[u8]) {
+    let len = u64::try_from(bytes.len()).unwrap_or(u64::MAX);
+    hasher.update(len.to_be_bytes());
+    hasher.update(bytes);
+}
+
+fn reshare_update_signer_keys_hash(hasher: &mut Sha256, signer_keys: &[SignerKey]) {
+    reshare_update_len_prefixed_hash(hasher, &(signer_keys.len() as u64).to_be_bytes());
+    for key in signer_keys {
+        reshare_update_len_prefixed_hash(hasher, key.key_id.as_bytes());
+        reshare_update_len_prefixed_hash(hasher, key.public_key_hex.as_bytes());
+    }
+}
+
+/// Target signer set and threshold for a [`begin_reshare`] operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReshareParams {
+    pub new_threshold: u32,
+    pub new_signer_keys: Vec<SignerKey>,
+}
+
+impl ReshareParams {
+    fn as_config(&self) -> ThresholdConfig {
+        ThresholdConfig {
+            threshold: self.new_threshold,
+            total_signers: u32::try_from(self.new_signer_keys.len()).unwrap_or(u32::MAX),
+            signer_keys: self.new_signer_keys.clone(),
+        }
+    }
+}
+
+/// An in-progress resharing handshake from `old_config` to `new_params`,
+/// produced by [`begin_reshare`]. `session_id` is a content-addressed digest
+/// of both sides of the transition, so a contribution can only endorse this
+/// exact old-set-to-new-set change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReshareSession {
+    pub session_id: String,
+    pub old_config: ThresholdConfig,
+    pub new_params: ReshareParams,
+}
+
+/// One existing signer's endorsement of a [`ReshareSession`], co-signing the
+/// new signer set and threshold in place of a trusted dealer.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReshareContribution {
+    pub signer_id: String,
+    pub key_id: String,
+    pub signature_hex: String,
+}
+
+impl std::fmt::Debug for ReshareContribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReshareContribution")
+            .field("signer_id", &self.signer_id)
+            .field("key_id", &self.key_id)
+            .field("signature_hex", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// The outcome of a completed [`finalize_reshare`]: a new `ThresholdConfig`
+/// for the resized signer set, tagged with the session it closed out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewShareSet {
+    pub config: ThresholdConfig,
+    pub session_id: String,
+}
+
+fn reshare_commitment_message(session: &ReshareSession) -> Vec<u8> {
+    let mut message = Vec::new();
+    message.extend_from_slice(RESHARE_COMMITMENT_DOMAIN);
+    let len = u64::try_from(session.session_id.len()).unwrap_or(u64::MAX);
+    message.extend_from_slice(&len.to_be_bytes());
+    message.extend_from_slice(session.session_id.as_bytes());
+    message
+}
+
+/// Begin a dealerless resharing of `old_config`'s signer set to `new_params`.
+///
+/// Validates both the current and target configurations but does not take
+/// effect on its own: call [`finalize_reshare`] with enough endorsements
+/// from `old_config`'s existing signers to actually install `new_params`.
+pub fn begin_reshare(
+    old_config: &ThresholdConfig,
+    new_params: ReshareParams,
+) -> Result<ReshareSession, ThresholdError> {
+    old_config.validate()?;
+    new_params.as_config().validate()?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(RESHARE_SESSION_DOMAIN);
+    reshare_update_len_prefixed_hash(&mut hasher, &old_config.threshold.to_be_bytes());
+    reshare_update_signer_keys_hash(&mut hasher, &old_config.signer_keys);
+    reshare_update_len_prefixed_hash(&mut hasher, &new_params.new_threshold.to_be_bytes());
+    reshare_update_signer_keys_hash(&mut hasher, &new_params.new_signer_keys);
+    let session_id = format!("reshare-{}", hex::encode(hasher.finalize()));
+
+    Ok(ReshareSession {
+        session_id,
+        old_config: old_config.clone(),
+        new_params,
+    })
+}
+
+/// Sign a [`ReshareSession`] as an existing signer's endorsement of the
+/// transition to its new parameters.
+pub fn sign_reshare_contribution(
+    signing_key: &SigningKey,
+    key_id: &str,
+    session: &ReshareSession,
+) -> ReshareContribution {
+    let message = reshare_commitment_message(session);
+    let signature = signing_key.sign(&message);
+    ReshareContribution {
+        signer_id: key_id.to_string(),
+        key_id: key_id.to_string(),
+        signature_hex: hex::encode(signature.to_bytes()),
+    }
+}
+
+/// Finalize a resharing once enough of `session.old_config`'s signers have
+/// endorsed it, installing `session.new_params` as the new signer set and
+/// threshold.
+///
+/// Every contribution is verified against `session.old_config` before it
+/// counts toward quorum: an unknown signer, a signer identity that doesn't
+/// match its claimed key, or an invalid signature fails the whole call
+/// rather than being silently dropped, since contributions are assembled by
+/// the caller rather than collected adversarially.
+pub fn finalize_reshare(
+    session: &ReshareSession,
+    contributions: &[ReshareContribution],
+) -> Result<NewShareSet, ThresholdError> {
+    let prepared_old = PreparedThresholdKeys::new_validated(&session.old_config)?;
+    let message = reshare_commitment_message(session);
+
+    let mut seen_key_ids = HashSet::with_capacity(contributions.len());
+    let mut valid_count = 0u32;
+    for contribution in contributions {
+        if validate_safe_identifier(&contribution.signer_id).is_err()
+            || !constant_time::ct_eq(&contribution.signer_id, &contribution.key_id)
+        {
+            return Err(ThresholdError::UnknownSigner {
+                signer_id: contribution.signer_id.clone(),
+            });
+        }
+
+        let VerifyingKeyLookupResult::Valid(verifying_key) =
+            prepared_old.lookup_verifying_key(&contribution.key_id)
+        else {
+            return Err(ThresholdError::UnknownSigner {
+                signer_id: contribution.signer_id.clone(),
+            });
+        };
+
+        let Some(signature) = parse_signature(&contribution.signature_hex) else {
+            return Err(ThresholdError::InvalidSignature {
+                signer_id: contribution.signer_id.clone(),
+            });
+        };
+
+        if !verify_parsed_signature_with_key(verifying_key, &message, &signature) {
+            return Err(ThresholdError::InvalidSignature {
+                signer_id: contribution.signer_id.clone(),
+            });
+        }
+
+        if seen_key_ids.insert(contribution.key_id.clone()) {
+            valid_count = valid_count.saturating_add(1);
+        }
+    }
+
+    if valid_count < session.old_config.threshold {
+        return Err(ThresholdError::BelowQuorum {
+            have: valid_count,
+            need: session.old_config.threshold,
+        });
+    }
+
+    let new_config = session.new_params.as_config();
+    new_config.validate()?;
+
+    Ok(NewShareSet {
+        config: new_config,
+        session_id: session.session_id.clone(),
+    })
+}
+
 // ── Errors ──────────────────────────────────────────────────────────
 
 /// Errors for threshold signature operations.
@@ -5056,4 +5259,154 @@ mod tests {
             );
         }
     }
+
+    // ── Dealerless resharing ─────────────────────────────────────────
+
+    #[test]
+    fn reshare_finalizes_with_quorum_from_old_signer_set() {
+        let (old_signing_keys, old_config) = test_config(2, 3);
+        let (_new_signing_keys, new_signer_keys) = test_keys(4);
+        let new_params = ReshareParams {
+            new_threshold: 3,
+            new_signer_keys,
+        };
+        let session = begin_reshare(&old_config, new_params.clone()).expect("begin_reshare");
+
+        let contributions: Vec<ReshareContribution> = old_signing_keys
+            .iter()
+            .zip(old_config.signer_keys.iter())
+            .take(old_config.threshold as usize)
+            .map(|(sk, key)| sign_reshare_contribution(sk, &key.key_id, &session))
+            .collect();
+
+        let new_share_set = finalize_reshare(&session, &contributions).expect("finalize_reshare");
+        assert_eq!(new_share_set.session_id, session.session_id);
+        assert_eq!(new_share_set.config.threshold, new_params.new_threshold);
+        assert_eq!(new_share_set.config.signer_keys, new_params.new_signer_keys);
+    }
+
+    #[test]
+    fn reshare_fails_below_quorum_of_old_signers() {
+        let (old_signing_keys, old_config) = test_config(2, 3);
+        let (_new_signing_keys, new_signer_keys) = test_keys(3);
+        let new_params = ReshareParams {
+            new_threshold: 2,
+            new_signer_keys,
+        };
+        let session = begin_reshare(&old_config, new_params).expect("begin_reshare");
+
+        let contributions = vec![sign_reshare_contribution(
+            &old_signing_keys[0],
+            &old_config.signer_keys[0].key_id,
+            &session,
+        )];
+
+        let err = finalize_reshare(&session, &contributions).unwrap_err();
+        assert_eq!(
+            err,
+            ThresholdError::BelowQuorum {
+                have: 1,
+                need: old_config.threshold
+            }
+        );
+    }
+
+    #[test]
+    fn reshare_rejects_contribution_from_signer_outside_old_config() {
+        let (_old_signing_keys, old_config) = test_config(2, 3);
+        let (new_signing_keys, new_signer_keys) = test_keys(3);
+        let new_params = ReshareParams {
+            new_threshold: 2,
+            new_signer_keys,
+        };
+        let session = begin_reshare(&old_config, new_params).expect("begin_reshare");
+
+        let outsider =
+            sign_reshare_contribution(&new_signing_keys[0], "not-an-old-signer", &session);
+        let err = finalize_reshare(&session, &[outsider]).unwrap_err();
+        assert_eq!(
+            err,
+            ThresholdError::UnknownSigner {
+                signer_id: "not-an-old-signer".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn reshare_new_signer_set_verifies_artifacts_signed_by_new_set() {
+        let (old_signing_keys, old_config) = test_config(2, 3);
+        let (new_signing_keys, new_signer_keys) = test_keys(3);
+        let new_params = ReshareParams {
+            new_threshold: 2,
+            new_signer_keys,
+        };
+        let session = begin_reshare(&old_config, new_params).expect("begin_reshare");
+        let contributions: Vec<ReshareContribution> = old_signing_keys
+            .iter()
+            .zip(old_config.signer_keys.iter())
+            .take(old_config.threshold as usize)
+            .map(|(sk, key)| sign_reshare_contribution(sk, &key.key_id, &session))
+            .collect();
+        let new_share_set = finalize_reshare(&session, &contributions).expect("finalize_reshare");
+
+        let hash = test_hash("reshare-new-set-artifact");
+        let artifact = signed_artifact(&new_signing_keys, &new_share_set.config, &hash, 2);
+        let result = verify_threshold(
+            &new_share_set.config,
+            &artifact,
+            "trace-1",
+            "2024-01-01T00:00:00Z",
+        );
+        assert!(result.verified);
+        assert_eq!(result.valid_signatures, 2);
+    }
+
+    #[test]
+    fn reshare_removed_signer_no_longer_counts_toward_new_threshold() {
+        let (old_signing_keys, old_config) = test_config(2, 3);
+        // Reshare onto a smaller set that drops the old signers entirely
+        // (fresh keys and ids; `test_keys` would reuse the old signer-0/1).
+        let new_signer_keys: Vec<SignerKey> = (0..2u32)
+            .map(|i| SignerKey {
+                key_id: format!("new-signer-{i}"),
+                public_key_hex: hex::encode(test_signing_key(100 + i).verifying_key().to_bytes()),
+            })
+            .collect();
+        let new_params = ReshareParams {
+            new_threshold: 2,
+            new_signer_keys,
+        };
+        let session = begin_reshare(&old_config, new_params).expect("begin_reshare");
+        let contributions: Vec<ReshareContribution> = old_signing_keys
+            .iter()
+            .zip(old_config.signer_keys.iter())
+            .take(old_config.threshold as usize)
+            .map(|(sk, key)| sign_reshare_contribution(sk, &key.key_id, &session))
+            .collect();
+        let new_share_set = finalize_reshare(&session, &contributions).expect("finalize_reshare");
+
+        // A signature from a removed old signer, replayed against the new
+        // config under its old key id, must not be accepted: the new config
+        // has no signer registered under that id.
+        let hash = test_hash("reshare-removed-signer-artifact");
+        let removed_signature = test_sign(
+            &old_signing_keys[0],
+            &old_config.signer_keys[0].key_id,
+            &hash,
+        );
+        let artifact = PublicationArtifact {
+            artifact_id: TEST_ARTIFACT_ID.into(),
+            connector_id: TEST_CONNECTOR_ID.into(),
+            content_hash: hash,
+            signatures: vec![removed_signature],
+        };
+        let result = verify_threshold(
+            &new_share_set.config,
+            &artifact,
+            "trace-1",
+            "2024-01-01T00:00:00Z",
+        );
+        assert!(!result.verified);
+        assert_eq!(result.valid_signatures, 0);
+    }
 }
@@ -0,0 +1,302 @@
+//! Deterministic sample constructors for 21 of the 22 typed models in
+//! [`crate::storage::models`] (there is no sample for
+//! `IsolationMeshSnapshotRecord`).
+//!
+//! Cross-module and conformance tests were hand-building `*Record` literals
+//! ad hoc, which drifts from the real schemas whenever a field is added.
+//! This module centralises one `sample_<model>()` per record plus
+//! [`sample_full_snapshot`], a [`ModelSnapshot`] whose cross-references are
+//! consistent with [`detect_orphans`].
+
+use super::models::{
+    ArtifactJournalRecord, CanonicalStateRootRecord, ControlChannelStateRecord,
+    CrdtMergeStateRecord, DurabilityModeRecord, DurableClaimAuditRecord, FencingLeaseRecord,
+    HealthGatePolicyRecord, LeaseConflictAuditRecord, LeaseQuorumRecord, LeaseServiceRecord,
+    LifecycleTransitionCacheRecord, ModelSnapshot, OfflineCoverageMetricRecord,
+    QuarantineEntryRecord, QuarantinePromotionRecord, RepairCycleAuditRecord,
+    RetentionPolicyRecord, RolloutStateRecord, SchemaMigrationRecord, SnapshotPolicyRecord,
+    TieredTrustArtifactRecord,
+};
+
+pub fn sample_fencing_lease() -> FencingLeaseRecord {
+    FencingLeaseRecord {
+        lease_seq: 1,
+        object_id: "object-1".to_string(),
+        holder_id: "holder-1".to_string(),
+        epoch: 1,
+        acquired_at: "2026-01-01T00:00:00Z".to_string(),
+        expires_at: "2026-01-01T01:00:00Z".to_string(),
+        fence_version: 1,
+    }
+}
+
+pub fn sample_lease_service() -> LeaseServiceRecord {
+    LeaseServiceRecord {
+        lease_id: "lease-1".to_string(),
+        holder_id: "holder-1".to_string(),
+        resource_key: "resource-1".to_string(),
+        state: "granted".to_string(),
+        epoch: 1,
+        granted_at: "2026-01-01T00:00:00Z".to_string(),
+        expires_at: "2026-01-01T01:00:00Z".to_string(),
+        renewed_count: 0,
+    }
+}
+
+pub fn sample_lease_quorum() -> LeaseQuorumRecord {
+    LeaseQuorumRecord {
+        quorum_id: "quorum-1".to_string(),
+        resource_key: "resource-1".to_string(),
+        participants: vec!["node-a".to_string(), "node-b".to_string()],
+        ack_count: 2,
+        required_acks: 2,
+        epoch: 1,
+        decided_at: Some("2026-01-01T00:00:00Z".to_string()),
+        outcome: "granted".to_string(),
+    }
+}
+
+pub fn sample_rollout_state() -> RolloutStateRecord {
+    RolloutStateRecord {
+        connector_id: "connector-1".to_string(),
+        rollout_epoch: 1,
+        lifecycle_state: "active".to_string(),
+        health_gate_passed: true,
+        rollout_phase: "stable".to_string(),
+        activated_at: Some("2026-01-01T00:00:00Z".to_string()),
+        persisted_at: "2026-01-01T00:00:00Z".to_string(),
+        version: 1,
+        rollback_reason: None,
+    }
+}
+
+pub fn sample_health_gate_policy() -> HealthGatePolicyRecord {
+    HealthGatePolicyRecord {
+        gate_id: "gate-1".to_string(),
+        connector_id: "connector-1".to_string(),
+        check_name: "liveness".to_string(),
+        required: true,
+        passed: true,
+        message: None,
+        evaluated_at: "2026-01-01T00:00:00Z".to_string(),
+        epoch: 1,
+    }
+}
+
+pub fn sample_control_channel_state() -> ControlChannelStateRecord {
+    ControlChannelStateRecord {
+        channel_id: "channel-1".to_string(),
+        last_seq: 10,
+        window_low: 0,
+        window_high: 20,
+        epoch: 1,
+        updated_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+pub fn sample_artifact_journal() -> ArtifactJournalRecord {
+    ArtifactJournalRecord {
+        entry_id: "journal-1".to_string(),
+        artifact_hash: "deadbeef".to_string(),
+        operation: "write".to_string(),
+        actor_id: "actor-1".to_string(),
+        epoch: 1,
+        timestamp: "2026-01-01T00:00:00Z".to_string(),
+        metadata_json: None,
+    }
+}
+
+pub fn sample_tiered_trust_artifact() -> TieredTrustArtifactRecord {
+    TieredTrustArtifactRecord {
+        artifact_id: "artifact-1".to_string(),
+        trust_tier: "tier1".to_string(),
+        publisher_id: "publisher-1".to_string(),
+        signature: "sig-1".to_string(),
+        assurance_level: 1,
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        expires_at: None,
+        revoked: false,
+    }
+}
+
+pub fn sample_canonical_state_root() -> CanonicalStateRootRecord {
+    CanonicalStateRootRecord {
+        root_hash: "root-hash-1".to_string(),
+        epoch: 1,
+        computed_at: "2026-01-01T00:00:00Z".to_string(),
+        input_count: 10,
+        algorithm: "sha256".to_string(),
+    }
+}
+
+pub fn sample_durability_mode() -> DurabilityModeRecord {
+    DurabilityModeRecord {
+        domain_name: "domain-1".to_string(),
+        mode: "sync".to_string(),
+        wal_enabled: true,
+        sync_interval_ms: 100,
+        updated_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+pub fn sample_durable_claim_audit() -> DurableClaimAuditRecord {
+    DurableClaimAuditRecord {
+        claim_id: "claim-1".to_string(),
+        actor_id: "actor-1".to_string(),
+        claim_type: "write".to_string(),
+        decision: "allowed".to_string(),
+        reason: "within budget".to_string(),
+        epoch: 1,
+        decided_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+pub fn sample_schema_migration() -> SchemaMigrationRecord {
+    SchemaMigrationRecord {
+        migration_id: "migration-1".to_string(),
+        version_from: "1.0.0".to_string(),
+        version_to: "1.1.0".to_string(),
+        applied_at: "2026-01-01T00:00:00Z".to_string(),
+        checksum: "checksum-1".to_string(),
+        reversible: true,
+    }
+}
+
+pub fn sample_snapshot_policy() -> SnapshotPolicyRecord {
+    SnapshotPolicyRecord {
+        policy_id: "policy-1".to_string(),
+        domain_name: "domain-1".to_string(),
+        interval_seconds: 3600,
+        last_snapshot_at: None,
+        next_snapshot_at: "2026-01-01T01:00:00Z".to_string(),
+        retention_count: 7,
+    }
+}
+
+pub fn sample_crdt_merge_state() -> CrdtMergeStateRecord {
+    CrdtMergeStateRecord {
+        crdt_id: "crdt-1".to_string(),
+        crdt_type: "g_counter".to_string(),
+        vector_clock_json: "{}".to_string(),
+        merge_count: 0,
+        last_merged_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+pub fn sample_quarantine_entry() -> QuarantineEntryRecord {
+    QuarantineEntryRecord {
+        entry_id: "entry-1".to_string(),
+        artifact_hash: "deadbeef".to_string(),
+        reason: "suspicious signature".to_string(),
+        severity: "high".to_string(),
+        quarantined_at: "2026-01-01T00:00:00Z".to_string(),
+        quarantined_by: "sentinel".to_string(),
+        released: false,
+    }
+}
+
+pub fn sample_quarantine_promotion() -> QuarantinePromotionRecord {
+    QuarantinePromotionRecord {
+        promotion_id: "promotion-1".to_string(),
+        entry_id: sample_quarantine_entry().entry_id,
+        promoted_by: "alice".to_string(),
+        promoted_at: "2026-01-01T01:00:00Z".to_string(),
+        justification: "false positive".to_string(),
+    }
+}
+
+pub fn sample_retention_policy() -> RetentionPolicyRecord {
+    RetentionPolicyRecord {
+        policy_id: "retention-1".to_string(),
+        domain_name: "domain-1".to_string(),
+        max_age_seconds: 86_400,
+        max_entries: 1_000,
+        last_purge_at: None,
+        next_purge_at: "2026-01-02T00:00:00Z".to_string(),
+    }
+}
+
+pub fn sample_repair_cycle_audit() -> RepairCycleAuditRecord {
+    RepairCycleAuditRecord {
+        cycle_id: "cycle-1".to_string(),
+        domain_name: "domain-1".to_string(),
+        trigger: "scheduled".to_string(),
+        items_repaired: 3,
+        items_failed: 0,
+        started_at: "2026-01-01T00:00:00Z".to_string(),
+        completed_at: "2026-01-01T00:05:00Z".to_string(),
+    }
+}
+
+pub fn sample_lease_conflict_audit() -> LeaseConflictAuditRecord {
+    LeaseConflictAuditRecord {
+        conflict_id: "conflict-1".to_string(),
+        resource_key: "resource-1".to_string(),
+        holder_a: "holder-1".to_string(),
+        holder_b: "holder-2".to_string(),
+        resolution: "holder-1 retained".to_string(),
+        resolved_at: "2026-01-01T00:00:00Z".to_string(),
+        epoch: 1,
+    }
+}
+
+pub fn sample_offline_coverage_metric() -> OfflineCoverageMetricRecord {
+    OfflineCoverageMetricRecord {
+        metric_id: "metric-1".to_string(),
+        domain_name: "domain-1".to_string(),
+        coverage_pct: 99.5,
+        sampled_at: "2026-01-01T00:00:00Z".to_string(),
+        sample_size: 200,
+    }
+}
+
+pub fn sample_lifecycle_transition_cache() -> LifecycleTransitionCacheRecord {
+    LifecycleTransitionCacheRecord {
+        transition_id: "transition-1".to_string(),
+        connector_id: sample_rollout_state().connector_id,
+        from_state: "provisioning".to_string(),
+        to_state: "active".to_string(),
+        triggered_by: "operator".to_string(),
+        transitioned_at: "2026-01-01T00:00:00Z".to_string(),
+    }
+}
+
+/// A [`ModelSnapshot`] whose cross-references are all present, so
+/// `detect_orphans` reports zero orphans against it: the sample promotion's
+/// `entry_id` matches the sample quarantine entry, and the sample health
+/// gate policy's `connector_id` matches the sample rollout state.
+pub fn sample_full_snapshot() -> ModelSnapshot {
+    ModelSnapshot {
+        quarantine_entries: vec![sample_quarantine_entry()],
+        quarantine_promotions: vec![sample_quarantine_promotion()],
+        rollout_states: vec![sample_rollout_state()],
+        health_gate_policies: vec![sample_health_gate_policy()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::models::detect_orphans;
+
+    #[test]
+    fn sample_full_snapshot_has_no_orphans() {
+        assert!(detect_orphans(&sample_full_snapshot()).is_empty());
+    }
+
+    #[test]
+    fn sample_quarantine_promotion_references_sample_quarantine_entry() {
+        assert_eq!(
+            sample_quarantine_promotion().entry_id,
+            sample_quarantine_entry().entry_id
+        );
+    }
+
+    #[test]
+    fn sample_health_gate_policy_references_sample_rollout_state() {
+        assert_eq!(
+            sample_health_gate_policy().connector_id,
+            sample_rollout_state().connector_id
+        );
+    }
+}
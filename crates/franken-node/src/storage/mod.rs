@@ -1,5 +1,7 @@
 pub mod cas;
 pub mod cleanup_receipts;
+#[cfg(any(test, feature = "test-support"))]
+pub mod fixtures;
 pub mod frankensqlite_adapter;
 pub mod models;
 pub mod retrievability_gate;
@@ -1,6 +1,6 @@
 //! bd-1v65: Typed model definitions for sqlmodel_rust integration.
 //!
-//! Contains persistence-layer model structs for all 21 domains classified in
+//! Contains persistence-layer model structs for all 22 domains classified in
 //! the sqlmodel_rust usage policy (bd-bt82). Each struct represents the
 //! schema contract between Rust types and the frankensqlite storage engine.
 //!
@@ -9,8 +9,9 @@
 //! - **Mandatory (12):** Fencing, lease service, lease quorum, rollout state,
 //!   health gate policy, control channel, artifact journal, tiered trust,
 //!   canonical state roots, durability mode, durable claim audit, schema migration.
-//! - **Should-use (7):** Snapshot policy, CRDT merge, quarantine store,
-//!   quarantine promotion, retention policy, repair cycle audit, lease conflict.
+//! - **Should-use (8):** Snapshot policy, CRDT merge, quarantine store,
+//!   quarantine promotion, retention policy, repair cycle audit, lease conflict,
+//!   isolation mesh snapshot.
 //! - **Optional (2):** Offline coverage metrics, lifecycle transition cache.
 //!
 //! # Event Codes
@@ -20,9 +21,22 @@
 //! - `SQLMODEL_ROUND_TRIP_PASS`: Round-trip serialisation/deserialisation passed
 //! - `SQLMODEL_ROUND_TRIP_FAIL`: Round-trip serialisation/deserialisation failed
 //! - `SQLMODEL_VERSION_COMPAT_FAIL`: Version compatibility check failed
-
+//!
+//! # Schema Evolution Policy
+//!
+//! Rows written by an older binary must keep deserializing cleanly under a
+//! newer one. Concretely: a field added to any record in this module MUST be
+//! `Option<T>` (or otherwise have a sensible zero value) and carry
+//! `#[serde(default)]`, so that a JSON blob persisted before the field
+//! existed still deserializes instead of erroring on a missing key. Fields
+//! that are part of a record's original schema stay mandatory; this policy
+//! only applies to fields added after a model has shipped.
+
+use chrono::DateTime;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 // ---------------------------------------------------------------------------
 // Model version constant
@@ -30,6 +44,78 @@ use std::collections::BTreeMap;
 
 pub const MODEL_SCHEMA_VERSION: &str = "1.0.0";
 
+// ---------------------------------------------------------------------------
+// Typed timestamps
+// ---------------------------------------------------------------------------
+
+/// Errors raised while validating the timestamp columns stored on the typed
+/// models in this file.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TimeError {
+    #[error("timestamp {raw:?} is not valid ISO-8601/RFC3339")]
+    InvalidFormat { raw: String },
+    #[error("expires_at {expires_at:?} is before acquired_at {acquired_at:?}")]
+    ExpiryBeforeAcquisition {
+        acquired_at: String,
+        expires_at: String,
+    },
+}
+
+/// A validated ISO-8601/RFC3339 timestamp, stored in its original string form.
+///
+/// Model fields such as `acquired_at` and `expires_at` remain plain `String`
+/// columns on the wire and in storage -- [`Timestamp`] only exists as a
+/// construction-time gate so a malformed value is rejected before it is
+/// persisted, rather than being threaded through as an opaque column type.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    /// Parses `raw` as RFC3339, returning the validated timestamp on success.
+    pub fn parse(raw: &str) -> Result<Self, TimeError> {
+        DateTime::parse_from_rfc3339(raw).map_err(|_| TimeError::InvalidFormat {
+            raw: raw.to_string(),
+        })?;
+        Ok(Self(raw.to_string()))
+    }
+
+    /// The original string representation, as stored.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Timestamp {
+    type Error = TimeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+/// Validates that `expires_at` is not before `acquired_at`, when both are
+/// present. Returns the parsed pair on success for callers that want to
+/// compare them further.
+fn validate_time_ordering_pair(
+    acquired_at: &str,
+    expires_at: &str,
+) -> Result<(Timestamp, Timestamp), TimeError> {
+    let acquired = Timestamp::parse(acquired_at)?;
+    let expires = Timestamp::parse(expires_at)?;
+    // Both parsed successfully as RFC3339, so re-parsing to `DateTime` here
+    // cannot fail; it only exists to get an orderable value out of the
+    // validated strings.
+    let acquired_dt = DateTime::parse_from_rfc3339(acquired.as_str()).expect("validated above");
+    let expires_dt = DateTime::parse_from_rfc3339(expires.as_str()).expect("validated above");
+    if expires_dt < acquired_dt {
+        return Err(TimeError::ExpiryBeforeAcquisition {
+            acquired_at: acquired_at.to_string(),
+            expires_at: expires_at.to_string(),
+        });
+    }
+    Ok((acquired, expires))
+}
+
 // ---------------------------------------------------------------------------
 // Mandatory models (12)
 // ---------------------------------------------------------------------------
@@ -74,6 +160,17 @@ impl FencingLeaseRecord {
             "fence_version",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Validates that `acquired_at` and `expires_at` are well-formed
+    /// ISO-8601/RFC3339 timestamps and that expiry does not precede
+    /// acquisition.
+    pub fn validate_time_ordering(&self) -> Result<(), TimeError> {
+        validate_time_ordering_pair(&self.acquired_at, &self.expires_at).map(|_| ())
+    }
 }
 
 /// Lease service record — persists lease lifecycle state.
@@ -118,6 +215,10 @@ impl LeaseServiceRecord {
             "renewed_count",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Lease quorum record — persists quorum-based lease coordination state.
@@ -162,6 +263,10 @@ impl LeaseQuorumRecord {
             "outcome",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["decided_at"]
+    }
 }
 
 /// Rollout state record — persists connector rollout phase and lifecycle state.
@@ -179,6 +284,11 @@ pub struct RolloutStateRecord {
     pub activated_at: Option<String>,
     pub persisted_at: String,
     pub version: u32,
+    /// Why the rollout was rolled back, if it was. Added after the model
+    /// shipped; defaults to `None` so rows persisted before this field
+    /// existed keep deserializing under the schema evolution policy above.
+    #[serde(default)]
+    pub rollback_reason: Option<String>,
 }
 
 impl RolloutStateRecord {
@@ -204,8 +314,13 @@ impl RolloutStateRecord {
             "activated_at",
             "persisted_at",
             "version",
+            "rollback_reason",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["activated_at", "rollback_reason"]
+    }
 }
 
 /// Health gate policy record — persists health gate evaluation results.
@@ -250,6 +365,19 @@ impl HealthGatePolicyRecord {
             "epoch",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["message"]
+    }
+
+    /// `connector_id` must reference an existing [`RolloutStateRecord`].
+    pub fn foreign_keys() -> &'static [ForeignKey] {
+        &[ForeignKey {
+            field: "connector_id",
+            target_model: "RolloutStateRecord",
+            target_field: "connector_id",
+        }]
+    }
 }
 
 /// Control channel state record — persists sequence window for control messages.
@@ -291,6 +419,10 @@ impl ControlChannelStateRecord {
         ]
     }
 
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
+
     /// Validates one control-channel sequence replay window before WAL replay.
     pub fn validate_replay_window(&self) -> Result<(), String> {
         if self.channel_id.is_empty() {
@@ -417,6 +549,10 @@ impl ArtifactJournalRecord {
             "metadata_json",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["metadata_json"]
+    }
 }
 
 /// Tiered trust artifact record — persists trust artifacts with tier classification.
@@ -476,6 +612,10 @@ impl TieredTrustArtifactRecord {
             "revoked",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["expires_at"]
+    }
 }
 
 /// Canonical state root record — persists state root hashes for integrity.
@@ -526,6 +666,10 @@ impl CanonicalStateRootRecord {
             "algorithm",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Durability mode record — persists durability policy per domain.
@@ -564,6 +708,10 @@ impl DurabilityModeRecord {
             "updated_at",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Durable claim audit record — persists audit trail for durable claim gate.
@@ -606,6 +754,10 @@ impl DurableClaimAuditRecord {
             "decided_at",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Schema migration record — tracks applied schema migrations.
@@ -646,6 +798,10 @@ impl SchemaMigrationRecord {
             "reversible",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -690,6 +846,10 @@ impl SnapshotPolicyRecord {
             "retention_count",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["last_snapshot_at"]
+    }
 }
 
 /// CRDT merge state record — persists CRDT merge vector state.
@@ -728,6 +888,10 @@ impl CrdtMergeStateRecord {
             "last_merged_at",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Quarantine entry record — persists quarantined artifact state.
@@ -784,6 +948,10 @@ impl QuarantineEntryRecord {
             "released",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Quarantine promotion record — persists promotion/release receipts.
@@ -822,6 +990,19 @@ impl QuarantinePromotionRecord {
             "justification",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// `entry_id` must reference an existing [`QuarantineEntryRecord`].
+    pub fn foreign_keys() -> &'static [ForeignKey] {
+        &[ForeignKey {
+            field: "entry_id",
+            target_model: "QuarantineEntryRecord",
+            target_field: "entry_id",
+        }]
+    }
 }
 
 /// Retention policy record — persists data retention scheduling.
@@ -862,6 +1043,10 @@ impl RetentionPolicyRecord {
             "next_purge_at",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &["last_purge_at"]
+    }
 }
 
 /// Repair cycle audit record — persists repair cycle outcomes.
@@ -904,6 +1089,10 @@ impl RepairCycleAuditRecord {
             "completed_at",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Lease conflict audit record — persists lease conflict resolution events.
@@ -946,6 +1135,57 @@ impl LeaseConflictAuditRecord {
             "epoch",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Isolation mesh snapshot record — persists a point-in-time checkpoint of
+/// an `IsolationMesh` (topology, workload placements with their elevation
+/// history, and per-rail state) so the mesh's audit trail survives a
+/// restart instead of living only in memory.
+///
+/// Owner: `runtime::isolation_mesh`
+/// Classification: should_use
+/// Source: hand_authored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IsolationMeshSnapshotRecord {
+    pub snapshot_id: String,
+    pub topology_json: String,
+    pub workloads_json: String,
+    pub rail_states_json: String,
+    pub event_seq: u64,
+    pub captured_at: String,
+}
+
+impl IsolationMeshSnapshotRecord {
+    pub fn model_name() -> &'static str {
+        "IsolationMeshSnapshotRecord"
+    }
+
+    pub fn model_version() -> &'static str {
+        MODEL_SCHEMA_VERSION
+    }
+
+    pub fn table_name() -> &'static str {
+        "isolation_mesh_snapshots"
+    }
+
+    pub fn column_names() -> &'static [&'static str] {
+        &[
+            "snapshot_id",
+            "topology_json",
+            "workloads_json",
+            "rail_states_json",
+            "event_seq",
+            "captured_at",
+        ]
+    }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -988,6 +1228,10 @@ impl OfflineCoverageMetricRecord {
             "sample_size",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Lifecycle transition cache record — caches recent state transitions.
@@ -1028,23 +1272,102 @@ impl LifecycleTransitionCacheRecord {
             "transitioned_at",
         ]
     }
+
+    pub fn nullable_columns() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Model registry — enumerates all defined models
 // ---------------------------------------------------------------------------
 
+/// A declared cross-model reference: `field` on the owning model must match
+/// `target_field` on some row of `target_model`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignKey {
+    pub field: &'static str,
+    pub target_model: &'static str,
+    pub target_field: &'static str,
+}
+
 /// Metadata for a single model definition.
 pub struct ModelMeta {
     pub name: &'static str,
     pub version: &'static str,
     pub table: &'static str,
     pub columns: &'static [&'static str],
+    /// Subset of `columns` that the struct declares as `Option<_>` and the
+    /// DDL must therefore mark `NULL` instead of `NOT NULL`.
+    pub nullable_columns: &'static [&'static str],
+    /// Cross-model references this model declares, if any. Most models
+    /// declare none and leave this empty.
+    pub foreign_keys: &'static [ForeignKey],
     pub classification: &'static str,
     pub source: &'static str,
     pub owner_module: &'static str,
 }
 
+fn schema_checksum_update_len_prefixed_hash(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_be_bytes());
+    hasher.update(bytes);
+}
+
+impl ModelMeta {
+    /// Compute a deterministic fingerprint of this model's declared schema,
+    /// for comparison against a live table's checksum to detect drift.
+    ///
+    /// Hashes the table name, the column list in declared order (a reorder
+    /// changes the checksum), and which of those columns are nullable.
+    /// [`ModelMeta`] does not track per-column SQL types or an explicit
+    /// primary key separately from `columns`, so this fingerprints the
+    /// schema dimensions the model registry actually declares rather than
+    /// inventing untracked ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use frankenengine_node::storage::models::all_model_metadata;
+    /// let meta = all_model_metadata();
+    /// let a = meta[0].schema_checksum();
+    /// let b = meta[0].schema_checksum();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn schema_checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(b"model_schema_checksum_v1:");
+        schema_checksum_update_len_prefixed_hash(&mut hasher, self.table.as_bytes());
+
+        schema_checksum_update_len_prefixed_hash(
+            &mut hasher,
+            &(self.columns.len() as u64).to_be_bytes(),
+        );
+        for column in self.columns {
+            schema_checksum_update_len_prefixed_hash(&mut hasher, column.as_bytes());
+            let nullable = self.nullable_columns.contains(column);
+            hasher.update([u8::from(nullable)]);
+        }
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Compare a model's declared schema checksum against a checksum observed on
+/// the live table, for drift detection during migrations.
+///
+/// # Examples
+///
+/// ```
+/// use frankenengine_node::storage::models::{all_model_metadata, verify_schema_checksum};
+/// let meta = &all_model_metadata()[0];
+/// let live_checksum = meta.schema_checksum();
+/// assert!(verify_schema_checksum(meta, &live_checksum));
+/// assert!(!verify_schema_checksum(meta, "drifted"));
+/// ```
+pub fn verify_schema_checksum(meta: &ModelMeta, live_checksum: &str) -> bool {
+    meta.schema_checksum() == live_checksum
+}
+
 /// Returns metadata for all 21 typed models in canonical order.
 ///
 /// # Examples
@@ -1063,6 +1386,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: FencingLeaseRecord::model_version(),
             table: FencingLeaseRecord::table_name(),
             columns: FencingLeaseRecord::column_names(),
+            nullable_columns: FencingLeaseRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::fencing",
@@ -1072,6 +1397,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: LeaseServiceRecord::model_version(),
             table: LeaseServiceRecord::table_name(),
             columns: LeaseServiceRecord::column_names(),
+            nullable_columns: LeaseServiceRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::lease_service",
@@ -1081,6 +1408,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: LeaseQuorumRecord::model_version(),
             table: LeaseQuorumRecord::table_name(),
             columns: LeaseQuorumRecord::column_names(),
+            nullable_columns: LeaseQuorumRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::lease_coordinator",
@@ -1090,6 +1419,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: RolloutStateRecord::model_version(),
             table: RolloutStateRecord::table_name(),
             columns: RolloutStateRecord::column_names(),
+            nullable_columns: RolloutStateRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "codegen",
             owner_module: "connector::rollout_state",
@@ -1099,6 +1430,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: HealthGatePolicyRecord::model_version(),
             table: HealthGatePolicyRecord::table_name(),
             columns: HealthGatePolicyRecord::column_names(),
+            nullable_columns: HealthGatePolicyRecord::nullable_columns(),
+            foreign_keys: HealthGatePolicyRecord::foreign_keys(),
             classification: "mandatory",
             source: "codegen",
             owner_module: "connector::health_gate",
@@ -1108,6 +1441,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: ControlChannelStateRecord::model_version(),
             table: ControlChannelStateRecord::table_name(),
             columns: ControlChannelStateRecord::column_names(),
+            nullable_columns: ControlChannelStateRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::control_channel",
@@ -1117,6 +1452,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: ArtifactJournalRecord::model_version(),
             table: ArtifactJournalRecord::table_name(),
             columns: ArtifactJournalRecord::column_names(),
+            nullable_columns: ArtifactJournalRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "codegen",
             owner_module: "connector::artifact_persistence",
@@ -1126,6 +1463,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: TieredTrustArtifactRecord::model_version(),
             table: TieredTrustArtifactRecord::table_name(),
             columns: TieredTrustArtifactRecord::column_names(),
+            nullable_columns: TieredTrustArtifactRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "codegen",
             owner_module: "connector::tiered_trust_storage",
@@ -1135,6 +1474,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: CanonicalStateRootRecord::model_version(),
             table: CanonicalStateRootRecord::table_name(),
             columns: CanonicalStateRootRecord::column_names(),
+            nullable_columns: CanonicalStateRootRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::state_model",
@@ -1144,6 +1485,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: DurabilityModeRecord::model_version(),
             table: DurabilityModeRecord::table_name(),
             columns: DurabilityModeRecord::column_names(),
+            nullable_columns: DurabilityModeRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::durability",
@@ -1153,6 +1496,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: DurableClaimAuditRecord::model_version(),
             table: DurableClaimAuditRecord::table_name(),
             columns: DurableClaimAuditRecord::column_names(),
+            nullable_columns: DurableClaimAuditRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "hand_authored",
             owner_module: "connector::durable_claim_gate",
@@ -1162,6 +1507,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: SchemaMigrationRecord::model_version(),
             table: SchemaMigrationRecord::table_name(),
             columns: SchemaMigrationRecord::column_names(),
+            nullable_columns: SchemaMigrationRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "mandatory",
             source: "codegen",
             owner_module: "connector::schema_migration",
@@ -1172,6 +1519,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: SnapshotPolicyRecord::model_version(),
             table: SnapshotPolicyRecord::table_name(),
             columns: SnapshotPolicyRecord::column_names(),
+            nullable_columns: SnapshotPolicyRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "should_use",
             source: "codegen",
             owner_module: "connector::snapshot_policy",
@@ -1181,6 +1530,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: CrdtMergeStateRecord::model_version(),
             table: CrdtMergeStateRecord::table_name(),
             columns: CrdtMergeStateRecord::column_names(),
+            nullable_columns: CrdtMergeStateRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "should_use",
             source: "hand_authored",
             owner_module: "connector::crdt",
@@ -1190,6 +1541,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: QuarantineEntryRecord::model_version(),
             table: QuarantineEntryRecord::table_name(),
             columns: QuarantineEntryRecord::column_names(),
+            nullable_columns: QuarantineEntryRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "should_use",
             source: "codegen",
             owner_module: "connector::quarantine_store",
@@ -1199,6 +1552,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: QuarantinePromotionRecord::model_version(),
             table: QuarantinePromotionRecord::table_name(),
             columns: QuarantinePromotionRecord::column_names(),
+            nullable_columns: QuarantinePromotionRecord::nullable_columns(),
+            foreign_keys: QuarantinePromotionRecord::foreign_keys(),
             classification: "should_use",
             source: "codegen",
             owner_module: "connector::quarantine_promotion",
@@ -1208,6 +1563,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: RetentionPolicyRecord::model_version(),
             table: RetentionPolicyRecord::table_name(),
             columns: RetentionPolicyRecord::column_names(),
+            nullable_columns: RetentionPolicyRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "should_use",
             source: "hand_authored",
             owner_module: "connector::retention_policy",
@@ -1217,6 +1574,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: RepairCycleAuditRecord::model_version(),
             table: RepairCycleAuditRecord::table_name(),
             columns: RepairCycleAuditRecord::column_names(),
+            nullable_columns: RepairCycleAuditRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "should_use",
             source: "hand_authored",
             owner_module: "connector::repair_controller",
@@ -1226,16 +1585,31 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: LeaseConflictAuditRecord::model_version(),
             table: LeaseConflictAuditRecord::table_name(),
             columns: LeaseConflictAuditRecord::column_names(),
+            nullable_columns: LeaseConflictAuditRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "should_use",
             source: "hand_authored",
             owner_module: "connector::lease_conflict",
         },
+        ModelMeta {
+            name: IsolationMeshSnapshotRecord::model_name(),
+            version: IsolationMeshSnapshotRecord::model_version(),
+            table: IsolationMeshSnapshotRecord::table_name(),
+            columns: IsolationMeshSnapshotRecord::column_names(),
+            nullable_columns: IsolationMeshSnapshotRecord::nullable_columns(),
+            foreign_keys: &[],
+            classification: "should_use",
+            source: "hand_authored",
+            owner_module: "runtime::isolation_mesh",
+        },
         // Optional (2)
         ModelMeta {
             name: OfflineCoverageMetricRecord::model_name(),
             version: OfflineCoverageMetricRecord::model_version(),
             table: OfflineCoverageMetricRecord::table_name(),
             columns: OfflineCoverageMetricRecord::column_names(),
+            nullable_columns: OfflineCoverageMetricRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "optional",
             source: "codegen",
             owner_module: "connector::offline_coverage",
@@ -1245,6 +1619,8 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
             version: LifecycleTransitionCacheRecord::model_version(),
             table: LifecycleTransitionCacheRecord::table_name(),
             columns: LifecycleTransitionCacheRecord::column_names(),
+            nullable_columns: LifecycleTransitionCacheRecord::nullable_columns(),
+            foreign_keys: &[],
             classification: "optional",
             source: "hand_authored",
             owner_module: "connector::lifecycle",
@@ -1252,6 +1628,88 @@ pub fn all_model_metadata() -> Vec<ModelMeta> {
     ]
 }
 
+// ---------------------------------------------------------------------------
+// Orphan detection — cross-model foreign key integrity
+// ---------------------------------------------------------------------------
+
+/// A row whose declared [`ForeignKey`] points at a target row that is
+/// absent from the snapshot it was checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Orphan {
+    pub model: &'static str,
+    pub row_key: String,
+    pub foreign_key: ForeignKey,
+    pub missing_value: String,
+}
+
+/// The subset of persisted rows needed to check the foreign keys declared
+/// via [`QuarantinePromotionRecord::foreign_keys`] and
+/// [`HealthGatePolicyRecord::foreign_keys`] in [`detect_orphans`].
+#[derive(Debug, Clone, Default)]
+pub struct ModelSnapshot {
+    pub quarantine_entries: Vec<QuarantineEntryRecord>,
+    pub quarantine_promotions: Vec<QuarantinePromotionRecord>,
+    pub rollout_states: Vec<RolloutStateRecord>,
+    pub health_gate_policies: Vec<HealthGatePolicyRecord>,
+}
+
+/// Finds rows in `snapshot` whose declared foreign key references a target
+/// row that is not present in the snapshot.
+///
+/// # Examples
+///
+/// ```
+/// use frankenengine_node::storage::models::{ModelSnapshot, QuarantinePromotionRecord, detect_orphans};
+/// let snapshot = ModelSnapshot {
+///     quarantine_promotions: vec![QuarantinePromotionRecord {
+///         promotion_id: "promo-1".to_string(),
+///         entry_id: "missing-entry".to_string(),
+///         promoted_by: "alice".to_string(),
+///         promoted_at: "2026-01-01T00:00:00Z".to_string(),
+///         justification: "false positive".to_string(),
+///     }],
+///     ..Default::default()
+/// };
+/// assert_eq!(detect_orphans(&snapshot).len(), 1);
+/// ```
+pub fn detect_orphans(snapshot: &ModelSnapshot) -> Vec<Orphan> {
+    let mut orphans = Vec::new();
+
+    let quarantine_entry_ids: BTreeSet<&str> = snapshot
+        .quarantine_entries
+        .iter()
+        .map(|entry| entry.entry_id.as_str())
+        .collect();
+    for promotion in &snapshot.quarantine_promotions {
+        if !quarantine_entry_ids.contains(promotion.entry_id.as_str()) {
+            orphans.push(Orphan {
+                model: QuarantinePromotionRecord::model_name(),
+                row_key: promotion.promotion_id.clone(),
+                foreign_key: QuarantinePromotionRecord::foreign_keys()[0],
+                missing_value: promotion.entry_id.clone(),
+            });
+        }
+    }
+
+    let rollout_connector_ids: BTreeSet<&str> = snapshot
+        .rollout_states
+        .iter()
+        .map(|rollout| rollout.connector_id.as_str())
+        .collect();
+    for policy in &snapshot.health_gate_policies {
+        if !rollout_connector_ids.contains(policy.connector_id.as_str()) {
+            orphans.push(Orphan {
+                model: HealthGatePolicyRecord::model_name(),
+                row_key: policy.gate_id.clone(),
+                foreign_key: HealthGatePolicyRecord::foreign_keys()[0],
+                missing_value: policy.connector_id.clone(),
+            });
+        }
+    }
+
+    orphans
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1277,8 +1735,8 @@ mod tests {
     }
 
     #[test]
-    fn all_model_metadata_returns_21_entries() {
-        assert_eq!(all_model_metadata().len(), 21);
+    fn all_model_metadata_returns_22_entries() {
+        assert_eq!(all_model_metadata().len(), 22);
     }
 
     #[test]
@@ -1291,12 +1749,12 @@ mod tests {
     }
 
     #[test]
-    fn should_use_models_count_is_7() {
+    fn should_use_models_count_is_8() {
         let count = all_model_metadata()
             .iter()
             .filter(|m| m.classification == "should_use")
             .count();
-        assert_eq!(count, 7);
+        assert_eq!(count, 8);
     }
 
     #[test]
@@ -1328,6 +1786,146 @@ mod tests {
         assert_eq!(tables.len(), total, "duplicate table names found");
     }
 
+    /// Verifies `nullable_columns()` agrees with which keys a fully-`None`
+    /// sample actually serializes as JSON `null`, so the two never drift.
+    fn assert_nullable_columns_match_option_fields(
+        sample_all_none: &serde_json::Value,
+        column_names: &[&str],
+        nullable_columns: &[&str],
+    ) {
+        for column in column_names {
+            let is_null = sample_all_none
+                .get(column)
+                .unwrap_or_else(|| panic!("column {column} missing from serialized sample"))
+                .is_null();
+            let declared_nullable = nullable_columns.contains(column);
+            assert_eq!(
+                is_null, declared_nullable,
+                "column {column}: nullable_columns() declares nullable={declared_nullable} \
+                 but the fully-None sample serialized it as null={is_null}"
+            );
+        }
+    }
+
+    #[test]
+    fn rollout_state_record_reports_activated_at_nullable_and_connector_id_not() {
+        assert_eq!(
+            RolloutStateRecord::nullable_columns(),
+            &["activated_at", "rollback_reason"]
+        );
+
+        let record = RolloutStateRecord {
+            connector_id: "conn-1".into(),
+            rollout_epoch: 5,
+            lifecycle_state: "active".into(),
+            health_gate_passed: true,
+            rollout_phase: "canary".into(),
+            activated_at: None,
+            persisted_at: "2026-01-01T00:01:00Z".into(),
+            version: 3,
+            rollback_reason: None,
+        };
+        let value = serde_json::to_value(&record).expect("serialize");
+        assert_nullable_columns_match_option_fields(
+            &value,
+            RolloutStateRecord::column_names(),
+            RolloutStateRecord::nullable_columns(),
+        );
+        assert!(value["activated_at"].is_null());
+        assert!(!value["connector_id"].is_null());
+    }
+
+    /// Forward/backward compatibility: a JSON blob persisted by a v1 binary,
+    /// written before `rollback_reason` existed, must still deserialize into
+    /// the current `RolloutStateRecord` rather than erroring on a missing
+    /// key. This is the schema evolution policy documented on this module.
+    #[test]
+    fn rollout_state_record_deserializes_v1_blob_missing_rollback_reason() {
+        let v1_json = serde_json::json!({
+            "connector_id": "conn-1",
+            "rollout_epoch": 5,
+            "lifecycle_state": "active",
+            "health_gate_passed": true,
+            "rollout_phase": "canary",
+            "activated_at": null,
+            "persisted_at": "2026-01-01T00:01:00Z",
+            "version": 3,
+        });
+
+        let record: RolloutStateRecord =
+            serde_json::from_value(v1_json).expect("v1 blob must deserialize under current schema");
+        assert_eq!(record.connector_id, "conn-1");
+        assert_eq!(record.rollback_reason, None);
+    }
+
+    #[test]
+    fn fencing_lease_record_has_no_nullable_columns() {
+        assert_eq!(FencingLeaseRecord::nullable_columns(), &[] as &[&str]);
+
+        let record = FencingLeaseRecord {
+            lease_seq: 42,
+            object_id: "obj-1".into(),
+            holder_id: "holder-a".into(),
+            epoch: 7,
+            acquired_at: "2026-01-01T00:00:00Z".into(),
+            expires_at: "2026-01-01T01:00:00Z".into(),
+            fence_version: 1,
+        };
+        let value = serde_json::to_value(&record).expect("serialize");
+        assert_nullable_columns_match_option_fields(
+            &value,
+            FencingLeaseRecord::column_names(),
+            FencingLeaseRecord::nullable_columns(),
+        );
+    }
+
+    fn fencing_lease_record(acquired_at: &str, expires_at: &str) -> FencingLeaseRecord {
+        FencingLeaseRecord {
+            lease_seq: 42,
+            object_id: "obj-1".into(),
+            holder_id: "holder-a".into(),
+            epoch: 7,
+            acquired_at: acquired_at.into(),
+            expires_at: expires_at.into(),
+            fence_version: 1,
+        }
+    }
+
+    #[test]
+    fn fencing_lease_record_with_well_ordered_times_validates() {
+        let record = fencing_lease_record("2026-01-01T00:00:00Z", "2026-01-01T01:00:00Z");
+        assert_eq!(record.validate_time_ordering(), Ok(()));
+    }
+
+    #[test]
+    fn fencing_lease_record_with_expiry_before_acquisition_is_rejected() {
+        let record = fencing_lease_record("2026-01-01T01:00:00Z", "2026-01-01T00:00:00Z");
+        assert_eq!(
+            record.validate_time_ordering(),
+            Err(TimeError::ExpiryBeforeAcquisition {
+                acquired_at: "2026-01-01T01:00:00Z".to_string(),
+                expires_at: "2026-01-01T00:00:00Z".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn fencing_lease_record_with_malformed_timestamp_is_rejected() {
+        let record = fencing_lease_record("not-a-timestamp", "2026-01-01T01:00:00Z");
+        assert_eq!(
+            record.validate_time_ordering(),
+            Err(TimeError::InvalidFormat {
+                raw: "not-a-timestamp".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn timestamp_parse_rejects_invalid_input() {
+        assert!(Timestamp::parse("2026-01-01T00:00:00Z").is_ok());
+        assert!(Timestamp::parse("tomorrow").is_err());
+    }
+
     #[test]
     fn all_models_have_nonempty_columns() {
         for m in all_model_metadata() {
@@ -1373,6 +1971,7 @@ mod tests {
             activated_at: Some("2026-01-01T00:00:00Z".into()),
             persisted_at: "2026-01-01T00:01:00Z".into(),
             version: 3,
+            rollback_reason: None,
         };
         let json = serde_json::to_string(&record).expect("serialize");
         let parsed: RolloutStateRecord = serde_json::from_str(&json).expect("deserialize");
@@ -2428,4 +3027,164 @@ mod tests {
             }
         }
     }
+
+    // --- Foreign keys / orphan detection ---
+
+    fn quarantine_entry(entry_id: &str) -> QuarantineEntryRecord {
+        QuarantineEntryRecord {
+            entry_id: entry_id.to_string(),
+            artifact_hash: "sha256:deadbeef".to_string(),
+            reason: "suspicious signature".to_string(),
+            severity: "high".to_string(),
+            quarantined_at: "2026-01-01T00:00:00Z".to_string(),
+            quarantined_by: "auto-scan".to_string(),
+            released: false,
+        }
+    }
+
+    fn quarantine_promotion(promotion_id: &str, entry_id: &str) -> QuarantinePromotionRecord {
+        QuarantinePromotionRecord {
+            promotion_id: promotion_id.to_string(),
+            entry_id: entry_id.to_string(),
+            promoted_by: "alice".to_string(),
+            promoted_at: "2026-01-02T00:00:00Z".to_string(),
+            justification: "confirmed false positive".to_string(),
+        }
+    }
+
+    fn rollout_state(connector_id: &str) -> RolloutStateRecord {
+        RolloutStateRecord {
+            connector_id: connector_id.to_string(),
+            rollout_epoch: 1,
+            lifecycle_state: "active".to_string(),
+            health_gate_passed: true,
+            rollout_phase: "ga".to_string(),
+            activated_at: Some("2026-01-01T00:00:00Z".to_string()),
+            persisted_at: "2026-01-01T00:00:00Z".to_string(),
+            version: 1,
+            rollback_reason: None,
+        }
+    }
+
+    fn health_gate_policy(gate_id: &str, connector_id: &str) -> HealthGatePolicyRecord {
+        HealthGatePolicyRecord {
+            gate_id: gate_id.to_string(),
+            connector_id: connector_id.to_string(),
+            check_name: "latency_budget".to_string(),
+            required: true,
+            passed: true,
+            message: None,
+            evaluated_at: "2026-01-01T00:00:00Z".to_string(),
+            epoch: 1,
+        }
+    }
+
+    #[test]
+    fn quarantine_promotion_foreign_keys_target_quarantine_entry() {
+        let fks = QuarantinePromotionRecord::foreign_keys();
+        assert_eq!(fks.len(), 1);
+        assert_eq!(fks[0].field, "entry_id");
+        assert_eq!(fks[0].target_model, "QuarantineEntryRecord");
+        assert_eq!(fks[0].target_field, "entry_id");
+    }
+
+    #[test]
+    fn health_gate_policy_foreign_keys_target_rollout_state() {
+        let fks = HealthGatePolicyRecord::foreign_keys();
+        assert_eq!(fks.len(), 1);
+        assert_eq!(fks[0].field, "connector_id");
+        assert_eq!(fks[0].target_model, "RolloutStateRecord");
+        assert_eq!(fks[0].target_field, "connector_id");
+    }
+
+    #[test]
+    fn detect_orphans_is_empty_when_all_references_resolve() {
+        let snapshot = ModelSnapshot {
+            quarantine_entries: vec![quarantine_entry("entry-1")],
+            quarantine_promotions: vec![quarantine_promotion("promo-1", "entry-1")],
+            rollout_states: vec![rollout_state("connector-1")],
+            health_gate_policies: vec![health_gate_policy("gate-1", "connector-1")],
+        };
+        assert!(detect_orphans(&snapshot).is_empty());
+    }
+
+    #[test]
+    fn detect_orphans_flags_promotion_referencing_missing_quarantine_entry() {
+        let snapshot = ModelSnapshot {
+            quarantine_entries: vec![],
+            quarantine_promotions: vec![quarantine_promotion("promo-1", "missing-entry")],
+            rollout_states: vec![],
+            health_gate_policies: vec![],
+        };
+
+        let orphans = detect_orphans(&snapshot);
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].model, "QuarantinePromotionRecord");
+        assert_eq!(orphans[0].row_key, "promo-1");
+        assert_eq!(orphans[0].missing_value, "missing-entry");
+        assert_eq!(orphans[0].foreign_key.target_model, "QuarantineEntryRecord");
+    }
+
+    #[test]
+    fn detect_orphans_flags_health_gate_policy_referencing_missing_rollout_state() {
+        let snapshot = ModelSnapshot {
+            quarantine_entries: vec![],
+            quarantine_promotions: vec![],
+            rollout_states: vec![],
+            health_gate_policies: vec![health_gate_policy("gate-1", "missing-connector")],
+        };
+
+        let orphans = detect_orphans(&snapshot);
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].model, "HealthGatePolicyRecord");
+        assert_eq!(orphans[0].row_key, "gate-1");
+        assert_eq!(orphans[0].missing_value, "missing-connector");
+        assert_eq!(orphans[0].foreign_key.target_model, "RolloutStateRecord");
+    }
+
+    fn sample_meta(columns: &'static [&'static str]) -> ModelMeta {
+        ModelMeta {
+            name: "TestRecord",
+            version: "1.0.0",
+            table: "test_record",
+            columns,
+            nullable_columns: &["note"],
+            foreign_keys: &[],
+            classification: "mandatory",
+            source: "hand_authored",
+            owner_module: "test",
+        }
+    }
+
+    #[test]
+    fn schema_checksum_is_stable_across_runs_for_identical_schemas() {
+        let a = sample_meta(&["id", "name", "note"]);
+        let b = sample_meta(&["id", "name", "note"]);
+        assert_eq!(a.schema_checksum(), b.schema_checksum());
+    }
+
+    #[test]
+    fn schema_checksum_changes_when_columns_are_reordered() {
+        let original = sample_meta(&["id", "name", "note"]);
+        let reordered = sample_meta(&["name", "id", "note"]);
+        assert_ne!(original.schema_checksum(), reordered.schema_checksum());
+    }
+
+    #[test]
+    fn verify_schema_checksum_accepts_matching_and_rejects_drifted() {
+        let meta = sample_meta(&["id", "name", "note"]);
+        let live_checksum = meta.schema_checksum();
+        assert!(verify_schema_checksum(&meta, &live_checksum));
+        assert!(!verify_schema_checksum(&meta, "0".repeat(64).as_str()));
+    }
+
+    #[test]
+    fn schema_checksums_for_all_21_models_are_unique() {
+        let meta = all_model_metadata();
+        let mut checksums: Vec<String> = meta.iter().map(ModelMeta::schema_checksum).collect();
+        let total = checksums.len();
+        checksums.sort();
+        checksums.dedup();
+        assert_eq!(checksums.len(), total, "duplicate schema checksums found");
+    }
 }
@@ -111,6 +111,11 @@ pub struct ManifestSignature {
     pub signature: String,
     pub threshold: Option<ThresholdSignaturePolicy>,
     pub signed_at: String,
+    /// RFC3339 timestamp after which the signature must no longer be
+    /// trusted. Checked by [`verify_manifest`], not by
+    /// [`validate_signed_manifest`] (which is structural-only and has no
+    /// notion of "now").
+    pub valid_until: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -281,6 +286,75 @@ pub fn validate_signed_manifest_with_trusted_publishers(
     validate_signed_manifest_inner(manifest, Some(trusted_publisher_keys))
 }
 
+/// Window ahead of `valid_until` during which [`manifest_freshness`] reports
+/// [`Freshness::ExpiringSoon`] rather than [`Freshness::Fresh`].
+pub const MANIFEST_EXPIRING_SOON_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Proactive-alert classification of a manifest's remaining signature
+/// lifetime, independent of whether the manifest otherwise validates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Freshness {
+    /// More than [`MANIFEST_EXPIRING_SOON_WINDOW_SECS`] remain before `valid_until`.
+    Fresh,
+    /// Within [`MANIFEST_EXPIRING_SOON_WINDOW_SECS`] of `valid_until`, but not expired yet.
+    ExpiringSoon,
+    /// `now_secs` is at or past `valid_until`.
+    Expired,
+}
+
+fn manifest_valid_until_secs(signature: &ManifestSignature) -> Result<i64, ManifestSchemaError> {
+    chrono::DateTime::parse_from_rfc3339(&signature.valid_until)
+        .map(|ts| ts.timestamp())
+        .map_err(|error| ManifestSchemaError::InvalidField {
+            field: "signature.valid_until".to_string(),
+            reason: format!("not a valid RFC3339 timestamp: {error}"),
+        })
+}
+
+/// Classify `signed.signature.valid_until` relative to `now_secs` as
+/// [`Freshness::Fresh`], [`Freshness::ExpiringSoon`], or [`Freshness::Expired`].
+///
+/// Returns `Err` if `valid_until` is not a parseable RFC3339 timestamp;
+/// callers that only need a best-effort alert classification (as opposed to
+/// hard enforcement) can treat that as "needs attention" same as `Expired`.
+pub fn manifest_freshness(
+    signed: &SignedExtensionManifest,
+    now_secs: u64,
+) -> Result<Freshness, ManifestSchemaError> {
+    let valid_until_secs = manifest_valid_until_secs(&signed.signature)?;
+    let now_secs = i64::try_from(now_secs).unwrap_or(i64::MAX);
+    if now_secs >= valid_until_secs {
+        Ok(Freshness::Expired)
+    } else if valid_until_secs - now_secs <= MANIFEST_EXPIRING_SOON_WINDOW_SECS {
+        Ok(Freshness::ExpiringSoon)
+    } else {
+        Ok(Freshness::Fresh)
+    }
+}
+
+/// Structural-validate `manifest` via [`validate_signed_manifest`], then
+/// reject it as [`ManifestSchemaError::ManifestExpired`] if `now_secs` is at
+/// or past `signature.valid_until`. Kept as a distinct error from
+/// [`ManifestSchemaError::SignatureMalformed`] so callers can tell "this
+/// manifest was never trustworthy" apart from "this manifest's signature has
+/// simply aged out and needs re-signing."
+pub fn verify_manifest(
+    manifest: &SignedExtensionManifest,
+    now_secs: u64,
+) -> Result<(), ManifestSchemaError> {
+    validate_signed_manifest(manifest)?;
+    let valid_until_secs = manifest_valid_until_secs(&manifest.signature)?;
+    let now_secs_i64 = i64::try_from(now_secs).unwrap_or(i64::MAX);
+    if now_secs_i64 >= valid_until_secs {
+        return Err(ManifestSchemaError::ManifestExpired {
+            valid_until: manifest.signature.valid_until.clone(),
+            now_secs,
+        });
+    }
+    Ok(())
+}
+
 fn validate_signed_manifest_inner(
     manifest: &SignedExtensionManifest,
     trusted_publisher_keys: Option<&BTreeMap<String, String>>,
@@ -322,6 +396,7 @@ fn validate_signed_manifest_inner(
         "signature.publisher_key_id",
     )?;
     ensure_manifest_text(&manifest.signature.signed_at, "signature.signed_at")?;
+    ensure_manifest_text(&manifest.signature.valid_until, "signature.valid_until")?;
     // Provenance text fields were previously unvalidated — enforce same bounds.
     ensure_manifest_text(&manifest.provenance.build_system, "provenance.build_system")?;
     ensure_manifest_text(
@@ -667,6 +742,13 @@ pub enum ManifestSchemaError {
         reason: String,
     },
     EngineManifestRejected(ManifestValidationError),
+    /// `valid_until` has passed as of the `now_secs` given to
+    /// [`verify_manifest`]. Distinct from [`Self::SignatureMalformed`]: the
+    /// signature itself may be perfectly well-formed and still be expired.
+    ManifestExpired {
+        valid_until: String,
+        now_secs: u64,
+    },
 }
 
 impl ManifestSchemaError {
@@ -685,6 +767,7 @@ impl ManifestSchemaError {
             Self::InvalidThresholdConfiguration { .. } => "EMS_THRESHOLD_INVALID",
             Self::EngineManifestProjection { .. } => "EMS_ENGINE_PROJECTION",
             Self::EngineManifestRejected(_) => "EMS_ENGINE_REJECTED",
+            Self::ManifestExpired { .. } => "EMS_MANIFEST_EXPIRED",
         }
     }
 }
@@ -744,6 +827,15 @@ impl fmt::Display for ManifestSchemaError {
             Self::EngineManifestRejected(error) => {
                 write!(f, "EMS_ENGINE_REJECTED: {error}")
             }
+            Self::ManifestExpired {
+                valid_until,
+                now_secs,
+            } => {
+                write!(
+                    f,
+                    "EMS_MANIFEST_EXPIRED: manifest expired at {valid_until}, now_secs={now_secs}"
+                )
+            }
         }
     }
 }
@@ -806,6 +898,7 @@ mod tests {
                     ],
                 }),
                 signed_at: "2026-02-20T00:00:00Z".to_string(),
+                valid_until: "2027-02-20T00:00:00Z".to_string(),
             },
         }
     }
@@ -816,6 +909,57 @@ mod tests {
         assert_eq!(validate_signed_manifest(&manifest), Ok(()));
     }
 
+    fn valid_until_secs(manifest: &SignedExtensionManifest) -> i64 {
+        chrono::DateTime::parse_from_rfc3339(&manifest.signature.valid_until)
+            .expect("fixture valid_until is RFC3339")
+            .timestamp()
+    }
+
+    #[test]
+    fn verify_manifest_accepts_a_manifest_within_its_validity_window() {
+        let manifest = valid_manifest();
+        let now_secs = u64::try_from(valid_until_secs(&manifest) - 3600).unwrap();
+        assert_eq!(verify_manifest(&manifest, now_secs), Ok(()));
+    }
+
+    #[test]
+    fn verify_manifest_rejects_an_expired_manifest_with_a_distinct_error() {
+        let manifest = valid_manifest();
+        let now_secs = u64::try_from(valid_until_secs(&manifest) + 1).unwrap();
+        let error = verify_manifest(&manifest, now_secs).expect_err("expired manifest");
+        assert_eq!(error.code(), "EMS_MANIFEST_EXPIRED");
+        assert!(!matches!(
+            error,
+            ManifestSchemaError::SignatureMalformed { .. }
+        ));
+    }
+
+    #[test]
+    fn manifest_freshness_classifies_the_expiring_soon_boundary() {
+        let manifest = valid_manifest();
+        let valid_until = valid_until_secs(&manifest);
+
+        let just_outside_window =
+            u64::try_from(valid_until - MANIFEST_EXPIRING_SOON_WINDOW_SECS - 1).unwrap();
+        assert_eq!(
+            manifest_freshness(&manifest, just_outside_window),
+            Ok(Freshness::Fresh)
+        );
+
+        let at_window_boundary =
+            u64::try_from(valid_until - MANIFEST_EXPIRING_SOON_WINDOW_SECS).unwrap();
+        assert_eq!(
+            manifest_freshness(&manifest, at_window_boundary),
+            Ok(Freshness::ExpiringSoon)
+        );
+
+        let at_expiry = u64::try_from(valid_until).unwrap();
+        assert_eq!(
+            manifest_freshness(&manifest, at_expiry),
+            Ok(Freshness::Expired)
+        );
+    }
+
     #[test]
     fn admission_validation_rejects_untrusted_trust_chain_ref() {
         // Schema validation accepts the manifest, but admission validation
@@ -90,6 +90,7 @@ mod tests {
                     ],
                 }),
                 signed_at: "2026-02-20T00:00:00Z".to_string(),
+                valid_until: "2027-02-20T00:00:00Z".to_string(),
             },
         }
     }
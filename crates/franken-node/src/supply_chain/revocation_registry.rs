@@ -9,7 +9,7 @@
 //! All string inputs are length-validated to prevent DoS attacks through
 //! oversized input strings that could cause memory exhaustion.
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
 use crate::push_bounded;
 
@@ -427,12 +427,79 @@ impl RevocationRegistry {
     }
 }
 
+/// Evidence recorded when [`propagate_revocation`] auto-marks a dependent
+/// artifact as revoked, rather than only reporting it.
+#[derive(Debug, Clone)]
+pub struct RevocationPropagationMark {
+    pub reason: String,
+    pub timestamp: String,
+    pub trace_id: String,
+}
+
+/// Walk the dependency graph from `revoked_id` and return every artifact
+/// that transitively depends on it, in breadth-first discovery order.
+///
+/// `resolver` returns the direct dependents of a given artifact id (e.g. a
+/// lookup into a package/module dependency graph maintained elsewhere).
+/// `propagate_revocation` does not store or own that graph itself — it only
+/// walks it through `resolver`.
+///
+/// Cycle-safe: each artifact id (including `revoked_id` itself) is visited
+/// at most once, so a dependency cycle cannot cause non-termination or a
+/// duplicate entry in the result. Deterministic: dependents are discovered
+/// breadth-first in the order `resolver` returns them.
+///
+/// When `mark` is `Some`, each newly discovered dependent is also recorded
+/// as revoked in `zone_id` via [`RevocationRegistry::advance_head`], using
+/// the zone's next sequence number and the evidence in `mark`. If marking a
+/// dependent fails (e.g. the zone is at capacity), propagation stops
+/// immediately and the error is returned; dependents already marked remain
+/// revoked, per INV-REV-MONOTONIC.
+pub fn propagate_revocation(
+    registry: &mut RevocationRegistry,
+    zone_id: &str,
+    revoked_id: &str,
+    resolver: impl Fn(&str) -> Vec<String>,
+    mark: Option<&RevocationPropagationMark>,
+) -> Result<Vec<String>, RevocationError> {
+    let mut visited: BTreeSet<String> = BTreeSet::new();
+    visited.insert(revoked_id.to_string());
+
+    let mut queue: VecDeque<String> = VecDeque::new();
+    queue.push_back(revoked_id.to_string());
+
+    let mut dependents = Vec::new();
+    while let Some(current) = queue.pop_front() {
+        for dependent in resolver(&current) {
+            if !visited.insert(dependent.clone()) {
+                continue;
+            }
+            if let Some(mark) = mark {
+                let sequence = registry.current_head(zone_id)?.saturating_add(1);
+                registry.advance_head(RevocationHead {
+                    zone_id: zone_id.to_string(),
+                    sequence,
+                    revoked_artifact: dependent.clone(),
+                    reason: mark.reason.clone(),
+                    timestamp: mark.timestamp.clone(),
+                    trace_id: mark.trace_id.clone(),
+                })?;
+            }
+            dependents.push(dependent.clone());
+            queue.push_back(dependent);
+        }
+    }
+
+    Ok(dependents)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         MAX_LOG_ENTRIES, MAX_REVOKED_PER_ZONE, RevocationAudit, RevocationError, RevocationHead,
-        RevocationRegistry, push_bounded,
+        RevocationPropagationMark, RevocationRegistry, propagate_revocation, push_bounded,
     };
+    use std::collections::BTreeMap;
 
     fn head(zone: &str, seq: u64, artifact: &str) -> RevocationHead {
         RevocationHead {
@@ -1181,6 +1248,138 @@ mod tests {
         assert_eq!(err.code(), "REV_RECOVERY_FAILED");
         assert!(err.to_string().contains("exceeds capacity"));
     }
+
+    // --- propagate_revocation tests ---
+
+    /// `base` <- `mid` (direct dependent) <- `leaf` (transitive dependent).
+    fn two_level_chain() -> BTreeMap<String, Vec<String>> {
+        let mut edges = BTreeMap::new();
+        edges.insert("base".to_string(), vec!["mid".to_string()]);
+        edges.insert("mid".to_string(), vec!["leaf".to_string()]);
+        edges
+    }
+
+    fn resolver_for(edges: BTreeMap<String, Vec<String>>) -> impl Fn(&str) -> Vec<String> {
+        move |id: &str| edges.get(id).cloned().unwrap_or_default()
+    }
+
+    #[test]
+    fn propagate_revocation_flags_direct_and_transitive_dependents() {
+        let mut reg = RevocationRegistry::new();
+        let dependents = propagate_revocation(
+            &mut reg,
+            "zone-a",
+            "base",
+            resolver_for(two_level_chain()),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(dependents, vec!["mid".to_string(), "leaf".to_string()]);
+    }
+
+    #[test]
+    fn propagate_revocation_does_not_mark_without_a_mark_option() {
+        let mut reg = RevocationRegistry::new();
+        reg.init_zone("zone-a").unwrap();
+        propagate_revocation(
+            &mut reg,
+            "zone-a",
+            "base",
+            resolver_for(two_level_chain()),
+            None,
+        )
+        .unwrap();
+
+        assert!(!reg.is_revoked("zone-a", "mid").unwrap());
+        assert!(!reg.is_revoked("zone-a", "leaf").unwrap());
+    }
+
+    #[test]
+    fn propagate_revocation_auto_marks_dependents_when_requested() {
+        let mut reg = RevocationRegistry::new();
+        reg.init_zone("zone-a").unwrap();
+        let mark = RevocationPropagationMark {
+            reason: "base artifact revoked".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            trace_id: "tr-propagate".to_string(),
+        };
+
+        let dependents = propagate_revocation(
+            &mut reg,
+            "zone-a",
+            "base",
+            resolver_for(two_level_chain()),
+            Some(&mark),
+        )
+        .unwrap();
+
+        assert_eq!(dependents, vec!["mid".to_string(), "leaf".to_string()]);
+        assert!(reg.is_revoked("zone-a", "mid").unwrap());
+        assert!(reg.is_revoked("zone-a", "leaf").unwrap());
+        assert_eq!(reg.current_head("zone-a").unwrap(), 2);
+    }
+
+    #[test]
+    fn propagate_revocation_is_cycle_safe_and_deterministic() {
+        let mut edges: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        edges.insert(
+            "base".to_string(),
+            vec!["mid".to_string(), "other".to_string()],
+        );
+        edges.insert("mid".to_string(), vec!["leaf".to_string()]);
+        // Cycle: leaf depends back on base.
+        edges.insert("leaf".to_string(), vec!["base".to_string()]);
+
+        let mut reg = RevocationRegistry::new();
+        let dependents =
+            propagate_revocation(&mut reg, "zone-a", "base", resolver_for(edges), None).unwrap();
+
+        assert_eq!(
+            dependents,
+            vec!["mid".to_string(), "other".to_string(), "leaf".to_string()]
+        );
+    }
+
+    #[test]
+    fn propagate_revocation_with_no_dependents_returns_empty() {
+        let mut reg = RevocationRegistry::new();
+        let dependents = propagate_revocation(
+            &mut reg,
+            "zone-a",
+            "standalone",
+            resolver_for(BTreeMap::new()),
+            None,
+        )
+        .unwrap();
+
+        assert!(dependents.is_empty());
+    }
+
+    #[test]
+    fn propagate_revocation_auto_mark_stops_on_first_failure() {
+        let mut reg = RevocationRegistry::new();
+        // zone-a is never initialized, so current_head() fails with
+        // REV_ZONE_NOT_FOUND on the first dependent, and propagation must
+        // stop rather than silently skipping the mark.
+        let mark = RevocationPropagationMark {
+            reason: "base artifact revoked".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            trace_id: "tr-propagate".to_string(),
+        };
+
+        let err = propagate_revocation(
+            &mut reg,
+            "zone-a",
+            "base",
+            resolver_for(two_level_chain()),
+            Some(&mark),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code(), "REV_ZONE_NOT_FOUND");
+        assert!(reg.is_revoked("zone-a", "mid").is_err());
+    }
 }
 
 #[cfg(test)]
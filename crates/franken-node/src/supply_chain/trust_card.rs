@@ -9,7 +9,7 @@
 mod fuzz_smoke_tests;
 
 use std::{
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     fs::{File, OpenOptions, TryLockError},
     io::Write,
     path::{Path, PathBuf},
@@ -25,10 +25,12 @@ use serde_json::Value;
 use sha2::{Digest, Sha256};
 use tempfile::NamedTempFile;
 
+use super::artifact_signing::sign_bytes;
 use super::certification::{DerivationMetadata, VerifiedEvidenceRef};
 use crate::connector::canonical_serializer::canonical_bytes;
 use crate::push_bounded;
 use crate::security::constant_time;
+use crate::security::crypto::Ed25519Verifier;
 use crate::security::trajectory_gaming::CamouflageHint;
 
 /// Source context for trust card registry snapshot validation.
@@ -48,6 +50,7 @@ const MAX_CARD_VERSIONS: usize = 512;
 const MAX_AUDIT_HISTORY: usize = 256;
 const MAX_TRUST_CARD_CAMOUFLAGE_HINTS: usize = 64;
 const MAX_TRUST_CARD_EVIDENCE_REFS: usize = 4096;
+const MAX_KEY_ROTATION_LOG: usize = 4096;
 /// Maximum number of camouflage hint records persisted on a single TrustCard.
 ///
 /// Sub-task 4 of bd-35m7.1 wires the trajectory-gaming detector into the
@@ -442,6 +445,11 @@ pub const TRUST_CARD_STALE_REFRESH: &str = "TRUST_CARD_STALE_REFRESH";
 pub const TRUST_CARD_FORCE_REFRESH: &str = "TRUST_CARD_FORCE_REFRESH";
 pub const TRUST_CARD_DIFF_COMPUTED: &str = "TRUST_CARD_DIFF_COMPUTED";
 pub const TRUST_CARD_CAMOUFLAGE_SUSPECTED: &str = "TRUST_CARD_CAMOUFLAGE_SUSPECTED";
+pub const TRUST_CARD_ASSURANCE_RECOMPUTED: &str = "TRUST_CARD_ASSURANCE_RECOMPUTED";
+pub const TRUST_CARD_PUBLISHER_KEY_PINNED: &str = "TRUST_CARD_PUBLISHER_KEY_PINNED";
+pub const TRUST_CARD_PUBLISHER_KEY_ROTATED: &str = "TRUST_CARD_PUBLISHER_KEY_ROTATED";
+pub const TRUST_CARD_REMOTE_SYNC_APPLIED: &str = "TRUST_CARD_REMOTE_SYNC_APPLIED";
+pub const TRUST_CARD_REMOTE_SYNC_CONFLICT: &str = "TRUST_CARD_REMOTE_SYNC_CONFLICT";
 
 const DEFAULT_CACHE_TTL_SECS: u64 = crate::config::timeouts::TRUST_CARD_CACHE_TTL_SECS;
 const DEFAULT_REGISTRY_KEY: &[u8] = b"franken-node-trust-card-registry-key-v1";
@@ -645,6 +653,15 @@ pub enum TrustCardError {
     /// Operator remediation: rotate or restore the registry signing key, refresh the card, and re-verify.
     #[error("trust card signature verification failed for extension `{0}`")]
     SignatureInvalid(String),
+    /// Operator remediation: pin the publisher's current signing key via `pin_publisher_key`,
+    /// or record a key rotation if the key changed legitimately, before retrying verification.
+    #[error(
+        "trust card for extension `{extension_id}` was signed by publisher `{publisher_id}` with an unpinned key"
+    )]
+    UnpinnedPublisherKey {
+        publisher_id: String,
+        extension_id: String,
+    },
     /// Operator remediation: discard the stale or tampered card, reload authoritative registry state, and recompute the hash.
     #[error("trust card hash mismatch for extension `{0}`")]
     CardHashMismatch(String),
@@ -687,6 +704,12 @@ pub enum TrustCardError {
     /// Operator remediation: verify parent directory permissions and disk space, then retry the atomic snapshot write.
     #[error("failed writing trust-card registry snapshot {path}: {detail}")]
     SnapshotWrite { path: PathBuf, detail: String },
+    /// Operator remediation: re-run the sync with `--force` to accept the remote version, or
+    /// leave the card untouched if the local change should win.
+    #[error(
+        "extension `{0}` has local changes unknown to the remote snapshot; rerun with force to overwrite"
+    )]
+    LocalModificationConflict(String),
 }
 
 impl TrustCardError {
@@ -703,6 +726,9 @@ impl TrustCardError {
             TrustCardError::SignatureInvalid(_) => {
                 "Rotate or restore the registry signing key, refresh the card, and re-verify."
             }
+            TrustCardError::UnpinnedPublisherKey { .. } => {
+                "Pin the publisher's current signing key, or record a key rotation if the key changed legitimately, before retrying verification."
+            }
             TrustCardError::CardHashMismatch(_) => {
                 "Discard the stale or tampered card, reload authoritative registry state, and recompute the hash."
             }
@@ -745,6 +771,9 @@ impl TrustCardError {
             TrustCardError::SnapshotWrite { .. } => {
                 "Verify parent directory permissions and disk space, then retry the atomic snapshot write."
             }
+            TrustCardError::LocalModificationConflict(_) => {
+                "Rerun the sync with --force to accept the remote version, or leave the card untouched if the local change should win."
+            }
         }
     }
 }
@@ -810,6 +839,30 @@ pub struct PublisherIdentity {
     pub display_name: String,
 }
 
+/// A detached Ed25519 publisher signature attached to a trust card, layered
+/// on top of the registry's HMAC integrity check. Verified against the
+/// publisher's pinned signing keys in
+/// [`TrustCardRegistry::pinned_publisher_keys`] by
+/// [`TrustCardRegistry::verify_publisher_pinning`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublisherSignature {
+    /// Raw Ed25519 verifying-key bytes that produced `signature`.
+    pub signing_key: [u8; 32],
+    /// Hex-encoded detached Ed25519 signature over the card's canonical hash.
+    pub signature: String,
+}
+
+/// One entry in a publisher's key-rotation audit trail, appended by
+/// [`TrustCardRegistry::record_key_rotation`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyRotationRecord {
+    pub publisher_id: String,
+    pub old_key: Option<[u8; 32]>,
+    pub new_key: [u8; 32],
+    pub justification: String,
+    pub recorded_at_secs: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CapabilityDeclaration {
     pub name: String,
@@ -885,6 +938,14 @@ pub struct TrustCard {
     /// camouflage signals.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub camouflage_hints: Vec<CamouflageHintRecord>,
+    /// Detached Ed25519 signature from the publisher's pinned signing key,
+    /// layered on top of the registry's HMAC over `card_hash`. `None` for
+    /// cards whose publisher has not pinned a signing key yet.
+    ///
+    /// `#[serde(default)]` preserves backward compatibility with snapshots
+    /// minted before this field existed.
+    #[serde(default)]
+    pub publisher_signature: Option<PublisherSignature>,
     pub card_hash: String,
     pub registry_signature: String,
 }
@@ -917,12 +978,33 @@ impl std::fmt::Debug for TrustCard {
             .field("audit_history", &self.audit_history)
             .field("derivation_evidence", &self.derivation_evidence)
             .field("camouflage_hints", &self.camouflage_hints)
+            .field("publisher_signature", &self.publisher_signature)
             .field("card_hash", &"[REDACTED]")
             .field("registry_signature", &"[REDACTED]")
             .finish()
     }
 }
 
+impl TrustCard {
+    /// Decay `reputation_score_basis_points` toward `floor_basis_points`
+    /// based on time elapsed since `last_verified_timestamp`, using
+    /// exponential half-life decay: every `half_life_secs` seconds, half of
+    /// the above-floor reputation is lost. A publisher who stops verifying
+    /// no longer keeps a stale high score forever.
+    ///
+    /// Sets `reputation_trend` to [`ReputationTrend::Declining`] when decay
+    /// actually lowers the score. Leaves the card untouched (including
+    /// `reputation_trend`) when no decay applies, e.g. `now_secs` predates
+    /// `last_verified_timestamp` or the score is already at the floor.
+    pub fn decay_reputation(&mut self, now_secs: u64, half_life_secs: u64, floor_basis_points: u16) {
+        let decayed = decayed_reputation_basis_points(self, now_secs, half_life_secs, floor_basis_points);
+        if decayed < self.reputation_score_basis_points {
+            self.reputation_score_basis_points = decayed;
+            self.reputation_trend = ReputationTrend::Declining;
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TrustCardInput {
     pub extension: ExtensionIdentity,
@@ -984,6 +1066,15 @@ impl TrustCardListFilter {
     }
 }
 
+/// A cheap, ids-only snapshot of a [`TrustCardRegistry::list`] query, captured
+/// at a point in time so callers can page through it without later pages
+/// shifting because of concurrent revocations or updates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListingSnapshot {
+    pub extension_ids: Vec<String>,
+    pub captured_at_secs: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TrustCardDiffEntry {
     pub field: String,
@@ -1013,6 +1104,20 @@ struct CachedCard {
     cached_at_secs: u64,
 }
 
+/// Composite signals used to compute a trust card's assurance level, kept
+/// separate from [`TrustCard`] itself so the level can never be set directly
+/// by a mutation or a bad feed -- it is always derived fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssuranceSignals {
+    /// Whether the card's canonical hash and registry signature verified
+    /// successfully against the registry's signing key.
+    pub signature_valid: bool,
+    /// Seconds elapsed since the card's revocation status was last checked
+    /// against the upstream revocation feed. Larger values mean the status
+    /// on record may be stale.
+    pub revocation_checked_seconds_ago: u64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TrustCardSyncReport {
     pub total_cards: usize,
@@ -1022,6 +1127,16 @@ pub struct TrustCardSyncReport {
     pub forced_refreshes: usize,
 }
 
+/// Outcome of reconciling a [`TrustCardRegistry`] against a remote snapshot
+/// via [`TrustCardRegistry::sync_from_remote`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrustCardRemoteSyncReport {
+    pub additions: usize,
+    pub updates: usize,
+    pub revocations: usize,
+    pub unchanged: usize,
+}
+
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct TrustCardRegistrySnapshot {
@@ -1103,6 +1218,9 @@ pub struct TrustCardRegistry {
     snapshot_epoch: u64,
     previous_snapshot_hash: Option<String>,
     last_snapshot_hash: Option<String>,
+    assurance_by_extension: BTreeMap<String, u32>,
+    pinned_publisher_keys: BTreeMap<String, BTreeSet<[u8; 32]>>,
+    key_rotation_log: Vec<KeyRotationRecord>,
 }
 
 impl Default for TrustCardRegistry {
@@ -1134,6 +1252,9 @@ impl TrustCardRegistry {
             snapshot_epoch: 0,
             previous_snapshot_hash: None,
             last_snapshot_hash: None,
+            assurance_by_extension: BTreeMap::new(),
+            pinned_publisher_keys: BTreeMap::new(),
+            key_rotation_log: Vec::new(),
         }
     }
 
@@ -1406,6 +1527,48 @@ impl TrustCardRegistry {
         Ok(registry)
     }
 
+    /// Load a signed registry snapshot from an untrusted remote source, for
+    /// reconciliation via [`TrustCardRegistry::sync_from_remote`].
+    ///
+    /// Unlike [`TrustCardRegistry::load_authoritative_state`], this does not
+    /// replace any in-memory registry state — it only parses and
+    /// cryptographically verifies the snapshot itself, using the same
+    /// eager-validation strategy as `SnapshotSourceContext::UntrustedNetwork`
+    /// (signature verified before the payload is trusted for parsing).
+    ///
+    /// # Parameters
+    /// - `path`: remote snapshot file to read and validate.
+    /// - `registry_key`: HMAC key expected to have signed the remote snapshot.
+    ///
+    /// # Returns
+    /// A signature-verified `TrustCardRegistrySnapshot`.
+    ///
+    /// # Errors
+    /// Returns `TrustCardError` if reading, parsing, or signature/bounds
+    /// validation fails. Error details are sanitized, matching untrusted-source handling.
+    pub fn load_remote_snapshot(
+        path: &Path,
+        registry_key: &[u8],
+    ) -> Result<TrustCardRegistrySnapshot, TrustCardError> {
+        let raw = std::fs::read_to_string(path).map_err(|err| TrustCardError::SnapshotRead {
+            path: path.to_path_buf(),
+            detail: err.to_string(),
+        })?;
+
+        verify_signature_before_parsing(&raw, registry_key)
+            .map_err(sanitize_error_for_untrusted)?;
+
+        let snapshot = serde_json::from_str::<TrustCardRegistrySnapshot>(&raw).map_err(|err| {
+            sanitize_error_for_untrusted(TrustCardError::SnapshotParse {
+                path: path.to_path_buf(),
+                detail: err.to_string(),
+            })
+        })?;
+
+        validate_comprehensive(&snapshot, registry_key).map_err(sanitize_error_for_untrusted)?;
+        Ok(snapshot)
+    }
+
     /// Persist the registry's authoritative snapshot and signed high-water marker atomically.
     ///
     /// # Parameters
@@ -1551,6 +1714,7 @@ impl TrustCardRegistry {
             }],
             derivation_evidence: Some(derivation),
             camouflage_hints: Vec::new(),
+            publisher_signature: None,
             card_hash: String::new(),
             registry_signature: String::new(),
         };
@@ -1717,6 +1881,93 @@ impl TrustCardRegistry {
         Ok(next)
     }
 
+    /// Revoke `extension_id` and cascade the revocation to every extension
+    /// that transitively depends on it via
+    /// [`TrustCard::dependency_trust_summary`].
+    ///
+    /// A foundational publisher going bad shouldn't leave extensions that
+    /// declare it as a dependency sitting at their old reputation — this
+    /// walks the dependency graph outward from `extension_id`, revoking each
+    /// dependent in turn.
+    ///
+    /// # Parameters
+    /// - `extension_id`: root extension to revoke.
+    /// - `reason`: revocation reason recorded on `extension_id` itself.
+    ///   Transitively-affected dependents instead get a derived reason of the
+    ///   form `"revoked: upstream <id> revoked"`, naming the upstream
+    ///   dependency that triggered their revocation.
+    /// - `now_secs`: unix timestamp used for `revoked_at`, audit history, and
+    ///   telemetry on every revocation in the cascade.
+    ///
+    /// # Returns
+    /// The IDs of every extension revoked by this call, with `extension_id`
+    /// first followed by transitively-affected dependents in discovery order.
+    /// A dependency cycle is visited at most once per extension, so a loop in
+    /// the dependency graph cannot revoke the same extension twice or loop
+    /// forever.
+    ///
+    /// # Errors
+    /// Returns `TrustCardError` if `extension_id` is missing or any
+    /// revocation in the cascade fails, e.g. due to a signature mismatch.
+    pub fn revoke_cascade(
+        &mut self,
+        extension_id: &str,
+        reason: &str,
+        now_secs: u64,
+    ) -> Result<Vec<String>, TrustCardError> {
+        let trace_id = "revoke_cascade";
+        let mut revoked = Vec::new();
+        let mut visited: BTreeSet<String> = BTreeSet::new();
+        let mut pending: VecDeque<(String, String)> = VecDeque::new();
+        pending.push_back((extension_id.to_string(), reason.to_string()));
+
+        while let Some((current_id, current_reason)) = pending.pop_front() {
+            if !visited.insert(current_id.clone()) {
+                continue;
+            }
+            self.update(
+                &current_id,
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: Some(RevocationStatus::Revoked {
+                        reason: current_reason,
+                        revoked_at: timestamp_from_secs(now_secs),
+                    }),
+                    active_quarantine: None,
+                    reputation_score_basis_points: None,
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                now_secs,
+                trace_id,
+            )?;
+            revoked.push(current_id.clone());
+
+            for (dependent_id, history) in &self.cards_by_extension {
+                if visited.contains(dependent_id) {
+                    continue;
+                }
+                let Some(card) = history.last() else {
+                    continue;
+                };
+                let depends_on_current = card
+                    .dependency_trust_summary
+                    .iter()
+                    .any(|dep| dep.dependency_id == current_id);
+                if depends_on_current {
+                    pending.push_back((
+                        dependent_id.clone(),
+                        format!("revoked: upstream {current_id} revoked"),
+                    ));
+                }
+            }
+        }
+
+        Ok(revoked)
+    }
+
     /// Mark the latest trust card with suspected trajectory-gaming camouflage.
     ///
     /// # Parameters
@@ -1835,6 +2086,134 @@ impl TrustCardRegistry {
         Ok(next)
     }
 
+    /// Pin a publisher's signing key as known-good, authorizing it to sign
+    /// that publisher's trust cards. Idempotent: pinning an already-pinned
+    /// key is a no-op.
+    ///
+    /// # Parameters
+    /// - `publisher_id`: publisher the key belongs to.
+    /// - `signing_key`: raw Ed25519 verifying-key bytes to pin.
+    /// - `now_secs`: unix timestamp recorded in telemetry.
+    /// - `trace_id`: operator-visible correlation ID recorded in telemetry.
+    pub fn pin_publisher_key(
+        &mut self,
+        publisher_id: &str,
+        signing_key: [u8; 32],
+        now_secs: u64,
+        trace_id: &str,
+    ) {
+        self.pinned_publisher_keys
+            .entry(publisher_id.to_string())
+            .or_default()
+            .insert(signing_key);
+        self.emit(
+            TRUST_CARD_PUBLISHER_KEY_PINNED,
+            None,
+            trace_id,
+            now_secs,
+            &format!("pinned signing key for publisher `{publisher_id}`"),
+        );
+    }
+
+    /// Record a publisher signing-key rotation in the audit log and update
+    /// the pin set: `new_key` becomes pinned, and `old_key` (if given) is
+    /// unpinned so cards signed with the retired key are flagged going
+    /// forward.
+    ///
+    /// # Parameters
+    /// - `publisher_id`: publisher whose key rotated.
+    /// - `old_key`: the previously pinned key being retired, if any.
+    /// - `new_key`: the new key to pin going forward.
+    /// - `justification`: operator-supplied reason for the rotation, recorded for audit.
+    /// - `now_secs`: unix timestamp recorded on the rotation entry.
+    /// - `trace_id`: operator-visible correlation ID recorded in telemetry.
+    pub fn record_key_rotation(
+        &mut self,
+        publisher_id: &str,
+        old_key: Option<[u8; 32]>,
+        new_key: [u8; 32],
+        justification: &str,
+        now_secs: u64,
+        trace_id: &str,
+    ) {
+        let pinned = self
+            .pinned_publisher_keys
+            .entry(publisher_id.to_string())
+            .or_default();
+        if let Some(old) = old_key {
+            pinned.remove(&old);
+        }
+        pinned.insert(new_key);
+
+        push_bounded(
+            &mut self.key_rotation_log,
+            KeyRotationRecord {
+                publisher_id: publisher_id.to_string(),
+                old_key,
+                new_key,
+                justification: justification.to_string(),
+                recorded_at_secs: now_secs,
+            },
+            MAX_KEY_ROTATION_LOG,
+        );
+        self.emit(
+            TRUST_CARD_PUBLISHER_KEY_ROTATED,
+            None,
+            trace_id,
+            now_secs,
+            &format!("rotated signing key for publisher `{publisher_id}`: {justification}"),
+        );
+    }
+
+    /// The publisher key-rotation audit trail, in the order rotations were recorded.
+    #[must_use]
+    pub fn key_rotation_log(&self) -> &[KeyRotationRecord] {
+        &self.key_rotation_log
+    }
+
+    /// The currently pinned signing keys for one publisher, if any have been pinned.
+    #[must_use]
+    pub fn pinned_keys_for_publisher(&self, publisher_id: &str) -> Option<&BTreeSet<[u8; 32]>> {
+        self.pinned_publisher_keys.get(publisher_id)
+    }
+
+    /// Verify a trust card's publisher signature against the publisher's
+    /// pinned signing keys, layered on top of the registry's HMAC integrity
+    /// check performed by [`verify_card_signature`].
+    ///
+    /// # Parameters
+    /// - `card`: trust card whose publisher signature should be checked.
+    ///
+    /// # Returns
+    /// `Ok(())` when the card carries a publisher signature from a pinned key
+    /// and the signature itself verifies.
+    ///
+    /// # Errors
+    /// Returns `TrustCardError::SignatureInvalid` if the card has no publisher
+    /// signature or the signature does not verify, or
+    /// `TrustCardError::UnpinnedPublisherKey` if the signing key is not in the
+    /// publisher's pin set -- an unexpected key is flagged rather than
+    /// silently accepted.
+    pub fn verify_publisher_pinning(&self, card: &TrustCard) -> Result<(), TrustCardError> {
+        let signature = card
+            .publisher_signature
+            .as_ref()
+            .ok_or_else(|| TrustCardError::SignatureInvalid(card.extension.extension_id.clone()))?;
+
+        let is_pinned = self
+            .pinned_publisher_keys
+            .get(&card.publisher.publisher_id)
+            .is_some_and(|keys| keys.contains(&signature.signing_key));
+        if !is_pinned {
+            return Err(TrustCardError::UnpinnedPublisherKey {
+                publisher_id: card.publisher.publisher_id.clone(),
+                extension_id: card.extension.extension_id.clone(),
+            });
+        }
+
+        verify_publisher_signature(card, signature)
+    }
+
     /// Read the latest verified trust card for one extension, using the cache when valid.
     ///
     /// # Parameters
@@ -1975,6 +2354,79 @@ impl TrustCardRegistry {
         Ok(out)
     }
 
+    /// Capture a stable, ordered snapshot of the extension IDs matching a
+    /// filter, for pagination that stays consistent across concurrent
+    /// revocations or updates.
+    ///
+    /// # Parameters
+    /// - `filter`: certification, publisher, and capability selectors.
+    /// - `trace_id`: operator-visible correlation ID recorded in telemetry.
+    /// - `now_secs`: unix timestamp used for telemetry timestamps and the
+    ///   snapshot's capture stamp.
+    ///
+    /// # Returns
+    /// A [`ListingSnapshot`] whose ordered extension ID list can be paged
+    /// with [`TrustCardRegistry::page_from_snapshot`].
+    ///
+    /// # Errors
+    /// Returns `TrustCardError` if any matched card fails signature verification.
+    pub fn begin_listing(
+        &mut self,
+        filter: &TrustCardListFilter,
+        trace_id: &str,
+        now_secs: u64,
+    ) -> Result<ListingSnapshot, TrustCardError> {
+        let matched = self.list(filter, trace_id, now_secs)?;
+        let extension_ids = matched
+            .into_iter()
+            .map(|card| card.extension.extension_id)
+            .collect();
+        Ok(ListingSnapshot {
+            extension_ids,
+            captured_at_secs: now_secs,
+        })
+    }
+
+    /// Page over a snapshot captured by [`TrustCardRegistry::begin_listing`].
+    ///
+    /// Each ID's current card state is looked up fresh, so the returned
+    /// values reflect live data while the set and order of IDs across pages
+    /// stays pinned to the moment the snapshot was taken.
+    ///
+    /// # Parameters
+    /// - `snapshot`: ordered extension ID list captured by `begin_listing`.
+    /// - `page`: one-based page number to read.
+    /// - `per_page`: maximum number of items to return.
+    ///
+    /// # Returns
+    /// The current trust cards for the snapshot's IDs on that page. IDs that
+    /// have since been removed from the registry are skipped.
+    ///
+    /// # Errors
+    /// Returns `TrustCardError::InvalidPagination` when `page` or `per_page`
+    /// is zero, or `TrustCardError` if any matched card fails signature
+    /// verification.
+    pub fn page_from_snapshot(
+        &self,
+        snapshot: &ListingSnapshot,
+        page: usize,
+        per_page: usize,
+    ) -> Result<Vec<TrustCard>, TrustCardError> {
+        let ids = paginate(&snapshot.extension_ids, page, per_page)?;
+        let mut out = Vec::with_capacity(ids.len());
+        for extension_id in &ids {
+            let Some(history) = self.cards_by_extension.get(extension_id) else {
+                continue;
+            };
+            let Some(card) = history.last() else {
+                continue;
+            };
+            verify_card_signature(card, &self.registry_key)?;
+            out.push(card.clone());
+        }
+        Ok(out)
+    }
+
     /// List the latest verified trust cards published by one publisher.
     ///
     /// # Parameters
@@ -2004,6 +2456,104 @@ impl TrustCardRegistry {
         )
     }
 
+    /// Like [`TrustCardRegistry::list`], but reports each card's reputation
+    /// as it would read after decaying toward `floor_basis_points` at
+    /// `half_life_secs`, evaluated as of `as_of_secs`. Stored card state is
+    /// never mutated; only the returned clones carry the decayed values.
+    ///
+    /// # Parameters
+    /// - `filter`: certification, publisher, and capability selectors.
+    /// - `trace_id`: operator-visible correlation ID recorded in telemetry.
+    /// - `now_secs`: unix timestamp used for telemetry timestamps.
+    /// - `as_of_secs`: unix timestamp decay is evaluated against.
+    /// - `half_life_secs`: seconds for above-floor reputation to halve.
+    /// - `floor_basis_points`: reputation floor decay will not cross.
+    ///
+    /// # Returns
+    /// A sorted vector of trust cards with decay applied to the returned copies.
+    ///
+    /// # Errors
+    /// Returns `TrustCardError` if any matched card fails signature verification.
+    pub fn list_decayed(
+        &mut self,
+        filter: &TrustCardListFilter,
+        trace_id: &str,
+        now_secs: u64,
+        as_of_secs: u64,
+        half_life_secs: u64,
+        floor_basis_points: u16,
+    ) -> Result<Vec<TrustCard>, TrustCardError> {
+        let mut cards = self.list(filter, trace_id, now_secs)?;
+        for card in &mut cards {
+            card.decay_reputation(as_of_secs, half_life_secs, floor_basis_points);
+        }
+        Ok(cards)
+    }
+
+    /// Recompute every card's assurance level from fresh composite signals
+    /// and replace the registry's cached levels in place.
+    ///
+    /// The revocation status and certification/reputation fields on each
+    /// card's latest version are read live; the signature is re-verified
+    /// rather than trusted from a stale cache, and the revocation check is
+    /// treated as happening right now (freshness of zero seconds). See
+    /// [`compute_assurance_level`] for the scoring rules.
+    ///
+    /// # Parameters
+    /// - `trace_id`: operator-visible correlation ID recorded in telemetry.
+    /// - `now_secs`: unix timestamp used for telemetry timestamps.
+    ///
+    /// # Returns
+    /// The recomputed assurance level for every extension currently
+    /// registered, keyed by extension ID.
+    ///
+    /// # Errors
+    /// This method does not return errors; a card whose signature fails
+    /// verification is scored at [`MINIMUM_ASSURANCE_LEVEL`] rather than
+    /// rejected, since the point of recomputation is to surface that fact.
+    pub fn recompute_assurance(&mut self, trace_id: &str, now_secs: u64) -> BTreeMap<String, u32> {
+        let mut levels = BTreeMap::new();
+        for (extension_id, history) in &self.cards_by_extension {
+            let Some(card) = history.last() else {
+                continue;
+            };
+            let signals = AssuranceSignals {
+                signature_valid: verify_card_signature(card, &self.registry_key).is_ok(),
+                revocation_checked_seconds_ago: 0,
+            };
+            levels.insert(
+                extension_id.clone(),
+                compute_assurance_level(card, &signals),
+            );
+        }
+        self.assurance_by_extension = levels.clone();
+        self.emit(
+            TRUST_CARD_ASSURANCE_RECOMPUTED,
+            None,
+            trace_id,
+            now_secs,
+            &format!("recomputed assurance for {} card(s)", levels.len()),
+        );
+        levels
+    }
+
+    /// Look up the assurance level computed by the most recent
+    /// [`TrustCardRegistry::recompute_assurance`] call.
+    ///
+    /// # Parameters
+    /// - `extension_id`: extension whose cached assurance level is requested.
+    ///
+    /// # Returns
+    /// `None` if `recompute_assurance` has never run or the extension is not
+    /// registered as of the last run.
+    ///
+    /// # Errors
+    /// This accessor does not return errors.
+    #[must_use]
+    pub fn assurance_level(&self, extension_id: &str) -> Option<u32> {
+        self.assurance_by_extension.get(extension_id).copied()
+    }
+
     /// Refresh trust-card cache entries and report the sync outcome.
     ///
     /// # Parameters
@@ -2137,6 +2687,149 @@ impl TrustCardRegistry {
         Ok(report)
     }
 
+    /// Reconcile this registry against a remote trust-card registry snapshot.
+    ///
+    /// Additions and updates (including revocations, which ride along as an
+    /// ordinary field change on the remote's latest card) are applied
+    /// directly. A card whose local history has diverged from everything the
+    /// remote snapshot knows about is treated as a local modification and is
+    /// left untouched unless `force` is set. The reconciliation is atomic:
+    /// either every extension in `remote` is applied, or none are.
+    ///
+    /// # Parameters
+    /// - `remote`: signed snapshot fetched from the configured remote source.
+    /// - `now_secs`: unix timestamp used for cache freshness and telemetry.
+    /// - `trace_id`: operator-visible correlation ID recorded in telemetry.
+    /// - `force`: overwrite locally-modified cards instead of refusing the sync.
+    ///
+    /// # Returns
+    /// A `TrustCardRemoteSyncReport` summarizing additions, updates,
+    /// revocations, and cards left unchanged because they already matched.
+    ///
+    /// # Errors
+    /// Returns `TrustCardError::InvalidSnapshot` if the remote snapshot or any
+    /// of its cards fail verification (fail-closed against a tampered
+    /// remote), `TrustCardError::LocalModificationConflict` if an extension
+    /// has local changes the remote snapshot does not know about and `force`
+    /// is not set, or `TrustCardError::RevocationIrreversible` if applying
+    /// the remote card would reactivate a revoked extension.
+    pub fn sync_from_remote(
+        &mut self,
+        remote: &TrustCardRegistrySnapshot,
+        now_secs: u64,
+        trace_id: &str,
+        force: bool,
+    ) -> Result<TrustCardRemoteSyncReport, TrustCardError> {
+        verify_snapshot_signature(remote, &self.registry_key)?;
+
+        enum PlannedAction {
+            Add,
+            Apply { revocation: bool },
+        }
+
+        let mut planned: Vec<(String, PlannedAction)> = Vec::new();
+        let mut report = TrustCardRemoteSyncReport::default();
+
+        for (extension_id, remote_history) in &remote.cards_by_extension {
+            validate_snapshot_history(extension_id, remote_history, &self.registry_key)?;
+            let remote_latest = remote_history.last().ok_or_else(|| {
+                TrustCardError::InvalidSnapshot(format!(
+                    "extension bucket `{extension_id}` cannot be empty"
+                ))
+            })?;
+
+            let Some(local_history) = self.cards_by_extension.get(extension_id) else {
+                planned.push((extension_id.clone(), PlannedAction::Add));
+                report.additions = report.additions.saturating_add(1);
+                continue;
+            };
+            let local_latest = local_history
+                .last()
+                .ok_or_else(|| TrustCardError::NotFound(extension_id.clone()))?;
+
+            if constant_time::ct_eq(&local_latest.card_hash, &remote_latest.card_hash) {
+                report.unchanged = report.unchanged.saturating_add(1);
+                continue;
+            }
+
+            let locally_modified = !remote_history
+                .iter()
+                .any(|card| constant_time::ct_eq(&card.card_hash, &local_latest.card_hash));
+            if locally_modified && !force {
+                self.emit(
+                    TRUST_CARD_REMOTE_SYNC_CONFLICT,
+                    Some(extension_id.clone()),
+                    trace_id,
+                    now_secs,
+                    "sync refused to overwrite a locally-modified card without force",
+                );
+                return Err(TrustCardError::LocalModificationConflict(
+                    extension_id.clone(),
+                ));
+            }
+
+            if matches!(
+                local_latest.revocation_status,
+                RevocationStatus::Revoked { .. }
+            ) && matches!(remote_latest.revocation_status, RevocationStatus::Active)
+            {
+                return Err(TrustCardError::RevocationIrreversible);
+            }
+
+            let revocation = matches!(
+                remote_latest.revocation_status,
+                RevocationStatus::Revoked { .. }
+            ) && !matches!(
+                local_latest.revocation_status,
+                RevocationStatus::Revoked { .. }
+            );
+            planned.push((extension_id.clone(), PlannedAction::Apply { revocation }));
+            if revocation {
+                report.revocations = report.revocations.saturating_add(1);
+            } else {
+                report.updates = report.updates.saturating_add(1);
+            }
+        }
+
+        for (extension_id, action) in planned {
+            let remote_history = remote
+                .cards_by_extension
+                .get(&extension_id)
+                .expect("planned extension exists in remote snapshot");
+            let remote_latest = remote_history
+                .last()
+                .expect("validated non-empty above")
+                .clone();
+            self.cards_by_extension
+                .insert(extension_id.clone(), remote_history.clone());
+            self.cache_by_extension.insert(
+                extension_id.clone(),
+                CachedCard {
+                    card: remote_latest,
+                    cached_at_secs: now_secs,
+                },
+            );
+            let detail = match action {
+                PlannedAction::Add => "sync added trust card unknown to local registry",
+                PlannedAction::Apply { revocation: true } => {
+                    "sync applied remote revocation to local card"
+                }
+                PlannedAction::Apply { revocation: false } => {
+                    "sync applied remote update to local card"
+                }
+            };
+            self.emit(
+                TRUST_CARD_REMOTE_SYNC_APPLIED,
+                Some(extension_id),
+                trace_id,
+                now_secs,
+                detail,
+            );
+        }
+
+        Ok(report)
+    }
+
     /// Search trust cards by extension ID, publisher ID, or capability name.
     ///
     /// # Parameters
@@ -2353,6 +3046,22 @@ impl TrustCardRegistry {
         &self.telemetry
     }
 
+    #[must_use]
+    /// Expose the registry's signing key, for verifying a remote snapshot
+    /// before reconciling it with [`TrustCardRegistry::sync_from_remote`].
+    ///
+    /// # Parameters
+    /// This accessor takes no parameters.
+    ///
+    /// # Returns
+    /// The HMAC key bytes this registry signs and verifies cards with.
+    ///
+    /// # Errors
+    /// This accessor does not return errors.
+    pub fn registry_key(&self) -> &[u8] {
+        &self.registry_key
+    }
+
     fn latest_card(&self, extension_id: &str) -> Option<&TrustCard> {
         if extension_id.len() > MAX_EXTENSION_ID_LEN {
             return None;
@@ -2661,6 +3370,75 @@ pub fn paginate<T: Clone>(
     Ok(items[start..end].to_vec())
 }
 
+/// The assurance level assigned to a revoked card, an unverifiable signature,
+/// or any other condition that must fail closed regardless of reputation or
+/// certification. See [`compute_assurance_level`].
+pub const MINIMUM_ASSURANCE_LEVEL: u32 = 0;
+
+/// The assurance level assigned to a card whose every signal is maximally
+/// trustworthy. See [`compute_assurance_level`].
+pub const MAXIMUM_ASSURANCE_LEVEL: u32 = 100;
+
+/// Revocation checks older than this are treated as fully stale and earn no
+/// freshness credit in [`compute_assurance_level`].
+const ASSURANCE_REVOCATION_FRESHNESS_WINDOW_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Compute a trust card's assurance level from composite signals, rather than
+/// trusting a value stored (and therefore spoofable) on the card itself.
+///
+/// The score is the sum of three independently-capped components that
+/// together add up to [`MAXIMUM_ASSURANCE_LEVEL`]:
+/// - certification level, up to 40 points (`Unknown` = 0 ... `Platinum` = 40);
+/// - reputation, up to 40 points, scaled linearly from
+///   `reputation_score_basis_points` (0..=10_000);
+/// - revocation-check freshness, up to 20 points, decaying linearly to 0 over
+///   [`ASSURANCE_REVOCATION_FRESHNESS_WINDOW_SECS`].
+///
+/// A revoked card or an invalid signature overrides every other signal and
+/// collapses the level to [`MINIMUM_ASSURANCE_LEVEL`] -- a bad feed cannot
+/// launder a revoked or forged card into a higher score by claiming good
+/// reputation.
+///
+/// # Parameters
+/// - `card`: trust card whose certification level, reputation, and
+///   revocation status feed the score.
+/// - `signals`: out-of-band signals (signature validity, revocation-check
+///   freshness) that are not safe to read from the card's own fields.
+///
+/// # Returns
+/// A deterministic assurance level in `MINIMUM_ASSURANCE_LEVEL..=MAXIMUM_ASSURANCE_LEVEL`.
+///
+/// # Errors
+/// This scoring function does not return errors.
+pub fn compute_assurance_level(card: &TrustCard, signals: &AssuranceSignals) -> u32 {
+    let is_revoked = matches!(card.revocation_status, RevocationStatus::Revoked { .. });
+    if is_revoked || !signals.signature_valid {
+        return MINIMUM_ASSURANCE_LEVEL;
+    }
+
+    let certification_points: u32 = match card.certification_level {
+        CertificationLevel::Unknown => 0,
+        CertificationLevel::Bronze => 10,
+        CertificationLevel::Silver => 20,
+        CertificationLevel::Gold => 30,
+        CertificationLevel::Platinum => 40,
+    };
+
+    let reputation_points =
+        u32::from(card.reputation_score_basis_points).saturating_mul(40) / 10_000;
+
+    let freshness_ratio = signals
+        .revocation_checked_seconds_ago
+        .min(ASSURANCE_REVOCATION_FRESHNESS_WINDOW_SECS);
+    let freshness_points = 20
+        - u32::try_from(
+            freshness_ratio.saturating_mul(20) / ASSURANCE_REVOCATION_FRESHNESS_WINDOW_SECS,
+        )
+        .unwrap_or(20);
+
+    (certification_points + reputation_points + freshness_points).min(MAXIMUM_ASSURANCE_LEVEL)
+}
+
 /// Render one trust card into the stable human-readable CLI summary format.
 ///
 /// # Parameters
@@ -2809,15 +3587,10 @@ pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String, Tru
     // with the prior canonicalize_value+to_string chain is verified
     // by bd-98xo5.4.3 commit a7015fc9 (proptest) and bd-98xo5.4.4
     // commit 2963516e (golden preservation gate, all 4 trust-card
-    // goldens pass).
+    // goldens pass). `crate::encoding::canonical_json` is the same
+    // encoder, shared with the other canonical-JSON callers in the tree.
     let raw = serde_json::to_value(value)?;
-    let bytes = canonical_bytes(&raw);
-    // canonical_bytes routes strings through serde_json::to_writer which
-    // emits valid UTF-8 (escape-correct per RFC 8259 §7). The from_utf8
-    // call here can only fail if a future regression in canonical_bytes
-    // bypasses the to_writer path; treat that as a Json error.
-    String::from_utf8(bytes)
-        .map_err(|err| TrustCardError::Json(format!("canonical bytes were not valid UTF-8: {err}")))
+    Ok(crate::encoding::canonical_json::canonical_json(&raw))
 }
 
 #[cfg(any(test, feature = "test-support"))]
@@ -3076,6 +3849,53 @@ pub fn sign_card_in_place(card: &mut TrustCard, registry_key: &[u8]) -> Result<(
     Ok(())
 }
 
+/// Attach a detached Ed25519 publisher signature to a trust card, signing
+/// over the card's canonical `card_hash`. Call after [`sign_card_in_place`]
+/// so the publisher signature covers the registry-signed hash.
+///
+/// # Parameters
+/// - `card`: the trust card to attach a publisher signature to; its
+///   `publisher_signature` field is overwritten.
+/// - `signing_key`: the publisher's Ed25519 signing key.
+///
+/// # Errors
+/// Returns `TrustCardError::SignatureInvalid` if the card has not yet been
+/// hashed (empty `card_hash`).
+pub fn sign_card_publisher_signature(
+    card: &mut TrustCard,
+    signing_key: &ed25519_dalek::SigningKey,
+) -> Result<(), TrustCardError> {
+    if card.card_hash.is_empty() {
+        return Err(TrustCardError::SignatureInvalid(
+            card.extension.extension_id.clone(),
+        ));
+    }
+    let signature_bytes = sign_bytes(signing_key, card.card_hash.as_bytes());
+    card.publisher_signature = Some(PublisherSignature {
+        signing_key: signing_key.verifying_key().to_bytes(),
+        signature: hex::encode(signature_bytes),
+    });
+    Ok(())
+}
+
+/// Verify a publisher signature over a trust card's canonical `card_hash`.
+///
+/// # Errors
+/// Returns `TrustCardError::SignatureInvalid` if the signing key or
+/// signature bytes are malformed, or the signature does not verify.
+fn verify_publisher_signature(
+    card: &TrustCard,
+    sig: &PublisherSignature,
+) -> Result<(), TrustCardError> {
+    let verifier = Ed25519Verifier::from_bytes(&sig.signing_key)
+        .map_err(|_| TrustCardError::SignatureInvalid(card.extension.extension_id.clone()))?;
+    let sig_bytes = hex::decode(&sig.signature)
+        .map_err(|_| TrustCardError::SignatureInvalid(card.extension.extension_id.clone()))?;
+    verifier
+        .verify(card.card_hash.as_bytes(), &sig_bytes)
+        .map_err(|_| TrustCardError::SignatureInvalid(card.extension.extension_id.clone()))
+}
+
 fn canonical_snapshot_without_hash_and_signature(
     snapshot: &TrustCardRegistrySnapshot,
 ) -> Result<Vec<u8>, TrustCardError> {
@@ -3360,6 +4180,41 @@ fn timestamp_from_secs(timestamp_secs: u64) -> String {
         .unwrap_or_else(|| "1970-01-01T00:00:00Z".to_string())
 }
 
+/// Inverse of [`timestamp_from_secs`]. Returns `None` for a timestamp that
+/// doesn't parse as RFC3339, so callers can fall back to treating the card as
+/// having no elapsed time rather than guessing an age.
+fn secs_from_timestamp(timestamp: &str) -> Option<u64> {
+    let parsed = chrono::DateTime::parse_from_rfc3339(timestamp).ok()?;
+    u64::try_from(parsed.timestamp()).ok()
+}
+
+/// Compute what `card.reputation_score_basis_points` would decay to `as_of_secs`
+/// without mutating the card. Exponential half-life decay toward
+/// `floor_basis_points`: `floor + above_floor * 0.5 ^ (elapsed / half_life_secs)`.
+///
+/// Returns the card's current score unchanged when `half_life_secs` is zero,
+/// `as_of_secs` doesn't postdate the card's last verification, or the last
+/// verification timestamp doesn't parse.
+fn decayed_reputation_basis_points(
+    card: &TrustCard,
+    as_of_secs: u64,
+    half_life_secs: u64,
+    floor_basis_points: u16,
+) -> u16 {
+    let Some(last_update_secs) = secs_from_timestamp(&card.last_verified_timestamp) else {
+        return card.reputation_score_basis_points;
+    };
+    if half_life_secs == 0 || as_of_secs <= last_update_secs {
+        return card.reputation_score_basis_points;
+    }
+    let floor = floor_basis_points.min(card.reputation_score_basis_points);
+    let above_floor = f64::from(card.reputation_score_basis_points - floor);
+    let elapsed_secs = as_of_secs - last_update_secs;
+    let half_lives = elapsed_secs as f64 / half_life_secs as f64;
+    let decayed_above_floor = above_floor * 0.5f64.powf(half_lives);
+    floor + decayed_above_floor.round() as u16
+}
+
 // bd-98xo5.4.5: production trust_card paths (to_canonical_json,
 // canonical_card_without_hash_and_signature, canonical_snapshot_without_hash_and_signature,
 // canonical_high_water_without_signature) all now route through
@@ -3915,6 +4770,80 @@ mod tests {
         assert!(matches!(err, TrustCardError::CardHashMismatch(_)));
     }
 
+    #[test]
+    fn page_from_snapshot_matches_direct_pagination() -> TestResult {
+        let mut registry = fixture_registry(1_000).map_err(|err| err.to_string())?;
+        let snapshot = registry
+            .begin_listing(&TrustCardListFilter::empty(), "trace", 1_010)
+            .map_err(|err| err.to_string())?;
+        let via_snapshot = registry
+            .page_from_snapshot(&snapshot, 1, 10)
+            .map_err(|err| err.to_string())?;
+        let via_list = registry
+            .list(&TrustCardListFilter::empty(), "trace", 1_010)
+            .map_err(|err| err.to_string())?;
+        assert_eq!(via_snapshot, via_list);
+        Ok(())
+    }
+
+    #[test]
+    fn page_from_snapshot_is_immune_to_revocation_after_capture() {
+        let mut registry = fixture_registry(1_000).expect("fixture registry");
+        let snapshot = registry
+            .begin_listing(&TrustCardListFilter::empty(), "trace", 1_010)
+            .expect("begin listing");
+        assert_eq!(snapshot.extension_ids.len(), 2);
+
+        registry
+            .update(
+                "npm:@acme/auth-guard",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: Some(RevocationStatus::Revoked {
+                        reason: "compromised key".to_string(),
+                        revoked_at: "2026-02-21T00:00:00Z".to_string(),
+                    }),
+                    active_quarantine: None,
+                    reputation_score_basis_points: None,
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_020,
+                "trace-revoke",
+            )
+            .expect("revoke");
+
+        let page_one = registry
+            .page_from_snapshot(&snapshot, 1, 1)
+            .expect("page one");
+        let page_two = registry
+            .page_from_snapshot(&snapshot, 2, 1)
+            .expect("page two");
+        let ids: Vec<&str> = [&page_one, &page_two]
+            .iter()
+            .flat_map(|page| page.iter().map(|card| card.extension.extension_id.as_str()))
+            .collect();
+        assert_eq!(ids, vec!["npm:@acme/auth-guard", "npm:@beta/telemetry-bridge"]);
+        assert!(matches!(
+            page_one[0].revocation_status,
+            RevocationStatus::Revoked { .. }
+        ));
+    }
+
+    #[test]
+    fn page_from_snapshot_rejects_zero_page() {
+        let mut registry = fixture_registry(1_000).expect("fixture registry");
+        let snapshot = registry
+            .begin_listing(&TrustCardListFilter::empty(), "trace", 1_010)
+            .expect("begin listing");
+        let err = registry
+            .page_from_snapshot(&snapshot, 0, 10)
+            .expect_err("must reject zero page");
+        assert!(matches!(err, TrustCardError::InvalidPagination { .. }));
+    }
+
     #[test]
     fn list_filter_by_publisher_and_capability() {
         let mut registry = fixture_registry(1_000).expect("fixture registry");
@@ -3964,6 +4893,87 @@ mod tests {
         assert_eq!(cards[0].extension.extension_id, "npm:@acme/auth-guard");
     }
 
+    #[test]
+    fn decay_reputation_loses_about_three_quarters_after_two_half_lives() {
+        let mut registry = TrustCardRegistry::default();
+        let card = registry
+            .create(sample_input(), 1_000, "trace")
+            .expect("create");
+        assert_eq!(card.reputation_score_basis_points, 900);
+
+        let last_update_secs =
+            secs_from_timestamp(&card.last_verified_timestamp).expect("valid timestamp");
+        let half_life_secs = 3_600;
+        let mut decayed = card;
+        decayed.decay_reputation(last_update_secs + 2 * half_life_secs, half_life_secs, 100);
+
+        // 900 -> floor 100, 800 above floor, ~75% decays over two half-lives,
+        // leaving roughly a quarter of the above-floor reputation (~200bp above floor).
+        assert!(
+            (295..=305).contains(&decayed.reputation_score_basis_points),
+            "expected ~300bp after two half-lives, got {}",
+            decayed.reputation_score_basis_points
+        );
+        assert_eq!(decayed.reputation_trend, ReputationTrend::Declining);
+    }
+
+    #[test]
+    fn decay_reputation_leaves_recently_updated_card_unaffected() {
+        let mut registry = TrustCardRegistry::default();
+        let card = registry
+            .create(sample_input(), 1_000, "trace")
+            .expect("create");
+        let last_update_secs =
+            secs_from_timestamp(&card.last_verified_timestamp).expect("valid timestamp");
+
+        let mut unchanged = card.clone();
+        unchanged.decay_reputation(last_update_secs, 3_600, 100);
+        assert_eq!(
+            unchanged.reputation_score_basis_points,
+            card.reputation_score_basis_points
+        );
+        assert_eq!(unchanged.reputation_trend, card.reputation_trend);
+
+        let mut barely_elapsed = card.clone();
+        barely_elapsed.decay_reputation(last_update_secs + 1, 3_600, 100);
+        assert_eq!(
+            barely_elapsed.reputation_score_basis_points,
+            card.reputation_score_basis_points
+        );
+    }
+
+    #[test]
+    fn list_decayed_applies_decay_without_mutating_stored_state() {
+        let mut registry = TrustCardRegistry::default();
+        let card = registry
+            .create(sample_input(), 1_000, "trace")
+            .expect("create");
+        let last_update_secs =
+            secs_from_timestamp(&card.last_verified_timestamp).expect("valid timestamp");
+
+        let cards = registry
+            .list_decayed(
+                &TrustCardListFilter::empty(),
+                "trace",
+                1_010,
+                last_update_secs + 7_200,
+                3_600,
+                100,
+            )
+            .expect("list_decayed");
+        assert_eq!(cards.len(), 1);
+        assert!(cards[0].reputation_score_basis_points < card.reputation_score_basis_points);
+
+        let stored = registry
+            .read("npm:@acme/plugin", 1_020, "trace")
+            .expect("read")
+            .expect("exists");
+        assert_eq!(
+            stored.reputation_score_basis_points,
+            card.reputation_score_basis_points
+        );
+    }
+
     #[test]
     fn compare_shows_changes() {
         let mut registry = fixture_registry(1_000).expect("fixture registry");
@@ -4395,6 +5405,190 @@ mod tests {
         assert!(codes.contains(&TRUST_CARD_FORCE_REFRESH));
     }
 
+    #[test]
+    fn sync_from_remote_applies_remote_revocations() {
+        let mut local = TrustCardRegistry::new(60, DEFAULT_REGISTRY_KEY);
+        local
+            .create(sample_input(), 1_000, "trace-create")
+            .expect("create");
+        let mut remote = local.clone();
+        remote
+            .update(
+                "npm:@acme/plugin",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: Some(RevocationStatus::Revoked {
+                        reason: "upstream revoke".to_string(),
+                        revoked_at: "2026-01-02T00:00:00Z".to_string(),
+                    }),
+                    active_quarantine: Some(true),
+                    reputation_score_basis_points: None,
+                    reputation_trend: Some(ReputationTrend::Declining),
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_100,
+                "trace-remote-revoke",
+            )
+            .expect("remote revoke");
+        let remote_snapshot = remote.snapshot().expect("remote snapshot");
+
+        let report = local
+            .sync_from_remote(&remote_snapshot, 1_200, "trace-sync", false)
+            .expect("sync should apply a clean remote revocation");
+
+        assert_eq!(
+            report,
+            TrustCardRemoteSyncReport {
+                additions: 0,
+                updates: 0,
+                revocations: 1,
+                unchanged: 0,
+            }
+        );
+        let card = local
+            .read("npm:@acme/plugin", 1_200, "trace-read")
+            .expect("read")
+            .expect("card present");
+        assert!(matches!(
+            card.revocation_status,
+            RevocationStatus::Revoked { .. }
+        ));
+    }
+
+    #[test]
+    fn sync_from_remote_blocks_conflicting_local_change_without_force() {
+        let mut base = TrustCardRegistry::new(60, DEFAULT_REGISTRY_KEY);
+        base.create(sample_input(), 1_000, "trace-create")
+            .expect("create");
+
+        let mut local = base.clone();
+        local
+            .update(
+                "npm:@acme/plugin",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: None,
+                    active_quarantine: None,
+                    reputation_score_basis_points: Some(500),
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_100,
+                "trace-local-edit",
+            )
+            .expect("local edit");
+
+        let mut remote = base;
+        remote
+            .update(
+                "npm:@acme/plugin",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: None,
+                    active_quarantine: None,
+                    reputation_score_basis_points: Some(700),
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_100,
+                "trace-remote-edit",
+            )
+            .expect("remote edit");
+        let remote_snapshot = remote.snapshot().expect("remote snapshot");
+
+        let err = local
+            .sync_from_remote(&remote_snapshot, 1_200, "trace-sync", false)
+            .expect_err("diverged local card must block the sync without force");
+
+        assert!(matches!(
+            err,
+            TrustCardError::LocalModificationConflict(extension_id)
+                if extension_id.eq("npm:@acme/plugin")
+        ));
+        let card = local
+            .read("npm:@acme/plugin", 1_200, "trace-read")
+            .expect("read")
+            .expect("card present");
+        assert_eq!(card.reputation_score_basis_points, 500);
+    }
+
+    #[test]
+    fn sync_from_remote_force_overrides_conflict() {
+        let mut base = TrustCardRegistry::new(60, DEFAULT_REGISTRY_KEY);
+        base.create(sample_input(), 1_000, "trace-create")
+            .expect("create");
+
+        let mut local = base.clone();
+        local
+            .update(
+                "npm:@acme/plugin",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: None,
+                    active_quarantine: None,
+                    reputation_score_basis_points: Some(500),
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_100,
+                "trace-local-edit",
+            )
+            .expect("local edit");
+
+        let mut remote = base;
+        remote
+            .update(
+                "npm:@acme/plugin",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: None,
+                    active_quarantine: None,
+                    reputation_score_basis_points: Some(700),
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_100,
+                "trace-remote-edit",
+            )
+            .expect("remote edit");
+        let remote_snapshot = remote.snapshot().expect("remote snapshot");
+
+        let report = local
+            .sync_from_remote(&remote_snapshot, 1_200, "trace-sync", true)
+            .expect("force must override the local-modification conflict");
+
+        assert_eq!(
+            report,
+            TrustCardRemoteSyncReport {
+                additions: 0,
+                updates: 1,
+                revocations: 0,
+                unchanged: 0,
+            }
+        );
+        let card = local
+            .read("npm:@acme/plugin", 1_200, "trace-read")
+            .expect("read")
+            .expect("card present");
+        assert_eq!(card.reputation_score_basis_points, 700);
+        let codes: Vec<&str> = local
+            .telemetry()
+            .iter()
+            .map(|evt| evt.event_code.as_str())
+            .collect();
+        assert!(codes.contains(&TRUST_CARD_REMOTE_SYNC_APPLIED));
+    }
+
     #[test]
     fn timestamp_from_secs_produces_valid_iso8601() {
         let ts = timestamp_from_secs(1_700_000_000);
@@ -6019,6 +7213,130 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn revoke_cascade_revokes_a_three_level_dependency_chain() {
+        let mut registry = TrustCardRegistry::default();
+
+        let root = TrustCardInput {
+            extension: ExtensionIdentity {
+                extension_id: "npm:@acme/root".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            dependency_trust_summary: vec![],
+            ..sample_input()
+        };
+        registry.create(root, 1_000, "trace").expect("create root");
+
+        let middle = TrustCardInput {
+            extension: ExtensionIdentity {
+                extension_id: "npm:@acme/middle".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            dependency_trust_summary: vec![DependencyTrustStatus {
+                dependency_id: "npm:@acme/root".to_string(),
+                trust_level: "verified".to_string(),
+            }],
+            ..sample_input()
+        };
+        registry.create(middle, 1_001, "trace").expect("create middle");
+
+        let leaf = TrustCardInput {
+            extension: ExtensionIdentity {
+                extension_id: "npm:@acme/leaf".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            dependency_trust_summary: vec![DependencyTrustStatus {
+                dependency_id: "npm:@acme/middle".to_string(),
+                trust_level: "verified".to_string(),
+            }],
+            ..sample_input()
+        };
+        registry.create(leaf, 1_002, "trace").expect("create leaf");
+
+        let mut revoked = registry
+            .revoke_cascade("npm:@acme/root", "compromised publisher key", 1_010)
+            .expect("cascade revoke");
+        revoked.sort();
+        assert_eq!(
+            revoked,
+            vec![
+                "npm:@acme/leaf".to_string(),
+                "npm:@acme/middle".to_string(),
+                "npm:@acme/root".to_string(),
+            ]
+        );
+
+        let filter = TrustCardListFilter::empty();
+        let cards = registry
+            .list(&filter, "trace", 1_020)
+            .expect("list after cascade");
+        for card in &cards {
+            match &card.revocation_status {
+                RevocationStatus::Revoked { reason, .. } => {
+                    if card.extension.extension_id == "npm:@acme/root" {
+                        assert_eq!(reason, "compromised publisher key");
+                    } else {
+                        assert_eq!(
+                            *reason,
+                            format!(
+                                "revoked: upstream {} revoked",
+                                match card.extension.extension_id.as_str() {
+                                    "npm:@acme/middle" => "npm:@acme/root",
+                                    "npm:@acme/leaf" => "npm:@acme/middle",
+                                    other => panic!("unexpected extension {other}"),
+                                }
+                            )
+                        );
+                    }
+                }
+                RevocationStatus::Active => panic!(
+                    "extension {} should be revoked",
+                    card.extension.extension_id
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn revoke_cascade_tolerates_a_dependency_cycle() {
+        let mut registry = TrustCardRegistry::default();
+
+        let a = TrustCardInput {
+            extension: ExtensionIdentity {
+                extension_id: "npm:@acme/a".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            dependency_trust_summary: vec![DependencyTrustStatus {
+                dependency_id: "npm:@acme/b".to_string(),
+                trust_level: "verified".to_string(),
+            }],
+            ..sample_input()
+        };
+        registry.create(a, 1_000, "trace").expect("create a");
+
+        let b = TrustCardInput {
+            extension: ExtensionIdentity {
+                extension_id: "npm:@acme/b".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            dependency_trust_summary: vec![DependencyTrustStatus {
+                dependency_id: "npm:@acme/a".to_string(),
+                trust_level: "verified".to_string(),
+            }],
+            ..sample_input()
+        };
+        registry.create(b, 1_001, "trace").expect("create b");
+
+        let mut revoked = registry
+            .revoke_cascade("npm:@acme/a", "cycle test", 1_010)
+            .expect("cascade revoke should terminate despite the cycle");
+        revoked.sort();
+        assert_eq!(
+            revoked,
+            vec!["npm:@acme/a".to_string(), "npm:@acme/b".to_string()]
+        );
+    }
+
     /// MR2: Trust-card mutation sequence commutativity for independent fields
     ///
     /// Property: mutate(field_A) → mutate(field_B) == mutate(field_B) → mutate(field_A)
@@ -6252,6 +7570,7 @@ mod tests {
             ],
             derivation_evidence: Some(derivation_evidence),
             camouflage_hints: Vec::new(),
+            publisher_signature: None,
             card_hash: String::new(),
             registry_signature: String::new(),
         };
@@ -7119,4 +8438,207 @@ mod tests {
             );
         }
     }
+
+    fn fresh_signals() -> AssuranceSignals {
+        AssuranceSignals {
+            signature_valid: true,
+            revocation_checked_seconds_ago: 0,
+        }
+    }
+
+    #[test]
+    fn compute_assurance_level_floors_revoked_cards_to_minimum() {
+        let mut registry = TrustCardRegistry::default();
+        registry
+            .create(sample_input(), 1_000, "trace")
+            .expect("create");
+        let revoked = registry
+            .update(
+                "npm:@acme/plugin",
+                TrustCardMutation {
+                    certification_level: None,
+                    revocation_status: Some(RevocationStatus::Revoked {
+                        reason: "malware reported".to_string(),
+                        revoked_at: "2026-01-02T00:00:00Z".to_string(),
+                    }),
+                    active_quarantine: None,
+                    reputation_score_basis_points: None,
+                    reputation_trend: None,
+                    user_facing_risk_assessment: None,
+                    last_verified_timestamp: None,
+                    evidence_refs: None,
+                },
+                1_001,
+                "trace",
+            )
+            .expect("revoke");
+
+        assert_eq!(
+            compute_assurance_level(&revoked, &fresh_signals()),
+            MINIMUM_ASSURANCE_LEVEL
+        );
+    }
+
+    #[test]
+    fn compute_assurance_level_floors_invalid_signature_to_minimum() {
+        let mut registry = TrustCardRegistry::default();
+        let card = registry
+            .create(sample_input(), 1_000, "trace")
+            .expect("create");
+        let forged_signals = AssuranceSignals {
+            signature_valid: false,
+            revocation_checked_seconds_ago: 0,
+        };
+        assert_eq!(
+            compute_assurance_level(&card, &forged_signals),
+            MINIMUM_ASSURANCE_LEVEL
+        );
+    }
+
+    #[test]
+    fn compute_assurance_level_rises_monotonically_with_reputation() {
+        let mut registry = TrustCardRegistry::default();
+        let mut input = sample_input();
+        input.reputation_score_basis_points = 100;
+        registry.create(input, 1_000, "trace").expect("create");
+
+        let mut previous = compute_assurance_level(
+            &registry
+                .read("npm:@acme/plugin", 1_000, "trace")
+                .unwrap()
+                .unwrap(),
+            &fresh_signals(),
+        );
+        for reputation in [2_000, 4_000, 6_000, 8_000, 10_000] {
+            let card = registry
+                .update(
+                    "npm:@acme/plugin",
+                    TrustCardMutation {
+                        certification_level: None,
+                        revocation_status: None,
+                        active_quarantine: None,
+                        reputation_score_basis_points: Some(reputation),
+                        reputation_trend: None,
+                        user_facing_risk_assessment: None,
+                        last_verified_timestamp: None,
+                        evidence_refs: None,
+                    },
+                    1_001,
+                    "trace",
+                )
+                .expect("update reputation");
+            let level = compute_assurance_level(&card, &fresh_signals());
+            assert!(
+                level >= previous,
+                "assurance level regressed from {previous} to {level} as reputation rose to {reputation}"
+            );
+            previous = level;
+        }
+    }
+
+    #[test]
+    fn recompute_assurance_refreshes_every_card_and_is_queryable() {
+        let mut registry = TrustCardRegistry::default();
+        registry
+            .create(sample_input(), 1_000, "trace")
+            .expect("create");
+
+        assert_eq!(registry.assurance_level("npm:@acme/plugin"), None);
+
+        let levels = registry.recompute_assurance("trace", 1_001);
+        assert_eq!(levels.len(), 1);
+        let expected = *levels.get("npm:@acme/plugin").expect("scored");
+        assert_eq!(registry.assurance_level("npm:@acme/plugin"), Some(expected));
+        assert!(expected > MINIMUM_ASSURANCE_LEVEL);
+    }
+
+    fn publisher_signing_key(seed: u8) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    fn card_signed_by_a_pinned_key_verifies() {
+        let mut registry = TrustCardRegistry::default();
+        let mut card = registry
+            .create(sample_input(), 1_000, "trace-pin")
+            .expect("create");
+        let signing_key = publisher_signing_key(7);
+        sign_card_publisher_signature(&mut card, &signing_key).expect("sign with publisher key");
+        registry.pin_publisher_key(
+            &card.publisher.publisher_id,
+            signing_key.verifying_key().to_bytes(),
+            1_000,
+            "trace-pin",
+        );
+
+        registry
+            .verify_publisher_pinning(&card)
+            .expect("card signed by a pinned key should verify");
+    }
+
+    #[test]
+    fn card_signed_by_an_unpinned_key_is_flagged() {
+        let mut registry = TrustCardRegistry::default();
+        let mut card = registry
+            .create(sample_input(), 1_000, "trace-unpinned")
+            .expect("create");
+        let unpinned_key = publisher_signing_key(9);
+        sign_card_publisher_signature(&mut card, &unpinned_key).expect("sign with publisher key");
+
+        // A different key is pinned for this publisher, so `unpinned_key` is
+        // not in the pin set.
+        registry.pin_publisher_key(
+            &card.publisher.publisher_id,
+            publisher_signing_key(1).verifying_key().to_bytes(),
+            1_000,
+            "trace-unpinned",
+        );
+
+        let err = registry
+            .verify_publisher_pinning(&card)
+            .expect_err("card signed by an unpinned key should be flagged");
+        assert!(matches!(err, TrustCardError::UnpinnedPublisherKey { .. }));
+    }
+
+    #[test]
+    fn recorded_key_rotation_updates_the_pin_set() {
+        let mut registry = TrustCardRegistry::default();
+        let old_key = publisher_signing_key(1);
+        let new_key = publisher_signing_key(2);
+        let publisher_id = "acme-corp";
+
+        registry.pin_publisher_key(
+            publisher_id,
+            old_key.verifying_key().to_bytes(),
+            1_000,
+            "trace-rotate",
+        );
+        assert!(
+            registry
+                .pinned_keys_for_publisher(publisher_id)
+                .expect("pinned")
+                .contains(&old_key.verifying_key().to_bytes())
+        );
+
+        registry.record_key_rotation(
+            publisher_id,
+            Some(old_key.verifying_key().to_bytes()),
+            new_key.verifying_key().to_bytes(),
+            "scheduled quarterly rotation",
+            1_001,
+            "trace-rotate",
+        );
+
+        let pinned = registry
+            .pinned_keys_for_publisher(publisher_id)
+            .expect("pinned after rotation");
+        assert!(!pinned.contains(&old_key.verifying_key().to_bytes()));
+        assert!(pinned.contains(&new_key.verifying_key().to_bytes()));
+        assert_eq!(registry.key_rotation_log().len(), 1);
+        assert_eq!(registry.key_rotation_log()[0].publisher_id, publisher_id);
+        assert_eq!(
+            registry.key_rotation_log()[0].justification,
+            "scheduled quarterly rotation"
+        );
+    }
 }
@@ -596,6 +596,7 @@ pub fn trust_cards() -> BoxedStrategy<TrustCard> {
                 audit_history,
                 derivation_evidence,
                 camouflage_hints: Vec::new(),
+                publisher_signature: None,
                 card_hash,
                 registry_signature,
             },
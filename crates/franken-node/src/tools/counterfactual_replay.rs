@@ -1088,30 +1088,7 @@ where
             message: format!("failed to serialize counterfactual output: {err}"),
         }
     })?;
-    let canonical = canonicalize_json(&value);
-    serde_json::to_string(&canonical).map_err(|err| {
-        CounterfactualReplayError::InvalidPolicyOverride {
-            message: format!("failed to encode counterfactual output as json: {err}"),
-        }
-    })
-}
-
-fn canonicalize_json(value: &Value) -> Value {
-    match value {
-        Value::Object(map) => {
-            let mut keys: Vec<&str> = map.keys().map(String::as_str).collect();
-            keys.sort_unstable();
-            let mut out = serde_json::Map::with_capacity(map.len());
-            for key in keys {
-                if let Some(value) = map.get(key) {
-                    out.insert(key.to_string(), canonicalize_json(value));
-                }
-            }
-            Value::Object(out)
-        }
-        Value::Array(items) => Value::Array(items.iter().map(canonicalize_json).collect()),
-        _ => value.clone(),
-    }
+    Ok(crate::encoding::canonical_json::canonical_json(&value))
 }
 
 pub fn summarize_output(output: &CounterfactualSimulationOutput) -> (usize, usize, i64) {
@@ -388,6 +388,7 @@ fn l1_lockstep_verdict_block() -> Value {
                 runtime_name: id.to_string(),
                 version: "golden".to_string(),
                 is_reference,
+                engine_family: id.to_string(),
             })
             .expect("register runtime");
     }
@@ -79,6 +79,7 @@ fn l1_lockstep_verdict_block() -> serde_json::Value {
                 runtime_name: id.to_string(),
                 version: "fixture".to_string(),
                 is_reference,
+                engine_family: id.to_string(),
             })
             .expect("register runtime");
     }
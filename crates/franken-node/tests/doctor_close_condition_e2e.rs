@@ -1061,6 +1061,7 @@ fn l1_lockstep_verdict_block() -> Value {
             runtime_name: "bun".to_string(),
             version: "1.0-fixture".to_string(),
             is_reference: true,
+            engine_family: "bun".to_string(),
         })
         .expect("register bun leg");
     oracle
@@ -1069,6 +1070,7 @@ fn l1_lockstep_verdict_block() -> Value {
             runtime_name: "franken-engine-native".to_string(),
             version: "0.1-fixture".to_string(),
             is_reference: false,
+            engine_family: "franken-engine-native".to_string(),
         })
         .expect("register franken leg");
     let mut outputs = std::collections::BTreeMap::new();
@@ -1519,6 +1521,7 @@ fn doctor_close_condition_fails_l1_when_lockstep_report_diverged() {
                 runtime_name: id.to_string(),
                 version: "test".to_string(),
                 is_reference,
+                engine_family: id.to_string(),
             })
             .expect("register runtime");
     }
@@ -26,6 +26,7 @@ fn oracle_runtime(id: &str) -> RuntimeEntry {
         runtime_name: id.to_string(),
         version: "1.0.0".to_string(),
         is_reference: false,
+        engine_family: id.to_string(),
     }
 }
 
@@ -428,6 +429,7 @@ fn k9_entry(id: &str, name: &str, version: &str, is_ref: bool) -> RuntimeEntry {
         runtime_name: name.to_string(),
         version: version.to_string(),
         is_reference: is_ref,
+        engine_family: name.to_string(),
     }
 }
 
@@ -15,6 +15,7 @@ fn make_label(id: &str, severity: u32) -> TaintLabel {
         id: id.to_string(),
         description: format!("{} label", id),
         severity,
+        expires_at_ms: None,
     }
 }
 
@@ -37,6 +38,8 @@ fn make_edge(edge_id: &str, source: &str, sink: &str, timestamp_ms: u64) -> Flow
         taint_set: TaintSet::new(),
         timestamp_ms,
         quarantined: false,
+        source_zone: None,
+        sink_zone: None,
     }
 }
 
@@ -501,6 +504,8 @@ fn scenario_scan_graph_finds_all_violations() {
             taint_set: ts.clone(),
             timestamp_ms: i as u64,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
     }
@@ -515,6 +520,8 @@ fn scenario_scan_graph_finds_all_violations() {
             taint_set: TaintSet::new(),
             timestamp_ms: (10 + i) as u64,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
     }
@@ -578,6 +585,8 @@ fn scenario_covert_channel_rapid_flow() {
             taint_set: TaintSet::new(),
             timestamp_ms: i as u64 * 10,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
     }
@@ -604,6 +613,8 @@ fn scenario_no_covert_channel_below_threshold() {
             taint_set: TaintSet::new(),
             timestamp_ms: i as u64,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
     }
@@ -663,6 +674,8 @@ fn scenario_snapshot_captures_full_state() {
             taint_set: TaintSet::new(),
             timestamp_ms: i as u64,
             quarantined: false,
+            source_zone: None,
+            sink_zone: None,
         };
         graph.append_edge(edge).unwrap();
     }